@@ -0,0 +1,512 @@
+//! Layer-1 chain follower
+//!
+//! Follows layer-1 blocks through a `ChainClient`, applies actions of the rollup cell
+//! to the store and checks the local global state against the rollup cell's data.
+//! The store is committed after each layer-1 block with a `SyncedBlock` record,
+//! the records are used to find the fork point and roll back the store on a reorg.
+//!
+//! An invalid block is recorded as an `UnverifiedBlock` instead of being applied,
+//! the store diverges from layer 1 until the block is reverted. Blocks submitted
+//! after it are recorded as unverified blocks too, while registrations and deposits
+//! are skipped since the main contract drops them on the revert.
+
+use crate::{
+    error::Error,
+    mempool::Mempool,
+    state,
+    store::Store,
+    types::{L1Block, RollupCell, SyncedBlock, UnverifiedBlock},
+};
+use godwoken_executor::executor::Executor;
+use godwoken_types::{
+    bytes::Bytes,
+    core::{Index, RollupID, CHALLENGE_REWARD_RATE, CKB_TOKEN_ID},
+    packed::*,
+    prelude::*,
+    signing::tx_hash,
+};
+use godwoken_utils::{hash::blake2b_256, mmr::merkle_root};
+use log::warn;
+use std::sync::Arc;
+
+/// Layer-1 RPC
+pub trait ChainClient {
+    /// number of the tip block
+    fn tip_number(&self) -> Result<u64, Error>;
+    fn get_block(&self, number: u64) -> Result<Option<L1Block>, Error>;
+    fn get_transaction(&self, tx_hash: &[u8; 32]) -> Result<Option<Transaction>, Error>;
+    /// number of the block which commits the tx, `None` if the tx isn't committed
+    fn get_transaction_block_number(&self, tx_hash: &[u8; 32]) -> Result<Option<u64>, Error>;
+    /// send a tx to the layer-1 pool, returns the tx hash
+    fn send_transaction(&self, tx: &Transaction) -> Result<[u8; 32], Error>;
+}
+
+impl<C: ChainClient + ?Sized> ChainClient for Arc<C> {
+    fn tip_number(&self) -> Result<u64, Error> {
+        self.as_ref().tip_number()
+    }
+
+    fn get_block(&self, number: u64) -> Result<Option<L1Block>, Error> {
+        self.as_ref().get_block(number)
+    }
+
+    fn get_transaction(&self, tx_hash: &[u8; 32]) -> Result<Option<Transaction>, Error> {
+        self.as_ref().get_transaction(tx_hash)
+    }
+
+    fn get_transaction_block_number(&self, tx_hash: &[u8; 32]) -> Result<Option<u64>, Error> {
+        self.as_ref().get_transaction_block_number(tx_hash)
+    }
+
+    fn send_transaction(&self, tx: &Transaction) -> Result<[u8; 32], Error> {
+        self.as_ref().send_transaction(tx)
+    }
+}
+
+/// hash of a layer-1 tx
+pub fn transaction_hash(tx: &Transaction) -> [u8; 32] {
+    blake2b_256(tx.raw().as_slice())
+}
+
+/// hash of a layer-1 script, the main contract's type hash is the `RollupID`
+pub fn script_hash(script: &Script) -> [u8; 32] {
+    blake2b_256(script.as_slice())
+}
+
+/// decode the action in the witness of the rollup cell's output
+pub fn load_action(tx: &Transaction, output_index: usize) -> Result<Action, Error> {
+    let witness: Bytes = tx
+        .witnesses()
+        .get(output_index)
+        .ok_or(Error::InvalidWitness)?
+        .unpack();
+    let witness_args = WitnessArgs::from_slice(&witness).map_err(|_| Error::InvalidWitness)?;
+    let action: Bytes = witness_args
+        .output_type()
+        .to_opt()
+        .ok_or(Error::InvalidWitness)?
+        .unpack();
+    Action::from_slice(&action).map_err(|_| Error::InvalidWitness)
+}
+
+/// the global state in the rollup cell's output data
+pub fn load_global_state(tx: &Transaction, output_index: usize) -> GlobalState {
+    let data: Bytes = tx
+        .raw()
+        .outputs_data()
+        .get(output_index)
+        .map(|data| data.unpack())
+        .unwrap_or_default();
+    GlobalState::new_unchecked(data)
+}
+
+pub struct ChainFollower<C> {
+    client: C,
+    rollup_id: RollupID,
+    executor: Executor,
+    start_number: u64,
+}
+
+impl<C: ChainClient> ChainFollower<C> {
+    /// `rollup_id` is the main contract's type hash,
+    /// blocks are synced from `start_number` which should be before the deployment
+    pub fn new(client: C, rollup_id: RollupID, start_number: u64) -> Self {
+        ChainFollower {
+            client,
+            rollup_id,
+            executor: Executor::new(rollup_id),
+            start_number,
+        }
+    }
+
+    pub fn executor(mut self, executor: Executor) -> Self {
+        self.executor = executor;
+        self
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// the rollup cell after the last synced block
+    pub fn rollup_cell(&self, store: &Store) -> Result<Option<RollupCell>, Error> {
+        Ok(store
+            .get_last_synced_block()?
+            .and_then(|synced_block| synced_block.rollup_cell))
+    }
+
+    /// sync to the layer-1 tip, returns the number of synced blocks,
+    /// the store is rolled back first if synced blocks are orphaned
+    pub fn sync(&self, store: &mut Store, mempool: &mut Mempool) -> Result<usize, Error> {
+        if let Some(number) = self.find_fork(store)? {
+            self.rollback(store, mempool, number)?;
+        }
+        let tip_number = self.client.tip_number()?;
+        let mut count = 0;
+        loop {
+            let last_synced_block = store.get_last_synced_block()?;
+            let number = match last_synced_block {
+                Some(ref synced_block) => synced_block.number + 1,
+                None => self.start_number,
+            };
+            if number > tip_number {
+                break;
+            }
+            let block = match self.client.get_block(number)? {
+                Some(block) => block,
+                None => break,
+            };
+            // layer 1 is reorganized during the sync
+            if let Some(synced_block) = last_synced_block {
+                if block.parent_hash != synced_block.hash {
+                    let number = self.find_fork(store)?.ok_or_else(|| {
+                        Error::Chain(format!("unexpected parent of block {}", block.number))
+                    })?;
+                    self.rollback(store, mempool, number)?;
+                    continue;
+                }
+            }
+            self.process_block(store, &block)?;
+            count += 1;
+        }
+        if count > 0 {
+            mempool.update(store)?;
+        }
+        Ok(count)
+    }
+
+    /// the first synced block which isn't on layer 1, returns `None` if there is no reorg
+    pub fn find_fork(&self, store: &Store) -> Result<Option<u64>, Error> {
+        let mut fork_number = None;
+        let mut synced_block = store.get_last_synced_block()?;
+        while let Some(current) = synced_block {
+            if let Some(block) = self.client.get_block(current.number)? {
+                if block.hash == current.hash {
+                    break;
+                }
+            }
+            fork_number = Some(current.number);
+            synced_block = if current.number > self.start_number {
+                store.get_synced_block(current.number - 1)?
+            } else {
+                None
+            };
+        }
+        Ok(fork_number)
+    }
+
+    /// Roll back the store to the state before layer-1 block `number`,
+    /// txs of the orphaned layer-2 blocks are pushed back to the mempool.
+    /// Txs with an empty witness are pushed back with the BLS signatures kept by the generator,
+    /// they are dropped if the block isn't generated by this aggregator.
+    /// Returns the number of txs pushed back.
+    pub fn rollback(
+        &self,
+        store: &mut Store,
+        mempool: &mut Mempool,
+        number: u64,
+    ) -> Result<usize, Error> {
+        // the rollup isn't deployed before the first synced block
+        let prev_synced_block = if number > self.start_number {
+            store.get_synced_block(number - 1)?
+        } else {
+            None
+        };
+        let global_state = match prev_synced_block {
+            Some(synced_block) => synced_block.global_state,
+            None => GlobalState::default(),
+        };
+        let block_count: u64 = global_state.block_count().unpack();
+        let mut orphaned_txs = Vec::new();
+        for block_number in block_count..store.block_count() {
+            if let Some(txs) = store.get_block_txs(block_number)? {
+                orphaned_txs.extend(txs);
+            }
+        }
+        store.reset(&global_state);
+        store.commit()?;
+        // records are removed after the commit, the rollback is redone if it's interrupted
+        store.remove_unverified_blocks_since(number)?;
+        store.remove_synced_blocks(number)?;
+        mempool.update(store)?;
+        let mut count = 0;
+        let mut dropped_bls_txs = 0;
+        for tx in orphaned_txs {
+            // txs may be invalid on the new fork, e.g. the sender's registration is orphaned
+            let result = if tx.witness().raw_data().is_empty() {
+                match store.get_bls_signature(&tx_hash(&tx.as_reader()))? {
+                    Some(signature) => mempool.push_bls_tx(store, tx, signature),
+                    None => {
+                        dropped_bls_txs += 1;
+                        continue;
+                    }
+                }
+            } else {
+                mempool.push_tx(store, tx)
+            };
+            if result.is_ok() {
+                count += 1;
+            }
+        }
+        if dropped_bls_txs > 0 {
+            warn!(
+                "{} orphaned txs are dropped without their BLS signatures",
+                dropped_bls_txs
+            );
+        }
+        Ok(count)
+    }
+
+    /// Apply the next layer-1 block, changes are discarded on error.
+    pub fn process_block(&self, store: &mut Store, block: &L1Block) -> Result<(), Error> {
+        let rollup_cell = self.rollup_cell(store)?;
+        match self.apply_block(store, block, rollup_cell) {
+            Ok(rollup_cell) => {
+                let synced_block = SyncedBlock {
+                    number: block.number,
+                    hash: block.hash,
+                    rollup_cell,
+                    global_state: store.get_global_state()?,
+                };
+                store.insert_synced_block(&synced_block)?;
+                store.commit()
+            }
+            Err(err) => {
+                store.discard()?;
+                Err(err)
+            }
+        }
+    }
+
+    /// returns the rollup cell after the block
+    fn apply_block(
+        &self,
+        store: &mut Store,
+        block: &L1Block,
+        mut rollup_cell: Option<RollupCell>,
+    ) -> Result<Option<RollupCell>, Error> {
+        for tx in &block.transactions {
+            if let Some(new_rollup_cell) =
+                self.apply_transaction(store, tx, block.number, rollup_cell.as_ref())?
+            {
+                rollup_cell = Some(new_rollup_cell);
+            }
+        }
+        Ok(rollup_cell)
+    }
+
+    /// returns the new rollup cell if the tx updates the rollup,
+    /// `l1_number` is the number of the layer-1 block which contains the tx
+    fn apply_transaction(
+        &self,
+        store: &mut Store,
+        tx: &Transaction,
+        l1_number: u64,
+        rollup_cell: Option<&RollupCell>,
+    ) -> Result<Option<RollupCell>, Error> {
+        let raw = tx.raw();
+        let output_index = match raw.outputs().into_iter().position(|output| {
+            output
+                .type_()
+                .to_opt()
+                .map(|type_script| script_hash(&type_script) == self.rollup_id)
+                .unwrap_or(false)
+        }) {
+            Some(output_index) => output_index,
+            None => return Ok(None),
+        };
+        // the main contract is deployed with the initial global state if there is no rollup cell
+        if let Some(rollup_cell) = rollup_cell {
+            let consumed = raw.inputs().into_iter().any(|input| {
+                input.previous_output().as_slice() == rollup_cell.out_point.as_slice()
+            });
+            if !consumed {
+                return Ok(None);
+            }
+            self.apply_action(store, tx, output_index, l1_number, rollup_cell)?;
+        }
+        let global_state = store.get_global_state()?;
+        let l1_global_state = load_global_state(tx, output_index);
+        if !store.has_unverified_blocks() && global_state.as_slice() != l1_global_state.as_slice() {
+            return Err(Error::GlobalStateMismatch(global_state, l1_global_state));
+        }
+        let out_point = OutPoint::new_builder()
+            .tx_hash(transaction_hash(tx).pack())
+            .index(output_index.try_pack()?)
+            .build();
+        let output = raw.outputs().get(output_index).expect("rollup output");
+        Ok(Some(RollupCell { out_point, output }))
+    }
+
+    fn apply_action(
+        &self,
+        store: &mut Store,
+        tx: &Transaction,
+        output_index: usize,
+        l1_number: u64,
+        rollup_cell: &RollupCell,
+    ) -> Result<(), Error> {
+        let action = load_action(tx, output_index)?;
+        let diverged = store.has_unverified_blocks();
+        match action.to_enum() {
+            ActionUnion::Register(_) | ActionUnion::Deposit(_) if diverged => {}
+            ActionUnion::Register(register) => {
+                let account = register.account();
+                let index: Index = account.index().unpack();
+                let deposit = deposit_capacity(tx, output_index, rollup_cell);
+                store.update_account(account)?;
+                store.update_balance(index, CKB_TOKEN_ID, u128::from(deposit))?;
+            }
+            ActionUnion::Deposit(deposit) => {
+                let index: Index = deposit.account().index().unpack();
+                let balance = store.get_balance(index, &CKB_TOKEN_ID)?;
+                let deposit = deposit_capacity(tx, output_index, rollup_cell);
+                store.update_balance(
+                    index,
+                    CKB_TOKEN_ID,
+                    balance.saturating_add(u128::from(deposit)),
+                )?;
+            }
+            ActionUnion::SubmitBlock(submit_block) => {
+                self.apply_submit_block(store, l1_number, submit_block)?;
+            }
+            ActionUnion::RevertBlock(revert_block) => {
+                self.apply_revert_block(store, tx, output_index, l1_number, revert_block)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// push the block, or record it as an unverified block if it's invalid
+    fn apply_submit_block(
+        &self,
+        store: &mut Store,
+        l1_number: u64,
+        submit_block: SubmitBlock,
+    ) -> Result<(), Error> {
+        let block = submit_block.block();
+        let txs: Vec<Tx> = submit_block.txs().into_iter().collect();
+        if !store.has_unverified_blocks() {
+            let prev_global_state = store.get_global_state()?;
+            if self.verify_block(store, &block, &txs)? {
+                return store.push_block(block, txs);
+            }
+            store.reset(&prev_global_state);
+        }
+        store.insert_unverified_block(&UnverifiedBlock {
+            l1_number,
+            block,
+            txs,
+        })
+    }
+
+    /// apply txs of the block, returns false if the block is invalid
+    fn verify_block(&self, store: &mut Store, block: &AgBlock, txs: &[Tx]) -> Result<bool, Error> {
+        let ag_index: Index = block.ag_index().unpack();
+        let aggregate_sig: [u8; 96] = block.aggregate_sig().unpack();
+        let run_results =
+            match state::apply_txs(&self.executor, store, txs, ag_index, &aggregate_sig) {
+                Ok(run_results) => run_results,
+                Err(Error::InvalidTx(..)) | Err(Error::Executor(_)) => return Ok(false),
+                Err(err) => return Err(err),
+            };
+        let logs_root = merkle_root(run_results.iter().map(|r| r.logs_hash()).collect());
+        let account_root: [u8; 32] = block.account_root().unpack();
+        let block_logs_root: [u8; 32] = block.logs_root().unpack();
+        Ok(store.account_root() == account_root && block_logs_root == logs_root)
+    }
+
+    /// replace the invalid block with a reverted block, the aggregator is penalized
+    fn apply_revert_block(
+        &self,
+        store: &mut Store,
+        tx: &Transaction,
+        output_index: usize,
+        l1_number: u64,
+        revert_block: RevertBlock,
+    ) -> Result<(), Error> {
+        let data_hash: [u8; 32] = revert_block.challenge_cell_data_hash().unpack();
+        let context = self.load_challenge_context(tx, &data_hash)?;
+        let block = context.block();
+        let number: u64 = block.number().unpack();
+        let ag_index: Index = block.ag_index().unpack();
+        let challenger_index: Index = context.challenger_index().unpack();
+        let l1_global_state = load_global_state(tx, output_index);
+        if let Some(invalid_block) = store.get_unverified_blocks()?.into_iter().next() {
+            let invalid_number: u64 = invalid_block.block.number().unpack();
+            store.remove_unverified_blocks(number)?;
+            if number > invalid_number {
+                // the block is built on the invalid block, so is the reverted block
+                let reverted_block = AgBlock::new_reverted_block(
+                    block.as_reader(),
+                    l1_global_state.account_root().unpack(),
+                    challenger_index,
+                );
+                return store.insert_unverified_block(&UnverifiedBlock {
+                    l1_number,
+                    block: reverted_block,
+                    txs: Vec::new(),
+                });
+            }
+        }
+        // the main contract reverts accounts to the state before the block,
+        // accounts registered after the block are dropped but still counted
+        let prev_global_state = store
+            .get_global_state()?
+            .as_builder()
+            .account_root(block.prev_account_root())
+            .account_count(l1_global_state.account_count())
+            .block_count(number.pack())
+            .build();
+        store.reset(&prev_global_state);
+        let ag_balance = store.get_balance(ag_index, &CKB_TOKEN_ID)?;
+        let reward = ag_balance.saturating_mul(CHALLENGE_REWARD_RATE.0) / CHALLENGE_REWARD_RATE.1;
+        store.update_balance(ag_index, CKB_TOKEN_ID, 0)?;
+        let challenger_balance = store.get_balance(challenger_index, &CKB_TOKEN_ID)?;
+        store.update_balance(
+            challenger_index,
+            CKB_TOKEN_ID,
+            challenger_balance.saturating_add(reward),
+        )?;
+        let reverted_block =
+            AgBlock::new_reverted_block(block.as_reader(), store.account_root(), challenger_index);
+        store.push_block(reverted_block, Vec::new())
+    }
+
+    /// find the challenge cell in the inputs of the tx
+    fn load_challenge_context(
+        &self,
+        tx: &Transaction,
+        data_hash: &[u8; 32],
+    ) -> Result<ChallengeContext, Error> {
+        for input in tx.raw().inputs().into_iter() {
+            let out_point = input.previous_output();
+            let prev_tx_hash: [u8; 32] = out_point.tx_hash().unpack();
+            let index: u32 = out_point.index().unpack();
+            let prev_tx = match self.client.get_transaction(&prev_tx_hash)? {
+                Some(prev_tx) => prev_tx,
+                None => continue,
+            };
+            let data: Bytes = match prev_tx.raw().outputs_data().get(index as usize) {
+                Some(data) => data.unpack(),
+                None => continue,
+            };
+            if &blake2b_256(&data) == data_hash {
+                return ChallengeContext::from_slice(&data).map_err(|_| Error::InvalidWitness);
+            }
+        }
+        Err(Error::MissingChallengeCell(*data_hash))
+    }
+}
+
+/// capacity deposited into the rollup cell
+fn deposit_capacity(tx: &Transaction, output_index: usize, rollup_cell: &RollupCell) -> u64 {
+    let input_capacity: u64 = rollup_cell.output.capacity().unpack();
+    let output_capacity: u64 = tx
+        .raw()
+        .outputs()
+        .get(output_index)
+        .map(|output| output.capacity().unpack())
+        .unwrap_or(0);
+    output_capacity.saturating_sub(input_capacity)
+}
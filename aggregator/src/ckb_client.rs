@@ -0,0 +1,480 @@
+//! Layer-1 client of a CKB node's JSON-RPC
+//!
+//! Only the fields used by the aggregator are decoded, blocks and txs are converted
+//! to the packed types of `godwoken-types`.
+
+use crate::{
+    chain::ChainClient,
+    error::Error,
+    rpc::{JsonBytes, JsonHash},
+    types::L1Block,
+    wallet::CellCollector,
+};
+use godwoken_tx_builder::provider::LiveCell;
+use godwoken_types::{bytes::Bytes, packed, prelude::*};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Value};
+use std::{
+    convert::{TryFrom, TryInto},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+// json types of CKB
+
+/// number in `0x` prefixed hex, e.g. `0x1a`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HexU64(pub u64);
+
+impl Serialize for HexU64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:#x}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for HexU64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        if !s.starts_with("0x") {
+            return Err(serde::de::Error::custom("expect a 0x prefixed hex number"));
+        }
+        u64::from_str_radix(&s[2..], 16)
+            .map(HexU64)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptHashType {
+    Data,
+    Type,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DepType {
+    Code,
+    DepGroup,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptJson {
+    pub code_hash: JsonHash,
+    pub hash_type: ScriptHashType,
+    pub args: JsonBytes,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutPointJson {
+    pub tx_hash: JsonHash,
+    pub index: HexU64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellDepJson {
+    pub out_point: OutPointJson,
+    pub dep_type: DepType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellInputJson {
+    pub since: HexU64,
+    pub previous_output: OutPointJson,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellOutputJson {
+    pub capacity: HexU64,
+    pub lock: ScriptJson,
+    #[serde(rename = "type")]
+    pub type_: Option<ScriptJson>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionJson {
+    pub version: HexU64,
+    pub cell_deps: Vec<CellDepJson>,
+    pub header_deps: Vec<JsonHash>,
+    pub inputs: Vec<CellInputJson>,
+    pub outputs: Vec<CellOutputJson>,
+    pub outputs_data: Vec<JsonBytes>,
+    pub witnesses: Vec<JsonBytes>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HeaderJson {
+    pub hash: JsonHash,
+    pub number: HexU64,
+    pub parent_hash: JsonHash,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BlockJson {
+    pub header: HeaderJson,
+    pub transactions: Vec<TransactionJson>,
+}
+
+/// a live cell of the indexer's `get_cells`
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexerCellJson {
+    pub out_point: OutPointJson,
+    pub output: CellOutputJson,
+    pub output_data: JsonBytes,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexerCellsJson {
+    pub objects: Vec<IndexerCellJson>,
+    pub last_cursor: JsonBytes,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TxStatusJson {
+    /// `pending`, `proposed` or `committed`
+    pub status: String,
+    pub block_hash: Option<JsonHash>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionWithStatusJson {
+    pub transaction: TransactionJson,
+    pub tx_status: TxStatusJson,
+}
+
+// conversions
+
+impl From<ScriptJson> for packed::Script {
+    fn from(script: ScriptJson) -> Self {
+        let hash_type: u8 = match script.hash_type {
+            ScriptHashType::Data => 0,
+            ScriptHashType::Type => 1,
+        };
+        packed::Script::new_builder()
+            .code_hash(script.code_hash.0.pack())
+            .hash_type(hash_type.into())
+            .args(script.args.0.pack())
+            .build()
+    }
+}
+
+impl From<packed::Script> for ScriptJson {
+    fn from(script: packed::Script) -> Self {
+        let hash_type: u8 = script.hash_type().into();
+        ScriptJson {
+            code_hash: JsonHash(script.code_hash().unpack()),
+            hash_type: if hash_type == 0 {
+                ScriptHashType::Data
+            } else {
+                ScriptHashType::Type
+            },
+            args: JsonBytes(script.args().unpack()),
+        }
+    }
+}
+
+impl TryFrom<OutPointJson> for packed::OutPoint {
+    type Error = ConversionError;
+
+    fn try_from(out_point: OutPointJson) -> Result<Self, Self::Error> {
+        let index = u32::try_from(out_point.index.0).map_err(|_| ConversionError::Overflow)?;
+        Ok(packed::OutPoint::new_builder()
+            .tx_hash(out_point.tx_hash.0.pack())
+            .index(index.pack())
+            .build())
+    }
+}
+
+impl From<packed::OutPoint> for OutPointJson {
+    fn from(out_point: packed::OutPoint) -> Self {
+        let index: u32 = out_point.index().unpack();
+        OutPointJson {
+            tx_hash: JsonHash(out_point.tx_hash().unpack()),
+            index: HexU64(index.into()),
+        }
+    }
+}
+
+impl From<CellOutputJson> for packed::CellOutput {
+    fn from(output: CellOutputJson) -> Self {
+        packed::CellOutput::new_builder()
+            .capacity(output.capacity.0.pack())
+            .lock(output.lock.into())
+            .type_(output.type_.map(Into::into).pack())
+            .build()
+    }
+}
+
+impl TryFrom<CellDepJson> for packed::CellDep {
+    type Error = ConversionError;
+
+    fn try_from(cell_dep: CellDepJson) -> Result<Self, Self::Error> {
+        let dep_type: u8 = match cell_dep.dep_type {
+            DepType::Code => 0,
+            DepType::DepGroup => 1,
+        };
+        Ok(packed::CellDep::new_builder()
+            .out_point(cell_dep.out_point.try_into()?)
+            .dep_type(dep_type.into())
+            .build())
+    }
+}
+
+impl TryFrom<TransactionJson> for packed::Transaction {
+    type Error = ConversionError;
+
+    fn try_from(tx: TransactionJson) -> Result<Self, Self::Error> {
+        let cell_deps = tx
+            .cell_deps
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<packed::CellDep>, _>>()?;
+        let header_deps: Vec<packed::Byte32> = tx
+            .header_deps
+            .into_iter()
+            .map(|hash| hash.0.pack())
+            .collect();
+        let inputs = tx
+            .inputs
+            .into_iter()
+            .map(|input| {
+                Ok(packed::CellInput::new_builder()
+                    .since(input.since.0.pack())
+                    .previous_output(input.previous_output.try_into()?)
+                    .build())
+            })
+            .collect::<Result<Vec<packed::CellInput>, ConversionError>>()?;
+        let outputs: Vec<packed::CellOutput> = tx.outputs.into_iter().map(Into::into).collect();
+        let outputs_data: Vec<packed::Bytes> = tx
+            .outputs_data
+            .into_iter()
+            .map(|data| data.0.pack())
+            .collect();
+        let witnesses: Vec<packed::Bytes> = tx
+            .witnesses
+            .into_iter()
+            .map(|witness| witness.0.pack())
+            .collect();
+        let version = u32::try_from(tx.version.0).map_err(|_| ConversionError::Overflow)?;
+        let raw = packed::RawTransaction::new_builder()
+            .version(version.pack())
+            .cell_deps(cell_deps.pack())
+            .header_deps(header_deps.pack())
+            .inputs(inputs.pack())
+            .outputs(outputs.pack())
+            .outputs_data(outputs_data.pack())
+            .build();
+        Ok(packed::Transaction::new_builder()
+            .raw(raw)
+            .witnesses(witnesses.pack())
+            .build())
+    }
+}
+
+impl From<packed::Transaction> for TransactionJson {
+    fn from(tx: packed::Transaction) -> Self {
+        let raw = tx.raw();
+        let version: u32 = raw.version().unpack();
+        TransactionJson {
+            version: HexU64(version.into()),
+            cell_deps: raw
+                .cell_deps()
+                .into_iter()
+                .map(|cell_dep| {
+                    let dep_type: u8 = cell_dep.dep_type().into();
+                    CellDepJson {
+                        out_point: cell_dep.out_point().into(),
+                        dep_type: if dep_type == 0 {
+                            DepType::Code
+                        } else {
+                            DepType::DepGroup
+                        },
+                    }
+                })
+                .collect(),
+            header_deps: raw
+                .header_deps()
+                .into_iter()
+                .map(|hash| JsonHash(hash.unpack()))
+                .collect(),
+            inputs: raw
+                .inputs()
+                .into_iter()
+                .map(|input| CellInputJson {
+                    since: HexU64(input.since().unpack()),
+                    previous_output: input.previous_output().into(),
+                })
+                .collect(),
+            outputs: raw
+                .outputs()
+                .into_iter()
+                .map(|output| CellOutputJson {
+                    capacity: HexU64(output.capacity().unpack()),
+                    lock: output.lock().into(),
+                    type_: output.type_().to_opt().map(Into::into),
+                })
+                .collect(),
+            outputs_data: raw
+                .outputs_data()
+                .into_iter()
+                .map(|data| JsonBytes(data.unpack()))
+                .collect(),
+            witnesses: tx
+                .witnesses()
+                .into_iter()
+                .map(|witness| {
+                    let witness: Bytes = witness.unpack();
+                    JsonBytes(witness)
+                })
+                .collect(),
+        }
+    }
+}
+
+// client
+
+/// live cells are queried by pages of the size
+const INDEXER_PAGE_SIZE: u64 = 100;
+
+pub struct CKBClient {
+    url: String,
+    /// RPC of the indexer, it's the node's RPC by default
+    indexer_url: String,
+    client: reqwest::blocking::Client,
+    id: AtomicU64,
+}
+
+impl CKBClient {
+    /// `url` of the node's RPC, e.g. `http://127.0.0.1:8114`
+    pub fn new(url: String) -> Self {
+        CKBClient {
+            indexer_url: url.clone(),
+            url,
+            client: reqwest::blocking::Client::new(),
+            id: AtomicU64::new(0),
+        }
+    }
+
+    /// query live cells from a standalone `ckb-indexer`
+    pub fn with_indexer_url(mut self, indexer_url: String) -> Self {
+        self.indexer_url = indexer_url;
+        self
+    }
+
+    fn request<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, Error> {
+        self.request_url(&self.url, method, params)
+    }
+
+    fn request_url<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        method: &str,
+        params: Value,
+    ) -> Result<T, Error> {
+        let id = self.id.fetch_add(1, Ordering::Relaxed);
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        let mut response: Value = self
+            .client
+            .post(url)
+            .json(&request)
+            .send()
+            .and_then(|response| response.json())
+            .map_err(|err| Error::Chain(format!("{}: {}", method, err)))?;
+        if let Some(err) = response.get("error") {
+            return Err(Error::Chain(format!("{}: {}", method, err)));
+        }
+        let result = response
+            .get_mut("result")
+            .map(Value::take)
+            .unwrap_or(Value::Null);
+        serde_json::from_value(result).map_err(|err| Error::Chain(format!("{}: {}", method, err)))
+    }
+}
+
+impl ChainClient for CKBClient {
+    fn tip_number(&self) -> Result<u64, Error> {
+        let number: HexU64 = self.request("get_tip_block_number", json!([]))?;
+        Ok(number.0)
+    }
+
+    fn get_block(&self, number: u64) -> Result<Option<L1Block>, Error> {
+        let block: Option<BlockJson> =
+            self.request("get_block_by_number", json!([HexU64(number)]))?;
+        let block = match block {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let transactions = block
+            .transactions
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, ConversionError>>()?;
+        Ok(Some(L1Block {
+            number: block.header.number.0,
+            hash: block.header.hash.0,
+            parent_hash: block.header.parent_hash.0,
+            transactions,
+        }))
+    }
+
+    fn get_transaction(&self, tx_hash: &[u8; 32]) -> Result<Option<packed::Transaction>, Error> {
+        let tx: Option<TransactionWithStatusJson> =
+            self.request("get_transaction", json!([JsonHash(*tx_hash)]))?;
+        let tx = match tx {
+            Some(tx) => tx.transaction.try_into()?,
+            None => return Ok(None),
+        };
+        Ok(Some(tx))
+    }
+
+    fn get_transaction_block_number(&self, tx_hash: &[u8; 32]) -> Result<Option<u64>, Error> {
+        let tx: Option<TransactionWithStatusJson> =
+            self.request("get_transaction", json!([JsonHash(*tx_hash)]))?;
+        let block_hash = match tx.and_then(|tx| tx.tx_status.block_hash) {
+            Some(block_hash) => block_hash,
+            None => return Ok(None),
+        };
+        let header: Option<HeaderJson> = self.request("get_header", json!([block_hash]))?;
+        Ok(header.map(|header| header.number.0))
+    }
+
+    fn send_transaction(&self, tx: &packed::Transaction) -> Result<[u8; 32], Error> {
+        let tx: TransactionJson = tx.clone().into();
+        let tx_hash: JsonHash = self.request("send_transaction", json!([tx]))?;
+        Ok(tx_hash.0)
+    }
+}
+
+impl CellCollector for CKBClient {
+    fn get_live_cells(&self, lock: &packed::Script) -> Result<Vec<LiveCell>, Error> {
+        let search_key = json!({
+            "script": ScriptJson::from(lock.clone()),
+            "script_type": "lock",
+        });
+        let mut cells = Vec::new();
+        let mut cursor: Option<JsonBytes> = None;
+        loop {
+            let page: IndexerCellsJson = self.request_url(
+                &self.indexer_url,
+                "get_cells",
+                json!([search_key, "asc", HexU64(INDEXER_PAGE_SIZE), cursor]),
+            )?;
+            let count = page.objects.len() as u64;
+            for cell in page.objects {
+                cells.push(LiveCell {
+                    out_point: cell.out_point.try_into()?,
+                    output: cell.output.into(),
+                    data: cell.output_data.0,
+                });
+            }
+            if count < INDEXER_PAGE_SIZE {
+                return Ok(cells);
+            }
+            cursor = Some(page.last_cursor);
+        }
+    }
+}
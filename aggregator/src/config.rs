@@ -0,0 +1,366 @@
+//! Config file of the aggregator daemon
+//!
+//! A TOML file, relative paths are resolved against the directory of the config file:
+//!
+//! ```toml
+//! rollup_type_hash = "0x..."
+//! ag_index = 0
+//! privkey_path = "ag.key"
+//! data_dir = "data"
+//!
+//! [chain]
+//! rpc_url = "http://127.0.0.1:8114"
+//! start_number = 0
+//!
+//! [rpc]
+//! listen_address = "127.0.0.1:8119"
+//!
+//! [block]
+//! interval_ms = 10000
+//! max_txs = 1000
+//! max_size = 65536
+//!
+//! # pays the layer-1 txs, the aggregator's key is used without `privkey_path`
+//! [wallet]
+//! lock_code_hash = "0x9bd7e06f3ecf4be0f2fcd2188b23f1b9fcc88e5d4b65a8637b17723bbda3cce8"
+//! lock_hash_type = "type"
+//! fee_rate = 1000
+//! cell_deps = [{ dep_type = "dep_group", out_point = { tx_hash = "0x...", index = "0x0" } }]
+//!
+//! # the challenge contract, required by the validator and the responder
+//! [challenge]
+//! code_hash = "0x..."
+//! hash_type = "type"
+//! cell_deps = [{ dep_type = "code", out_point = { tx_hash = "0x...", index = "0x0" } }]
+//!
+//! # challenge invalid blocks of other aggregators
+//! [validator]
+//! enabled = true
+//! challenger_index = 1
+//! challenge_capacity = 50000000000
+//! challenge_lock = { code_hash = "0x...", hash_type = "data", args = "0x" }
+//! withdraw_lock_hash = "0x..."
+//!
+//! # answer challenges of valid blocks
+//! [responder]
+//! enabled = true
+//! resend_blocks = 20
+//! ```
+
+use crate::{
+    ckb_client::{CellDepJson, ScriptHashType, ScriptJson},
+    error::Error,
+    generator::GeneratorConfig,
+    mempool::MempoolConfig,
+    responder::ResponderConfig,
+    rpc::JsonHash,
+    validator::ValidatorConfig,
+    wallet::WalletConfig,
+};
+use godwoken_types::{
+    core::{Index, RollupID},
+    packed::CellDep,
+    prelude::ConversionError,
+};
+use secp256k1::SecretKey;
+use serde::Deserialize;
+use std::convert::TryInto;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    /// type hash of the main contract, i.e. the `RollupID`
+    pub rollup_type_hash: JsonHash,
+    /// index of the aggregator's account
+    pub ag_index: Index,
+    /// file of the aggregator's secp256k1 private key in hex
+    pub privkey_path: PathBuf,
+    /// directory of the store
+    pub data_dir: PathBuf,
+    pub chain: ChainConfig,
+    #[serde(default)]
+    pub rpc: RpcConfig,
+    #[serde(default)]
+    pub block: BlockConfig,
+    #[serde(default)]
+    pub mempool: PoolConfig,
+    pub wallet: PaymentConfig,
+    pub challenge: Option<ChallengeScriptConfig>,
+    pub validator: Option<ChallengerConfig>,
+    pub responder: Option<ResponseConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainConfig {
+    /// CKB node's RPC
+    pub rpc_url: String,
+    /// layer-1 block to sync from, it should be before the main contract's deployment
+    pub start_number: u64,
+    /// interval of polling the layer-1 tip
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// RPC of a standalone `ckb-indexer`, the node's RPC serves live cells by default
+    pub indexer_url: Option<String>,
+}
+
+fn default_poll_interval_ms() -> u64 {
+    3000
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RpcConfig {
+    pub listen_address: SocketAddr,
+    pub threads: usize,
+}
+
+impl Default for RpcConfig {
+    fn default() -> Self {
+        RpcConfig {
+            listen_address: ([127, 0, 0, 1], 8119).into(),
+            threads: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct BlockConfig {
+    /// min interval between submitted blocks
+    pub interval_ms: u64,
+    /// max number of txs in a block
+    pub max_txs: usize,
+    /// max total size of txs in a block, at most `MAX_BLOCK_TXS_SIZE`
+    pub max_size: usize,
+    /// cell deps of the `SubmitBlock` tx, in the format of CKB's JSON-RPC
+    pub cell_deps: Vec<CellDepJson>,
+}
+
+impl Default for BlockConfig {
+    fn default() -> Self {
+        let generator_config = GeneratorConfig::default();
+        BlockConfig {
+            interval_ms: 10_000,
+            max_txs: generator_config.max_txs,
+            max_size: generator_config.max_size,
+            cell_deps: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PoolConfig {
+    pub max_txs: usize,
+    pub max_tx_size: usize,
+    pub max_nonce_gap: u32,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        let mempool_config = MempoolConfig::default();
+        PoolConfig {
+            max_txs: mempool_config.max_txs,
+            max_tx_size: mempool_config.max_tx_size,
+            max_nonce_gap: mempool_config.max_nonce_gap,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaymentConfig {
+    /// file of the wallet's secp256k1 private key, the aggregator's key by default
+    pub privkey_path: Option<PathBuf>,
+    /// code hash of the secp256k1 blake160 sighash-all lock
+    pub lock_code_hash: JsonHash,
+    pub lock_hash_type: ScriptHashType,
+    /// fee rate in shannons per 1000 bytes
+    #[serde(default = "default_fee_rate")]
+    pub fee_rate: u64,
+    /// cell deps of the lock, e.g. the secp256k1 dep group
+    pub cell_deps: Vec<CellDepJson>,
+}
+
+fn default_fee_rate() -> u64 {
+    1000
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChallengeScriptConfig {
+    /// code hash of the challenge contract
+    pub code_hash: JsonHash,
+    pub hash_type: ScriptHashType,
+    /// cell deps of the challenge contract's code
+    pub cell_deps: Vec<CellDepJson>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChallengerConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// account to receive the challenge reward
+    pub challenger_index: Index,
+    /// capacity of the challenge cell, it's the bond of the challenge
+    pub challenge_capacity: u64,
+    /// lock of the challenge cell, it must differ from the wallet's lock
+    pub challenge_lock: ScriptJson,
+    /// lock hash of the cell which withdraws the challenge cell
+    pub withdraw_lock_hash: JsonHash,
+    /// layer-1 blocks to wait before the challenge tx is sent again
+    #[serde(default = "default_resend_blocks")]
+    pub resend_blocks: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResponseConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// layer-1 blocks to wait before a response is sent again
+    #[serde(default = "default_resend_blocks")]
+    pub resend_blocks: u64,
+}
+
+fn default_resend_blocks() -> u64 {
+    20
+}
+
+impl Config {
+    /// parse a config file, relative paths are resolved against its directory
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        let mut config = Self::parse(&content)?;
+        if let Some(dir) = path.parent() {
+            config.privkey_path = dir.join(&config.privkey_path);
+            if let Some(privkey_path) = &config.wallet.privkey_path {
+                config.wallet.privkey_path = Some(dir.join(privkey_path));
+            }
+            config.data_dir = dir.join(&config.data_dir);
+        }
+        Ok(config)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        toml::from_str(content).map_err(|err| Error::Config(err.to_string()))
+    }
+
+    pub fn rollup_id(&self) -> RollupID {
+        self.rollup_type_hash.0
+    }
+
+    /// read the private key file, the key is in hex with an optional `0x` prefix
+    pub fn load_privkey(&self) -> Result<SecretKey, Error> {
+        read_privkey(&self.privkey_path)
+    }
+
+    /// the wallet's private key, it's the aggregator's key by default
+    pub fn load_wallet_privkey(&self) -> Result<SecretKey, Error> {
+        match &self.wallet.privkey_path {
+            Some(privkey_path) => read_privkey(privkey_path),
+            None => self.load_privkey(),
+        }
+    }
+
+    pub fn mempool_config(&self) -> MempoolConfig {
+        MempoolConfig {
+            max_txs: self.mempool.max_txs,
+            max_tx_size: self.mempool.max_tx_size,
+            max_nonce_gap: self.mempool.max_nonce_gap,
+        }
+    }
+
+    /// fails if a cell dep doesn't fit the packed `CellDep`
+    pub fn generator_config(&self) -> Result<GeneratorConfig, Error> {
+        Ok(GeneratorConfig {
+            max_txs: self.block.max_txs,
+            max_size: self.block.max_size,
+            cell_deps: pack_cell_deps(&self.block.cell_deps)?,
+        })
+    }
+
+    pub fn wallet_config(&self) -> Result<WalletConfig, Error> {
+        Ok(WalletConfig {
+            lock_code_hash: self.wallet.lock_code_hash.0,
+            lock_hash_type: hash_type(self.wallet.lock_hash_type),
+            cell_deps: pack_cell_deps(&self.wallet.cell_deps)?,
+            fee_rate: self.wallet.fee_rate,
+        })
+    }
+
+    /// `None` if the validator isn't enabled, it requires the `[challenge]` section
+    pub fn validator_config(&self) -> Result<Option<ValidatorConfig>, Error> {
+        let validator = match &self.validator {
+            Some(validator) if validator.enabled => validator,
+            _ => return Ok(None),
+        };
+        let challenge = self.challenge_config()?;
+        // the revert tx updates the rollup cell besides the challenge cell
+        let mut cell_deps = pack_cell_deps(&self.block.cell_deps)?;
+        cell_deps.extend(pack_cell_deps(&challenge.cell_deps)?);
+        Ok(Some(ValidatorConfig {
+            challenger_index: validator.challenger_index,
+            challenge_capacity: validator.challenge_capacity,
+            challenge_lock: validator.challenge_lock.clone().into(),
+            challenge_code_hash: challenge.code_hash.0,
+            challenge_hash_type: hash_type(challenge.hash_type),
+            withdraw_lock_hash: validator.withdraw_lock_hash.0,
+            cell_deps,
+            resend_blocks: validator.resend_blocks,
+        }))
+    }
+
+    /// `None` if the responder isn't enabled, it requires the `[challenge]` section
+    pub fn responder_config(&self) -> Result<Option<ResponderConfig>, Error> {
+        let responder = match &self.responder {
+            Some(responder) if responder.enabled => responder,
+            _ => return Ok(None),
+        };
+        let challenge = self.challenge_config()?;
+        Ok(Some(ResponderConfig {
+            challenge_code_hash: challenge.code_hash.0,
+            challenge_hash_type: hash_type(challenge.hash_type),
+            cell_deps: pack_cell_deps(&challenge.cell_deps)?,
+            resend_blocks: responder.resend_blocks,
+        }))
+    }
+
+    fn challenge_config(&self) -> Result<&ChallengeScriptConfig, Error> {
+        self.challenge
+            .as_ref()
+            .ok_or_else(|| Error::Config("missing [challenge] section".to_string()))
+    }
+}
+
+fn read_privkey(path: &Path) -> Result<SecretKey, Error> {
+    let content = fs::read_to_string(path)?;
+    let content = content.trim();
+    let content = if content.starts_with("0x") {
+        &content[2..]
+    } else {
+        content
+    };
+    let privkey = hex::decode(content).map_err(|err| Error::Config(err.to_string()))?;
+    SecretKey::parse_slice(&privkey).map_err(|_| {
+        Error::Config(format!(
+            "invalid secp256k1 private key in {}",
+            path.display()
+        ))
+    })
+}
+
+fn pack_cell_deps(cell_deps: &[CellDepJson]) -> Result<Vec<CellDep>, Error> {
+    let cell_deps = cell_deps
+        .iter()
+        .cloned()
+        .map(TryInto::try_into)
+        .collect::<Result<_, ConversionError>>()?;
+    Ok(cell_deps)
+}
+
+fn hash_type(hash_type: ScriptHashType) -> u8 {
+    match hash_type {
+        ScriptHashType::Data => 0,
+        ScriptHashType::Type => 1,
+    }
+}
@@ -0,0 +1,101 @@
+use godwoken_executor::error::{BlockError, Error as ExecutorError};
+use godwoken_tx_builder::error::Error as BuilderError;
+use godwoken_types::{packed::GlobalState, prelude::ConversionError};
+use godwoken_utils::mmr;
+use sparse_merkle_tree::error::Error as SMTError;
+
+#[derive(Debug)]
+pub enum Error {
+    DB(sled::Error),
+    IO(std::io::Error),
+    /// invalid config file or key file
+    Config(String),
+    SMT(SMTError),
+    MMR(mmr::Error),
+    /// expected block number, block's number
+    InvalidBlockNumber(u64, u64),
+    Executor(ExecutorError),
+    /// index of the tx in the block, the tx's error
+    InvalidTx(usize, ExecutorError),
+    /// account index
+    MissingAccount(u64),
+    /// account's nonce, tx's nonce
+    StaleNonce(u32, u32),
+    /// account's nonce, tx's nonce
+    NonceTooHigh(u32, u32),
+    /// max size, tx's size
+    TxTooLarge(usize, usize),
+    /// the fee rate is not higher than the pending tx of the same nonce
+    FeeTooLow,
+    PoolFull,
+    /// the sender can't pay for its pending txs
+    InsufficientBalance,
+    /// layer-1 client error
+    Chain(String),
+    /// the rollup cell's witness isn't a valid action
+    InvalidWitness,
+    /// local global state, global state on layer 1
+    GlobalStateMismatch(GlobalState, GlobalState),
+    /// data hash of the challenge cell
+    MissingChallengeCell([u8; 32]),
+    /// number of the invalid block, count of the blocks after it,
+    /// the main contract only reverts the last block
+    BlocksAfterInvalidBlock(u64, usize),
+    /// hash of a storage value committed in the account tree without its preimage
+    MissingStorageValue([u8; 32]),
+    /// a value doesn't fit its molecule type
+    Conversion(ConversionError),
+    /// the wallet can't complete a layer-1 tx
+    Builder(BuilderError),
+}
+
+impl From<sled::Error> for Error {
+    fn from(err: sled::Error) -> Self {
+        Error::DB(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::IO(err)
+    }
+}
+
+impl From<SMTError> for Error {
+    fn from(err: SMTError) -> Self {
+        Error::SMT(err)
+    }
+}
+
+impl From<mmr::Error> for Error {
+    fn from(err: mmr::Error) -> Self {
+        Error::MMR(err)
+    }
+}
+
+impl From<ExecutorError> for Error {
+    fn from(err: ExecutorError) -> Self {
+        Error::Executor(err)
+    }
+}
+
+impl From<BlockError> for Error {
+    fn from(err: BlockError) -> Self {
+        match err {
+            BlockError::InvalidAggregateSignature(err) => Error::Executor(err),
+            BlockError::InvalidTx(i, err) => Error::InvalidTx(i, err),
+        }
+    }
+}
+
+impl From<ConversionError> for Error {
+    fn from(err: ConversionError) -> Self {
+        Error::Conversion(err)
+    }
+}
+
+impl From<BuilderError> for Error {
+    fn from(err: BuilderError) -> Self {
+        Error::Builder(err)
+    }
+}
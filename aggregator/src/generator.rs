@@ -0,0 +1,237 @@
+//! Block generator
+//!
+//! Packages txs from the mempool into a block on top of the store's current state,
+//! and assembles the layer-1 transaction of the `SubmitBlock` action.
+//! The store is left unchanged, the block is applied once it's committed on layer 1.
+
+use crate::{
+    error::Error,
+    mempool::{Mempool, PoolTx},
+    state,
+    store::Store,
+    types::RollupCell,
+};
+use godwoken_executor::{error::Error as ExecutorError, executor::Executor};
+use godwoken_tx_builder::witness::action_witness;
+use godwoken_types::{
+    cache::KVMap,
+    core::{Index, RollupID, CKB_TOKEN_ID, MAX_BLOCK_TXS_SIZE},
+    packed::*,
+    prelude::*,
+    signing::{block_signing_message, tx_hash},
+};
+use godwoken_utils::{bls, mmr::merkle_root, smt};
+use secp256k1::{Message, SecretKey};
+
+pub struct GeneratorConfig {
+    /// max number of txs in a block
+    pub max_txs: usize,
+    /// max total size of txs in a block, at most `MAX_BLOCK_TXS_SIZE`
+    pub max_size: usize,
+    /// cell deps of the layer-1 tx, e.g. the main contract's code
+    pub cell_deps: Vec<CellDep>,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        GeneratorConfig {
+            max_txs: 1000,
+            max_size: MAX_BLOCK_TXS_SIZE,
+            cell_deps: Vec::new(),
+        }
+    }
+}
+
+pub struct GeneratedBlock {
+    pub block: AgBlock,
+    pub txs: Vec<Tx>,
+    pub submit_block: SubmitBlock,
+    /// global state after the block, the data of the new rollup cell
+    pub global_state: GlobalState,
+    /// unsigned layer-1 tx, the rollup cell is the first input and output
+    pub transaction: Transaction,
+}
+
+pub struct Generator {
+    rollup_id: RollupID,
+    executor: Executor,
+    ag_index: Index,
+    privkey: SecretKey,
+    config: GeneratorConfig,
+}
+
+impl Generator {
+    /// `privkey` is the aggregator account's secp256k1 key, blocks are signed with scheme 0
+    pub fn new(
+        rollup_id: RollupID,
+        ag_index: Index,
+        privkey: SecretKey,
+        config: GeneratorConfig,
+    ) -> Self {
+        Generator {
+            rollup_id,
+            executor: Executor::new(rollup_id),
+            ag_index,
+            privkey,
+            config,
+        }
+    }
+
+    /// Generate the next block, failed txs are removed from the mempool.
+    pub fn generate(
+        &self,
+        store: &mut Store,
+        mempool: &mut Mempool,
+        rollup_cell: &RollupCell,
+    ) -> Result<GeneratedBlock, Error> {
+        let prev_global_state = store.get_global_state()?;
+        let result = self.generate_block(store, mempool, rollup_cell, &prev_global_state);
+        store.reset(&prev_global_state);
+        result
+    }
+
+    fn generate_block(
+        &self,
+        store: &mut Store,
+        mempool: &mut Mempool,
+        rollup_cell: &RollupCell,
+        prev_global_state: &GlobalState,
+    ) -> Result<GeneratedBlock, Error> {
+        let ag_index = self.ag_index;
+        // aggregator's state before the block
+        let ag_account = store
+            .get_account(ag_index)?
+            .ok_or(Error::MissingAccount(ag_index))?;
+        let mut token_kv = KVMap::default();
+        token_kv.insert(CKB_TOKEN_ID, store.get_balance(ag_index, &CKB_TOKEN_ID)?);
+        let account_proof = store.account_merkle_proof(vec![
+            smt::account_index_key(ag_index),
+            smt::token_id_key(ag_index, &CKB_TOKEN_ID),
+        ])?;
+        let number = store.block_count();
+        let (last_block_hash, block_proof) = if number == 0 {
+            ([0u8; 32], Vec::new())
+        } else {
+            let last_block_hash = store
+                .get_block_hash(number - 1)?
+                .expect("last block exists");
+            (last_block_hash, store.block_merkle_proof(number - 1)?)
+        };
+
+        // the main contract rejects a block larger than `MAX_BLOCK_TXS_SIZE`
+        let max_size = self.config.max_size.min(MAX_BLOCK_TXS_SIZE);
+        // run txs, drop failed txs and retry
+        let mut pool_txs = mempool.package(store, self.config.max_txs, max_size)?;
+        let (txs, aggregate_sig, run_results) = loop {
+            let txs: Vec<Tx> = pool_txs.iter().map(|pool_tx| pool_tx.tx.clone()).collect();
+            let aggregate_sig = aggregate_signature(&pool_txs)?;
+            match state::apply_txs(&self.executor, store, &txs, ag_index, &aggregate_sig) {
+                Ok(run_results) => break (txs, aggregate_sig, run_results),
+                Err(Error::InvalidTx(i, _err)) => {
+                    // following txs of the sender are removed too
+                    mempool.remove_tx(&pool_txs[i].tx_hash);
+                    pool_txs.retain(|pool_tx| mempool.get_tx(&pool_tx.tx_hash).is_some());
+                }
+                Err(err) => return Err(err),
+            }
+        };
+        // individual signatures can't be recovered from the aggregate signature
+        for pool_tx in &pool_txs {
+            if let Some(signature) = &pool_tx.bls_signature {
+                store.insert_bls_signature(&pool_tx.tx_hash, signature)?;
+            }
+        }
+
+        let tx_root = merkle_root(txs.iter().map(|tx| tx_hash(&tx.as_reader())).collect());
+        let logs_root = merkle_root(run_results.iter().map(|r| r.logs_hash()).collect());
+        let block = AgBlock::new_builder()
+            .number(number.pack())
+            .tx_root(tx_root.pack())
+            .txs_count(txs.len().try_pack()?)
+            .logs_root(logs_root.pack())
+            .prev_account_root(prev_global_state.account_root())
+            .prev_account_count(prev_global_state.account_count())
+            .account_root(store.account_root().pack())
+            .ag_index(ag_index.pack())
+            .aggregate_sig(aggregate_sig.pack())
+            .build();
+        let ag_sig = self.sign_block(&block);
+        let block = block.as_builder().ag_sig(ag_sig.pack()).build();
+        let global_state = prev_global_state
+            .clone()
+            .as_builder()
+            .account_root(store.account_root().pack())
+            .block_root(store.next_block_root(&block)?.pack())
+            .block_count((number + 1).pack())
+            .build();
+
+        let submit_block = SubmitBlock::new_builder()
+            .txs(TxVec::new_builder().set(txs.clone()).build())
+            .block(block.clone())
+            .last_block_hash(last_block_hash.pack())
+            .block_proof(
+                block_proof
+                    .into_iter()
+                    .map(|item| item.pack())
+                    .collect::<Vec<_>>()
+                    .pack(),
+            )
+            .ag_account(ag_account)
+            .token_kv(token_kv.pack())
+            .account_proof(account_proof)
+            .build();
+        let transaction = self.build_transaction(rollup_cell, &submit_block, &global_state);
+        Ok(GeneratedBlock {
+            block,
+            txs,
+            submit_block,
+            global_state,
+            transaction,
+        })
+    }
+
+    fn sign_block(&self, block: &AgBlock) -> [u8; 65] {
+        let message = block_signing_message(&self.rollup_id, &block.as_reader());
+        let (signature, recovery_id) = secp256k1::sign(&Message::parse(&message), &self.privkey);
+        let mut sig = [0u8; 65];
+        sig[..64].copy_from_slice(&signature.serialize());
+        sig[64] = recovery_id.serialize();
+        sig
+    }
+
+    fn build_transaction(
+        &self,
+        rollup_cell: &RollupCell,
+        submit_block: &SubmitBlock,
+        global_state: &GlobalState,
+    ) -> Transaction {
+        let witness = action_witness(submit_block.clone());
+        let input = CellInput::new_builder()
+            .previous_output(rollup_cell.out_point.clone())
+            .build();
+        let raw = RawTransaction::new_builder()
+            .cell_deps(self.config.cell_deps.clone().pack())
+            .inputs(vec![input].pack())
+            .outputs(vec![rollup_cell.output.clone()].pack())
+            .outputs_data(vec![global_state.as_bytes().pack()].pack())
+            .build();
+        Transaction::new_builder()
+            .raw(raw)
+            .witnesses(vec![witness.pack()].pack())
+            .build()
+    }
+}
+
+/// aggregate BLS signatures of txs with empty witness, zeros if there is no such tx
+fn aggregate_signature(pool_txs: &[PoolTx]) -> Result<[u8; 96], Error> {
+    let signatures: Vec<[u8; 96]> = pool_txs
+        .iter()
+        .filter_map(|pool_tx| pool_tx.bls_signature)
+        .collect();
+    if signatures.is_empty() {
+        return Ok([0u8; 96]);
+    }
+    // signatures are verified when pushed into the mempool
+    bls::aggregate_signatures(&signatures)
+        .map_err(|_| Error::Executor(ExecutorError::InvalidSignature))
+}
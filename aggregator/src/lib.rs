@@ -1,3 +1,13 @@
-mod store;
-mod mempool;
-mod chain;
+pub mod chain;
+pub mod ckb_client;
+pub mod config;
+pub mod error;
+pub mod generator;
+pub mod mempool;
+pub mod responder;
+pub mod rpc;
+pub mod state;
+pub mod store;
+pub mod types;
+pub mod validator;
+pub mod wallet;
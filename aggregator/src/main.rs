@@ -0,0 +1,231 @@
+//! Aggregator daemon
+//!
+//! Usage: `godwoken-aggregator [config.toml]`
+//!
+//! Follows layer 1, serves the JSON-RPC and submits a block of pending txs every block interval.
+//! Layer-1 txs are paid and signed by the secp256k1 wallet. With `[validator]` or `[responder]`
+//! enabled, invalid blocks are challenged and challenges of valid blocks are answered.
+//! Ctrl-C stops the daemon after the current round, the store is committed after each synced
+//! layer-1 block and pending txs in the mempool are dropped.
+
+use godwoken_aggregator::{
+    chain::{ChainClient, ChainFollower},
+    ckb_client::CKBClient,
+    config::Config,
+    error::Error,
+    generator::Generator,
+    mempool::Mempool,
+    responder::Responder,
+    rpc::RpcServer,
+    store::Store,
+    validator::{Validator, Wallet},
+    wallet::Secp256k1Wallet,
+};
+use godwoken_types::{packed::OutPoint, prelude::*};
+use log::{error, info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// a block is submitted again if it isn't committed in time
+const PENDING_BLOCK_TIMEOUT: Duration = Duration::from_secs(300);
+const EXIT_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// a block sent to layer 1 but not synced yet
+struct PendingBlock {
+    number: u64,
+    /// the rollup cell consumed by the block's tx
+    rollup_out_point: OutPoint,
+    sent_at: Instant,
+}
+
+type DaemonWallet = Arc<Secp256k1Wallet<Arc<CKBClient>>>;
+
+struct Aggregator {
+    follower: ChainFollower<Arc<CKBClient>>,
+    generator: Generator,
+    wallet: DaemonWallet,
+    validator: Option<Validator<Arc<CKBClient>, DaemonWallet>>,
+    responder: Option<Responder<Arc<CKBClient>, DaemonWallet>>,
+    store: Arc<Mutex<Store>>,
+    mempool: Arc<Mutex<Mempool>>,
+    block_interval: Duration,
+    last_block_at: Instant,
+    pending_block: Option<PendingBlock>,
+}
+
+impl Aggregator {
+    /// sync layer 1, process challenges, then submit a block if it's time
+    fn tick(&mut self) -> Result<(), Error> {
+        let mut store = self.store.lock().expect("lock store");
+        let mut mempool = self.mempool.lock().expect("lock mempool");
+        let count = self.follower.sync(&mut store, &mut mempool)?;
+        if count > 0 {
+            info!(
+                "synced {} layer-1 blocks, layer-2 block count {}",
+                count,
+                store.block_count()
+            );
+        }
+        // challenges are retried in the next round without blocking the blocks
+        if let Some(responder) = &mut self.responder {
+            match responder.process(&mut store) {
+                Ok(tx_hashes) => {
+                    for tx_hash in tx_hashes {
+                        info!(
+                            "answered a challenge in layer-1 tx 0x{}",
+                            hex::encode(tx_hash)
+                        );
+                    }
+                }
+                Err(err) => warn!("responder error: {:?}", err),
+            }
+        }
+        let rollup_cell = match self.follower.rollup_cell(&store)? {
+            Some(rollup_cell) => rollup_cell,
+            // the main contract isn't deployed
+            None => return Ok(()),
+        };
+        if let Some(validator) = &mut self.validator {
+            match validator.process(&store, &rollup_cell) {
+                Ok(Some(tx_hash)) => {
+                    info!("sent layer-1 tx 0x{} of a challenge", hex::encode(tx_hash));
+                }
+                Ok(None) => {}
+                Err(err) => warn!("validator error: {:?}", err),
+            }
+        }
+        if let Some(pending_block) = &self.pending_block {
+            let waiting = pending_block.rollup_out_point.as_slice()
+                == rollup_cell.out_point.as_slice()
+                && pending_block.sent_at.elapsed() < PENDING_BLOCK_TIMEOUT;
+            if waiting {
+                return Ok(());
+            }
+            if store.block_count() <= pending_block.number {
+                warn!("block {} is not committed", pending_block.number);
+            }
+            self.pending_block = None;
+        }
+        // blocks can't be submitted on layer 1 until the invalid block is reverted
+        if store.has_unverified_blocks() {
+            return Ok(());
+        }
+        if mempool.is_empty() || self.last_block_at.elapsed() < self.block_interval {
+            return Ok(());
+        }
+        let generated = self
+            .generator
+            .generate(&mut store, &mut mempool, &rollup_cell)?;
+        let number: u64 = generated.block.number().unpack();
+        let transaction = self.wallet.complete_transaction(generated.transaction)?;
+        let tx_hash = self.follower.client().send_transaction(&transaction)?;
+        info!(
+            "submitted block {} with {} txs in layer-1 tx 0x{}",
+            number,
+            generated.txs.len(),
+            hex::encode(tx_hash)
+        );
+        self.pending_block = Some(PendingBlock {
+            number,
+            rollup_out_point: rollup_cell.out_point,
+            sent_at: Instant::now(),
+        });
+        self.last_block_at = Instant::now();
+        Ok(())
+    }
+}
+
+fn run(config_path: &str, exit: Arc<AtomicBool>) -> Result<(), Error> {
+    let config = Config::load(config_path)?;
+    let rollup_id = config.rollup_id();
+    let store = Arc::new(Mutex::new(Store::open(&config.data_dir)?));
+    let mempool = Arc::new(Mutex::new(Mempool::new(rollup_id, config.mempool_config())));
+    let mut client = CKBClient::new(config.chain.rpc_url.clone());
+    if let Some(indexer_url) = &config.chain.indexer_url {
+        client = client.with_indexer_url(indexer_url.clone());
+    }
+    let client = Arc::new(client);
+    let wallet = Arc::new(Secp256k1Wallet::new(
+        Arc::clone(&client),
+        config.load_wallet_privkey()?,
+        config.wallet_config()?,
+    ));
+    info!(
+        "wallet lock args 0x{}",
+        hex::encode(wallet.lock().args().raw_data())
+    );
+    let validator = config.validator_config()?.map(|validator_config| {
+        Validator::new(
+            Arc::clone(&client),
+            Arc::clone(&wallet),
+            rollup_id,
+            validator_config,
+        )
+    });
+    let responder = config.responder_config()?.map(|responder_config| {
+        Responder::new(
+            Arc::clone(&client),
+            Arc::clone(&wallet),
+            rollup_id,
+            responder_config,
+            config.chain.start_number,
+        )
+    });
+    let mut aggregator = Aggregator {
+        follower: ChainFollower::new(client, rollup_id, config.chain.start_number),
+        generator: Generator::new(
+            rollup_id,
+            config.ag_index,
+            config.load_privkey()?,
+            config.generator_config()?,
+        ),
+        wallet,
+        validator,
+        responder,
+        store: Arc::clone(&store),
+        mempool: Arc::clone(&mempool),
+        block_interval: Duration::from_millis(config.block.interval_ms),
+        last_block_at: Instant::now(),
+        pending_block: None,
+    };
+    let rpc_server = RpcServer::start(
+        &config.rpc.listen_address,
+        config.rpc.threads,
+        store,
+        mempool,
+    )?;
+    info!("JSON-RPC server listening on {}", rpc_server.address());
+
+    let poll_interval = Duration::from_millis(config.chain.poll_interval_ms);
+    while !exit.load(Ordering::SeqCst) {
+        // layer-1 errors are retried in the next round
+        if let Err(err) = aggregator.tick() {
+            warn!("aggregator error: {:?}", err);
+        }
+        let started_at = Instant::now();
+        while !exit.load(Ordering::SeqCst) && started_at.elapsed() < poll_interval {
+            thread::sleep(EXIT_CHECK_INTERVAL);
+        }
+    }
+    info!("shutting down");
+    rpc_server.close();
+    Ok(())
+}
+
+fn main() {
+    env_logger::init();
+    let config_path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "config.toml".to_string());
+    let exit = Arc::new(AtomicBool::new(false));
+    {
+        let exit = Arc::clone(&exit);
+        ctrlc::set_handler(move || exit.store(true, Ordering::SeqCst)).expect("set Ctrl-C handler");
+    }
+    if let Err(err) = run(&config_path, exit) {
+        error!("{:?}", err);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,436 @@
+//! Pool of pending txs
+//!
+//! Txs are queued per sender in nonce order, the sender's next tx is ready to be packaged.
+//! Ready txs are packaged by fee rate, an underfunded or outdated tx is evicted with
+//! the following txs of its sender.
+
+use crate::{error::Error, store::Store};
+use godwoken_executor::{error::Error as ExecutorError, executor::Executor};
+use godwoken_types::{
+    cache::TxWithHash,
+    core::{Index, RollupID, TokenID, CKB_TOKEN_ID, TX_VERSION},
+    packed::*,
+    prelude::*,
+    signing::tx_signing_message,
+};
+use godwoken_utils::bls;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+
+pub struct MempoolConfig {
+    /// max number of txs in the pool
+    pub max_txs: usize,
+    /// max size of a tx
+    pub max_tx_size: usize,
+    /// max distance between a tx's nonce and the sender's nonce
+    pub max_nonce_gap: u32,
+}
+
+impl Default for MempoolConfig {
+    fn default() -> Self {
+        MempoolConfig {
+            max_txs: 10_000,
+            max_tx_size: 16 * 1024,
+            max_nonce_gap: 64,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PoolTx {
+    pub tx: Tx,
+    pub tx_hash: [u8; 32],
+    /// BLS signature of a tx with empty witness, it's aggregated into the block's `aggregate_sig`
+    pub bls_signature: Option<[u8; 96]>,
+    /// CKB fee in shannons per 1000 bytes, fees paid in other tokens have no priority
+    pub fee_rate: u64,
+}
+
+impl PoolTx {
+    pub fn sender_index(&self) -> Index {
+        self.tx.raw().sender_index().unpack()
+    }
+
+    pub fn nonce(&self) -> u32 {
+        self.tx.raw().nonce().unpack()
+    }
+}
+
+fn fee_rate(raw_tx: &RawTx, size: usize) -> u64 {
+    let (token_id, fee): (TokenID, u128) = raw_tx.fee().unpack();
+    if token_id != CKB_TOKEN_ID || size == 0 {
+        return 0;
+    }
+    let fee_rate = fee.saturating_mul(1000) / size as u128;
+    if fee_rate > u64::max_value() as u128 {
+        u64::max_value()
+    } else {
+        fee_rate as u64
+    }
+}
+
+/// tokens spent by a tx, including the fee
+fn tx_cost(raw_tx: &RawTx) -> Vec<(TokenID, u128)> {
+    let mut cost = vec![raw_tx.fee().unpack()];
+    match raw_tx.kind().to_enum() {
+        TxKindUnion::Transfer(transfer) => cost.push(transfer.amount().unpack()),
+        TxKindUnion::MultiTransfer(multi_transfer) => {
+            for item in multi_transfer.transfers().into_iter() {
+                cost.push(item.amount().unpack());
+            }
+        }
+        TxKindUnion::WithdrawRequest(withdraw_request) => {
+            cost.push(withdraw_request.amount().unpack())
+        }
+        TxKindUnion::KeyRotation(_) => {}
+    }
+    cost
+}
+
+/// Contract accounts can't receive txs, the challenge contract can't execute contracts
+/// and a block calling them can't be proved.
+fn check_receivers(store: &Store, raw_tx: &RawTx) -> Result<(), Error> {
+    let receivers: Vec<Index> = match raw_tx.kind().to_enum() {
+        TxKindUnion::Transfer(transfer) => vec![transfer.to_index().unpack()],
+        TxKindUnion::MultiTransfer(multi_transfer) => multi_transfer
+            .transfers()
+            .into_iter()
+            .map(|item| item.to_index().unpack())
+            .collect(),
+        TxKindUnion::WithdrawRequest(_) | TxKindUnion::KeyRotation(_) => Vec::new(),
+    };
+    for index in receivers {
+        if let Some(receiver) = store.get_account(index)? {
+            if receiver.script().to_opt().is_some() {
+                return Err(ExecutorError::ContractNotSupported.into());
+            }
+        }
+    }
+    Ok(())
+}
+
+pub struct Mempool {
+    rollup_id: RollupID,
+    executor: Executor,
+    config: MempoolConfig,
+    /// sender index -> nonce -> tx
+    queues: HashMap<Index, BTreeMap<u32, PoolTx>>,
+    /// tx hash -> (sender index, nonce)
+    tx_index: HashMap<[u8; 32], (Index, u32)>,
+}
+
+impl Mempool {
+    pub fn new(rollup_id: RollupID, config: MempoolConfig) -> Self {
+        Mempool {
+            rollup_id,
+            executor: Executor::new(rollup_id),
+            config,
+            queues: HashMap::default(),
+            tx_index: HashMap::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tx_index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tx_index.is_empty()
+    }
+
+    pub fn get_tx(&self, tx_hash: &[u8; 32]) -> Option<&PoolTx> {
+        let (sender_index, nonce) = self.tx_index.get(tx_hash)?;
+        self.queues.get(sender_index)?.get(nonce)
+    }
+
+    /// push a tx signed in the witness, returns the tx hash
+    pub fn push_tx(&mut self, store: &Store, tx: Tx) -> Result<[u8; 32], Error> {
+        self.push(store, tx, None)
+    }
+
+    /// push a tx with empty witness and its BLS signature, returns the tx hash
+    pub fn push_bls_tx(
+        &mut self,
+        store: &Store,
+        tx: Tx,
+        signature: [u8; 96],
+    ) -> Result<[u8; 32], Error> {
+        self.push(store, tx, Some(signature))
+    }
+
+    fn push(
+        &mut self,
+        store: &Store,
+        tx: Tx,
+        bls_signature: Option<[u8; 96]>,
+    ) -> Result<[u8; 32], Error> {
+        let size = tx.as_slice().len();
+        if size > self.config.max_tx_size {
+            return Err(Error::TxTooLarge(self.config.max_tx_size, size));
+        }
+        let raw_tx = tx.raw();
+        let version: u8 = raw_tx.version().into();
+        if version != TX_VERSION {
+            return Err(ExecutorError::InvalidTxVersion(version).into());
+        }
+        let sender_index: Index = raw_tx.sender_index().unpack();
+        let sender = store
+            .get_account(sender_index)?
+            .ok_or(Error::MissingAccount(sender_index))?;
+        // check nonce, txs after the sender's next nonce are queued
+        let nonce: u32 = sender.nonce().unpack();
+        let tx_nonce: u32 = raw_tx.nonce().unpack();
+        if tx_nonce <= nonce {
+            return Err(Error::StaleNonce(nonce, tx_nonce));
+        }
+        if tx_nonce - nonce > self.config.max_nonce_gap {
+            return Err(Error::NonceTooHigh(nonce, tx_nonce));
+        }
+        // check signature
+        let tx_with_hash = TxWithHash::new(tx.as_reader());
+        let tx_hash = tx_with_hash.tx_hash;
+        let has_witness = !tx.witness().raw_data().is_empty();
+        match bls_signature {
+            Some(signature) if !has_witness => {
+                let bls_pubkey: [u8; 48] = sender
+                    .bls_pubkey()
+                    .to_opt()
+                    .ok_or(ExecutorError::InvalidSignature)?
+                    .unpack();
+                let message = tx_signing_message(&self.rollup_id, &tx_with_hash.raw_tx_hash);
+                bls::verify(&bls_pubkey, &message, &signature)
+                    .map_err(|_| ExecutorError::InvalidSignature)?;
+            }
+            None if has_witness => self.executor.verify_tx_signature(&sender, &tx_with_hash)?,
+            _ => return Err(ExecutorError::InvalidSignature.into()),
+        }
+        check_receivers(store, &raw_tx)?;
+
+        let pool_tx = PoolTx {
+            fee_rate: fee_rate(&raw_tx, size),
+            tx,
+            tx_hash,
+            bls_signature,
+        };
+        let existing = self
+            .queues
+            .get(&sender_index)
+            .and_then(|queue| queue.get(&tx_nonce));
+        let replaced_hash = match existing {
+            Some(existing) if existing.tx_hash == tx_hash => return Ok(tx_hash),
+            // replace the pending tx by a higher fee rate
+            Some(existing) if existing.fee_rate >= pool_tx.fee_rate => {
+                return Err(Error::FeeTooLow)
+            }
+            Some(existing) => Some(existing.tx_hash),
+            None => None,
+        };
+        // the pool is unchanged if the sender can't pay for the tx
+        self.check_funding(store, sender_index, tx_nonce, &raw_tx)?;
+        match replaced_hash {
+            Some(replaced_hash) => {
+                self.tx_index.remove(&replaced_hash);
+            }
+            None if self.len() >= self.config.max_txs => {
+                self.evict_lowest(sender_index, tx_nonce, pool_tx.fee_rate)?
+            }
+            None => {}
+        }
+        self.tx_index.insert(tx_hash, (sender_index, tx_nonce));
+        self.queues
+            .entry(sender_index)
+            .or_default()
+            .insert(tx_nonce, pool_tx);
+        // the following txs of the sender may be underfunded by the new tx
+        self.evict_underfunded(store, sender_index)?;
+        Ok(tx_hash)
+    }
+
+    /// check the sender can pay for its pending txs before `nonce` and the new tx
+    fn check_funding(
+        &self,
+        store: &Store,
+        sender_index: Index,
+        nonce: u32,
+        raw_tx: &RawTx,
+    ) -> Result<(), Error> {
+        let mut costs: Vec<(TokenID, u128)> = self
+            .queues
+            .get(&sender_index)
+            .into_iter()
+            .flat_map(|queue| queue.range(..nonce))
+            .flat_map(|(_nonce, pool_tx)| tx_cost(&pool_tx.tx.raw()))
+            .collect();
+        costs.extend(tx_cost(raw_tx));
+        let mut spent: BTreeMap<TokenID, u128> = BTreeMap::new();
+        for (token_id, amount) in costs {
+            let total = spent.entry(token_id).or_insert(0);
+            *total = total.saturating_add(amount);
+            if *total > store.get_balance(sender_index, &token_id)? {
+                return Err(Error::InsufficientBalance);
+            }
+        }
+        Ok(())
+    }
+
+    /// evict the last tx of a sender with the lowest fee rate to make room for a new tx,
+    /// txs of the new tx's sender before its nonce are kept, the new tx isn't ready without them
+    fn evict_lowest(
+        &mut self,
+        new_sender: Index,
+        new_nonce: u32,
+        fee_rate: u64,
+    ) -> Result<(), Error> {
+        let lowest = self
+            .queues
+            .iter()
+            .filter_map(|(sender_index, queue)| {
+                let (nonce, pool_tx) = queue.iter().next_back()?;
+                if *sender_index == new_sender && *nonce < new_nonce {
+                    return None;
+                }
+                Some((pool_tx.fee_rate, *sender_index, *nonce))
+            })
+            .min();
+        match lowest {
+            Some((lowest_fee_rate, sender_index, nonce)) if lowest_fee_rate < fee_rate => {
+                self.remove_from(sender_index, nonce);
+                Ok(())
+            }
+            _ => Err(Error::PoolFull),
+        }
+    }
+
+    /// evict txs from the first one the sender can't pay for
+    fn evict_underfunded(&mut self, store: &Store, sender_index: Index) -> Result<(), Error> {
+        let queue = match self.queues.get(&sender_index) {
+            Some(queue) => queue,
+            None => return Ok(()),
+        };
+        let mut spent: BTreeMap<TokenID, u128> = BTreeMap::new();
+        let mut underfunded_nonce = None;
+        'txs: for (nonce, pool_tx) in queue {
+            for (token_id, amount) in tx_cost(&pool_tx.tx.raw()) {
+                let total = spent.entry(token_id).or_insert(0);
+                *total = total.saturating_add(amount);
+                if *total > store.get_balance(sender_index, &token_id)? {
+                    underfunded_nonce = Some(*nonce);
+                    break 'txs;
+                }
+            }
+        }
+        if let Some(nonce) = underfunded_nonce {
+            self.remove_from(sender_index, nonce);
+        }
+        Ok(())
+    }
+
+    /// remove txs of a sender from `nonce`
+    fn remove_from(&mut self, sender_index: Index, nonce: u32) {
+        let queue = match self.queues.get_mut(&sender_index) {
+            Some(queue) => queue,
+            None => return,
+        };
+        for pool_tx in queue.split_off(&nonce).values() {
+            self.tx_index.remove(&pool_tx.tx_hash);
+        }
+        if queue.is_empty() {
+            self.queues.remove(&sender_index);
+        }
+    }
+
+    /// remove a tx and the following txs of its sender, e.g. the tx failed to execute
+    pub fn remove_tx(&mut self, tx_hash: &[u8; 32]) {
+        if let Some((sender_index, nonce)) = self.tx_index.get(tx_hash).cloned() {
+            self.remove_from(sender_index, nonce);
+        }
+    }
+
+    /// evict outdated and underfunded txs, it should be called after the store is updated
+    pub fn update(&mut self, store: &Store) -> Result<(), Error> {
+        let senders: Vec<Index> = self.queues.keys().cloned().collect();
+        for sender_index in senders {
+            let nonce: u32 = match store.get_account(sender_index)? {
+                Some(sender) => sender.nonce().unpack(),
+                None => {
+                    self.remove_from(sender_index, 0);
+                    continue;
+                }
+            };
+            if let Some(queue) = self.queues.get_mut(&sender_index) {
+                let pending = queue.split_off(&nonce.saturating_add(1));
+                for pool_tx in queue.values() {
+                    self.tx_index.remove(&pool_tx.tx_hash);
+                }
+                *queue = pending;
+                if queue.is_empty() {
+                    self.queues.remove(&sender_index);
+                }
+            }
+            self.evict_underfunded(store, sender_index)?;
+        }
+        Ok(())
+    }
+
+    /// sender's nonce after its ready txs are committed, the next tx of the sender should use `nonce + 1`,
+    /// returns `None` if the account doesn't exist
+    pub fn get_pending_nonce(&self, store: &Store, index: Index) -> Result<Option<u32>, Error> {
+        let mut nonce: u32 = match store.get_account(index)? {
+            Some(account) => account.nonce().unpack(),
+            None => return Ok(None),
+        };
+        if let Some(queue) = self.queues.get(&index) {
+            while let Some(next_nonce) = nonce.checked_add(1) {
+                if !queue.contains_key(&next_nonce) {
+                    break;
+                }
+                nonce = next_nonce;
+            }
+        }
+        Ok(Some(nonce))
+    }
+
+    /// ready txs ordered by fee rate, txs of a sender are in nonce order
+    pub fn package(
+        &self,
+        store: &Store,
+        max_txs: usize,
+        max_size: usize,
+    ) -> Result<Vec<PoolTx>, Error> {
+        // the next tx of each sender
+        let mut heads = BinaryHeap::new();
+        for (sender_index, queue) in &self.queues {
+            let nonce: u32 = match store.get_account(*sender_index)? {
+                Some(sender) => sender.nonce().unpack(),
+                None => continue,
+            };
+            if let Some(next_nonce) = nonce.checked_add(1) {
+                if let Some(pool_tx) = queue.get(&next_nonce) {
+                    heads.push((pool_tx.fee_rate, Reverse(*sender_index), next_nonce));
+                }
+            }
+        }
+        let mut txs = Vec::new();
+        let mut size = 0;
+        while let Some((_fee_rate, Reverse(sender_index), nonce)) = heads.pop() {
+            if txs.len() >= max_txs {
+                break;
+            }
+            let queue = &self.queues[&sender_index];
+            let pool_tx = &queue[&nonce];
+            let tx_size = pool_tx.tx.as_slice().len();
+            if size + tx_size > max_size {
+                // skip the rest txs of the sender
+                continue;
+            }
+            size += tx_size;
+            txs.push(pool_tx.clone());
+            if let Some(next_nonce) = nonce.checked_add(1) {
+                if let Some(next_tx) = queue.get(&next_nonce) {
+                    heads.push((next_tx.fee_rate, Reverse(sender_index), next_nonce));
+                }
+            }
+        }
+        Ok(txs)
+    }
+}
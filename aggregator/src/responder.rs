@@ -0,0 +1,262 @@
+//! Responder of challenges
+//!
+//! Scans layer-1 blocks synced by the `ChainFollower` for challenge cells of the rollup.
+//! A challenge of a valid block is answered with `InvalidChallenge`, which proves the block
+//! by re-executing its txs on the touched accounts, otherwise the block would be reverted
+//! after `CHALLENGE_CELL_WAIT_BLOCKS` and the aggregator penalized.
+
+use crate::{
+    chain::{transaction_hash, ChainClient},
+    error::Error,
+    state::{self, AccountEntry},
+    store::Store,
+    types::L1Block,
+    validator::Wallet,
+};
+use godwoken_tx_builder::{
+    challenge::{invalid_challenge, touched_accounts_keys},
+    witness::challenge_unlock_witness,
+};
+use godwoken_types::{
+    bytes::Bytes,
+    core::{Index, RollupID, CHALLENGE_CELL_WAIT_BLOCKS},
+    packed::*,
+    prelude::*,
+};
+use godwoken_utils::hash::block_hash;
+use std::collections::BTreeMap;
+
+pub struct ResponderConfig {
+    /// code hash of the challenge contract
+    pub challenge_code_hash: [u8; 32],
+    pub challenge_hash_type: u8,
+    /// cell deps of the response tx, e.g. the challenge contract's code
+    pub cell_deps: Vec<CellDep>,
+    /// layer-1 blocks to wait before a response is sent again
+    pub resend_blocks: u64,
+}
+
+/// A live challenge cell of the rollup
+#[derive(Debug, Clone)]
+pub struct ChallengeCell {
+    pub out_point: OutPoint,
+    pub context: ChallengeContext,
+    /// number of the layer-1 block which commits the cell
+    pub l1_number: u64,
+    /// layer-1 tip number when the response is sent
+    pub responded_at: Option<u64>,
+}
+
+pub struct Responder<C, W> {
+    client: C,
+    wallet: W,
+    rollup_id: RollupID,
+    config: ResponderConfig,
+    /// next layer-1 block to scan
+    next_number: u64,
+    /// hashes of recently scanned layer-1 blocks, used to find orphaned blocks
+    scanned_blocks: BTreeMap<u64, [u8; 32]>,
+    challenges: Vec<ChallengeCell>,
+}
+
+impl<C: ChainClient, W: Wallet> Responder<C, W> {
+    /// layer-1 blocks are scanned from `start_number`
+    pub fn new(
+        client: C,
+        wallet: W,
+        rollup_id: RollupID,
+        config: ResponderConfig,
+        start_number: u64,
+    ) -> Self {
+        Responder {
+            client,
+            wallet,
+            rollup_id,
+            config,
+            next_number: start_number,
+            scanned_blocks: BTreeMap::new(),
+            challenges: Vec::new(),
+        }
+    }
+
+    pub fn challenges(&self) -> &[ChallengeCell] {
+        &self.challenges
+    }
+
+    /// Scan synced layer-1 blocks and answer challenges of valid blocks,
+    /// it should be called after each sync. Returns hashes of the sent txs.
+    pub fn process(&mut self, store: &mut Store) -> Result<Vec<[u8; 32]>, Error> {
+        self.rewind(store)?;
+        let last_synced_number = match store.get_last_synced_block()? {
+            Some(synced_block) => synced_block.number,
+            None => return Ok(Vec::new()),
+        };
+        while self.next_number <= last_synced_number {
+            let synced_block = store
+                .get_synced_block(self.next_number)?
+                .ok_or_else(|| Error::Chain(format!("block {} isn't synced", self.next_number)))?;
+            let block = match self.client.get_block(self.next_number)? {
+                Some(block) if block.hash == synced_block.hash => block,
+                // layer 1 is reorganized after the sync, the block is scanned in the next round
+                _ => break,
+            };
+            self.scan_block(&block)?;
+            self.scanned_blocks.insert(block.number, block.hash);
+            self.next_number += 1;
+        }
+        while self.scanned_blocks.len() as u64 > CHALLENGE_CELL_WAIT_BLOCKS {
+            let number = *self.scanned_blocks.keys().next().expect("scanned block");
+            self.scanned_blocks.remove(&number);
+        }
+
+        let tip_number = self.client.tip_number()?;
+        let mut tx_hashes = Vec::new();
+        for i in 0..self.challenges.len() {
+            let challenge = &self.challenges[i];
+            if let Some(responded_at) = challenge.responded_at {
+                if tip_number < responded_at + self.config.resend_blocks {
+                    continue;
+                }
+            }
+            if !is_valid_block(store, &challenge.context.block())? {
+                continue;
+            }
+            let tx = self.build_response_transaction(store, challenge)?;
+            let tx_hash = self
+                .client
+                .send_transaction(&self.wallet.complete_transaction(tx)?)?;
+            self.challenges[i].responded_at = Some(tip_number);
+            tx_hashes.push(tx_hash);
+        }
+        Ok(tx_hashes)
+    }
+
+    /// drop scanned blocks which are rolled back by the follower
+    fn rewind(&mut self, store: &Store) -> Result<(), Error> {
+        while let Some((&number, &hash)) = self.scanned_blocks.iter().next_back() {
+            let synced_hash = store.get_synced_block(number)?.map(|block| block.hash);
+            if synced_hash == Some(hash) {
+                break;
+            }
+            self.scanned_blocks.remove(&number);
+            self.next_number = number;
+        }
+        let next_number = self.next_number;
+        self.challenges
+            .retain(|challenge| challenge.l1_number < next_number);
+        Ok(())
+    }
+
+    /// record new challenge cells of the rollup and drop consumed ones
+    fn scan_block(&mut self, block: &L1Block) -> Result<(), Error> {
+        for tx in &block.transactions {
+            let raw = tx.raw();
+            for input in raw.inputs().into_iter() {
+                let out_point = input.previous_output();
+                self.challenges
+                    .retain(|challenge| challenge.out_point.as_slice() != out_point.as_slice());
+            }
+            let tx_hash = transaction_hash(tx);
+            for (index, output) in raw.outputs().into_iter().enumerate() {
+                if !self.is_challenge_cell(&output) {
+                    continue;
+                }
+                let data: Bytes = raw
+                    .outputs_data()
+                    .get(index)
+                    .map(|data| data.unpack())
+                    .unwrap_or_default();
+                // the challenge contract rejects invalid contexts
+                let context = match ChallengeContext::from_slice(&data) {
+                    Ok(context) => context,
+                    Err(_) => continue,
+                };
+                self.challenges.push(ChallengeCell {
+                    out_point: OutPoint::new_builder()
+                        .tx_hash(tx_hash.pack())
+                        .index(index.try_pack()?)
+                        .build(),
+                    context,
+                    l1_number: block.number,
+                    responded_at: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn is_challenge_cell(&self, output: &CellOutput) -> bool {
+        let type_script = match output.type_().to_opt() {
+            Some(type_script) => type_script,
+            None => return false,
+        };
+        let code_hash: [u8; 32] = type_script.code_hash().unpack();
+        let hash_type: u8 = type_script.hash_type().into();
+        if code_hash != self.config.challenge_code_hash
+            || hash_type != self.config.challenge_hash_type
+        {
+            return false;
+        }
+        let args: Bytes = type_script.args().unpack();
+        match ChallengeArgs::from_slice(&args) {
+            Ok(args) => {
+                let main_type_hash: [u8; 32] = args.main_type_hash().unpack();
+                main_type_hash == self.rollup_id
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// the challenge cell is the only input, the bond is collected by the wallet's change
+    fn build_response_transaction(
+        &self,
+        store: &mut Store,
+        challenge: &ChallengeCell,
+    ) -> Result<Transaction, Error> {
+        let block = challenge.context.block();
+        let txs: Vec<Tx> = challenge.context.txs().into_iter().collect();
+        let ag_index: Index = block.ag_index().unpack();
+        // touched accounts before the block
+        let global_state = store.get_global_state()?;
+        let prev_global_state = global_state
+            .clone()
+            .as_builder()
+            .account_root(block.prev_account_root())
+            .account_count(block.prev_account_count())
+            .build();
+        store.reset(&prev_global_state);
+        let result = load_touched_accounts(store, &txs, ag_index);
+        store.reset(&global_state);
+        let (accounts, proof) = result?;
+
+        let witness = challenge_unlock_witness(invalid_challenge(&accounts, proof));
+        let input = CellInput::new_builder()
+            .previous_output(challenge.out_point.clone())
+            .build();
+        let raw = RawTransaction::new_builder()
+            .cell_deps(self.config.cell_deps.clone().pack())
+            .inputs(vec![input].pack())
+            .build();
+        Ok(Transaction::new_builder()
+            .raw(raw)
+            .witnesses(vec![witness.pack()].pack())
+            .build())
+    }
+}
+
+/// the challenged block is a block of the store, i.e. it's valid
+fn is_valid_block(store: &Store, block: &AgBlock) -> Result<bool, Error> {
+    let number: u64 = block.number().unpack();
+    Ok(store.get_block_hash(number)? == Some(block_hash(block)))
+}
+
+/// touched accounts of the txs and their merkle proof against the current account root
+fn load_touched_accounts(
+    store: &Store,
+    txs: &[Tx],
+    ag_index: Index,
+) -> Result<(Vec<AccountEntry>, SMTProof), Error> {
+    let accounts = state::load_accounts(store, &state::touched_accounts(txs, ag_index))?;
+    let proof = store.account_merkle_proof(touched_accounts_keys(&accounts))?;
+    Ok((accounts, proof))
+}
@@ -0,0 +1,386 @@
+//! JSON-RPC server
+//!
+//! Molecule structures, e.g. `Tx`, `Account` and `AgBlock`, are exchanged as `0x` prefixed hex
+//! of their molecule encoding, clients decode them with `godwoken-types`.
+//! Hashes and token ids are `0x` prefixed hex of 32 bytes, balances are `0x` prefixed hex numbers,
+//! other numbers are JSON numbers.
+
+use crate::{error::Error, mempool::Mempool, store::Store};
+use godwoken_types::{
+    bytes::Bytes,
+    cache::KVMap,
+    core::Index,
+    packed::{Account, AgBlock, GlobalState, KeyValueMap, SMTProof, Tx},
+    prelude::*,
+};
+use godwoken_utils::smt;
+use jsonrpc_core::{Error as RpcError, ErrorCode, IoHandler, Result};
+use jsonrpc_derive::rpc;
+use jsonrpc_http_server::{Server, ServerBuilder};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use sparse_merkle_tree::H256;
+use std::fmt;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+// json types
+
+fn encode_hex(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(data))
+}
+
+fn decode_hex<E: de::Error>(s: &str) -> std::result::Result<Vec<u8>, E> {
+    if !s.starts_with("0x") {
+        return Err(E::custom("expect a 0x prefixed hex string"));
+    }
+    hex::decode(&s[2..]).map_err(E::custom)
+}
+
+/// bytes in `0x` prefixed hex
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct JsonBytes(pub Bytes);
+
+impl JsonBytes {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// molecule encoding of `entity`
+    pub fn from_entity<T: Entity>(entity: &T) -> Self {
+        JsonBytes(entity.as_bytes())
+    }
+}
+
+impl Serialize for JsonBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode_hex(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(JsonBytes(decode_hex(&s)?.into()))
+    }
+}
+
+/// 32 bytes hash in `0x` prefixed hex
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JsonHash(pub [u8; 32]);
+
+impl Serialize for JsonHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode_hex(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let buf = decode_hex::<D::Error>(&s)?;
+        if buf.len() != 32 {
+            return Err(de::Error::invalid_length(buf.len(), &"32 bytes"));
+        }
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&buf);
+        Ok(JsonHash(hash))
+    }
+}
+
+/// u128 in `0x` prefixed hex, JSON numbers can't hold it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JsonUint128(pub u128);
+
+impl Serialize for JsonUint128 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:#x}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonUint128 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        if !s.starts_with("0x") {
+            return Err(de::Error::custom("expect a 0x prefixed hex string"));
+        }
+        let value = u128::from_str_radix(&s[2..], 16).map_err(de::Error::custom)?;
+        Ok(JsonUint128(value))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TxStatus {
+    /// in the mempool
+    Pending,
+    /// in a block
+    Committed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxView {
+    /// molecule `Tx`
+    pub tx: JsonBytes,
+    pub status: TxStatus,
+    /// `None` for a pending tx
+    pub block_number: Option<u64>,
+    /// index of the tx in the block, `None` for a pending tx
+    pub tx_index: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockView {
+    pub hash: JsonHash,
+    /// molecule `AgBlock`
+    pub block: JsonBytes,
+    /// molecule `Tx`s of the block
+    pub txs: Vec<JsonBytes>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountProofView {
+    /// account root after the block
+    pub account_root: JsonHash,
+    /// molecule `Account`, `None` if the account doesn't exist after the block
+    pub account: Option<JsonBytes>,
+    /// molecule `KeyValueMap` of the requested tokens' balances
+    pub token_kv: JsonBytes,
+    /// molecule `SMTProof` of the account and the balances against `account_root`
+    pub proof: JsonBytes,
+}
+
+fn decode<T: Entity>(bytes: &JsonBytes) -> Result<T> {
+    T::from_slice(bytes.as_bytes()).map_err(|err| RpcError::invalid_params(format!("{:?}", err)))
+}
+
+fn rpc_error(err: Error) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(-1),
+        message: format!("{:?}", err),
+        data: None,
+    }
+}
+
+// methods
+
+#[rpc(server)]
+pub trait AggregatorRpc {
+    /// push a tx into the mempool, a tx with empty witness is signed by `bls_signature`,
+    /// returns the tx hash
+    #[rpc(name = "send_transaction")]
+    fn send_transaction(&self, tx: JsonBytes, bls_signature: Option<JsonBytes>)
+        -> Result<JsonHash>;
+
+    #[rpc(name = "get_account")]
+    fn get_account(&self, index: Index) -> Result<Option<JsonBytes>>;
+
+    #[rpc(name = "get_balance")]
+    fn get_balance(&self, index: Index, token_id: JsonHash) -> Result<JsonUint128>;
+
+    /// account's nonce after its pending txs, the next tx should use `nonce + 1`
+    #[rpc(name = "get_nonce")]
+    fn get_nonce(&self, index: Index) -> Result<Option<u32>>;
+
+    #[rpc(name = "get_block")]
+    fn get_block(&self, number: u64) -> Result<Option<BlockView>>;
+
+    /// a committed tx or a pending tx in the mempool
+    #[rpc(name = "get_transaction")]
+    fn get_transaction(&self, tx_hash: JsonHash) -> Result<Option<TxView>>;
+
+    /// molecule `GlobalState`
+    #[rpc(name = "get_global_state")]
+    fn get_global_state(&self) -> Result<JsonBytes>;
+
+    /// merkle proof of an account and its balances against the account root after a block
+    #[rpc(name = "get_account_proof")]
+    fn get_account_proof(
+        &self,
+        index: Index,
+        token_ids: Vec<JsonHash>,
+        block_number: u64,
+    ) -> Result<Option<AccountProofView>>;
+}
+
+/// Shares the store and the mempool with the aggregator,
+/// the store is always locked before the mempool.
+pub struct AggregatorRpcImpl {
+    store: Arc<Mutex<Store>>,
+    mempool: Arc<Mutex<Mempool>>,
+}
+
+impl AggregatorRpcImpl {
+    pub fn new(store: Arc<Mutex<Store>>, mempool: Arc<Mutex<Mempool>>) -> Self {
+        AggregatorRpcImpl { store, mempool }
+    }
+}
+
+impl AggregatorRpc for AggregatorRpcImpl {
+    fn send_transaction(
+        &self,
+        tx: JsonBytes,
+        bls_signature: Option<JsonBytes>,
+    ) -> Result<JsonHash> {
+        let tx: Tx = decode(&tx)?;
+        let store = self.store.lock().expect("lock store");
+        let mut mempool = self.mempool.lock().expect("lock mempool");
+        let tx_hash = match bls_signature {
+            Some(signature) => {
+                if signature.as_bytes().len() != 96 {
+                    return Err(RpcError::invalid_params("expect a 96 bytes BLS signature"));
+                }
+                let mut buf = [0u8; 96];
+                buf.copy_from_slice(signature.as_bytes());
+                mempool.push_bls_tx(&store, tx, buf)
+            }
+            None => mempool.push_tx(&store, tx),
+        }
+        .map_err(rpc_error)?;
+        Ok(JsonHash(tx_hash))
+    }
+
+    fn get_account(&self, index: Index) -> Result<Option<JsonBytes>> {
+        let store = self.store.lock().expect("lock store");
+        let account = store.get_account(index).map_err(rpc_error)?;
+        Ok(account.as_ref().map(JsonBytes::from_entity))
+    }
+
+    fn get_balance(&self, index: Index, token_id: JsonHash) -> Result<JsonUint128> {
+        let store = self.store.lock().expect("lock store");
+        let balance = store.get_balance(index, &token_id.0).map_err(rpc_error)?;
+        Ok(JsonUint128(balance))
+    }
+
+    fn get_nonce(&self, index: Index) -> Result<Option<u32>> {
+        let store = self.store.lock().expect("lock store");
+        let mempool = self.mempool.lock().expect("lock mempool");
+        mempool.get_pending_nonce(&store, index).map_err(rpc_error)
+    }
+
+    fn get_block(&self, number: u64) -> Result<Option<BlockView>> {
+        let store = self.store.lock().expect("lock store");
+        let block = match store.get_block(number).map_err(rpc_error)? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let hash = store
+            .get_block_hash(number)
+            .map_err(rpc_error)?
+            .expect("block hash exists");
+        let txs = store
+            .get_block_txs(number)
+            .map_err(rpc_error)?
+            .unwrap_or_default();
+        Ok(Some(BlockView {
+            hash: JsonHash(hash),
+            block: JsonBytes::from_entity(&block),
+            txs: txs.iter().map(JsonBytes::from_entity).collect(),
+        }))
+    }
+
+    fn get_transaction(&self, tx_hash: JsonHash) -> Result<Option<TxView>> {
+        let store = self.store.lock().expect("lock store");
+        if let Some((tx, block_number, tx_index)) = store.get_tx(&tx_hash.0).map_err(rpc_error)? {
+            return Ok(Some(TxView {
+                tx: JsonBytes::from_entity(&tx),
+                status: TxStatus::Committed,
+                block_number: Some(block_number),
+                tx_index: Some(tx_index),
+            }));
+        }
+        let mempool = self.mempool.lock().expect("lock mempool");
+        Ok(mempool.get_tx(&tx_hash.0).map(|pool_tx| TxView {
+            tx: JsonBytes::from_entity(&pool_tx.tx),
+            status: TxStatus::Pending,
+            block_number: None,
+            tx_index: None,
+        }))
+    }
+
+    fn get_global_state(&self) -> Result<JsonBytes> {
+        let store = self.store.lock().expect("lock store");
+        let global_state: GlobalState = store.get_global_state().map_err(rpc_error)?;
+        Ok(JsonBytes::from_entity(&global_state))
+    }
+
+    fn get_account_proof(
+        &self,
+        index: Index,
+        token_ids: Vec<JsonHash>,
+        block_number: u64,
+    ) -> Result<Option<AccountProofView>> {
+        let store = self.store.lock().expect("lock store");
+        let block: AgBlock = match store.get_block(block_number).map_err(rpc_error)? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let account_root: [u8; 32] = block.account_root().unpack();
+        let tree = store.account_tree(account_root);
+        let account_key = smt::account_index_key(index);
+        let value = tree
+            .get(&account_key)
+            .map_err(|err| rpc_error(err.into()))?;
+        let account: Option<Account> = if value.is_zero() {
+            None
+        } else {
+            Some(value.into())
+        };
+        let mut keys: Vec<H256> = vec![account_key];
+        let mut token_kv = KVMap::default();
+        for JsonHash(token_id) in token_ids {
+            let key = smt::token_id_key(index, &token_id);
+            let balance: u128 = tree.get(&key).map_err(|err| rpc_error(err.into()))?.into();
+            token_kv.insert(token_id, balance);
+            keys.push(key);
+        }
+        let token_kv: KeyValueMap = token_kv.pack();
+        let proof: SMTProof = store
+            .account_merkle_proof_at(account_root, keys)
+            .map_err(rpc_error)?;
+        Ok(Some(AccountProofView {
+            account_root: JsonHash(account_root),
+            account: account.as_ref().map(JsonBytes::from_entity),
+            token_kv: JsonBytes::from_entity(&token_kv),
+            proof: JsonBytes::from_entity(&proof),
+        }))
+    }
+}
+
+/// A running HTTP JSON-RPC server
+pub struct RpcServer {
+    server: Server,
+}
+
+impl RpcServer {
+    /// serve on `listen_address` with `threads` worker threads
+    pub fn start(
+        listen_address: &SocketAddr,
+        threads: usize,
+        store: Arc<Mutex<Store>>,
+        mempool: Arc<Mutex<Mempool>>,
+    ) -> std::result::Result<Self, Error> {
+        let mut io = IoHandler::new();
+        io.extend_with(AggregatorRpcImpl::new(store, mempool).to_delegate());
+        let server = ServerBuilder::new(io)
+            .threads(threads)
+            .start_http(listen_address)?;
+        Ok(RpcServer { server })
+    }
+
+    pub fn address(&self) -> &SocketAddr {
+        self.server.address()
+    }
+
+    /// stop serving, in-flight requests are finished first
+    pub fn close(self) {
+        self.server.close()
+    }
+}
+
+impl fmt::Debug for RpcServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RpcServer")
+            .field("address", self.address())
+            .finish()
+    }
+}
@@ -0,0 +1,115 @@
+//! Execution of txs against the store
+//!
+//! Txs of a block run on a `State` of the block's touched accounts: the aggregator,
+//! the senders and the receivers of the txs. The challenge contract re-executes a block
+//! on the same accounts, contracts can't access other accounts in both places.
+
+use crate::{error::Error, store::Store};
+use godwoken_executor::{
+    executor::Executor,
+    state::{AccountState, State},
+    types::RunResult,
+};
+use godwoken_types::{
+    cache::{KVMap, StorageMap, TxWithHash},
+    core::Index,
+    packed::*,
+    prelude::*,
+};
+
+pub use godwoken_executor::state::touched_accounts;
+pub use godwoken_tx_builder::provider::AccountEntry;
+
+/// load accounts with all their tokens and storage slots, missing accounts are skipped
+pub fn load_accounts(store: &Store, indexes: &[Index]) -> Result<Vec<AccountEntry>, Error> {
+    let mut accounts = Vec::with_capacity(indexes.len());
+    for &index in indexes {
+        let account = match store.get_account(index)? {
+            Some(account) => account,
+            None => continue,
+        };
+        let mut kv = KVMap::default();
+        for token_id in store.get_account_tokens(index)? {
+            kv.insert(token_id, store.get_balance(index, &token_id)?);
+        }
+        let mut withdraw_kv = KVMap::default();
+        for token_id in store.get_account_withdraw_tokens(index)? {
+            withdraw_kv.insert(token_id, store.get_withdraw(index, &token_id)?);
+        }
+        let mut storage = StorageMap::default();
+        for key in store.get_account_storage_keys(index)? {
+            storage.insert(key, store.get_storage(index, &key)?);
+        }
+        accounts.push(AccountEntry {
+            account,
+            kv,
+            withdraw_kv,
+            storage,
+        });
+    }
+    Ok(accounts)
+}
+
+pub fn build_state(accounts: &[AccountEntry]) -> State {
+    State::new(
+        accounts
+            .iter()
+            .map(|entry| AccountState {
+                account: entry.account.as_reader(),
+                kv: entry.kv.clone(),
+                withdraw_kv: entry.withdraw_kv.clone(),
+                storage: entry.storage.clone(),
+            })
+            .collect(),
+    )
+}
+
+/// write changed accounts of `state` back to the store
+pub fn write_state(store: &mut Store, state: &State) -> Result<(), Error> {
+    for (account, kv) in state.iter() {
+        let index: Index = account.index().unpack();
+        if store.get_account(index)?.as_ref().map(|a| a.as_slice()) != Some(account.as_slice()) {
+            store.update_account(account.clone())?;
+        }
+        for (token_id, balance) in kv {
+            if store.get_balance(index, token_id)? != *balance {
+                store.update_balance(index, *token_id, *balance)?;
+            }
+        }
+        if let Some(withdraw_kv) = state.get_account_withdraw(index) {
+            for (token_id, balance) in withdraw_kv {
+                if store.get_withdraw(index, token_id)? != *balance {
+                    store.update_withdraw(index, *token_id, *balance)?;
+                }
+            }
+        }
+        if let Some(storage) = state.get_account_storage(index) {
+            for (key, value) in storage {
+                if &store.get_storage(index, key)? != value {
+                    store.update_storage(index, *key, value)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run txs of a block and write the new state to the store, returns results of txs.
+/// The store is unchanged if the aggregate signature or any tx is invalid.
+pub fn apply_txs(
+    executor: &Executor,
+    store: &mut Store,
+    txs: &[Tx],
+    ag_index: Index,
+    aggregate_sig: &[u8; 96],
+) -> Result<Vec<RunResult>, Error> {
+    let accounts = load_accounts(store, &touched_accounts(txs, ag_index))?;
+    let mut state = build_state(&accounts);
+    let tx_with_hashes: Vec<_> = txs
+        .iter()
+        .map(|tx| TxWithHash::new(tx.as_reader()))
+        .collect();
+    let run_results = executor.run_block(&mut state, tx_with_hashes, aggregate_sig, ag_index)?;
+    write_state(store, &state)?;
+    Ok(run_results)
+}
@@ -1,8 +1,767 @@
-/// Storage for contract related data
+//! Storage for contract related data
+//!
+//! Accounts SMT nodes, the blocks MMR, blocks, txs and the global state after each block
+//! are persisted in an embedded database, the aggregator restarts from the last committed state.
+//!
+//! SMT nodes are never removed, an account tree of any previous root can be reopened,
+//! see `Store::reset`.
 
-pub struct Store {
-    accounts: Vec<(Account, SMT)>,
-    account_count: u32,
-    blocks: HashMMR,
-    block_count: u32,
+use crate::{
+    error::Error,
+    types::{RollupCell, SyncedBlock, UnverifiedBlock},
+};
+use ckb_merkle_mountain_range::{
+    leaf_index_to_mmr_size, leaf_index_to_pos, Error as MMRError, MMRStore, Result as MMRResult,
+    MMR,
+};
+use godwoken_types::{
+    core::{Index, TokenID},
+    packed::*,
+    prelude::*,
+    signing::tx_hash,
 };
+use godwoken_utils::{
+    hash::block_hash,
+    mmr::HashMerge,
+    smt::{self, Blake2bHasher, SMTPrefix, Value, H256},
+};
+use sparse_merkle_tree::{
+    error::Error as SMTError,
+    traits::Store as SMTStoreTrait,
+    tree::{BranchNode, LeafNode},
+    SparseMerkleTree,
+};
+use std::convert::TryFrom;
+use std::path::Path;
+
+const GLOBAL_STATE_KEY: &[u8] = b"global_state";
+
+pub type AccountSMT = SparseMerkleTree<Blake2bHasher, Value, SMTStore>;
+type BlockMMR = MMR<[u8; 32], HashMerge, BlockMMRStore>;
+
+/// SMT nodes
+#[derive(Clone)]
+pub struct SMTStore {
+    branches: sled::Tree,
+    leaves: sled::Tree,
+}
+
+fn smt_store_error(err: sled::Error) -> SMTError {
+    SMTError::Store(err.to_string())
+}
+
+fn to_h256(slice: &[u8]) -> H256 {
+    let mut buf = [0u8; 32];
+    buf.copy_from_slice(slice);
+    buf.into()
+}
+
+impl SMTStoreTrait<Value> for SMTStore {
+    fn get_branch(&self, node: &H256) -> Result<Option<BranchNode>, SMTError> {
+        let branch = self
+            .branches
+            .get(node.as_slice())
+            .map_err(smt_store_error)?;
+        Ok(branch.map(|branch| BranchNode {
+            fork_height: branch[0],
+            key: to_h256(&branch[1..33]),
+            node: to_h256(&branch[33..65]),
+            sibling: to_h256(&branch[65..97]),
+        }))
+    }
+
+    fn get_leaf(&self, leaf_hash: &H256) -> Result<Option<LeafNode<Value>>, SMTError> {
+        let leaf = self
+            .leaves
+            .get(leaf_hash.as_slice())
+            .map_err(smt_store_error)?;
+        Ok(leaf.map(|leaf| LeafNode {
+            key: to_h256(&leaf[..32]),
+            value: leaf[32..].to_vec().into(),
+        }))
+    }
+
+    fn insert_branch(&mut self, node: H256, branch: BranchNode) -> Result<(), SMTError> {
+        let mut buf = Vec::with_capacity(97);
+        buf.push(branch.fork_height);
+        buf.extend_from_slice(branch.key.as_slice());
+        buf.extend_from_slice(branch.node.as_slice());
+        buf.extend_from_slice(branch.sibling.as_slice());
+        self.branches
+            .insert(node.as_slice(), buf)
+            .map_err(smt_store_error)?;
+        Ok(())
+    }
+
+    fn insert_leaf(&mut self, leaf_hash: H256, leaf: LeafNode<Value>) -> Result<(), SMTError> {
+        let mut buf = Vec::with_capacity(32 + leaf.value.as_slice().len());
+        buf.extend_from_slice(leaf.key.as_slice());
+        buf.extend_from_slice(leaf.value.as_slice());
+        self.leaves
+            .insert(leaf_hash.as_slice(), buf)
+            .map_err(smt_store_error)?;
+        Ok(())
+    }
+
+    // nodes are shared with the trees of previous roots, we keep them to support `Store::reset`
+
+    fn remove_branch(&mut self, _node: &H256) -> Result<(), SMTError> {
+        Ok(())
+    }
+
+    fn remove_leaf(&mut self, _leaf_hash: &H256) -> Result<(), SMTError> {
+        Ok(())
+    }
+}
+
+/// MMR elements of blocks
+#[derive(Clone)]
+pub struct BlockMMRStore(sled::Tree);
+
+impl MMRStore<[u8; 32]> for BlockMMRStore {
+    fn get_elem(&self, pos: u64) -> MMRResult<Option<[u8; 32]>> {
+        let elem = self
+            .0
+            .get(pos.to_be_bytes())
+            .map_err(|err| MMRError::StoreError(err.to_string()))?;
+        Ok(elem.map(|elem| {
+            let mut buf = [0u8; 32];
+            buf.copy_from_slice(&elem);
+            buf
+        }))
+    }
+
+    fn append(&mut self, pos: u64, elems: Vec<[u8; 32]>) -> MMRResult<()> {
+        for (i, elem) in elems.into_iter().enumerate() {
+            self.0
+                .insert((pos + i as u64).to_be_bytes(), &elem[..])
+                .map_err(|err| MMRError::StoreError(err.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+/// key of `Store::account_keys`: index | prefix | key
+fn account_key(index: Index, prefix: SMTPrefix, key: &[u8; 32]) -> Vec<u8> {
+    let mut buf = account_key_prefix(index, prefix);
+    buf.extend_from_slice(key);
+    buf
+}
+
+fn account_key_prefix(index: Index, prefix: SMTPrefix) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(41);
+    buf.extend_from_slice(&index.to_be_bytes());
+    buf.push(prefix as u8);
+    buf
+}
+
+fn pack_merkle_proof(tree: &AccountSMT, keys: Vec<H256>) -> Result<SMTProof, Error> {
+    let proof = tree.merkle_proof(keys)?;
+    let branches: Vec<([u8; 32], u8)> = proof
+        .proof()
+        .iter()
+        .map(|(node, height)| ((*node).into(), *height))
+        .collect();
+    let proof = SMTProof::new_builder()
+        .leaves_path(proof.leaves_path().to_owned().pack())
+        .proof(branches.pack())
+        .build();
+    Ok(proof)
+}
+
+/// value of `Store::tx_index`: block number | index in the block
+fn tx_location(number: u64, index: u32) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(12);
+    buf.extend_from_slice(&number.to_be_bytes());
+    buf.extend_from_slice(&index.to_be_bytes());
+    buf
+}
+
+/// value of `Store::unverified_blocks`: layer-1 block number | block's size | block | txs
+fn encode_unverified_block(unverified_block: &UnverifiedBlock) -> Result<Vec<u8>, Error> {
+    let block = unverified_block.block.as_slice();
+    let txs = TxVec::new_builder()
+        .set(unverified_block.txs.clone())
+        .build();
+    let mut buf = Vec::with_capacity(12 + block.len() + txs.as_slice().len());
+    buf.extend_from_slice(&unverified_block.l1_number.to_be_bytes());
+    let block_size = u32::try_from(block.len()).map_err(|_| ConversionError::Overflow)?;
+    buf.extend_from_slice(&block_size.to_be_bytes());
+    buf.extend_from_slice(block);
+    buf.extend_from_slice(txs.as_slice());
+    Ok(buf)
+}
+
+fn decode_unverified_block(buf: &[u8]) -> UnverifiedBlock {
+    let mut l1_number = [0u8; 8];
+    l1_number.copy_from_slice(&buf[..8]);
+    let mut block_size = [0u8; 4];
+    block_size.copy_from_slice(&buf[8..12]);
+    let block_end = 12 + u32::from_be_bytes(block_size) as usize;
+    UnverifiedBlock {
+        l1_number: u64::from_be_bytes(l1_number),
+        block: AgBlock::new_unchecked(buf[12..block_end].to_vec().into()),
+        txs: TxVec::new_unchecked(buf[block_end..].to_vec().into())
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// value of `Store::synced_blocks`: hash | global state | rollup cell's out point | output
+fn encode_synced_block(synced_block: &SyncedBlock) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&synced_block.hash);
+    buf.extend_from_slice(synced_block.global_state.as_slice());
+    if let Some(rollup_cell) = &synced_block.rollup_cell {
+        buf.extend_from_slice(rollup_cell.out_point.as_slice());
+        buf.extend_from_slice(rollup_cell.output.as_slice());
+    }
+    buf
+}
+
+fn decode_synced_block(number: u64, buf: &[u8]) -> SyncedBlock {
+    let global_state_end = 32 + GlobalState::TOTAL_SIZE;
+    let out_point_end = global_state_end + OutPoint::TOTAL_SIZE;
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&buf[..32]);
+    let global_state = GlobalState::new_unchecked(buf[32..global_state_end].to_vec().into());
+    let rollup_cell = if buf.len() > global_state_end {
+        Some(RollupCell {
+            out_point: OutPoint::new_unchecked(
+                buf[global_state_end..out_point_end].to_vec().into(),
+            ),
+            output: CellOutput::new_unchecked(buf[out_point_end..].to_vec().into()),
+        })
+    } else {
+        None
+    };
+    SyncedBlock {
+        number,
+        hash,
+        rollup_cell,
+        global_state,
+    }
+}
+
+pub struct Store {
+    db: sled::Db,
+    smt_store: SMTStore,
+    mmr_store: BlockMMRStore,
+    /// block number -> block
+    blocks: sled::Tree,
+    /// block number -> txs of the block
+    block_txs: sled::Tree,
+    /// block number -> global state after the block
+    block_states: sled::Tree,
+    /// tx hash -> location of the tx
+    tx_index: sled::Tree,
+    /// tokens, withdrawing tokens and storage keys of accounts.
+    /// It indexes the keys ever written under any account root and is never pruned,
+    /// so it stays valid after `reset` / `discard` to an older root; a key absent
+    /// from the current root reads as zero or an empty slot.
+    account_keys: sled::Tree,
+    /// hash -> storage value, account tree only keeps the hash.
+    /// Never pruned for the same reason as `account_keys`.
+    storage_values: sled::Tree,
+    /// tx hash -> BLS signature of a tx with empty witness in a generated block,
+    /// the tx is pushed back to the mempool with it if the block is orphaned
+    bls_signatures: sled::Tree,
+    /// layer-1 block number -> the rollup's state after the block
+    synced_blocks: sled::Tree,
+    /// block number -> unverified block
+    unverified_blocks: sled::Tree,
+    account_smt: AccountSMT,
+    account_count: u64,
+    block_count: u64,
+}
+
+impl Store {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let db = sled::open(path)?;
+        Self::from_db(db)
+    }
+
+    /// open a store which is removed on drop
+    pub fn open_temporary() -> Result<Self, Error> {
+        let db = sled::Config::new().temporary(true).open()?;
+        Self::from_db(db)
+    }
+
+    fn from_db(db: sled::Db) -> Result<Self, Error> {
+        let smt_store = SMTStore {
+            branches: db.open_tree("smt_branches")?,
+            leaves: db.open_tree("smt_leaves")?,
+        };
+        let mut store = Store {
+            account_smt: AccountSMT::new(H256::zero(), smt_store.clone()),
+            smt_store,
+            mmr_store: BlockMMRStore(db.open_tree("block_mmr")?),
+            blocks: db.open_tree("blocks")?,
+            block_txs: db.open_tree("block_txs")?,
+            block_states: db.open_tree("block_states")?,
+            tx_index: db.open_tree("tx_index")?,
+            account_keys: db.open_tree("account_keys")?,
+            storage_values: db.open_tree("storage_values")?,
+            bls_signatures: db.open_tree("bls_signatures")?,
+            synced_blocks: db.open_tree("synced_blocks")?,
+            unverified_blocks: db.open_tree("unverified_blocks")?,
+            account_count: 0,
+            block_count: 0,
+            db,
+        };
+        store.discard()?;
+        Ok(store)
+    }
+
+    /// the last committed global state
+    pub fn get_committed_global_state(&self) -> Result<GlobalState, Error> {
+        let global_state = match self.db.get(GLOBAL_STATE_KEY)? {
+            Some(global_state) => GlobalState::new_unchecked(global_state.to_vec().into()),
+            None => GlobalState::default(),
+        };
+        Ok(global_state)
+    }
+
+    /// the current global state, including uncommitted changes
+    pub fn get_global_state(&self) -> Result<GlobalState, Error> {
+        let global_state = GlobalState::new_builder()
+            .account_root(self.account_root().pack())
+            .block_root(self.block_root()?.pack())
+            .account_count(self.account_count.pack())
+            .block_count(self.block_count.pack())
+            .build();
+        Ok(global_state)
+    }
+
+    /// persist the current state
+    pub fn commit(&mut self) -> Result<(), Error> {
+        let global_state = self.get_global_state()?;
+        self.db.insert(GLOBAL_STATE_KEY, global_state.as_slice())?;
+        self.db.flush()?;
+        Ok(())
+    }
+
+    /// drop uncommitted changes
+    pub fn discard(&mut self) -> Result<(), Error> {
+        let global_state = self.get_committed_global_state()?;
+        self.reset(&global_state);
+        Ok(())
+    }
+
+    /// reset the current state to a previous global state, e.g. the state after a block,
+    /// blocks after `global_state.block_count` are dropped
+    pub fn reset(&mut self, global_state: &GlobalState) {
+        let account_root: [u8; 32] = global_state.account_root().unpack();
+        self.account_smt = self.account_tree(account_root);
+        self.account_count = global_state.account_count().unpack();
+        self.block_count = global_state.block_count().unpack();
+    }
+
+    pub fn account_root(&self) -> [u8; 32] {
+        (*self.account_smt.root()).into()
+    }
+
+    pub fn block_root(&self) -> Result<[u8; 32], Error> {
+        if self.block_count == 0 {
+            return Ok([0u8; 32]);
+        }
+        Ok(self.block_mmr().get_root()?)
+    }
+
+    pub fn account_count(&self) -> u64 {
+        self.account_count
+    }
+
+    pub fn block_count(&self) -> u64 {
+        self.block_count
+    }
+
+    fn block_mmr(&self) -> BlockMMR {
+        let mmr_size = if self.block_count == 0 {
+            0
+        } else {
+            leaf_index_to_mmr_size(self.block_count - 1)
+        };
+        MMR::new(mmr_size, self.mmr_store.clone())
+    }
+
+    // accounts
+
+    pub fn get_account(&self, index: Index) -> Result<Option<Account>, Error> {
+        let value = self.account_smt.get(&smt::account_index_key(index))?;
+        if value.is_zero() {
+            return Ok(None);
+        }
+        Ok(Some(value.into()))
+    }
+
+    pub fn get_balance(&self, index: Index, token_id: &TokenID) -> Result<u128, Error> {
+        let value = self.account_smt.get(&smt::token_id_key(index, token_id))?;
+        Ok(value.into())
+    }
+
+    /// withdrawing balance
+    pub fn get_withdraw(&self, index: Index, token_id: &TokenID) -> Result<u128, Error> {
+        let value = self.account_smt.get(&smt::withdraw_key(index, token_id))?;
+        Ok(value.into())
+    }
+
+    /// storage slot of a contract account, returns empty value for a missing slot
+    pub fn get_storage(&self, index: Index, key: &[u8; 32]) -> Result<Vec<u8>, Error> {
+        let value_hash = self.account_smt.get(&smt::storage_key(index, key))?;
+        if value_hash.is_zero() {
+            return Ok(Vec::new());
+        }
+        // the preimage is lost, an empty value would not match the account tree
+        let value = self
+            .storage_values
+            .get(value_hash.as_slice())?
+            .ok_or_else(|| {
+                Error::MissingStorageValue(smt::ValueTrait::to_h256(&value_hash).into())
+            })?;
+        Ok(value.to_vec())
+    }
+
+    /// tokens ever held by an account
+    pub fn get_account_tokens(&self, index: Index) -> Result<Vec<TokenID>, Error> {
+        self.get_account_keys(index, SMTPrefix::Token)
+    }
+
+    /// tokens ever withdrawn by an account
+    pub fn get_account_withdraw_tokens(&self, index: Index) -> Result<Vec<TokenID>, Error> {
+        self.get_account_keys(index, SMTPrefix::Withdraw)
+    }
+
+    /// storage keys ever written by a contract account
+    pub fn get_account_storage_keys(&self, index: Index) -> Result<Vec<[u8; 32]>, Error> {
+        self.get_account_keys(index, SMTPrefix::Store)
+    }
+
+    fn get_account_keys(&self, index: Index, prefix: SMTPrefix) -> Result<Vec<[u8; 32]>, Error> {
+        let prefix = account_key_prefix(index, prefix);
+        let mut keys = Vec::new();
+        for item in self.account_keys.scan_prefix(&prefix) {
+            let (account_key, _) = item?;
+            let mut key = [0u8; 32];
+            key.copy_from_slice(&account_key[prefix.len()..]);
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+
+    fn insert_account_key(
+        &self,
+        index: Index,
+        prefix: SMTPrefix,
+        key: &[u8; 32],
+    ) -> Result<(), Error> {
+        self.account_keys
+            .insert(account_key(index, prefix, key), &[][..])?;
+        Ok(())
+    }
+
+    /// insert a new account or update an existing account
+    pub fn update_account(&mut self, account: Account) -> Result<(), Error> {
+        let index: Index = account.index().unpack();
+        let key = smt::account_index_key(index);
+        if self.account_smt.get(&key)?.is_zero() {
+            self.account_count += 1;
+        }
+        self.account_smt.update(key, account.into())?;
+        Ok(())
+    }
+
+    pub fn update_balance(
+        &mut self,
+        index: Index,
+        token_id: TokenID,
+        balance: u128,
+    ) -> Result<(), Error> {
+        self.insert_account_key(index, SMTPrefix::Token, &token_id)?;
+        self.account_smt
+            .update(smt::token_id_key(index, &token_id), balance.into())?;
+        Ok(())
+    }
+
+    pub fn update_withdraw(
+        &mut self,
+        index: Index,
+        token_id: TokenID,
+        balance: u128,
+    ) -> Result<(), Error> {
+        self.insert_account_key(index, SMTPrefix::Withdraw, &token_id)?;
+        self.account_smt
+            .update(smt::withdraw_key(index, &token_id), balance.into())?;
+        Ok(())
+    }
+
+    /// update a storage slot, an empty value removes the slot
+    pub fn update_storage(
+        &mut self,
+        index: Index,
+        key: [u8; 32],
+        value: &[u8],
+    ) -> Result<(), Error> {
+        self.insert_account_key(index, SMTPrefix::Store, &key)?;
+        let value_hash = smt::storage_value(value);
+        if !value_hash.is_zero() {
+            self.storage_values.insert(value_hash.as_slice(), value)?;
+        }
+        self.account_smt
+            .update(smt::storage_key(index, &key), value_hash)?;
+        Ok(())
+    }
+
+    /// account tree of a previous account root, e.g. the root after a block
+    pub fn account_tree(&self, account_root: [u8; 32]) -> AccountSMT {
+        AccountSMT::new(account_root.into(), self.smt_store.clone())
+    }
+
+    /// merkle proof of SMT keys against the current account root
+    pub fn account_merkle_proof(&self, keys: Vec<H256>) -> Result<SMTProof, Error> {
+        pack_merkle_proof(&self.account_smt, keys)
+    }
+
+    /// merkle proof of SMT keys against a previous account root
+    pub fn account_merkle_proof_at(
+        &self,
+        account_root: [u8; 32],
+        keys: Vec<H256>,
+    ) -> Result<SMTProof, Error> {
+        pack_merkle_proof(&self.account_tree(account_root), keys)
+    }
+
+    // blocks
+
+    /// append a block and its txs,
+    /// the accounts must be updated to the block's `account_root` before pushing
+    pub fn push_block(&mut self, block: AgBlock, txs: Vec<Tx>) -> Result<(), Error> {
+        let number: u64 = block.number().unpack();
+        if number != self.block_count {
+            return Err(Error::InvalidBlockNumber(self.block_count, number));
+        }
+        let mut block_mmr = self.block_mmr();
+        block_mmr.push(block_hash(&block))?;
+        block_mmr.commit()?;
+        let key = number.to_be_bytes();
+        self.blocks.insert(key, block.as_slice())?;
+        for (i, tx) in txs.iter().enumerate() {
+            let tx_hash = tx_hash(&tx.as_reader());
+            let i = u32::try_from(i).map_err(|_| ConversionError::Overflow)?;
+            self.tx_index.insert(&tx_hash[..], tx_location(number, i))?;
+        }
+        let txs = TxVec::new_builder().set(txs).build();
+        self.block_txs.insert(key, txs.as_slice())?;
+        self.block_count += 1;
+        let global_state = self.get_global_state()?;
+        self.block_states.insert(key, global_state.as_slice())?;
+        Ok(())
+    }
+
+    /// block root after appending the block, the store is unchanged
+    pub fn next_block_root(&self, block: &AgBlock) -> Result<[u8; 32], Error> {
+        let mut block_mmr = self.block_mmr();
+        block_mmr.push(block_hash(block))?;
+        Ok(block_mmr.get_root()?)
+    }
+
+    pub fn get_block(&self, number: u64) -> Result<Option<AgBlock>, Error> {
+        if number >= self.block_count {
+            return Ok(None);
+        }
+        let block = self
+            .blocks
+            .get(number.to_be_bytes())?
+            .map(|block| AgBlock::new_unchecked(block.to_vec().into()));
+        Ok(block)
+    }
+
+    pub fn get_block_hash(&self, number: u64) -> Result<Option<[u8; 32]>, Error> {
+        if number >= self.block_count {
+            return Ok(None);
+        }
+        let block_hash = self.mmr_store.get_elem(leaf_index_to_pos(number))?;
+        Ok(block_hash)
+    }
+
+    pub fn get_block_txs(&self, number: u64) -> Result<Option<Vec<Tx>>, Error> {
+        if number >= self.block_count {
+            return Ok(None);
+        }
+        let txs = self.block_txs.get(number.to_be_bytes())?.map(|txs| {
+            TxVec::new_unchecked(txs.to_vec().into())
+                .into_iter()
+                .collect()
+        });
+        Ok(txs)
+    }
+
+    /// keep the BLS signature of a tx with empty witness, it's aggregated in the block
+    pub fn insert_bls_signature(
+        &self,
+        tx_hash: &[u8; 32],
+        signature: &[u8; 96],
+    ) -> Result<(), Error> {
+        self.bls_signatures.insert(&tx_hash[..], &signature[..])?;
+        Ok(())
+    }
+
+    pub fn get_bls_signature(&self, tx_hash: &[u8; 32]) -> Result<Option<[u8; 96]>, Error> {
+        let signature = self.bls_signatures.get(&tx_hash[..])?.map(|value| {
+            let mut signature = [0u8; 96];
+            signature.copy_from_slice(&value);
+            signature
+        });
+        Ok(signature)
+    }
+
+    /// global state after the block
+    pub fn get_block_global_state(&self, number: u64) -> Result<Option<GlobalState>, Error> {
+        if number >= self.block_count {
+            return Ok(None);
+        }
+        let global_state = self
+            .block_states
+            .get(number.to_be_bytes())?
+            .map(|global_state| GlobalState::new_unchecked(global_state.to_vec().into()));
+        Ok(global_state)
+    }
+
+    /// returns the tx, number of the block and index of the tx in the block
+    pub fn get_tx(&self, hash: &[u8; 32]) -> Result<Option<(Tx, u64, u32)>, Error> {
+        let location = match self.tx_index.get(&hash[..])? {
+            Some(location) => location,
+            None => return Ok(None),
+        };
+        let mut number = [0u8; 8];
+        number.copy_from_slice(&location[..8]);
+        let number = u64::from_be_bytes(number);
+        let mut index = [0u8; 4];
+        index.copy_from_slice(&location[8..]);
+        let index = u32::from_be_bytes(index);
+        // the block may be replaced after a reset
+        let tx = self
+            .get_block_txs(number)?
+            .and_then(|txs| txs.into_iter().nth(index as usize))
+            .filter(|tx| &tx_hash(&tx.as_reader()) == hash);
+        Ok(tx.map(|tx| (tx, number, index)))
+    }
+
+    /// merkle proof of a block against the current block root
+    pub fn block_merkle_proof(&self, number: u64) -> Result<Vec<[u8; 32]>, Error> {
+        let proof = self
+            .block_mmr()
+            .gen_proof(vec![leaf_index_to_pos(number)])?;
+        Ok(proof.proof_items().to_owned())
+    }
+
+    // layer-1 blocks
+
+    /// record a synced layer-1 block, it should be inserted with the state after the block
+    pub fn insert_synced_block(&mut self, synced_block: &SyncedBlock) -> Result<(), Error> {
+        self.synced_blocks.insert(
+            synced_block.number.to_be_bytes(),
+            encode_synced_block(synced_block),
+        )?;
+        Ok(())
+    }
+
+    pub fn get_synced_block(&self, number: u64) -> Result<Option<SyncedBlock>, Error> {
+        let synced_block = self
+            .synced_blocks
+            .get(number.to_be_bytes())?
+            .map(|buf| decode_synced_block(number, &buf));
+        Ok(synced_block)
+    }
+
+    /// remove records of layer-1 blocks from `number`, e.g. the blocks are orphaned by a reorg
+    pub fn remove_synced_blocks(&mut self, number: u64) -> Result<(), Error> {
+        let keys = self
+            .synced_blocks
+            .range(number.to_be_bytes()..)
+            .keys()
+            .collect::<Result<Vec<_>, _>>()?;
+        for key in keys {
+            self.synced_blocks.remove(key)?;
+        }
+        Ok(())
+    }
+
+    /// the last synced layer-1 block
+    pub fn get_last_synced_block(&self) -> Result<Option<SyncedBlock>, Error> {
+        let synced_block = match self.synced_blocks.last()? {
+            Some((key, buf)) => {
+                let mut number = [0u8; 8];
+                number.copy_from_slice(&key);
+                Some(decode_synced_block(u64::from_be_bytes(number), &buf))
+            }
+            None => None,
+        };
+        Ok(synced_block)
+    }
+
+    // unverified blocks
+
+    /// record a block which isn't applied, see `UnverifiedBlock`
+    pub fn insert_unverified_block(
+        &mut self,
+        unverified_block: &UnverifiedBlock,
+    ) -> Result<(), Error> {
+        let number: u64 = unverified_block.block.number().unpack();
+        self.unverified_blocks.insert(
+            number.to_be_bytes(),
+            encode_unverified_block(unverified_block)?,
+        )?;
+        Ok(())
+    }
+
+    /// unverified blocks in number order, the first one is the invalid block
+    pub fn get_unverified_blocks(&self) -> Result<Vec<UnverifiedBlock>, Error> {
+        let mut unverified_blocks = Vec::new();
+        for item in self.unverified_blocks.iter() {
+            let (_key, buf) = item?;
+            unverified_blocks.push(decode_unverified_block(&buf));
+        }
+        Ok(unverified_blocks)
+    }
+
+    /// the store is behind layer 1 until the invalid block is reverted
+    pub fn has_unverified_blocks(&self) -> bool {
+        !self.unverified_blocks.is_empty()
+    }
+
+    /// remove unverified blocks from block `number`, e.g. the blocks are reverted
+    pub fn remove_unverified_blocks(&mut self, number: u64) -> Result<(), Error> {
+        let keys = self
+            .unverified_blocks
+            .range(number.to_be_bytes()..)
+            .keys()
+            .collect::<Result<Vec<_>, _>>()?;
+        for key in keys {
+            self.unverified_blocks.remove(key)?;
+        }
+        Ok(())
+    }
+
+    /// remove unverified blocks submitted from layer-1 block `l1_number`,
+    /// e.g. the layer-1 blocks are orphaned by a reorg
+    pub fn remove_unverified_blocks_since(&mut self, l1_number: u64) -> Result<(), Error> {
+        for unverified_block in self.get_unverified_blocks()? {
+            if unverified_block.l1_number >= l1_number {
+                let number: u64 = unverified_block.block.number().unpack();
+                self.unverified_blocks.remove(number.to_be_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// merkle proof of a block against the block root on layer 1, which includes unverified blocks
+    pub fn unverified_block_merkle_proof(&self, number: u64) -> Result<Vec<[u8; 32]>, Error> {
+        let mut block_mmr = self.block_mmr();
+        for unverified_block in self.get_unverified_blocks()? {
+            block_mmr.push(block_hash(&unverified_block.block))?;
+        }
+        let proof = block_mmr.gen_proof(vec![leaf_index_to_pos(number)])?;
+        Ok(proof.proof_items().to_owned())
+    }
+}
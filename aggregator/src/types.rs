@@ -0,0 +1,37 @@
+use godwoken_types::packed::{AgBlock, CellOutput, GlobalState, OutPoint, Transaction, Tx};
+
+/// The live cell of the main contract, its data is the rollup's `GlobalState`
+#[derive(Debug, Clone)]
+pub struct RollupCell {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+}
+
+/// A layer-1 block
+#[derive(Debug, Clone)]
+pub struct L1Block {
+    pub number: u64,
+    pub hash: [u8; 32],
+    pub parent_hash: [u8; 32],
+    pub transactions: Vec<Transaction>,
+}
+
+/// The rollup's state after a synced layer-1 block
+#[derive(Debug, Clone)]
+pub struct SyncedBlock {
+    pub number: u64,
+    pub hash: [u8; 32],
+    /// `None` before the main contract is deployed
+    pub rollup_cell: Option<RollupCell>,
+    pub global_state: GlobalState,
+}
+
+/// A layer-2 block committed on layer 1 which isn't applied to the store,
+/// the block is invalid or it's built on an invalid block
+#[derive(Debug, Clone)]
+pub struct UnverifiedBlock {
+    /// number of the layer-1 block which contains the `SubmitBlock` action
+    pub l1_number: u64,
+    pub block: AgBlock,
+    pub txs: Vec<Tx>,
+}
@@ -0,0 +1,343 @@
+//! Validator
+//!
+//! Watches blocks synced by the `ChainFollower`, the first unverified block of the store
+//! is an invalid block. The validator creates a challenge cell of the invalid block,
+//! then reverts the block with the challenge cell after `CHALLENGE_CELL_WAIT_BLOCKS`.
+//! The challenge tx is sent again if it isn't committed after `resend_blocks`.
+//! The main contract only reverts the last block, so the revert fails with
+//! `BlocksAfterInvalidBlock` while blocks are submitted after the invalid block.
+
+use crate::{
+    chain::{load_global_state, transaction_hash, ChainClient},
+    error::Error,
+    store::{AccountSMT, Store},
+    types::{RollupCell, UnverifiedBlock},
+};
+use godwoken_tx_builder::{
+    challenge::{challenge_context, challenge_type_script},
+    constants::SINCE_RELATIVE_FLAG,
+    witness::{action_witness, challenge_unlock_witness, output_type_witness, pack_proof_items},
+};
+use godwoken_types::{
+    bytes::Bytes,
+    cache::KVMap,
+    core::{Index, RollupID, CHALLENGE_CELL_WAIT_BLOCKS, CHALLENGE_REWARD_RATE, CKB_TOKEN_ID},
+    packed::*,
+    prelude::*,
+};
+use godwoken_utils::{
+    hash::{blake2b_256, block_hash},
+    mmr::compute_block_root,
+    smt,
+};
+use std::sync::Arc;
+
+/// Pays for the layer-1 txs of the validator
+pub trait Wallet {
+    /// Add inputs for the outputs' capacity and the fee, a change output and signatures.
+    /// Existing inputs, outputs and the `output_type` of witnesses must be kept in place.
+    fn complete_transaction(&self, tx: Transaction) -> Result<Transaction, Error>;
+}
+
+impl<W: Wallet + ?Sized> Wallet for Arc<W> {
+    fn complete_transaction(&self, tx: Transaction) -> Result<Transaction, Error> {
+        self.as_ref().complete_transaction(tx)
+    }
+}
+
+pub struct ValidatorConfig {
+    /// account to receive the challenge reward
+    pub challenger_index: Index,
+    /// capacity of the challenge cell, it's the bond of the challenge
+    pub challenge_capacity: u64,
+    /// lock of the challenge cell
+    pub challenge_lock: Script,
+    /// code hash of the challenge contract
+    pub challenge_code_hash: [u8; 32],
+    pub challenge_hash_type: u8,
+    /// lock hash of the cell which withdraws the challenge cell
+    pub withdraw_lock_hash: [u8; 32],
+    /// cell deps of the layer-1 txs, e.g. the main contract's and the challenge contract's code
+    pub cell_deps: Vec<CellDep>,
+    /// layer-1 blocks to wait before the challenge tx is sent again if it isn't committed
+    pub resend_blocks: u64,
+}
+
+/// A challenge cell created by the validator
+#[derive(Debug, Clone)]
+pub struct Challenge {
+    /// number of the challenged block
+    pub number: u64,
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    /// `ChallengeContext` of the block
+    pub data: Bytes,
+    /// the signed challenge tx, it's sent again if it's dropped from the layer-1 pool
+    pub transaction: Transaction,
+    /// layer-1 tip number when the challenge tx is sent
+    pub sent_at: u64,
+    /// number of the layer-1 block which commits the challenge cell
+    pub committed_number: Option<u64>,
+    /// the rollup cell consumed by the sent revert tx
+    pub reverting_rollup_cell: Option<OutPoint>,
+}
+
+pub struct Validator<C, W> {
+    client: C,
+    wallet: W,
+    rollup_id: RollupID,
+    config: ValidatorConfig,
+    challenge: Option<Challenge>,
+}
+
+impl<C: ChainClient, W: Wallet> Validator<C, W> {
+    pub fn new(client: C, wallet: W, rollup_id: RollupID, config: ValidatorConfig) -> Self {
+        Validator {
+            client,
+            wallet,
+            rollup_id,
+            config,
+            challenge: None,
+        }
+    }
+
+    /// the challenge of the current invalid block
+    pub fn challenge(&self) -> Option<&Challenge> {
+        self.challenge.as_ref()
+    }
+
+    /// Challenge the invalid block, or revert it once the challenge cell is ready.
+    /// It should be called after each sync, returns the hash of the sent layer-1 tx.
+    pub fn process(
+        &mut self,
+        store: &Store,
+        rollup_cell: &RollupCell,
+    ) -> Result<Option<[u8; 32]>, Error> {
+        let unverified_blocks = store.get_unverified_blocks()?;
+        let invalid_block = match unverified_blocks.first() {
+            Some(invalid_block) => invalid_block,
+            None => {
+                self.challenge = None;
+                return Ok(None);
+            }
+        };
+        let number: u64 = invalid_block.block.number().unpack();
+        let mut challenge = match self.challenge.clone() {
+            Some(challenge) if challenge.number == number => challenge,
+            // the challenged block is reverted or orphaned
+            _ => {
+                let (challenge, tx_hash) = self.send_challenge(invalid_block)?;
+                self.challenge = Some(challenge);
+                return Ok(Some(tx_hash));
+            }
+        };
+        let result = self.try_revert(store, rollup_cell, &unverified_blocks, &mut challenge);
+        self.challenge = Some(challenge);
+        result
+    }
+
+    fn try_revert(
+        &self,
+        store: &Store,
+        rollup_cell: &RollupCell,
+        unverified_blocks: &[UnverifiedBlock],
+        challenge: &mut Challenge,
+    ) -> Result<Option<[u8; 32]>, Error> {
+        if challenge.committed_number.is_none() {
+            let tx_hash: [u8; 32] = challenge.out_point.tx_hash().unpack();
+            challenge.committed_number = self.client.get_transaction_block_number(&tx_hash)?;
+        }
+        let tip_number = self.client.tip_number()?;
+        let committed_number = match challenge.committed_number {
+            Some(committed_number) => committed_number,
+            // the same tx is sent again, a new one would create another challenge cell
+            None if tip_number >= challenge.sent_at + self.config.resend_blocks => {
+                let tx_hash = self.client.send_transaction(&challenge.transaction)?;
+                challenge.sent_at = tip_number;
+                return Ok(Some(tx_hash));
+            }
+            None => return Ok(None),
+        };
+        if tip_number < committed_number + CHALLENGE_CELL_WAIT_BLOCKS {
+            return Ok(None);
+        }
+        if unverified_blocks.len() > 1 {
+            return Err(Error::BlocksAfterInvalidBlock(
+                challenge.number,
+                unverified_blocks.len() - 1,
+            ));
+        }
+        if let Some(out_point) = &challenge.reverting_rollup_cell {
+            if out_point.as_slice() == rollup_cell.out_point.as_slice() {
+                return Ok(None);
+            }
+        }
+        let tx =
+            self.build_revert_transaction(store, rollup_cell, &unverified_blocks[0], challenge)?;
+        let tx_hash = self
+            .client
+            .send_transaction(&self.wallet.complete_transaction(tx)?)?;
+        challenge.reverting_rollup_cell = Some(rollup_cell.out_point.clone());
+        Ok(Some(tx_hash))
+    }
+
+    /// send a tx which creates the challenge cell as the first output
+    fn send_challenge(
+        &self,
+        invalid_block: &UnverifiedBlock,
+    ) -> Result<(Challenge, [u8; 32]), Error> {
+        let number: u64 = invalid_block.block.number().unpack();
+        let (context, proof) = challenge_context(
+            invalid_block.block.clone(),
+            invalid_block.txs.clone(),
+            self.config.challenger_index,
+        )?;
+        let type_script = challenge_type_script(
+            self.config.challenge_code_hash,
+            self.config.challenge_hash_type,
+            self.rollup_id,
+            self.config.withdraw_lock_hash,
+        );
+        let output = CellOutput::new_builder()
+            .capacity(self.config.challenge_capacity.pack())
+            .lock(self.config.challenge_lock.clone())
+            .type_(Some(type_script).pack())
+            .build();
+        let witness = output_type_witness(proof.as_bytes());
+        let raw = RawTransaction::new_builder()
+            .cell_deps(self.config.cell_deps.clone().pack())
+            .outputs(vec![output.clone()].pack())
+            .outputs_data(vec![context.as_bytes().pack()].pack())
+            .build();
+        let tx = Transaction::new_builder()
+            .raw(raw)
+            .witnesses(vec![witness.pack()].pack())
+            .build();
+        let tx = self.wallet.complete_transaction(tx)?;
+        let sent_at = self.client.tip_number()?;
+        let tx_hash = self.client.send_transaction(&tx)?;
+        let challenge = Challenge {
+            number,
+            out_point: OutPoint::new_builder()
+                .tx_hash(transaction_hash(&tx).pack())
+                .index(0u32.pack())
+                .build(),
+            output,
+            data: context.as_bytes(),
+            transaction: tx,
+            sent_at,
+            committed_number: None,
+            reverting_rollup_cell: None,
+        };
+        Ok((challenge, tx_hash))
+    }
+
+    /// The rollup cell and the challenge cell are the inputs,
+    /// the challenge cell is kept in the outputs for the challenge contract.
+    fn build_revert_transaction(
+        &self,
+        store: &Store,
+        rollup_cell: &RollupCell,
+        invalid_block: &UnverifiedBlock,
+        challenge: &Challenge,
+    ) -> Result<Transaction, Error> {
+        let block = &invalid_block.block;
+        let number: u64 = block.number().unpack();
+        let ag_index: Index = block.ag_index().unpack();
+        let challenger_index = self.config.challenger_index;
+        let prev_account_root: [u8; 32] = block.prev_account_root().unpack();
+
+        // accounts before the block
+        let mut tree = store.account_tree(prev_account_root);
+        let (ag_account, ag_balance) = load_account(&tree, ag_index)?;
+        let (challenger_account, challenger_balance) = load_account(&tree, challenger_index)?;
+        let accounts_proof = store.account_merkle_proof_at(
+            prev_account_root,
+            vec![
+                smt::account_index_key(ag_index),
+                smt::token_id_key(ag_index, &CKB_TOKEN_ID),
+                smt::account_index_key(challenger_index),
+                smt::token_id_key(challenger_index, &CKB_TOKEN_ID),
+            ],
+        )?;
+        let mut ag_kv = KVMap::default();
+        ag_kv.insert(CKB_TOKEN_ID, ag_balance);
+        let mut challenger_kv = KVMap::default();
+        challenger_kv.insert(CKB_TOKEN_ID, challenger_balance);
+
+        // the aggregator is penalized the same as the main contract
+        let reward = ag_balance.saturating_mul(CHALLENGE_REWARD_RATE.0) / CHALLENGE_REWARD_RATE.1;
+        tree.update(smt::token_id_key(ag_index, &CKB_TOKEN_ID), 0u128.into())?;
+        tree.update(
+            smt::token_id_key(challenger_index, &CKB_TOKEN_ID),
+            challenger_balance.saturating_add(reward).into(),
+        )?;
+        let reverted_account_root: [u8; 32] = (*tree.root()).into();
+        let reverted_block =
+            AgBlock::new_reverted_block(block.as_reader(), reverted_account_root, challenger_index);
+        let block_proof = store.unverified_block_merkle_proof(number)?;
+        let reverted_block_root = compute_block_root(
+            vec![(number as usize, block_hash(&reverted_block))],
+            number + 1,
+            block_proof.clone(),
+        )?;
+        let global_state = self
+            .load_rollup_global_state(rollup_cell)?
+            .as_builder()
+            .account_root(reverted_account_root.pack())
+            .block_root(reverted_block_root.pack())
+            .build();
+
+        let revert_block = RevertBlock::new_builder()
+            .challenge_cell_data_hash(blake2b_256(&challenge.data).pack())
+            .block_proof(pack_proof_items(block_proof))
+            .ag_account(ag_account)
+            .challenger_account(challenger_account)
+            .aggregator_token_kv(ag_kv.pack())
+            .challenger_token_kv(challenger_kv.pack())
+            .accounts_proof(accounts_proof)
+            .build();
+        let rollup_witness = action_witness(revert_block);
+        let challenge_witness = challenge_unlock_witness(RevertBlockWithChallenge::default());
+        let inputs = vec![
+            CellInput::new_builder()
+                .previous_output(rollup_cell.out_point.clone())
+                .build(),
+            CellInput::new_builder()
+                .since((SINCE_RELATIVE_FLAG | CHALLENGE_CELL_WAIT_BLOCKS).pack())
+                .previous_output(challenge.out_point.clone())
+                .build(),
+        ];
+        let raw = RawTransaction::new_builder()
+            .cell_deps(self.config.cell_deps.clone().pack())
+            .inputs(inputs.pack())
+            .outputs(vec![rollup_cell.output.clone(), challenge.output.clone()].pack())
+            .outputs_data(vec![global_state.as_bytes().pack(), challenge.data.pack()].pack())
+            .build();
+        Ok(Transaction::new_builder()
+            .raw(raw)
+            .witnesses(vec![rollup_witness.pack(), challenge_witness.pack()].pack())
+            .build())
+    }
+
+    /// the global state on layer 1, it differs from the store's after the invalid block
+    fn load_rollup_global_state(&self, rollup_cell: &RollupCell) -> Result<GlobalState, Error> {
+        let tx_hash: [u8; 32] = rollup_cell.out_point.tx_hash().unpack();
+        let index: u32 = rollup_cell.out_point.index().unpack();
+        let tx = self
+            .client
+            .get_transaction(&tx_hash)?
+            .ok_or_else(|| Error::Chain(format!("missing tx 0x{}", hex::encode(tx_hash))))?;
+        Ok(load_global_state(&tx, index as usize))
+    }
+}
+
+/// account and its CKB balance
+fn load_account(tree: &AccountSMT, index: Index) -> Result<(Account, u128), Error> {
+    let value = tree.get(&smt::account_index_key(index))?;
+    if value.is_zero() {
+        return Err(Error::MissingAccount(index));
+    }
+    let balance = tree.get(&smt::token_id_key(index, &CKB_TOKEN_ID))?.into();
+    Ok((value.into(), balance))
+}
@@ -0,0 +1,234 @@
+//! Wallet of the daemon
+//!
+//! Pays the capacity and the fee of the layer-1 txs with cells of the secp256k1
+//! blake160 sighash-all lock, i.e. CKB's default lock, and signs the inputs of the lock.
+//! Live cells of the lock are queried from the node's indexer.
+
+use crate::{
+    chain::{transaction_hash, ChainClient},
+    error::Error,
+    validator::Wallet,
+};
+use godwoken_tx_builder::{provider::LiveCell, skeleton::TransactionSkeleton};
+use godwoken_types::{bytes::Bytes, packed::*, prelude::*};
+use godwoken_utils::hash::{blake2b_256, new_blake2b};
+use secp256k1::{Message, PublicKey, SecretKey};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// cells spent by a completed tx are reused after the timeout if the tx isn't committed
+const SPENT_CELL_TIMEOUT: Duration = Duration::from_secs(300);
+const SIGNATURE_SIZE: usize = 65;
+
+/// Live cells on layer 1
+pub trait CellCollector {
+    /// live cells locked by `lock`
+    fn get_live_cells(&self, lock: &Script) -> Result<Vec<LiveCell>, Error>;
+}
+
+impl<C: CellCollector + ?Sized> CellCollector for Arc<C> {
+    fn get_live_cells(&self, lock: &Script) -> Result<Vec<LiveCell>, Error> {
+        self.as_ref().get_live_cells(lock)
+    }
+}
+
+pub struct WalletConfig {
+    /// code hash of the secp256k1 blake160 sighash-all lock
+    pub lock_code_hash: [u8; 32],
+    pub lock_hash_type: u8,
+    /// cell deps of the lock, e.g. the secp256k1 dep group
+    pub cell_deps: Vec<CellDep>,
+    /// fee rate in shannons per 1000 bytes
+    pub fee_rate: u64,
+}
+
+/// A wallet of the secp256k1 blake160 sighash-all lock
+///
+/// Cells without a type script and data are payment cells. The first witness of the
+/// lock's inputs must be a `WitnessArgs`, so the lock must differ from the challenge
+/// cell's lock, whose witness is a `ChallengeUnlock`.
+pub struct Secp256k1Wallet<C> {
+    client: C,
+    privkey: SecretKey,
+    lock: Script,
+    config: WalletConfig,
+    /// out points spent by completed txs, they may not be committed yet
+    spent_cells: Mutex<HashMap<Vec<u8>, Instant>>,
+}
+
+impl<C: ChainClient + CellCollector> Secp256k1Wallet<C> {
+    pub fn new(client: C, privkey: SecretKey, config: WalletConfig) -> Self {
+        let pubkey = PublicKey::from_secret_key(&privkey);
+        let pubkey_hash = blake2b_256(&pubkey.serialize_compressed());
+        let lock = Script::new_builder()
+            .code_hash(config.lock_code_hash.pack())
+            .hash_type(config.lock_hash_type.into())
+            .args(Bytes::from(pubkey_hash[..20].to_vec()).pack())
+            .build();
+        Secp256k1Wallet {
+            client,
+            privkey,
+            lock,
+            config,
+            spent_cells: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// lock of the payment cells and the change output
+    pub fn lock(&self) -> &Script {
+        &self.lock
+    }
+
+    /// the cell of an input, it's a committed output on layer 1
+    fn load_cell_output(&self, out_point: &OutPoint) -> Result<CellOutput, Error> {
+        let tx_hash: [u8; 32] = out_point.tx_hash().unpack();
+        let index: u32 = out_point.index().unpack();
+        self.client
+            .get_transaction(&tx_hash)?
+            .and_then(|tx| tx.raw().outputs().get(index as usize))
+            .ok_or_else(|| {
+                Error::Chain(format!("missing cell 0x{}:{}", hex::encode(tx_hash), index))
+            })
+    }
+
+    /// live payment cells which aren't spent by a pending tx
+    fn payment_cells(&self) -> Result<Vec<LiveCell>, Error> {
+        let mut spent_cells = self.spent_cells.lock().expect("lock spent cells");
+        spent_cells.retain(|_, spent_at| spent_at.elapsed() < SPENT_CELL_TIMEOUT);
+        let cells = self
+            .client
+            .get_live_cells(&self.lock)?
+            .into_iter()
+            .filter(|cell| {
+                cell.output.type_().to_opt().is_none()
+                    && cell.data.is_empty()
+                    && !spent_cells.contains_key(cell.out_point.as_slice())
+            })
+            .collect();
+        Ok(cells)
+    }
+
+    /// Sign the inputs of the lock with sighash-all,
+    /// the signature is put in the `lock` of the group's first witness.
+    fn sign(&self, tx: Transaction, group: &[usize]) -> Result<Transaction, Error> {
+        let first_index = match group.first() {
+            Some(&first_index) => first_index,
+            None => return Ok(tx),
+        };
+        let mut witnesses: Vec<Bytes> = tx.witnesses().unpack();
+        if witnesses.len() <= first_index {
+            witnesses.resize(first_index + 1, Bytes::new());
+        }
+        let witness_args = if witnesses[first_index].is_empty() {
+            WitnessArgs::default()
+        } else {
+            WitnessArgs::from_slice(&witnesses[first_index])
+                .map_err(|_| Error::Chain(format!("invalid witness {}", first_index)))?
+        };
+        let zero_lock = Bytes::from(vec![0u8; SIGNATURE_SIZE]);
+        let witness = witness_args
+            .clone()
+            .as_builder()
+            .lock(BytesOpt::new_builder().set(Some(zero_lock.pack())).build())
+            .build()
+            .as_bytes();
+
+        let mut hasher = new_blake2b();
+        hasher.update(&transaction_hash(&tx));
+        hasher.update(&(witness.len() as u64).to_le_bytes());
+        hasher.update(&witness);
+        // the lock script stops at the first input without a witness
+        let input_count = tx.raw().inputs().len();
+        let group_witnesses = group[1..]
+            .iter()
+            .take_while(|&&index| index < witnesses.len())
+            .map(|&index| &witnesses[index]);
+        let extra_witnesses = witnesses.iter().skip(input_count);
+        for witness in group_witnesses.chain(extra_witnesses) {
+            hasher.update(&(witness.len() as u64).to_le_bytes());
+            hasher.update(witness);
+        }
+        let mut message = [0u8; 32];
+        hasher.finalize(&mut message);
+
+        let (signature, recovery_id) = secp256k1::sign(&Message::parse(&message), &self.privkey);
+        let mut sig = [0u8; SIGNATURE_SIZE];
+        sig[..64].copy_from_slice(&signature.serialize());
+        sig[64] = recovery_id.serialize();
+        witnesses[first_index] = witness_args
+            .as_builder()
+            .lock(
+                BytesOpt::new_builder()
+                    .set(Some(Bytes::from(sig.to_vec()).pack()))
+                    .build(),
+            )
+            .build()
+            .as_bytes();
+        Ok(tx.as_builder().witnesses(witnesses.pack()).build())
+    }
+}
+
+impl<C: ChainClient + CellCollector> Wallet for Secp256k1Wallet<C> {
+    fn complete_transaction(&self, tx: Transaction) -> Result<Transaction, Error> {
+        let raw = tx.raw();
+        let mut skeleton = TransactionSkeleton::new(raw.cell_deps().into_iter().collect());
+        for cell_dep in &self.config.cell_deps {
+            if !skeleton
+                .cell_deps
+                .iter()
+                .any(|dep| dep.as_slice() == cell_dep.as_slice())
+            {
+                skeleton.cell_deps.push(cell_dep.clone());
+            }
+        }
+        // inputs of the lock are signed besides the payment cells
+        let mut group = Vec::new();
+        for (index, input) in raw.inputs().into_iter().enumerate() {
+            let output = self.load_cell_output(&input.previous_output())?;
+            if output.lock().as_slice() == self.lock.as_slice() {
+                group.push(index);
+            }
+            skeleton.inputs.push((input, output.capacity().unpack()));
+        }
+        for (output, data) in raw
+            .outputs()
+            .into_iter()
+            .zip(raw.outputs_data().into_iter())
+        {
+            skeleton.output(output, data.unpack());
+        }
+        for witness in tx.witnesses().into_iter() {
+            skeleton.witness(witness.unpack());
+        }
+
+        let input_count = skeleton.inputs.len();
+        let completed =
+            skeleton.complete_with_lock(self.payment_cells()?, &self.lock, self.config.fee_rate)?;
+        let completed_raw = completed.raw();
+        let completed = completed
+            .as_builder()
+            .raw(
+                completed_raw
+                    .clone()
+                    .as_builder()
+                    .version(raw.version())
+                    .header_deps(raw.header_deps())
+                    .build(),
+            )
+            .build();
+        let payment_inputs: Vec<CellInput> = completed_raw
+            .inputs()
+            .into_iter()
+            .skip(input_count)
+            .collect();
+        group.extend(input_count..input_count + payment_inputs.len());
+        let signed = self.sign(completed, &group)?;
+
+        let mut spent_cells = self.spent_cells.lock().expect("lock spent cells");
+        for input in payment_inputs {
+            spent_cells.insert(input.previous_output().as_slice().to_vec(), Instant::now());
+        }
+        Ok(signed)
+    }
+}
@@ -0,0 +1,222 @@
+//! In-process layer-1 chain for the aggregator tests
+
+use godwoken_aggregator::{
+    chain::{script_hash, transaction_hash, ChainClient},
+    error::Error,
+    types::{L1Block, RollupCell},
+    wallet::CellCollector,
+};
+use godwoken_tx_builder::provider::LiveCell;
+use godwoken_types::{bytes::Bytes, packed::*, prelude::*};
+use godwoken_utils::hash::new_blake2b;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+pub fn rollup_type_script() -> Script {
+    Script::new_builder().code_hash([1u8; 32].pack()).build()
+}
+
+pub fn rollup_id() -> [u8; 32] {
+    script_hash(&rollup_type_script())
+}
+
+/// deploy the main contract with the initial global state
+pub fn deploy_tx(capacity: u64) -> Transaction {
+    let output = CellOutput::new_builder()
+        .capacity(capacity.pack())
+        .type_(Some(rollup_type_script()).pack())
+        .build();
+    let raw = RawTransaction::new_builder()
+        .outputs(vec![output].pack())
+        .outputs_data(vec![GlobalState::default().as_bytes().pack()].pack())
+        .build();
+    Transaction::new_builder().raw(raw).build()
+}
+
+/// update the rollup cell with an action, `inputs` are consumed besides the rollup cell
+pub fn rollup_tx(
+    rollup_cell: &RollupCell,
+    action: Action,
+    capacity: u64,
+    global_state: &GlobalState,
+    inputs: Vec<OutPoint>,
+) -> Transaction {
+    let witness = WitnessArgs::new_builder()
+        .output_type(
+            BytesOpt::new_builder()
+                .set(Some(action.as_bytes().pack()))
+                .build(),
+        )
+        .build();
+    let inputs: Vec<CellInput> = Some(rollup_cell.out_point.clone())
+        .into_iter()
+        .chain(inputs)
+        .map(|out_point| CellInput::new_builder().previous_output(out_point).build())
+        .collect();
+    let output = rollup_cell
+        .output
+        .clone()
+        .as_builder()
+        .capacity(capacity.pack())
+        .build();
+    let raw = RawTransaction::new_builder()
+        .inputs(inputs.pack())
+        .outputs(vec![output].pack())
+        .outputs_data(vec![global_state.as_bytes().pack()].pack())
+        .build();
+    Transaction::new_builder()
+        .raw(raw)
+        .witnesses(vec![witness.as_bytes().pack()].pack())
+        .build()
+}
+
+/// a tx creates a cell with `data`, returns the tx and the cell's out point
+pub fn cell_tx(data: Bytes) -> (Transaction, OutPoint) {
+    let output = CellOutput::new_builder()
+        .capacity((data.len() as u64).pack())
+        .build();
+    let raw = RawTransaction::new_builder()
+        .outputs(vec![output].pack())
+        .outputs_data(vec![data.pack()].pack())
+        .build();
+    let tx = Transaction::new_builder().raw(raw).build();
+    let out_point = OutPoint::new_builder()
+        .tx_hash(transaction_hash(&tx).pack())
+        .index(0u32.pack())
+        .build();
+    (tx, out_point)
+}
+
+#[derive(Default)]
+pub struct MockChain {
+    blocks: Mutex<Vec<L1Block>>,
+    txs: Mutex<HashMap<[u8; 32], Transaction>>,
+    /// txs sent to the pool, they are committed by `commit_sent_txs`
+    sent_txs: Mutex<Vec<Transaction>>,
+    /// distinguishes blocks of different forks
+    nonce: Mutex<u64>,
+}
+
+impl MockChain {
+    /// a chain with the genesis block
+    pub fn new() -> Self {
+        let chain = MockChain::default();
+        chain.push_block(Vec::new());
+        chain
+    }
+
+    /// append a block on the tip, returns the block number
+    pub fn push_block(&self, transactions: Vec<Transaction>) -> u64 {
+        let mut blocks = self.blocks.lock().expect("lock");
+        let (number, parent_hash) = match blocks.last() {
+            Some(parent) => (parent.number + 1, parent.hash),
+            None => (0, [0u8; 32]),
+        };
+        let mut nonce = self.nonce.lock().expect("lock");
+        *nonce += 1;
+        let mut hasher = new_blake2b();
+        hasher.update(&parent_hash);
+        hasher.update(&nonce.to_le_bytes());
+        let mut hash = [0u8; 32];
+        hasher.finalize(&mut hash);
+        let mut txs = self.txs.lock().expect("lock");
+        for tx in &transactions {
+            txs.insert(transaction_hash(tx), tx.clone());
+        }
+        blocks.push(L1Block {
+            number,
+            hash,
+            parent_hash,
+            transactions,
+        });
+        number
+    }
+
+    /// push a block with the sent txs, returns the block number
+    pub fn commit_sent_txs(&self) -> u64 {
+        let txs = std::mem::replace(&mut *self.sent_txs.lock().expect("lock"), Vec::new());
+        self.push_block(txs)
+    }
+
+    /// drop the sent txs as if they were evicted from the pool
+    pub fn drop_sent_txs(&self) {
+        self.sent_txs.lock().expect("lock").clear();
+    }
+
+    /// drop blocks after `number`, following blocks are pushed on a new fork
+    pub fn fork(&self, number: u64) {
+        self.blocks
+            .lock()
+            .expect("lock")
+            .truncate(number as usize + 1);
+    }
+}
+
+impl ChainClient for MockChain {
+    fn tip_number(&self) -> Result<u64, Error> {
+        Ok(self.blocks.lock().expect("lock").len() as u64 - 1)
+    }
+
+    fn get_block(&self, number: u64) -> Result<Option<L1Block>, Error> {
+        Ok(self
+            .blocks
+            .lock()
+            .expect("lock")
+            .get(number as usize)
+            .cloned())
+    }
+
+    fn get_transaction(&self, tx_hash: &[u8; 32]) -> Result<Option<Transaction>, Error> {
+        Ok(self.txs.lock().expect("lock").get(tx_hash).cloned())
+    }
+
+    fn get_transaction_block_number(&self, tx_hash: &[u8; 32]) -> Result<Option<u64>, Error> {
+        let number = self
+            .blocks
+            .lock()
+            .expect("lock")
+            .iter()
+            .find(|block| {
+                block
+                    .transactions
+                    .iter()
+                    .any(|tx| &transaction_hash(tx) == tx_hash)
+            })
+            .map(|block| block.number);
+        Ok(number)
+    }
+
+    fn send_transaction(&self, tx: &Transaction) -> Result<[u8; 32], Error> {
+        self.sent_txs.lock().expect("lock").push(tx.clone());
+        Ok(transaction_hash(tx))
+    }
+}
+
+impl CellCollector for MockChain {
+    fn get_live_cells(&self, lock: &Script) -> Result<Vec<LiveCell>, Error> {
+        let blocks = self.blocks.lock().expect("lock");
+        let mut cells: Vec<LiveCell> = Vec::new();
+        for tx in blocks.iter().flat_map(|block| block.transactions.iter()) {
+            let raw = tx.raw();
+            for input in raw.inputs().into_iter() {
+                let out_point = input.previous_output();
+                cells.retain(|cell| cell.out_point.as_slice() != out_point.as_slice());
+            }
+            let tx_hash = transaction_hash(tx);
+            for (index, output) in raw.outputs().into_iter().enumerate() {
+                if output.lock().as_slice() != lock.as_slice() {
+                    continue;
+                }
+                cells.push(LiveCell {
+                    out_point: OutPoint::new_builder()
+                        .tx_hash(tx_hash.pack())
+                        .index((index as u32).pack())
+                        .build(),
+                    output,
+                    data: raw.outputs_data().get(index).expect("data").unpack(),
+                });
+            }
+        }
+        Ok(cells)
+    }
+}
@@ -0,0 +1,34 @@
+pub mod mock_chain;
+mod test_chain;
+mod test_config;
+mod test_generator;
+mod test_mempool;
+mod test_responder;
+mod test_rpc;
+mod test_store;
+mod test_validator;
+mod test_wallet;
+
+use crate::tests::utils::constants::CKB_TOKEN_ID;
+use godwoken_aggregator::store::Store;
+use godwoken_types::{core::Index, packed::Account, prelude::*};
+
+pub const ROLLUP_ID: [u8; 32] = [42u8; 32];
+
+/// open a temporary store with accounts of (pubkey_hash, CKB balance)
+pub fn prepare_store(accounts: Vec<([u8; 20], u128)>) -> Store {
+    let mut store = Store::open_temporary().expect("open");
+    for (index, (pubkey_hash, balance)) in accounts.into_iter().enumerate() {
+        let index = index as Index;
+        let account = Account::new_builder()
+            .index(index.pack())
+            .pubkey_hash(pubkey_hash.pack())
+            .build();
+        store.update_account(account).expect("update account");
+        store
+            .update_balance(index, CKB_TOKEN_ID, balance)
+            .expect("update balance");
+    }
+    store.commit().expect("commit");
+    store
+}
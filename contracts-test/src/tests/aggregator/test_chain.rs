@@ -0,0 +1,488 @@
+use super::mock_chain::{cell_tx, deploy_tx, rollup_id, rollup_tx, MockChain};
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_aggregator::{
+    chain::{transaction_hash, ChainClient, ChainFollower},
+    error::Error,
+    generator::{Generator, GeneratorConfig},
+    mempool::{Mempool, MempoolConfig},
+    store::Store,
+    types::RollupCell,
+};
+use godwoken_types::{
+    core::Index,
+    packed::*,
+    prelude::*,
+    signing::{raw_tx_hash, tx_signing_message},
+};
+use godwoken_utils::{bls, hash::blake2b_256};
+use std::sync::Arc;
+
+pub(super) type Follower = ChainFollower<Arc<MockChain>>;
+
+pub(super) fn setup() -> (Follower, Store, Mempool) {
+    let follower = ChainFollower::new(Arc::new(MockChain::new()), rollup_id(), 0);
+    let mut store = Store::open_temporary().expect("open");
+    let mut mempool = Mempool::new(rollup_id(), MempoolConfig::default());
+    follower.client().push_block(vec![deploy_tx(1000)]);
+    assert_eq!(follower.sync(&mut store, &mut mempool).expect("sync"), 2);
+    (follower, store, mempool)
+}
+
+pub(super) fn rollup_cell(follower: &Follower, store: &Store) -> RollupCell {
+    follower
+        .rollup_cell(store)
+        .expect("get")
+        .expect("rollup cell")
+}
+
+/// commit the tx in a new layer-1 block and sync
+pub(super) fn submit_tx(
+    follower: &Follower,
+    store: &mut Store,
+    mempool: &mut Mempool,
+    tx: Transaction,
+) -> Result<usize, Error> {
+    follower.client().push_block(vec![tx]);
+    follower.sync(store, mempool)
+}
+
+/// the global state after `f` is applied to the store, the store is unchanged
+fn expected_global_state<F: FnOnce(&mut Store)>(store: &mut Store, f: F) -> GlobalState {
+    f(store);
+    let global_state = store.get_global_state().expect("get");
+    store.discard().expect("discard");
+    global_state
+}
+
+pub(super) fn register_tx(
+    follower: &Follower,
+    store: &mut Store,
+    pubkey_hash: [u8; 20],
+    deposit: u64,
+) -> Transaction {
+    let account = Account::new_builder()
+        .pubkey_hash(pubkey_hash.pack())
+        .build();
+    register_account_tx(follower, store, account, deposit)
+}
+
+fn register_account_tx(
+    follower: &Follower,
+    store: &mut Store,
+    account: Account,
+    deposit: u64,
+) -> Transaction {
+    let rollup_cell = rollup_cell(follower, store);
+    let index: Index = store.account_count();
+    let account = account.as_builder().index(index.pack()).build();
+    let global_state = expected_global_state(store, |store| {
+        store.update_account(account.clone()).expect("update");
+        store
+            .update_balance(index, CKB_TOKEN_ID, u128::from(deposit))
+            .expect("update");
+    });
+    let register = Register::new_builder().account(account).build();
+    let action = Action::new_builder().set(register).build();
+    let capacity: u64 = rollup_cell.output.capacity().unpack();
+    rollup_tx(
+        &rollup_cell,
+        action,
+        capacity + deposit,
+        &global_state,
+        Vec::new(),
+    )
+}
+
+/// generate a block with a wrong account root, returns the block and its layer-1 tx
+pub(super) fn invalid_block_tx(
+    follower: &Follower,
+    store: &mut Store,
+    mempool: &mut Mempool,
+    generator: &Generator,
+) -> (AgBlock, Transaction) {
+    let cell = rollup_cell(follower, store);
+    let generated = generator.generate(store, mempool, &cell).expect("generate");
+    let block = generated
+        .block
+        .as_builder()
+        .account_root([9u8; 32].pack())
+        .build();
+    let global_state = generated
+        .global_state
+        .as_builder()
+        .account_root(block.account_root())
+        .block_root(store.next_block_root(&block).expect("root").pack())
+        .build();
+    let submit_block = generated
+        .submit_block
+        .as_builder()
+        .block(block.clone())
+        .build();
+    let action = Action::new_builder().set(submit_block).build();
+    let capacity: u64 = cell.output.capacity().unpack();
+    let tx = rollup_tx(&cell, action, capacity, &global_state, Vec::new());
+    (block, tx)
+}
+
+fn deposit_tx(follower: &Follower, store: &mut Store, index: Index, deposit: u64) -> Transaction {
+    let rollup_cell = rollup_cell(follower, store);
+    let account = store.get_account(index).expect("get").expect("account");
+    let balance = store.get_balance(index, &CKB_TOKEN_ID).expect("get");
+    let global_state = expected_global_state(store, |store| {
+        store
+            .update_balance(index, CKB_TOKEN_ID, balance + u128::from(deposit))
+            .expect("update");
+    });
+    let deposit_action = Deposit::new_builder().account(account).build();
+    let action = Action::new_builder().set(deposit_action).build();
+    let capacity: u64 = rollup_cell.output.capacity().unpack();
+    rollup_tx(
+        &rollup_cell,
+        action,
+        capacity + deposit,
+        &global_state,
+        Vec::new(),
+    )
+}
+
+#[test]
+fn test_sync_actions() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = deposit_tx(&follower, &mut store, 1, 50);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("deposit");
+    assert_eq!(store.account_count(), 2);
+    assert_eq!(store.get_balance(1, &CKB_TOKEN_ID).expect("get"), 150);
+    let capacity: u64 = rollup_cell(&follower, &store).output.capacity().unpack();
+    assert_eq!(capacity, 1000 + 5000 + 100 + 50);
+
+    // submit a block
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1);
+    mempool
+        .push_tx(&store, sign_tx(&privkey, &rollup_id(), tx))
+        .expect("push tx");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let cell = rollup_cell(&follower, &store);
+    let generated = generator
+        .generate(&mut store, &mut mempool, &cell)
+        .expect("generate");
+    let transaction = generated.transaction.clone();
+    submit_tx(&follower, &mut store, &mut mempool, transaction.clone()).expect("submit block");
+    assert_eq!(store.block_count(), 1);
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 5011);
+    assert_eq!(store.get_balance(1, &CKB_TOKEN_ID).expect("get"), 139);
+    // committed txs are removed from the mempool
+    assert!(mempool.is_empty());
+    assert_eq!(
+        store.get_committed_global_state().expect("get").as_slice(),
+        generated.global_state.as_slice()
+    );
+    let out_point_tx_hash: [u8; 32] = rollup_cell(&follower, &store).out_point.tx_hash().unpack();
+    assert_eq!(out_point_tx_hash, transaction_hash(&transaction));
+    let synced_block = store
+        .get_last_synced_block()
+        .expect("get")
+        .expect("synced block");
+    assert_eq!(synced_block.number, 5);
+}
+
+#[test]
+fn test_reject_global_state_mismatch() {
+    let (_privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let rollup_cell = rollup_cell(&follower, &store);
+    let account = Account::new_builder()
+        .pubkey_hash(pubkey_hash.pack())
+        .build();
+    let register = Register::new_builder().account(account).build();
+    let action = Action::new_builder().set(register).build();
+    // the global state isn't updated
+    let tx = rollup_tx(
+        &rollup_cell,
+        action,
+        2000,
+        &GlobalState::default(),
+        Vec::new(),
+    );
+    match submit_tx(&follower, &mut store, &mut mempool, tx) {
+        Err(Error::GlobalStateMismatch(_local, _on_chain)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert_eq!(store.account_count(), 0);
+    let synced_block = store
+        .get_last_synced_block()
+        .expect("get")
+        .expect("synced block");
+    assert_eq!(synced_block.number, 1);
+}
+
+#[test]
+fn test_revert_block() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (_privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let cell = rollup_cell(&follower, &store);
+    let generated = generator
+        .generate(&mut store, &mut mempool, &cell)
+        .expect("generate");
+    submit_tx(&follower, &mut store, &mut mempool, generated.transaction).expect("submit block");
+    assert_eq!(store.block_count(), 1);
+
+    // the challenge isn't answered
+    let context = ChallengeContext::new_builder()
+        .block(generated.block.clone())
+        .challenger_index(1u64.pack())
+        .build();
+    let (challenge_tx, challenge_cell) = cell_tx(context.as_bytes());
+    follower.client().push_block(vec![challenge_tx]);
+    let revert_block = RevertBlock::new_builder()
+        .challenge_cell_data_hash(blake2b_256(context.as_slice()).pack())
+        .build();
+    let action = Action::new_builder().set(revert_block).build();
+    // the block has no txs, accounts before the block are the current accounts
+    let global_state = {
+        let prev_global_state = store
+            .get_global_state()
+            .expect("get")
+            .as_builder()
+            .block_count(0u64.pack())
+            .build();
+        store.reset(&prev_global_state);
+        store.update_balance(0, CKB_TOKEN_ID, 0).expect("update");
+        store.update_balance(1, CKB_TOKEN_ID, 4100).expect("update");
+        let reverted_block =
+            AgBlock::new_reverted_block(generated.block.as_reader(), store.account_root(), 1);
+        let global_state = prev_global_state
+            .as_builder()
+            .account_root(store.account_root().pack())
+            .block_root(store.next_block_root(&reverted_block).expect("root").pack())
+            .block_count(1u64.pack())
+            .build();
+        store.discard().expect("discard");
+        global_state
+    };
+    let rollup_cell = rollup_cell(&follower, &store);
+    let capacity: u64 = rollup_cell.output.capacity().unpack();
+    let tx = rollup_tx(
+        &rollup_cell,
+        action,
+        capacity,
+        &global_state,
+        vec![challenge_cell],
+    );
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("revert block");
+    assert_eq!(store.block_count(), 1);
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 0);
+    assert_eq!(store.get_balance(1, &CKB_TOKEN_ID).expect("get"), 4100);
+    let block = store.get_block(0).expect("get").expect("block");
+    assert!(block.as_reader().is_reverted_block());
+}
+
+#[test]
+fn test_invalid_block_is_unverified() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (_privkey, new_pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let fork_number = follower.client().tip_number().expect("tip");
+    let global_state = store.get_committed_global_state().expect("get");
+
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1);
+    mempool
+        .push_tx(&store, sign_tx(&privkey, &rollup_id(), tx))
+        .expect("push tx");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let (block, tx) = invalid_block_tx(&follower, &mut store, &mut mempool, &generator);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("submit block");
+    // the block isn't applied
+    assert_eq!(store.block_count(), 0);
+    assert_eq!(
+        store.get_committed_global_state().expect("get").as_slice(),
+        global_state.as_slice()
+    );
+    let unverified_blocks = store.get_unverified_blocks().expect("get");
+    assert_eq!(unverified_blocks.len(), 1);
+    assert_eq!(unverified_blocks[0].block.as_slice(), block.as_slice());
+    assert_eq!(unverified_blocks[0].txs.len(), 1);
+    assert_eq!(
+        unverified_blocks[0].l1_number,
+        follower.client().tip_number().expect("tip")
+    );
+
+    // the registration is dropped by the revert, it's skipped
+    let tx = register_tx(&follower, &mut store, new_pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    assert_eq!(store.account_count(), 2);
+
+    // records of orphaned blocks are removed
+    follower.client().fork(fork_number);
+    follower.client().push_block(Vec::new());
+    follower.client().push_block(Vec::new());
+    follower.client().push_block(Vec::new());
+    assert_eq!(follower.sync(&mut store, &mut mempool).expect("sync"), 3);
+    assert!(!store.has_unverified_blocks());
+    assert_eq!(
+        store.get_committed_global_state().expect("get").as_slice(),
+        global_state.as_slice()
+    );
+}
+
+#[test]
+fn test_reorg_orphans_block() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let fork_number = follower.client().tip_number().expect("tip");
+    let global_state = store.get_committed_global_state().expect("get");
+    let deployed_cell = rollup_cell(&follower, &store);
+
+    let tx = sign_tx(
+        &privkey,
+        &rollup_id(),
+        gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1),
+    );
+    let tx_hash = mempool.push_tx(&store, tx).expect("push tx");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let cell = rollup_cell(&follower, &store);
+    let generated = generator
+        .generate(&mut store, &mut mempool, &cell)
+        .expect("generate");
+    submit_tx(&follower, &mut store, &mut mempool, generated.transaction).expect("submit block");
+    assert_eq!(store.block_count(), 1);
+    assert!(mempool.get_tx(&tx_hash).is_none());
+
+    // a longer fork without the block
+    follower.client().fork(fork_number);
+    follower.client().push_block(Vec::new());
+    follower.client().push_block(Vec::new());
+    assert_eq!(follower.sync(&mut store, &mut mempool).expect("sync"), 2);
+    assert_eq!(store.block_count(), 0);
+    assert_eq!(store.get_balance(1, &CKB_TOKEN_ID).expect("get"), 100);
+    assert_eq!(
+        store.get_committed_global_state().expect("get").as_slice(),
+        global_state.as_slice()
+    );
+    assert_eq!(
+        rollup_cell(&follower, &store).out_point.as_slice(),
+        deployed_cell.out_point.as_slice()
+    );
+    // the tx of the orphaned block is pushed back
+    assert!(mempool.get_tx(&tx_hash).is_some());
+    let tip = follower
+        .client()
+        .get_block(fork_number + 2)
+        .expect("get")
+        .expect("block");
+    let synced_block = store
+        .get_last_synced_block()
+        .expect("get")
+        .expect("synced block");
+    assert_eq!(synced_block.number, tip.number);
+    assert_eq!(synced_block.hash, tip.hash);
+}
+
+#[test]
+fn test_reorg_pushes_back_bls_tx() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (_privkey, pubkey_hash) = gen_key_pair();
+    let secret_key = [7u8; 32];
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let account = Account::new_builder()
+        .pubkey_hash(pubkey_hash.pack())
+        .bls_pubkey(Some(bls::pubkey(&secret_key).expect("pubkey")).pack())
+        .build();
+    let tx = register_account_tx(&follower, &mut store, account, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let fork_number = follower.client().tip_number().expect("tip");
+
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1);
+    let message = tx_signing_message(&rollup_id(), &raw_tx_hash(&tx.raw().as_reader()));
+    let signature = bls::sign(&secret_key, &message).expect("sign");
+    let tx_hash = mempool.push_bls_tx(&store, tx, signature).expect("push tx");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let cell = rollup_cell(&follower, &store);
+    let generated = generator
+        .generate(&mut store, &mut mempool, &cell)
+        .expect("generate");
+    submit_tx(&follower, &mut store, &mut mempool, generated.transaction).expect("submit block");
+    assert_eq!(store.block_count(), 1);
+    assert!(mempool.get_tx(&tx_hash).is_none());
+
+    // the signature is aggregated in the orphaned block
+    follower.client().fork(fork_number);
+    follower.client().push_block(Vec::new());
+    follower.client().push_block(Vec::new());
+    assert_eq!(follower.sync(&mut store, &mut mempool).expect("sync"), 2);
+    assert_eq!(store.block_count(), 0);
+    let pool_tx = mempool.get_tx(&tx_hash).expect("pushed back");
+    assert_eq!(pool_tx.bls_signature, Some(signature));
+}
+
+#[test]
+fn test_rollback_registration() {
+    let (_privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let fork_number = follower.client().tip_number().expect("tip");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    assert_eq!(store.account_count(), 1);
+
+    assert_eq!(
+        follower
+            .rollback(&mut store, &mut mempool, fork_number + 1)
+            .expect("rollback"),
+        0
+    );
+    assert_eq!(store.account_count(), 0);
+    assert!(store.get_account(0).expect("get").is_none());
+    let synced_block = store
+        .get_last_synced_block()
+        .expect("get")
+        .expect("synced block");
+    assert_eq!(synced_block.number, fork_number);
+
+    // the registration is committed again on a new fork
+    follower.client().fork(fork_number);
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 300);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    assert_eq!(store.account_count(), 1);
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 300);
+}
+
+#[test]
+fn test_reorg_before_deployment() {
+    let (follower, mut store, mut mempool) = setup();
+    // the deployment is orphaned
+    follower.client().fork(0);
+    follower.client().push_block(Vec::new());
+    follower.client().push_block(Vec::new());
+    assert_eq!(follower.sync(&mut store, &mut mempool).expect("sync"), 2);
+    assert!(follower.rollup_cell(&store).expect("get").is_none());
+    assert_eq!(
+        store.get_committed_global_state().expect("get").as_slice(),
+        GlobalState::default().as_slice()
+    );
+}
@@ -0,0 +1,198 @@
+use super::mock_chain::deploy_tx;
+use godwoken_aggregator::{
+    ckb_client::{HexU64, OutPointJson, TransactionJson},
+    config::Config,
+    error::Error,
+    rpc::JsonHash,
+};
+use godwoken_types::{packed::*, prelude::*};
+use std::convert::TryFrom;
+use std::fs;
+
+const CONFIG: &str = r#"
+rollup_type_hash = "0x2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a"
+ag_index = 3
+privkey_path = "ag.key"
+data_dir = "data"
+
+[chain]
+rpc_url = "http://127.0.0.1:8114"
+start_number = 100
+
+[block]
+interval_ms = 5000
+max_txs = 20
+
+[[block.cell_deps]]
+dep_type = "dep_group"
+out_point = { tx_hash = "0x0101010101010101010101010101010101010101010101010101010101010101", index = "0x1" }
+
+[wallet]
+lock_code_hash = "0x0505050505050505050505050505050505050505050505050505050505050505"
+lock_hash_type = "type"
+
+[[wallet.cell_deps]]
+dep_type = "dep_group"
+out_point = { tx_hash = "0x0606060606060606060606060606060606060606060606060606060606060606", index = "0x0" }
+"#;
+
+const CHALLENGE_CONFIG: &str = r#"
+[challenge]
+code_hash = "0x0707070707070707070707070707070707070707070707070707070707070707"
+hash_type = "data"
+cell_deps = [{ dep_type = "code", out_point = { tx_hash = "0x0808080808080808080808080808080808080808080808080808080808080808", index = "0x2" } }]
+
+[validator]
+enabled = true
+challenger_index = 4
+challenge_capacity = 50000000000
+challenge_lock = { code_hash = "0x0909090909090909090909090909090909090909090909090909090909090909", hash_type = "data", args = "0x" }
+withdraw_lock_hash = "0x0303030303030303030303030303030303030303030303030303030303030303"
+
+[responder]
+enabled = true
+"#;
+
+#[test]
+fn test_parse_config() {
+    let config = Config::parse(CONFIG).expect("parse");
+    assert_eq!(config.rollup_id(), [42u8; 32]);
+    assert_eq!(config.ag_index, 3);
+    assert_eq!(config.chain.start_number, 100);
+    assert_eq!(config.block.interval_ms, 5000);
+    // unspecified fields are default
+    assert_eq!(config.rpc.listen_address.port(), 8119);
+    let generator_config = config.generator_config().expect("generator config");
+    assert_eq!(generator_config.max_txs, 20);
+    assert_eq!(generator_config.max_size, 64 * 1024);
+    let expected_cell_dep = CellDep::new_builder()
+        .out_point(
+            OutPoint::new_builder()
+                .tx_hash([1u8; 32].pack())
+                .index(1u32.pack())
+                .build(),
+        )
+        .dep_type(1u8.into())
+        .build();
+    assert_eq!(generator_config.cell_deps.len(), 1);
+    assert_eq!(
+        generator_config.cell_deps[0].as_slice(),
+        expected_cell_dep.as_slice()
+    );
+
+    let wallet_config = config.wallet_config().expect("wallet config");
+    assert_eq!(wallet_config.lock_code_hash, [5u8; 32]);
+    assert_eq!(wallet_config.lock_hash_type, 1);
+    assert_eq!(wallet_config.fee_rate, 1000);
+    assert_eq!(wallet_config.cell_deps.len(), 1);
+    // challenges are disabled by default
+    assert!(config
+        .validator_config()
+        .expect("validator config")
+        .is_none());
+    assert!(config
+        .responder_config()
+        .expect("responder config")
+        .is_none());
+
+    // missing required field
+    match Config::parse("ag_index = 0") {
+        Err(Error::Config(_)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_parse_challenge_config() {
+    let content = format!("{}{}", CONFIG, CHALLENGE_CONFIG);
+    let config = Config::parse(&content).expect("parse");
+    let validator_config = config
+        .validator_config()
+        .expect("validator config")
+        .expect("enabled");
+    assert_eq!(validator_config.challenger_index, 4);
+    assert_eq!(validator_config.challenge_capacity, 50_000_000_000);
+    assert_eq!(validator_config.challenge_code_hash, [7u8; 32]);
+    assert_eq!(validator_config.challenge_hash_type, 0);
+    assert_eq!(validator_config.withdraw_lock_hash, [3u8; 32]);
+    assert_eq!(validator_config.resend_blocks, 20);
+    let challenge_lock_code_hash: [u8; 32] = validator_config.challenge_lock.code_hash().unpack();
+    assert_eq!(challenge_lock_code_hash, [9u8; 32]);
+    // the main contract's and the challenge contract's
+    assert_eq!(validator_config.cell_deps.len(), 2);
+    let responder_config = config
+        .responder_config()
+        .expect("responder config")
+        .expect("enabled");
+    assert_eq!(responder_config.challenge_code_hash, [7u8; 32]);
+    assert_eq!(responder_config.resend_blocks, 20);
+    assert_eq!(responder_config.cell_deps.len(), 1);
+
+    // the validator requires the challenge contract
+    let content = format!(
+        "{}{}",
+        CONFIG,
+        CHALLENGE_CONFIG.replace("[challenge]", "[unused]")
+    );
+    match Config::parse(&content).map(|config| config.validator_config().map(|_| ())) {
+        Ok(Err(Error::Config(_))) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_load_config_files() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let config_path = dir.path().join("config.toml");
+    fs::write(&config_path, CONFIG).expect("write");
+    fs::write(
+        dir.path().join("ag.key"),
+        format!("0x{}\n", "01".repeat(32)),
+    )
+    .expect("write");
+    let config = Config::load(&config_path).expect("load");
+    assert_eq!(config.data_dir, dir.path().join("data"));
+    let privkey = config.load_privkey().expect("load privkey");
+    assert_eq!(privkey.serialize(), [1u8; 32]);
+    // the wallet uses the aggregator's key by default
+    let wallet_privkey = config.load_wallet_privkey().expect("load wallet privkey");
+    assert_eq!(wallet_privkey.serialize(), [1u8; 32]);
+
+    fs::write(dir.path().join("ag.key"), "0x01").expect("write");
+    match config.load_privkey() {
+        Err(Error::Config(_)) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_ckb_transaction_json() {
+    let tx = deploy_tx(1000);
+    let json = serde_json::to_string(&TransactionJson::from(tx.clone())).expect("serialize");
+    let tx_json: TransactionJson = serde_json::from_str(&json).expect("deserialize");
+    let json_tx = Transaction::try_from(tx_json).expect("convert");
+    assert_eq!(json_tx.as_slice(), tx.as_slice());
+    let value: serde_json::Value = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(value["outputs"][0]["capacity"], "0x3e8");
+    assert_eq!(value["outputs"][0]["type"]["hash_type"], "data");
+    assert_eq!(value["outputs"][0]["lock"]["args"], "0x");
+}
+
+#[test]
+fn test_ckb_json_overflow() {
+    let tx = deploy_tx(1000);
+    let mut tx_json = TransactionJson::from(tx);
+    tx_json.version = HexU64(u64::from(u32::max_value()) + 1);
+    match Transaction::try_from(tx_json) {
+        Err(ConversionError::Overflow) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let out_point = OutPointJson {
+        tx_hash: JsonHash([1u8; 32]),
+        index: HexU64(u64::from(u32::max_value()) + 1),
+    };
+    match OutPoint::try_from(out_point) {
+        Err(ConversionError::Overflow) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
@@ -0,0 +1,224 @@
+use super::{prepare_store, ROLLUP_ID};
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_aggregator::{
+    generator::{GeneratedBlock, Generator, GeneratorConfig},
+    mempool::{Mempool, MempoolConfig},
+    state,
+    store::Store,
+    types::RollupCell,
+};
+use godwoken_executor::executor::Executor;
+use godwoken_types::{
+    bytes::Bytes,
+    packed::*,
+    prelude::*,
+    signing::{block_signing_message, tx_hash},
+};
+use godwoken_utils::{hash::block_hash, mmr::compute_new_block_root, signature::verify_signature};
+
+const AG_INDEX: u64 = 0;
+
+fn rollup_cell() -> RollupCell {
+    RollupCell {
+        out_point: OutPoint::new_builder().index(1u32.pack()).build(),
+        output: CellOutput::new_builder().capacity(10_000u64.pack()).build(),
+    }
+}
+
+/// apply the generated block to the store as the chain follower does
+fn apply_block(store: &mut Store, generated: &GeneratedBlock) {
+    let block = &generated.block;
+    let aggregate_sig: [u8; 96] = block.aggregate_sig().unpack();
+    state::apply_txs(
+        &Executor::new(ROLLUP_ID),
+        store,
+        &generated.txs,
+        AG_INDEX,
+        &aggregate_sig,
+    )
+    .expect("apply txs");
+    store
+        .push_block(block.clone(), generated.txs.clone())
+        .expect("push block");
+    store.commit().expect("commit");
+}
+
+#[test]
+fn test_generate_block() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![
+        (ag_pubkey_hash, 5000),
+        (pubkey_hash, 100),
+        ([0u8; 20], 0),
+    ]);
+    let prev_global_state = store.get_global_state().expect("get");
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let tx = sign_tx(
+        &privkey,
+        &ROLLUP_ID,
+        gen_transfer_tx(1, 2, 1, CKB_TOKEN_ID, 15, 3),
+    );
+    mempool.push_tx(&store, tx.clone()).expect("push tx");
+    let generator = Generator::new(ROLLUP_ID, AG_INDEX, ag_privkey, GeneratorConfig::default());
+    let generated = generator
+        .generate(&mut store, &mut mempool, &rollup_cell())
+        .expect("generate");
+
+    // the store is unchanged
+    assert_eq!(
+        store.get_global_state().expect("get").as_slice(),
+        prev_global_state.as_slice()
+    );
+    let block = &generated.block;
+    let number: u64 = block.number().unpack();
+    let txs_count: u32 = block.txs_count().unpack();
+    assert_eq!(number, 0);
+    assert_eq!(txs_count, 1);
+    assert_eq!(
+        block.prev_account_root().as_slice(),
+        prev_global_state.account_root().as_slice()
+    );
+    assert_ne!(
+        block.account_root().as_slice(),
+        block.prev_account_root().as_slice()
+    );
+    let tx_root: [u8; 32] = block.tx_root().unpack();
+    assert_eq!(tx_root, tx_hash(&tx.as_reader()));
+    let ag_sig: [u8; 65] = block.ag_sig().unpack();
+    let message = block_signing_message(&ROLLUP_ID, &block.as_reader());
+    verify_signature(0, &ag_sig, &message, &ag_pubkey_hash).expect("verify ag_sig");
+
+    // the witness of the rollup cell is the SubmitBlock action
+    let raw = generated.transaction.raw();
+    assert_eq!(raw.inputs().len(), 1);
+    assert_eq!(
+        raw.inputs()
+            .get(0)
+            .expect("input")
+            .previous_output()
+            .as_slice(),
+        rollup_cell().out_point.as_slice()
+    );
+    let output_data: Bytes = raw.outputs_data().get(0).expect("data").unpack();
+    assert_eq!(&output_data[..], generated.global_state.as_slice());
+    let witness: Bytes = generated
+        .transaction
+        .witnesses()
+        .get(0)
+        .expect("witness")
+        .unpack();
+    let witness_args = WitnessArgs::from_slice(&witness).expect("witness args");
+    let action: Bytes = witness_args
+        .output_type()
+        .to_opt()
+        .expect("output type")
+        .unpack();
+    let action = Action::from_slice(&action).expect("action");
+    match action.to_enum() {
+        ActionUnion::SubmitBlock(submit_block) => {
+            assert_eq!(submit_block.as_slice(), generated.submit_block.as_slice())
+        }
+        _ => panic!("unexpected action"),
+    }
+
+    // the new global state matches the store after the block is applied
+    apply_block(&mut store, &generated);
+    assert_eq!(
+        store.get_global_state().expect("get").as_slice(),
+        generated.global_state.as_slice()
+    );
+    assert_eq!(store.get_balance(2, &CKB_TOKEN_ID).expect("get"), 15);
+    assert_eq!(
+        store.get_balance(AG_INDEX, &CKB_TOKEN_ID).expect("get"),
+        5003
+    );
+}
+
+#[test]
+fn test_drop_failed_txs() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey_a, pubkey_hash_a) = gen_key_pair();
+    let (privkey_b, pubkey_hash_b) = gen_key_pair();
+    let mut store = prepare_store(vec![
+        (ag_pubkey_hash, 5000),
+        (pubkey_hash_a, 100),
+        (pubkey_hash_b, 100),
+    ]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    // transfer to a missing account, the following tx of the sender is dropped too
+    let txs = vec![
+        (&privkey_a, gen_transfer_tx(1, 42, 1, CKB_TOKEN_ID, 10, 5)),
+        (&privkey_a, gen_transfer_tx(1, 2, 2, CKB_TOKEN_ID, 10, 5)),
+        (&privkey_b, gen_transfer_tx(2, 1, 1, CKB_TOKEN_ID, 10, 1)),
+    ];
+    for (privkey, tx) in txs {
+        mempool
+            .push_tx(&store, sign_tx(privkey, &ROLLUP_ID, tx))
+            .expect("push tx");
+    }
+    let generator = Generator::new(ROLLUP_ID, AG_INDEX, ag_privkey, GeneratorConfig::default());
+    let generated = generator
+        .generate(&mut store, &mut mempool, &rollup_cell())
+        .expect("generate");
+    assert_eq!(generated.txs.len(), 1);
+    let sender_index: u64 = generated.txs[0].raw().sender_index().unpack();
+    assert_eq!(sender_index, 2);
+    assert_eq!(mempool.len(), 1);
+}
+
+#[test]
+fn test_generate_following_block() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![
+        (ag_pubkey_hash, 5000),
+        (pubkey_hash, 100),
+        ([0u8; 20], 0),
+    ]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let generator = Generator::new(ROLLUP_ID, AG_INDEX, ag_privkey, GeneratorConfig::default());
+    for nonce in 1..=3 {
+        let tx = gen_transfer_tx(1, 2, nonce, CKB_TOKEN_ID, 10, 1);
+        mempool
+            .push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx))
+            .expect("push tx");
+        let generated = generator
+            .generate(&mut store, &mut mempool, &rollup_cell())
+            .expect("generate");
+        let submit_block = &generated.submit_block;
+        let number: u64 = generated.block.number().unpack();
+        assert_eq!(number, nonce as u64 - 1);
+        // the new block root is provable from the last block
+        if number > 0 {
+            let last_block_hash: [u8; 32] = submit_block.last_block_hash().unpack();
+            assert_eq!(
+                Some(last_block_hash),
+                store.get_block_hash(number - 1).expect("get")
+            );
+            let block_proof: Vec<[u8; 32]> = submit_block
+                .block_proof()
+                .into_iter()
+                .map(|item| item.unpack())
+                .collect();
+            let block_root = compute_new_block_root(
+                last_block_hash,
+                number - 1,
+                block_hash(&generated.block),
+                number,
+                number + 1,
+                block_proof,
+            )
+            .expect("compute block root");
+            let expected_root: [u8; 32] = generated.global_state.block_root().unpack();
+            assert_eq!(block_root, expected_root);
+        }
+        apply_block(&mut store, &generated);
+        mempool.update(&store).expect("update");
+        assert!(mempool.is_empty());
+    }
+    assert_eq!(store.block_count(), 3);
+}
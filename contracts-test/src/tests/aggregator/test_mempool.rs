@@ -0,0 +1,330 @@
+use super::{prepare_store, ROLLUP_ID};
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_multi_transfer_tx, gen_transfer_tx, sign_tx},
+};
+use godwoken_aggregator::{
+    error::Error,
+    mempool::{Mempool, MempoolConfig, PoolTx},
+};
+use godwoken_executor::error::Error as ExecutorError;
+use godwoken_types::{
+    core::Index,
+    packed::{Account, AccountScript, AccountScriptOpt},
+    prelude::*,
+    signing::{raw_tx_hash, tx_signing_message},
+};
+use godwoken_utils::bls;
+
+fn nonces(txs: Vec<PoolTx>) -> Vec<(Index, u32)> {
+    txs.into_iter()
+        .map(|pool_tx| (pool_tx.sender_index(), pool_tx.nonce()))
+        .collect()
+}
+
+#[test]
+fn test_package_in_nonce_order() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let store = prepare_store(vec![(pubkey_hash, 100), ([0u8; 20], 0)]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    for nonce in &[2, 1] {
+        let tx = gen_transfer_tx(0, 1, *nonce, CKB_TOKEN_ID, 10, 1);
+        mempool
+            .push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx))
+            .expect("push tx");
+    }
+    // nonce 4 is not ready
+    let tx = gen_transfer_tx(0, 1, 4, CKB_TOKEN_ID, 10, 1);
+    mempool
+        .push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx))
+        .expect("push tx");
+    assert_eq!(mempool.len(), 3);
+    assert_eq!(mempool.get_pending_nonce(&store, 0).expect("get"), Some(2));
+    let txs = mempool.package(&store, 10, 1024 * 1024).expect("package");
+    assert_eq!(nonces(txs), vec![(0, 1), (0, 2)]);
+}
+
+#[test]
+fn test_package_by_fee_rate() {
+    let (privkey_a, pubkey_hash_a) = gen_key_pair();
+    let (privkey_b, pubkey_hash_b) = gen_key_pair();
+    let store = prepare_store(vec![(pubkey_hash_a, 100), (pubkey_hash_b, 100)]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let txs = vec![
+        (&privkey_a, gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 10, 1)),
+        (&privkey_a, gen_transfer_tx(0, 1, 2, CKB_TOKEN_ID, 10, 8)),
+        (&privkey_b, gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 5)),
+    ];
+    for (privkey, tx) in txs {
+        mempool
+            .push_tx(&store, sign_tx(privkey, &ROLLUP_ID, tx))
+            .expect("push tx");
+    }
+    let txs = mempool.package(&store, 10, 1024 * 1024).expect("package");
+    assert_eq!(nonces(txs), vec![(1, 1), (0, 1), (0, 2)]);
+    let txs = mempool.package(&store, 1, 1024 * 1024).expect("package");
+    assert_eq!(nonces(txs), vec![(1, 1)]);
+}
+
+#[test]
+fn test_reject_invalid_txs() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (wrong_privkey, _pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![(pubkey_hash, 100), ([0u8; 20], 0)]);
+    let sender = Account::new_builder()
+        .index(0u64.pack())
+        .nonce(3u32.pack())
+        .pubkey_hash(pubkey_hash.pack())
+        .build();
+    store.update_account(sender).expect("update account");
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let tx = gen_transfer_tx(0, 1, 4, CKB_TOKEN_ID, 10, 1);
+    match mempool.push_tx(&store, sign_tx(&wrong_privkey, &ROLLUP_ID, tx.clone())) {
+        Err(Error::Executor(ExecutorError::InvalidSignature)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    // unsigned
+    match mempool.push_tx(&store, tx) {
+        Err(Error::Executor(ExecutorError::InvalidSignature)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let tx = gen_transfer_tx(0, 1, 3, CKB_TOKEN_ID, 10, 1);
+    match mempool.push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx)) {
+        Err(Error::StaleNonce(3, 3)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let tx = gen_transfer_tx(0, 1, 1000, CKB_TOKEN_ID, 10, 1);
+    match mempool.push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx)) {
+        Err(Error::NonceTooHigh(3, 1000)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert!(mempool.is_empty());
+}
+
+#[test]
+fn test_reject_txs_to_contracts() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![(pubkey_hash, 100), ([0u8; 20], 0), ([0u8; 20], 0)]);
+    let contract = Account::new_builder()
+        .index(2u64.pack())
+        .script(
+            AccountScriptOpt::new_builder()
+                .set(Some(AccountScript::default()))
+                .build(),
+        )
+        .build();
+    store.update_account(contract).expect("update account");
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let txs = vec![
+        gen_transfer_tx(0, 2, 1, CKB_TOKEN_ID, 10, 1),
+        gen_multi_transfer_tx(0, vec![(1, 10), (2, 10)], 1, CKB_TOKEN_ID, 1),
+    ];
+    for tx in txs {
+        match mempool.push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx)) {
+            Err(Error::Executor(ExecutorError::ContractNotSupported)) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+    assert!(mempool.is_empty());
+    let tx = gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 10, 1);
+    mempool
+        .push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx))
+        .expect("push tx");
+}
+
+#[test]
+fn test_evict_underfunded_txs() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![(pubkey_hash, 100), ([0u8; 20], 0)]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    for nonce in 1..=2 {
+        let tx = gen_transfer_tx(0, 1, nonce, CKB_TOKEN_ID, 40, 1);
+        mempool
+            .push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx))
+            .expect("push tx");
+    }
+    let tx = gen_transfer_tx(0, 1, 3, CKB_TOKEN_ID, 40, 1);
+    match mempool.push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx)) {
+        Err(Error::InsufficientBalance) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert_eq!(mempool.len(), 2);
+    // the balance is spent
+    store
+        .update_balance(0, CKB_TOKEN_ID, 50)
+        .expect("update balance");
+    mempool.update(&store).expect("update");
+    assert_eq!(mempool.len(), 1);
+    assert_eq!(mempool.get_pending_nonce(&store, 0).expect("get"), Some(1));
+}
+
+#[test]
+fn test_evict_outdated_txs() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![(pubkey_hash, 100), ([0u8; 20], 0)]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    for nonce in 1..=3 {
+        let tx = gen_transfer_tx(0, 1, nonce, CKB_TOKEN_ID, 10, 1);
+        mempool
+            .push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx))
+            .expect("push tx");
+    }
+    // txs of nonce 1 and 2 are committed
+    let sender = store.get_account(0).expect("get").expect("sender");
+    let sender = sender.as_builder().nonce(2u32.pack()).build();
+    store.update_account(sender).expect("update account");
+    mempool.update(&store).expect("update");
+    let txs = mempool.package(&store, 10, 1024 * 1024).expect("package");
+    assert_eq!(nonces(txs), vec![(0, 3)]);
+    assert_eq!(mempool.get_pending_nonce(&store, 0).expect("get"), Some(3));
+}
+
+#[test]
+fn test_replace_by_fee() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let store = prepare_store(vec![(pubkey_hash, 100), ([0u8; 20], 0)]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let tx = sign_tx(
+        &privkey,
+        &ROLLUP_ID,
+        gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 10, 2),
+    );
+    mempool.push_tx(&store, tx).expect("push tx");
+    let tx = sign_tx(
+        &privkey,
+        &ROLLUP_ID,
+        gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 20, 1),
+    );
+    match mempool.push_tx(&store, tx) {
+        Err(Error::FeeTooLow) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let tx = sign_tx(
+        &privkey,
+        &ROLLUP_ID,
+        gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 20, 5),
+    );
+    let tx_hash = mempool.push_tx(&store, tx).expect("push tx");
+    assert_eq!(mempool.len(), 1);
+    assert!(mempool.get_tx(&tx_hash).is_some());
+}
+
+#[test]
+fn test_replace_by_underfunded_tx() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let store = prepare_store(vec![(pubkey_hash, 100), ([0u8; 20], 0)]);
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let mut tx_hashes = Vec::new();
+    for nonce in 1..=2 {
+        let tx = gen_transfer_tx(0, 1, nonce, CKB_TOKEN_ID, 10, 2);
+        let tx_hash = mempool
+            .push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx))
+            .expect("push tx");
+        tx_hashes.push(tx_hash);
+    }
+    // a higher fee rate but the sender can't pay for it
+    let tx = gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 200, 5);
+    match mempool.push_tx(&store, sign_tx(&privkey, &ROLLUP_ID, tx)) {
+        Err(Error::InsufficientBalance) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    // the replaced tx and the following tx are kept
+    assert_eq!(mempool.len(), 2);
+    for tx_hash in &tx_hashes {
+        assert!(mempool.get_tx(tx_hash).is_some());
+    }
+    let txs = mempool.package(&store, 10, 1024 * 1024).expect("package");
+    assert_eq!(nonces(txs), vec![(0, 1), (0, 2)]);
+}
+
+#[test]
+fn test_evict_when_full_keeps_sender_queue() {
+    let (privkey_a, pubkey_hash_a) = gen_key_pair();
+    let (privkey_b, pubkey_hash_b) = gen_key_pair();
+    let store = prepare_store(vec![(pubkey_hash_a, 100), (pubkey_hash_b, 100)]);
+    let config = MempoolConfig {
+        max_txs: 2,
+        ..Default::default()
+    };
+    let mut mempool = Mempool::new(ROLLUP_ID, config);
+    let tx = gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 10, 1);
+    let first_tx_hash = mempool
+        .push_tx(&store, sign_tx(&privkey_a, &ROLLUP_ID, tx))
+        .expect("push tx");
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 5);
+    let other_tx_hash = mempool
+        .push_tx(&store, sign_tx(&privkey_b, &ROLLUP_ID, tx))
+        .expect("push tx");
+    // the sender's own tx has the lowest fee rate, evicting it would strand the new tx
+    let tx = gen_transfer_tx(0, 1, 2, CKB_TOKEN_ID, 10, 10);
+    mempool
+        .push_tx(&store, sign_tx(&privkey_a, &ROLLUP_ID, tx))
+        .expect("push tx");
+    assert_eq!(mempool.len(), 2);
+    assert!(mempool.get_tx(&first_tx_hash).is_some());
+    assert!(mempool.get_tx(&other_tx_hash).is_none());
+    let txs = mempool.package(&store, 10, 1024 * 1024).expect("package");
+    assert_eq!(nonces(txs), vec![(0, 1), (0, 2)]);
+
+    // only the sender's own txs are left
+    let tx = gen_transfer_tx(0, 1, 3, CKB_TOKEN_ID, 10, 20);
+    match mempool.push_tx(&store, sign_tx(&privkey_a, &ROLLUP_ID, tx)) {
+        Err(Error::PoolFull) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert_eq!(mempool.len(), 2);
+}
+
+#[test]
+fn test_evict_lowest_fee_rate_when_full() {
+    let (privkey_a, pubkey_hash_a) = gen_key_pair();
+    let (privkey_b, pubkey_hash_b) = gen_key_pair();
+    let store = prepare_store(vec![(pubkey_hash_a, 100), (pubkey_hash_b, 100)]);
+    let config = MempoolConfig {
+        max_txs: 1,
+        ..Default::default()
+    };
+    let mut mempool = Mempool::new(ROLLUP_ID, config);
+    let tx = gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 10, 2);
+    let low_fee_tx_hash = mempool
+        .push_tx(&store, sign_tx(&privkey_a, &ROLLUP_ID, tx))
+        .expect("push tx");
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1);
+    match mempool.push_tx(&store, sign_tx(&privkey_b, &ROLLUP_ID, tx)) {
+        Err(Error::PoolFull) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 5);
+    mempool
+        .push_tx(&store, sign_tx(&privkey_b, &ROLLUP_ID, tx))
+        .expect("push tx");
+    assert_eq!(mempool.len(), 1);
+    assert!(mempool.get_tx(&low_fee_tx_hash).is_none());
+}
+
+#[test]
+fn test_push_bls_tx() {
+    let secret_key = [7u8; 32];
+    let bls_pubkey = bls::pubkey(&secret_key).expect("pubkey");
+    let mut store = prepare_store(vec![([0u8; 20], 100), ([0u8; 20], 0)]);
+    let sender = Account::new_builder()
+        .index(0u64.pack())
+        .bls_pubkey(Some(bls_pubkey).pack())
+        .build();
+    store.update_account(sender).expect("update account");
+    let mut mempool = Mempool::new(ROLLUP_ID, MempoolConfig::default());
+    let tx = gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 10, 1);
+    let raw_tx_hash = raw_tx_hash(&tx.raw().as_reader());
+    let message = tx_signing_message(&ROLLUP_ID, &raw_tx_hash);
+    let wrong_signature = bls::sign(&[8u8; 32], &message).expect("sign");
+    match mempool.push_bls_tx(&store, tx.clone(), wrong_signature) {
+        Err(Error::Executor(ExecutorError::InvalidSignature)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let signature = bls::sign(&secret_key, &message).expect("sign");
+    let tx_hash = mempool
+        .push_bls_tx(&store, tx, signature)
+        .expect("push bls tx");
+    let pool_tx = mempool.get_tx(&tx_hash).expect("pool tx");
+    assert_eq!(pool_tx.bls_signature, Some(signature));
+}
@@ -0,0 +1,189 @@
+use super::mock_chain::{rollup_id, MockChain};
+use super::test_chain::{register_tx, rollup_cell, setup, submit_tx};
+use super::test_validator::MockWallet;
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_aggregator::{
+    chain::ChainClient,
+    generator::{Generator, GeneratorConfig},
+    responder::{Responder, ResponderConfig},
+};
+use godwoken_executor::{
+    executor::Executor,
+    state::{AccountState, State},
+};
+use godwoken_types::{
+    bytes::Bytes,
+    cache::{KVMap, StorageMap, TxWithHash},
+    packed::*,
+    prelude::*,
+};
+use godwoken_utils::smt::{self, compute_root_with_proof};
+use std::sync::Arc;
+
+const CHALLENGE_CODE_HASH: [u8; 32] = [5u8; 32];
+
+fn responder_config() -> ResponderConfig {
+    ResponderConfig {
+        challenge_code_hash: CHALLENGE_CODE_HASH,
+        challenge_hash_type: 0,
+        cell_deps: Vec::new(),
+        resend_blocks: 10,
+    }
+}
+
+/// a tx creates a challenge cell
+fn challenge_tx(main_type_hash: [u8; 32], context: &ChallengeContext) -> Transaction {
+    let args = ChallengeArgs::new_builder()
+        .main_type_hash(main_type_hash.pack())
+        .build();
+    let type_script = Script::new_builder()
+        .code_hash(CHALLENGE_CODE_HASH.pack())
+        .args(args.as_bytes().pack())
+        .build();
+    let output = CellOutput::new_builder()
+        .capacity(500u64.pack())
+        .type_(Some(type_script).pack())
+        .build();
+    let raw = RawTransaction::new_builder()
+        .outputs(vec![output].pack())
+        .outputs_data(vec![context.as_bytes().pack()].pack())
+        .build();
+    Transaction::new_builder().raw(raw).build()
+}
+
+/// root of the state with the proof of the response
+fn state_root(state: &State, proof: SMTProofReader) -> [u8; 32] {
+    let leaves_path = proof.leaves_path().unpack();
+    let merkle_branches: Vec<(smt::H256, u8)> =
+        Unpack::<Vec<([u8; 32], u8)>>::unpack(&proof.proof())
+            .into_iter()
+            .map(|(node, height)| (node.into(), height))
+            .collect();
+    compute_root_with_proof(state.merkle_leaves(), leaves_path, merkle_branches)
+        .expect("compute root")
+        .into()
+}
+
+fn load_response(chain: &MockChain, tx_hash: &[u8; 32]) -> InvalidChallenge {
+    let tx = chain
+        .get_transaction(tx_hash)
+        .expect("get")
+        .expect("response tx");
+    let witness: Bytes = tx.witnesses().get(0).expect("witness").unpack();
+    match ChallengeUnlock::from_slice(&witness)
+        .expect("challenge unlock")
+        .to_enum()
+    {
+        ChallengeUnlockUnion::InvalidChallenge(invalid_challenge) => invalid_challenge,
+        _ => panic!("unexpected challenge unlock"),
+    }
+}
+
+#[test]
+fn test_respond_bogus_challenge() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1);
+    mempool
+        .push_tx(&store, sign_tx(&privkey, &rollup_id(), tx))
+        .expect("push tx");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let cell = rollup_cell(&follower, &store);
+    let generated = generator
+        .generate(&mut store, &mut mempool, &cell)
+        .expect("generate");
+    submit_tx(&follower, &mut store, &mut mempool, generated.transaction).expect("submit block");
+    assert_eq!(store.block_count(), 1);
+
+    // challenges of the valid block, a block not in the store and another rollup
+    let context = ChallengeContext::new_builder()
+        .block(generated.block.clone())
+        .txs(TxVec::new_builder().set(generated.txs.clone()).build())
+        .challenger_index(1u64.pack())
+        .build();
+    let unknown_block = generated
+        .block
+        .clone()
+        .as_builder()
+        .account_root([9u8; 32].pack())
+        .build();
+    let unknown_context = context.clone().as_builder().block(unknown_block).build();
+    let chain = Arc::clone(follower.client());
+    chain.push_block(vec![
+        challenge_tx(rollup_id(), &context),
+        challenge_tx(rollup_id(), &unknown_context),
+        challenge_tx([7u8; 32], &context),
+    ]);
+    follower.sync(&mut store, &mut mempool).expect("sync");
+
+    let mut responder = Responder::new(
+        Arc::clone(&chain),
+        MockWallet,
+        rollup_id(),
+        responder_config(),
+        0,
+    );
+    let tx_hashes = responder.process(&mut store).expect("process");
+    assert_eq!(tx_hashes.len(), 1);
+    assert_eq!(responder.challenges().len(), 2);
+    // the response isn't sent again before `resend_blocks`
+    assert!(responder.process(&mut store).expect("process").is_empty());
+    chain.commit_sent_txs();
+    follower.sync(&mut store, &mut mempool).expect("sync");
+
+    // the response proves the block like the challenge contract
+    let invalid_challenge = load_response(&chain, &tx_hashes[0]);
+    let reader = invalid_challenge.as_reader();
+    assert_eq!(reader.touched_accounts().len(), 2);
+    let mut state = State::new(
+        reader
+            .touched_accounts()
+            .iter()
+            .zip(reader.touched_accounts_token_kv().iter())
+            .zip(reader.touched_accounts_withdraw_kv().iter())
+            .zip(reader.touched_accounts_storage().iter())
+            .map(|(((account, kv), withdraw_kv), storage)| {
+                let kv: KVMap = kv.unpack();
+                let withdraw_kv: KVMap = withdraw_kv.unpack();
+                let storage: StorageMap = storage.unpack();
+                AccountState {
+                    account,
+                    kv,
+                    withdraw_kv,
+                    storage,
+                }
+            })
+            .collect(),
+    );
+    let prev_account_root: [u8; 32] = generated.block.prev_account_root().unpack();
+    assert_eq!(
+        state_root(&state, reader.touched_accounts_proof()),
+        prev_account_root
+    );
+    let executor = Executor::new(rollup_id());
+    for tx in context.as_reader().txs().iter() {
+        executor
+            .run(&mut state, TxWithHash::new(tx), 0)
+            .expect("run tx");
+    }
+    let account_root: [u8; 32] = generated.block.account_root().unpack();
+    assert_eq!(
+        state_root(&state, reader.touched_accounts_proof()),
+        account_root
+    );
+
+    // the answered challenge cell is consumed
+    assert!(responder.process(&mut store).expect("process").is_empty());
+    assert_eq!(responder.challenges().len(), 1);
+    let remaining_block = responder.challenges()[0].context.block();
+    let remaining_account_root: [u8; 32] = remaining_block.account_root().unpack();
+    assert_eq!(remaining_account_root, [9u8; 32]);
+}
@@ -0,0 +1,199 @@
+use super::{prepare_store, ROLLUP_ID};
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_aggregator::{
+    mempool::{Mempool, MempoolConfig},
+    rpc::{AggregatorRpc, AggregatorRpcImpl, JsonBytes, JsonHash, JsonUint128, TxStatus},
+    state,
+    store::Store,
+};
+use godwoken_executor::executor::Executor;
+use godwoken_types::{packed::*, prelude::*, signing::tx_hash};
+use godwoken_utils::smt;
+use std::sync::{Arc, Mutex};
+
+const AG_INDEX: u64 = 0;
+
+/// push a block of `txs` and commit
+fn push_block(store: &mut Store, txs: Vec<Tx>) {
+    let number = store.block_count();
+    state::apply_txs(&Executor::new(ROLLUP_ID), store, &txs, AG_INDEX, &[0u8; 96])
+        .expect("apply txs");
+    let block = AgBlock::new_builder()
+        .number(number.pack())
+        .txs_count((txs.len() as u32).pack())
+        .account_root(store.account_root().pack())
+        .ag_index(AG_INDEX.pack())
+        .build();
+    store.push_block(block, txs).expect("push block");
+    store.commit().expect("commit");
+}
+
+fn setup(store: Store) -> (AggregatorRpcImpl, Arc<Mutex<Store>>) {
+    let store = Arc::new(Mutex::new(store));
+    let mempool = Arc::new(Mutex::new(Mempool::new(
+        ROLLUP_ID,
+        MempoolConfig::default(),
+    )));
+    (AggregatorRpcImpl::new(Arc::clone(&store), mempool), store)
+}
+
+#[test]
+fn test_send_transaction() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let store = prepare_store(vec![([0u8; 20], 0), (pubkey_hash, 100), ([0u8; 20], 0)]);
+    let (rpc, _store) = setup(store);
+    assert_eq!(rpc.get_nonce(1).expect("get nonce"), Some(0));
+    assert_eq!(rpc.get_nonce(3).expect("get nonce"), None);
+    let tx = sign_tx(
+        &privkey,
+        &ROLLUP_ID,
+        gen_transfer_tx(1, 2, 1, CKB_TOKEN_ID, 15, 3),
+    );
+    let hash = rpc
+        .send_transaction(JsonBytes::from_entity(&tx), None)
+        .expect("send tx");
+    assert_eq!(hash, JsonHash(tx_hash(&tx.as_reader())));
+    // the pending tx is counted
+    assert_eq!(rpc.get_nonce(1).expect("get nonce"), Some(1));
+    let view = rpc
+        .get_transaction(hash)
+        .expect("get tx")
+        .expect("tx exists");
+    assert_eq!(view.status, TxStatus::Pending);
+    assert_eq!(view.tx.as_bytes(), tx.as_slice());
+    assert_eq!(view.block_number, None);
+
+    // invalid txs are rejected
+    let unsigned_tx = gen_transfer_tx(1, 2, 2, CKB_TOKEN_ID, 15, 3);
+    assert!(rpc
+        .send_transaction(JsonBytes::from_entity(&unsigned_tx), None)
+        .is_err());
+    assert!(rpc
+        .send_transaction(JsonBytes(vec![1u8, 2, 3].into()), None)
+        .is_err());
+    assert!(rpc
+        .send_transaction(JsonBytes::from_entity(&tx), Some(JsonBytes::default()))
+        .is_err());
+}
+
+#[test]
+fn test_query_blocks_and_accounts() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![([0u8; 20], 0), (pubkey_hash, 100), ([0u8; 20], 0)]);
+    push_block(&mut store, Vec::new());
+    let tx = sign_tx(
+        &privkey,
+        &ROLLUP_ID,
+        gen_transfer_tx(1, 2, 1, CKB_TOKEN_ID, 15, 3),
+    );
+    push_block(&mut store, vec![tx.clone()]);
+    let global_state = store.get_global_state().expect("get");
+    let (rpc, _store) = setup(store);
+
+    assert_eq!(
+        rpc.get_global_state().expect("get").as_bytes(),
+        global_state.as_slice()
+    );
+    let account = rpc.get_account(1).expect("get").expect("exists");
+    let account = Account::from_slice(account.as_bytes()).expect("account");
+    let nonce: u32 = account.nonce().unpack();
+    assert_eq!(nonce, 1);
+    assert!(rpc.get_account(3).expect("get").is_none());
+    assert_eq!(
+        rpc.get_balance(1, JsonHash(CKB_TOKEN_ID)).expect("get"),
+        JsonUint128(82)
+    );
+    assert_eq!(
+        rpc.get_balance(2, JsonHash(CKB_TOKEN_ID)).expect("get"),
+        JsonUint128(15)
+    );
+
+    let block = rpc.get_block(1).expect("get").expect("exists");
+    assert_eq!(block.txs.len(), 1);
+    assert_eq!(block.txs[0].as_bytes(), tx.as_slice());
+    assert!(rpc.get_block(2).expect("get").is_none());
+    let view = rpc
+        .get_transaction(JsonHash(tx_hash(&tx.as_reader())))
+        .expect("get tx")
+        .expect("tx exists");
+    assert_eq!(view.status, TxStatus::Committed);
+    assert_eq!(view.block_number, Some(1));
+    assert_eq!(view.tx_index, Some(0));
+    assert!(rpc
+        .get_transaction(JsonHash([1u8; 32]))
+        .expect("get tx")
+        .is_none());
+}
+
+#[test]
+fn test_get_account_proof() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut store = prepare_store(vec![([0u8; 20], 0), (pubkey_hash, 100), ([0u8; 20], 0)]);
+    push_block(&mut store, Vec::new());
+    let prev_account_root = store.account_root();
+    let tx = sign_tx(
+        &privkey,
+        &ROLLUP_ID,
+        gen_transfer_tx(1, 2, 1, CKB_TOKEN_ID, 15, 3),
+    );
+    push_block(&mut store, vec![tx]);
+    let expected_proof = store
+        .account_merkle_proof(vec![
+            smt::account_index_key(1),
+            smt::token_id_key(1, &CKB_TOKEN_ID),
+        ])
+        .expect("proof");
+    let (rpc, _store) = setup(store);
+
+    // against the latest block
+    let view = rpc
+        .get_account_proof(1, vec![JsonHash(CKB_TOKEN_ID)], 1)
+        .expect("get proof")
+        .expect("block exists");
+    assert_eq!(view.proof.as_bytes(), expected_proof.as_slice());
+    let token_kv = KeyValueMap::from_slice(view.token_kv.as_bytes()).expect("token kv");
+    let balance: u128 = token_kv.get(0).expect("kv").value().unpack();
+    assert_eq!(balance, 82);
+
+    // against a previous block
+    let view = rpc
+        .get_account_proof(1, vec![JsonHash(CKB_TOKEN_ID)], 0)
+        .expect("get proof")
+        .expect("block exists");
+    assert_eq!(view.account_root, JsonHash(prev_account_root));
+    let account = Account::from_slice(view.account.expect("account").as_bytes()).expect("account");
+    let nonce: u32 = account.nonce().unpack();
+    assert_eq!(nonce, 0);
+    let token_kv = KeyValueMap::from_slice(view.token_kv.as_bytes()).expect("token kv");
+    let balance: u128 = token_kv.get(0).expect("kv").value().unpack();
+    assert_eq!(balance, 100);
+
+    // missing account and block
+    let view = rpc
+        .get_account_proof(3, Vec::new(), 1)
+        .expect("get proof")
+        .expect("block exists");
+    assert!(view.account.is_none());
+    assert!(rpc
+        .get_account_proof(1, Vec::new(), 2)
+        .expect("get proof")
+        .is_none());
+}
+
+#[test]
+fn test_json_uint128() {
+    let value = JsonUint128(u128::max_value());
+    let json = serde_json::to_string(&value).expect("serialize");
+    assert_eq!(json, format!("\"0x{}\"", "f".repeat(32)));
+    let decoded: JsonUint128 = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(decoded, value);
+    assert_eq!(
+        serde_json::to_string(&JsonUint128(82)).expect("serialize"),
+        "\"0x52\""
+    );
+    assert!(serde_json::from_str::<JsonUint128>("\"52\"").is_err());
+    assert!(serde_json::from_str::<JsonUint128>("82").is_err());
+}
@@ -0,0 +1,157 @@
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_accounts, gen_transfer_tx},
+};
+use godwoken_aggregator::{error::Error, store::Store};
+use godwoken_types::{packed::AgBlock, prelude::*, signing::tx_hash};
+use godwoken_utils::hash::block_hash;
+
+fn prepare_accounts(store: &mut Store) {
+    for account in gen_accounts(0, 2) {
+        let index = account.index().unpack();
+        store.update_account(account).expect("update account");
+        store
+            .update_balance(index, CKB_TOKEN_ID, 100)
+            .expect("update balance");
+    }
+}
+
+fn gen_block(store: &Store) -> AgBlock {
+    AgBlock::new_builder()
+        .number(store.block_count().pack())
+        .account_root(store.account_root().pack())
+        .build()
+}
+
+#[test]
+fn test_reopen_store() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let tx = gen_transfer_tx(0, 1, 1, CKB_TOKEN_ID, 15, 3);
+    let (global_state, block) = {
+        let mut store = Store::open(dir.path()).expect("open");
+        prepare_accounts(&mut store);
+        store
+            .update_storage(1, [1u8; 32], &[42u8; 64])
+            .expect("update storage");
+        let block = gen_block(&store);
+        store
+            .push_block(block.clone(), vec![tx.clone()])
+            .expect("push block");
+        store.commit().expect("commit");
+        (store.get_global_state().expect("global state"), block)
+    };
+    let store = Store::open(dir.path()).expect("reopen");
+    assert_eq!(
+        store.get_global_state().expect("global state").as_slice(),
+        global_state.as_slice()
+    );
+    assert_eq!(store.account_count(), 2);
+    assert_eq!(store.block_count(), 1);
+    assert!(store.get_account(1).expect("get").is_some());
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 100);
+    assert_eq!(
+        store.get_account_tokens(0).expect("get"),
+        vec![CKB_TOKEN_ID]
+    );
+    assert_eq!(
+        store.get_storage(1, &[1u8; 32]).expect("get"),
+        vec![42u8; 64]
+    );
+    assert_eq!(
+        store.get_block(0).expect("get").expect("block").as_slice(),
+        block.as_slice()
+    );
+    assert_eq!(
+        store.get_block_hash(0).expect("get"),
+        Some(block_hash(&block))
+    );
+    let (stored_tx, number, index) = store
+        .get_tx(&tx_hash(&tx.as_reader()))
+        .expect("get")
+        .expect("tx");
+    assert_eq!(stored_tx.as_slice(), tx.as_slice());
+    assert_eq!((number, index), (0, 0));
+}
+
+#[test]
+fn test_discard_uncommitted_changes() {
+    let mut store = Store::open_temporary().expect("open");
+    prepare_accounts(&mut store);
+    store.commit().expect("commit");
+    let global_state = store.get_global_state().expect("global state");
+    store
+        .update_balance(0, CKB_TOKEN_ID, 42)
+        .expect("update balance");
+    let block = gen_block(&store);
+    store.push_block(block, Vec::new()).expect("push block");
+    store.discard().expect("discard");
+    assert_eq!(
+        store.get_global_state().expect("global state").as_slice(),
+        global_state.as_slice()
+    );
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 100);
+    assert!(store.get_block(0).expect("get").is_none());
+}
+
+#[test]
+fn test_reset_to_block_state() {
+    let mut store = Store::open_temporary().expect("open");
+    prepare_accounts(&mut store);
+    store
+        .push_block(gen_block(&store), Vec::new())
+        .expect("push block");
+    let block_state = store
+        .get_block_global_state(0)
+        .expect("get")
+        .expect("block state");
+    // block 1 spends the balance
+    store
+        .update_balance(0, CKB_TOKEN_ID, 0)
+        .expect("update balance");
+    store
+        .push_block(gen_block(&store), Vec::new())
+        .expect("push block");
+    store.commit().expect("commit");
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 0);
+    // reset to the state after block 0
+    store.reset(&block_state);
+    assert_eq!(store.block_count(), 1);
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 100);
+    assert!(store.get_block(1).expect("get").is_none());
+    assert_eq!(
+        store.get_global_state().expect("global state").as_slice(),
+        block_state.as_slice()
+    );
+}
+
+#[test]
+fn test_missing_storage_value() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    {
+        let mut store = Store::open(dir.path()).expect("open");
+        prepare_accounts(&mut store);
+        store
+            .update_storage(1, [1u8; 32], &[42u8; 64])
+            .expect("update storage");
+        store.commit().expect("commit");
+    }
+    // lose the preimages of storage values
+    {
+        let db = sled::open(dir.path()).expect("open db");
+        db.open_tree("storage_values")
+            .expect("open tree")
+            .clear()
+            .expect("clear");
+        db.flush().expect("flush");
+    }
+    let store = Store::open(dir.path()).expect("reopen");
+    match store.get_storage(1, &[1u8; 32]) {
+        Err(Error::MissingStorageValue(_)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    // a missing slot is still empty
+    assert_eq!(
+        store.get_storage(1, &[2u8; 32]).expect("get"),
+        Vec::<u8>::new()
+    );
+}
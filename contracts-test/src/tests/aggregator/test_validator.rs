@@ -0,0 +1,172 @@
+use super::mock_chain::rollup_id;
+use super::test_chain::{invalid_block_tx, register_tx, rollup_cell, setup, submit_tx};
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_aggregator::{
+    chain::{transaction_hash, ChainClient},
+    constants::CHALLENGE_CELL_WAIT_BLOCKS,
+    error::Error,
+    generator::{Generator, GeneratorConfig},
+    validator::{Validator, ValidatorConfig, Wallet},
+};
+use godwoken_types::{packed::*, prelude::*};
+use std::sync::Arc;
+
+const RESEND_BLOCKS: u64 = 20;
+
+/// the txs are sent as they are
+pub(super) struct MockWallet;
+
+impl Wallet for MockWallet {
+    fn complete_transaction(&self, tx: Transaction) -> Result<Transaction, Error> {
+        Ok(tx)
+    }
+}
+
+fn validator_config(challenger_index: u64) -> ValidatorConfig {
+    ValidatorConfig {
+        challenger_index,
+        challenge_capacity: 500,
+        challenge_lock: Script::default(),
+        challenge_code_hash: [0u8; 32],
+        challenge_hash_type: 0,
+        withdraw_lock_hash: [3u8; 32],
+        cell_deps: Vec::new(),
+        resend_blocks: RESEND_BLOCKS,
+    }
+}
+
+#[test]
+fn test_challenge_and_revert_invalid_block() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1);
+    mempool
+        .push_tx(&store, sign_tx(&privkey, &rollup_id(), tx))
+        .expect("push tx");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let (block, tx) = invalid_block_tx(&follower, &mut store, &mut mempool, &generator);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("submit block");
+    let chain = Arc::clone(follower.client());
+    let mut validator = Validator::new(
+        Arc::clone(&chain),
+        MockWallet,
+        rollup_id(),
+        validator_config(1),
+    );
+
+    // challenge the block
+    let cell = rollup_cell(&follower, &store);
+    let tx_hash = validator
+        .process(&store, &cell)
+        .expect("process")
+        .expect("challenge");
+    let challenge = validator.challenge().expect("challenge").clone();
+    let out_point_tx_hash: [u8; 32] = challenge.out_point.tx_hash().unpack();
+    assert_eq!(out_point_tx_hash, tx_hash);
+    assert_eq!(challenge.number, 0);
+    let context = ChallengeContext::from_slice(&challenge.data).expect("context");
+    assert_eq!(context.block().as_slice(), block.as_slice());
+    assert_eq!(context.txs().len(), 1);
+    let challenger_index: u64 = context.challenger_index().unpack();
+    assert_eq!(challenger_index, 1);
+    let challenge_tx = chain.get_transaction(&tx_hash).expect("get");
+    assert!(challenge_tx.is_none());
+    let committed_number = chain.commit_sent_txs();
+    follower.sync(&mut store, &mut mempool).expect("sync");
+    let challenge_tx = chain.get_transaction(&tx_hash).expect("get").expect("tx");
+    assert_eq!(transaction_hash(&challenge_tx), tx_hash);
+
+    // wait for the challenge cell
+    assert!(validator.process(&store, &cell).expect("process").is_none());
+    assert_eq!(
+        validator.challenge().expect("challenge").committed_number,
+        Some(committed_number)
+    );
+    for _ in 1..CHALLENGE_CELL_WAIT_BLOCKS {
+        chain.push_block(Vec::new());
+    }
+    follower.sync(&mut store, &mut mempool).expect("sync");
+    assert!(validator.process(&store, &cell).expect("process").is_none());
+    chain.push_block(Vec::new());
+    follower.sync(&mut store, &mut mempool).expect("sync");
+
+    // revert the block, the tx is sent once
+    let revert_tx_hash = validator.process(&store, &cell).expect("process");
+    assert!(revert_tx_hash.is_some());
+    assert!(validator.process(&store, &cell).expect("process").is_none());
+    chain.commit_sent_txs();
+    follower
+        .sync(&mut store, &mut mempool)
+        .expect("sync revert block");
+    assert!(!store.has_unverified_blocks());
+    assert_eq!(store.block_count(), 1);
+    let reverted_block = store.get_block(0).expect("get").expect("block");
+    assert!(reverted_block.as_reader().is_reverted_block());
+    assert_eq!(store.get_balance(0, &CKB_TOKEN_ID).expect("get"), 0);
+    assert_eq!(store.get_balance(1, &CKB_TOKEN_ID).expect("get"), 4100);
+    let cell = rollup_cell(&follower, &store);
+    let out_point_tx_hash: [u8; 32] = cell.out_point.tx_hash().unpack();
+    assert_eq!(Some(out_point_tx_hash), revert_tx_hash);
+    assert!(validator.process(&store, &cell).expect("process").is_none());
+    assert!(validator.challenge().is_none());
+}
+
+#[test]
+fn test_resend_dropped_challenge() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let tx = register_tx(&follower, &mut store, ag_pubkey_hash, 5000);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = register_tx(&follower, &mut store, pubkey_hash, 100);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("register");
+    let tx = gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1);
+    mempool
+        .push_tx(&store, sign_tx(&privkey, &rollup_id(), tx))
+        .expect("push tx");
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let (_block, tx) = invalid_block_tx(&follower, &mut store, &mut mempool, &generator);
+    submit_tx(&follower, &mut store, &mut mempool, tx).expect("submit block");
+    let chain = Arc::clone(follower.client());
+    let mut validator = Validator::new(
+        Arc::clone(&chain),
+        MockWallet,
+        rollup_id(),
+        validator_config(1),
+    );
+    let cell = rollup_cell(&follower, &store);
+    let tx_hash = validator
+        .process(&store, &cell)
+        .expect("process")
+        .expect("challenge");
+    chain.drop_sent_txs();
+
+    for _ in 1..RESEND_BLOCKS {
+        chain.push_block(Vec::new());
+    }
+    follower.sync(&mut store, &mut mempool).expect("sync");
+    assert!(validator.process(&store, &cell).expect("process").is_none());
+    chain.push_block(Vec::new());
+    follower.sync(&mut store, &mut mempool).expect("sync");
+    // the same tx is sent again
+    assert_eq!(
+        validator.process(&store, &cell).expect("process"),
+        Some(tx_hash)
+    );
+    assert!(validator.process(&store, &cell).expect("process").is_none());
+    let committed_number = chain.commit_sent_txs();
+    follower.sync(&mut store, &mut mempool).expect("sync");
+    assert!(validator.process(&store, &cell).expect("process").is_none());
+    assert_eq!(
+        validator.challenge().expect("challenge").committed_number,
+        Some(committed_number)
+    );
+}
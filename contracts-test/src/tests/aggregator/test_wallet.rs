@@ -0,0 +1,166 @@
+use super::mock_chain::MockChain;
+use godwoken_aggregator::{
+    chain::{transaction_hash, ChainClient},
+    error::Error,
+    validator::Wallet,
+    wallet::{Secp256k1Wallet, WalletConfig},
+};
+use godwoken_tx_builder::{constants::BYTE_SHANNONS, error::Error as BuilderError};
+use godwoken_types::{bytes::Bytes, packed::*, prelude::*};
+use godwoken_utils::hash::new_blake2b;
+use secp256k1::{Message, PublicKey, RecoveryId, SecretKey, Signature};
+use std::sync::Arc;
+
+const FEE_RATE: u64 = 1000;
+
+fn wallet_config() -> WalletConfig {
+    WalletConfig {
+        lock_code_hash: [5u8; 32],
+        lock_hash_type: 1,
+        cell_deps: vec![CellDep::new_builder()
+            .out_point(OutPoint::new_builder().tx_hash([6u8; 32].pack()).build())
+            .dep_type(1u8.into())
+            .build()],
+        fee_rate: FEE_RATE,
+    }
+}
+
+/// a tx with outputs of the lock
+fn payment_tx(lock: &Script, capacities: &[u64]) -> Transaction {
+    let outputs: Vec<CellOutput> = capacities
+        .iter()
+        .map(|capacity| {
+            CellOutput::new_builder()
+                .capacity(capacity.pack())
+                .lock(lock.clone())
+                .build()
+        })
+        .collect();
+    let raw = RawTransaction::new_builder()
+        .outputs_data(vec![Bytes::new().pack(); outputs.len()].pack())
+        .outputs(outputs.pack())
+        .build();
+    Transaction::new_builder().raw(raw).build()
+}
+
+/// a tx creating a challenge-like cell, the proof is in the `output_type` of the witness
+fn unpaid_tx(capacity: u64) -> Transaction {
+    let output = CellOutput::new_builder()
+        .capacity(capacity.pack())
+        .type_(Some(Script::new_builder().code_hash([7u8; 32].pack()).build()).pack())
+        .build();
+    let witness = WitnessArgs::new_builder()
+        .output_type(
+            BytesOpt::new_builder()
+                .set(Some(Bytes::from(vec![1u8, 2, 3]).pack()))
+                .build(),
+        )
+        .build();
+    let raw = RawTransaction::new_builder()
+        .outputs(vec![output].pack())
+        .outputs_data(vec![Bytes::new().pack()].pack())
+        .build();
+    Transaction::new_builder()
+        .raw(raw)
+        .witnesses(vec![witness.as_bytes().pack()].pack())
+        .build()
+}
+
+/// recover the pubkey of the sighash-all signature of a tx with one input of the lock
+fn recover_signer(tx: &Transaction) -> PublicKey {
+    let witness: Bytes = tx.witnesses().get(0).expect("witness").unpack();
+    let witness_args = WitnessArgs::from_slice(&witness).expect("witness args");
+    let sig: Bytes = witness_args.lock().to_opt().expect("signature").unpack();
+    assert_eq!(sig.len(), 65);
+    let zero_witness = witness_args
+        .as_builder()
+        .lock(
+            BytesOpt::new_builder()
+                .set(Some(Bytes::from(vec![0u8; 65]).pack()))
+                .build(),
+        )
+        .build()
+        .as_bytes();
+    let mut hasher = new_blake2b();
+    hasher.update(&transaction_hash(tx));
+    hasher.update(&(zero_witness.len() as u64).to_le_bytes());
+    hasher.update(&zero_witness);
+    let mut message = [0u8; 32];
+    hasher.finalize(&mut message);
+    secp256k1::recover(
+        &Message::parse(&message),
+        &Signature::parse_slice(&sig[..64]).expect("signature"),
+        &RecoveryId::parse(sig[64]).expect("recovery id"),
+    )
+    .expect("recover")
+}
+
+#[test]
+fn test_complete_transaction() {
+    let chain = Arc::new(MockChain::new());
+    let privkey = SecretKey::parse(&[1u8; 32]).expect("privkey");
+    let wallet = Secp256k1Wallet::new(Arc::clone(&chain), privkey.clone(), wallet_config());
+    let input_capacity = 1000 * BYTE_SHANNONS;
+    chain.push_block(vec![payment_tx(wallet.lock(), &[input_capacity])]);
+
+    let output_capacity = 200 * BYTE_SHANNONS;
+    let tx = unpaid_tx(output_capacity);
+    let completed = wallet.complete_transaction(tx.clone()).expect("complete");
+    let raw = completed.raw();
+    assert_eq!(raw.inputs().len(), 1);
+    assert_eq!(raw.outputs().len(), 2);
+    assert_eq!(
+        raw.outputs().get(0).expect("output").as_slice(),
+        tx.raw().outputs().get(0).expect("output").as_slice()
+    );
+    let change = raw.outputs().get(1).expect("change");
+    assert_eq!(change.lock().as_slice(), wallet.lock().as_slice());
+    let change_capacity: u64 = change.capacity().unpack();
+    let fee = input_capacity - output_capacity - change_capacity;
+    let min_fee = (completed.as_slice().len() as u64 * FEE_RATE + 999) / 1000;
+    assert!(fee >= min_fee);
+    assert_eq!(
+        raw.cell_deps().get(0).expect("cell dep").as_slice(),
+        wallet_config().cell_deps[0].as_slice()
+    );
+
+    // the proof is kept beside the signature
+    let witness: Bytes = completed.witnesses().get(0).expect("witness").unpack();
+    let witness_args = WitnessArgs::from_slice(&witness).expect("witness args");
+    let output_type: Bytes = witness_args.output_type().to_opt().expect("proof").unpack();
+    assert_eq!(output_type.as_ref(), &[1u8, 2, 3]);
+    assert_eq!(
+        recover_signer(&completed).serialize_compressed()[..],
+        PublicKey::from_secret_key(&privkey).serialize_compressed()[..]
+    );
+}
+
+#[test]
+fn test_spent_cells_are_not_reused() {
+    let chain = Arc::new(MockChain::new());
+    let privkey = SecretKey::parse(&[1u8; 32]).expect("privkey");
+    let wallet = Secp256k1Wallet::new(Arc::clone(&chain), privkey, wallet_config());
+    chain.push_block(vec![payment_tx(wallet.lock(), &[1000 * BYTE_SHANNONS])]);
+
+    let tx = unpaid_tx(200 * BYTE_SHANNONS);
+    let completed = wallet.complete_transaction(tx.clone()).expect("complete");
+    // the first tx isn't committed yet
+    match wallet.complete_transaction(tx.clone()) {
+        Err(Error::Builder(BuilderError::InsufficientCapacity(_, 0))) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    // the change of the committed tx pays the next tx
+    chain.send_transaction(&completed).expect("send");
+    chain.commit_sent_txs();
+    let next = wallet.complete_transaction(tx).expect("complete");
+    let input = next.raw().inputs().get(0).expect("input");
+    let expected_out_point = OutPoint::new_builder()
+        .tx_hash(transaction_hash(&completed).pack())
+        .index(1u32.pack())
+        .build();
+    assert_eq!(
+        input.previous_output().as_slice(),
+        expected_out_point.as_slice()
+    );
+}
@@ -0,0 +1,120 @@
+//! Run the challenge contract on txs built by `godwoken-tx-builder`
+
+mod test_challenge;
+mod test_invalid_challenge;
+
+use crate::tests::{
+    aggregator::mock_chain::rollup_type_script,
+    tx_builder::{output_cell, StoreProvider, FEE_RATE},
+    utils::shortcut::default_context,
+    CHALLENGE_CONTRACT_BIN, CHALLENGE_CONTRACT_HASH, DUMMY_LOCK_HASH,
+};
+use ckb_tool::{
+    ckb_error::Error as CKBError,
+    ckb_types::{core::Cycle, packed as ckb_packed, prelude::Entity as _},
+};
+use godwoken_aggregator::{chain::script_hash, store::Store};
+use godwoken_tx_builder::{
+    challenge::ChallengeBuilder, config::BuilderConfig, constants::BYTE_SHANNONS,
+    provider::LiveCell,
+};
+use godwoken_types::{bytes::Bytes, core::ScriptHashType, packed::*, prelude::*};
+
+/// re-executing txs takes more cycles than the main contract
+pub const MAX_CYCLES: u64 = 200_000_000;
+pub const CHALLENGER_INDEX: u64 = 1;
+
+#[derive(Debug)]
+#[repr(i8)]
+pub enum Error {
+    InvalidEncoding = -1,
+    NoUnlockCell = -2,
+    InvalidMerkleProof = -3,
+    InvalidProveChallege = -5,
+    InvalidTx = -8,
+}
+
+/// it unlocks any cell
+pub fn dummy_lock() -> Script {
+    Script::new_builder()
+        .code_hash(DUMMY_LOCK_HASH.pack())
+        .hash_type(ScriptHashType::Data.into())
+        .build()
+}
+
+/// the challenger's lock, its cell in the inputs withdraws a challenge cell
+pub fn withdraw_lock() -> Script {
+    dummy_lock()
+        .as_builder()
+        .args(Bytes::from(vec![6u8]).pack())
+        .build()
+}
+
+/// config of the deployed contracts, cells are locked by the dummy lock
+pub fn builder_config() -> BuilderConfig {
+    BuilderConfig {
+        cell_deps: Vec::new(),
+        fee_rate: FEE_RATE,
+        change_lock: dummy_lock(),
+        challenge_capacity: 500 * BYTE_SHANNONS,
+        challenge_lock: dummy_lock(),
+        challenge_code_hash: *CHALLENGE_CONTRACT_HASH,
+        challenge_hash_type: ScriptHashType::Data.into(),
+        withdraw_lock_hash: script_hash(&withdraw_lock()),
+    }
+}
+
+/// a cell of the dummy lock
+pub fn payment_cell(n: u8, capacity: u64) -> LiveCell {
+    LiveCell {
+        out_point: OutPoint::new_builder().tx_hash([n; 32].pack()).build(),
+        output: CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(dummy_lock())
+            .build(),
+        data: Default::default(),
+    }
+}
+
+pub fn rollup_cell(store: &Store) -> LiveCell {
+    LiveCell {
+        out_point: OutPoint::new_builder().tx_hash([7u8; 32].pack()).build(),
+        output: CellOutput::new_builder()
+            .capacity((1000 * BYTE_SHANNONS).pack())
+            .type_(Some(rollup_type_script()).pack())
+            .build(),
+        data: store.get_global_state().expect("get").as_bytes(),
+    }
+}
+
+pub fn provider(store: &Store, payment_cells: Vec<LiveCell>) -> StoreProvider {
+    StoreProvider::new(store, rollup_cell(store), payment_cells)
+}
+
+/// the tx creates a challenge cell of the block, the challenge cell is its first output
+pub fn create_challenge(store: &Store, block: &AgBlock, txs: &[Tx]) -> (Transaction, LiveCell) {
+    let payment = payment_cell(1, 1000 * BYTE_SHANNONS);
+    let tx = ChallengeBuilder::new(block.clone(), txs.to_vec(), CHALLENGER_INDEX)
+        .build(&provider(store, vec![payment]), &builder_config())
+        .expect("build challenge");
+    let challenge_cell = output_cell(&tx, 0);
+    (tx, challenge_cell)
+}
+
+/// run the scripts of a tx, `inputs` are the live cells consumed by the tx
+pub fn verify_tx(tx: &Transaction, inputs: &[LiveCell]) -> Result<Cycle, CKBError> {
+    let mut context = default_context();
+    context.deploy_contract(CHALLENGE_CONTRACT_BIN.clone());
+    for cell in inputs {
+        context.create_cell_with_out_point(
+            ckb_packed::OutPoint::from_slice(cell.out_point.as_slice()).expect("out point"),
+            ckb_packed::CellOutput::from_slice(cell.output.as_slice()).expect("cell output"),
+            cell.data.to_vec().into(),
+        );
+    }
+    let tx = ckb_packed::Transaction::from_slice(tx.as_slice())
+        .expect("transaction")
+        .into_view();
+    let tx = context.complete_tx(tx);
+    context.verify_tx(&tx, MAX_CYCLES)
+}
@@ -0,0 +1,170 @@
+use super::{
+    builder_config, create_challenge, dummy_lock, payment_cell, provider, verify_tx, withdraw_lock,
+    Error,
+};
+use crate::tests::{
+    aggregator::prepare_store,
+    tx_builder::{output_cell, StoreProvider},
+    utils::{constants::CKB_TOKEN_ID, shortcut::gen_transfer_tx},
+};
+use ckb_tool::{ckb_error::assert_error_eq, ckb_script::ScriptError};
+use godwoken_aggregator::store::Store;
+use godwoken_tx_builder::{
+    challenge::{ChallengeBuilder, WithdrawChallengeBuilder},
+    constants::BYTE_SHANNONS,
+    provider::LiveCell,
+    revert_block::RevertBlockBuilder,
+};
+use godwoken_types::{bytes::Bytes, packed::*, prelude::*, signing::tx_hash};
+use godwoken_utils::mmr::merkle_root;
+
+/// accounts of the aggregator and the challenger, a block of a wrong account root is pushed
+/// to the store, the accounts are kept before the block
+fn prepare_block(tx_root: Option<[u8; 32]>) -> (Store, AgBlock, Vec<Tx>) {
+    let mut store = prepare_store(vec![([1u8; 20], 5000), ([2u8; 20], 100)]);
+    let txs = vec![gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1)];
+    let tx_root = tx_root
+        .unwrap_or_else(|| merkle_root(txs.iter().map(|tx| tx_hash(&tx.as_reader())).collect()));
+    let block = AgBlock::new_builder()
+        .tx_root(tx_root.pack())
+        .txs_count(1u32.pack())
+        .prev_account_root(store.account_root().pack())
+        .prev_account_count(store.account_count().pack())
+        .account_root([9u8; 32].pack())
+        .build();
+    store
+        .push_block(block.clone(), txs.clone())
+        .expect("push block");
+    (store, block, txs)
+}
+
+/// a rollup cell of the dummy lock as type, the main contract isn't run
+fn dummy_rollup_cell(store: &Store) -> LiveCell {
+    let type_script = dummy_lock()
+        .as_builder()
+        .args(Bytes::from(vec![1u8]).pack())
+        .build();
+    LiveCell {
+        out_point: OutPoint::new_builder().tx_hash([7u8; 32].pack()).build(),
+        output: CellOutput::new_builder()
+            .capacity((1000 * BYTE_SHANNONS).pack())
+            .lock(dummy_lock())
+            .type_(Some(type_script).pack())
+            .build(),
+        data: store.get_global_state().expect("get").as_bytes(),
+    }
+}
+
+#[test]
+fn test_create_challenge() {
+    let (store, block, txs) = prepare_block(None);
+    let (tx, _challenge_cell) = create_challenge(&store, &block, &txs);
+    verify_tx(&tx, &[payment_cell(1, 1000 * BYTE_SHANNONS)]).expect("pass verification");
+}
+
+#[test]
+fn test_create_challenge_of_wrong_txs() {
+    // the txs aren't in the block
+    let (store, block, txs) = prepare_block(Some([8u8; 32]));
+    let (tx, _challenge_cell) = create_challenge(&store, &block, &txs);
+    let verify_result = verify_tx(&tx, &[payment_cell(1, 1000 * BYTE_SHANNONS)]);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::InvalidMerkleProof as i8)
+    );
+}
+
+#[test]
+fn test_revert_block() {
+    let (store, block, txs) = prepare_block(None);
+    let rollup_cell = dummy_rollup_cell(&store);
+    let bond = payment_cell(1, 1000 * BYTE_SHANNONS);
+    let payment = payment_cell(2, 1000 * BYTE_SHANNONS);
+    let challenge_tx = ChallengeBuilder::new(block, txs, 1)
+        .build(
+            &StoreProvider::new(&store, rollup_cell.clone(), vec![bond]),
+            &builder_config(),
+        )
+        .expect("build challenge");
+    let challenge_cell = output_cell(&challenge_tx, 0);
+    let tx = RevertBlockBuilder::new(challenge_cell.clone())
+        .build(
+            &StoreProvider::new(&store, rollup_cell.clone(), vec![payment.clone()]),
+            &builder_config(),
+        )
+        .expect("build revert block");
+    // the rollup cell isn't in the challenge cell's script group
+    let inputs = [rollup_cell, challenge_cell, payment];
+    verify_tx(&tx, &inputs).expect("pass verification");
+
+    // the challenge cell must be kept
+    let raw = tx.raw();
+    let outputs: Vec<CellOutput> = raw
+        .outputs()
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| *index != 1)
+        .map(|(_, output)| output)
+        .collect();
+    let outputs_data: Vec<Bytes> = raw
+        .outputs_data()
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| *index != 1)
+        .map(|(_, data)| data.unpack())
+        .collect();
+    let tx = tx
+        .as_builder()
+        .raw(
+            raw.as_builder()
+                .outputs(outputs.pack())
+                .outputs_data(outputs_data.pack())
+                .build(),
+        )
+        .build();
+    let verify_result = verify_tx(&tx, &inputs);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::InvalidProveChallege as i8)
+    );
+}
+
+#[test]
+fn test_withdraw_challenge() {
+    let (store, block, txs) = prepare_block(None);
+    let (_tx, challenge_cell) = create_challenge(&store, &block, &txs);
+    // the bond pays the fee
+    let tx = WithdrawChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build withdraw challenge");
+    // anyone else can't withdraw
+    let verify_result = verify_tx(&tx, &[challenge_cell.clone()]);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::NoUnlockCell as i8)
+    );
+
+    // the challenger unlocks a cell of the withdraw lock in the tx
+    let withdraw_cell = LiveCell {
+        output: CellOutput::new_builder()
+            .capacity((100 * BYTE_SHANNONS).pack())
+            .lock(withdraw_lock())
+            .build(),
+        ..payment_cell(2, 0)
+    };
+    let raw = tx.raw();
+    let inputs = raw
+        .inputs()
+        .as_builder()
+        .push(
+            CellInput::new_builder()
+                .previous_output(withdraw_cell.out_point.clone())
+                .build(),
+        )
+        .build();
+    let tx = tx
+        .as_builder()
+        .raw(raw.as_builder().inputs(inputs).build())
+        .build();
+    verify_tx(&tx, &[challenge_cell, withdraw_cell]).expect("pass verification");
+}
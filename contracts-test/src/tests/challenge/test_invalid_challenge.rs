@@ -0,0 +1,293 @@
+use super::{
+    builder_config, create_challenge, payment_cell, provider, verify_tx, Error, CHALLENGER_INDEX,
+};
+use crate::tests::{
+    aggregator::{mock_chain::rollup_id, prepare_store},
+    tx_builder::output_cell,
+    utils::{
+        constants::CKB_TOKEN_ID,
+        shortcut::{
+            gen_key_pair, gen_multi_transfer_tx, gen_transfer_tx, gen_withdraw_request_tx, sign_tx,
+        },
+    },
+};
+use ckb_tool::{ckb_error::assert_error_eq, ckb_script::ScriptError};
+use godwoken_aggregator::{state, store::Store};
+use godwoken_executor::executor::Executor;
+use godwoken_tx_builder::{
+    challenge::{ChallengeBuilder, InvalidChallengeBuilder},
+    constants::BYTE_SHANNONS,
+};
+use godwoken_types::{
+    bytes::Bytes,
+    packed::*,
+    prelude::*,
+    signing::{raw_tx_hash, tx_hash, tx_signing_message},
+};
+use godwoken_utils::{bls, mmr::merkle_root};
+
+const AG_INDEX: u64 = 0;
+const SENDER_INDEX: u64 = 2;
+const RECEIVER_INDEX: u64 = 3;
+const BLS_TXS: u32 = 4;
+
+/// accounts of the aggregator, the challenger, the sender and the receiver,
+/// returns the sender's key
+fn prepare_accounts() -> (Store, secp256k1::SecretKey) {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let store = prepare_store(vec![
+        ([1u8; 20], 5000),
+        ([2u8; 20], 100),
+        (pubkey_hash, 100),
+        ([3u8; 20], 0),
+    ]);
+    (store, privkey)
+}
+
+/// the account root and the logs root after the txs, the store is unchanged
+fn run_txs(store: &mut Store, txs: &[Tx]) -> ([u8; 32], [u8; 32]) {
+    run_txs_with_aggregate_sig(store, txs, &[0u8; 96])
+}
+
+fn run_txs_with_aggregate_sig(
+    store: &mut Store,
+    txs: &[Tx],
+    aggregate_sig: &[u8; 96],
+) -> ([u8; 32], [u8; 32]) {
+    let global_state = store.get_global_state().expect("get");
+    let run_results = state::apply_txs(
+        &Executor::new(rollup_id()),
+        store,
+        txs,
+        AG_INDEX,
+        aggregate_sig,
+    )
+    .expect("apply txs");
+    let account_root = store.account_root();
+    store.reset(&global_state);
+    let logs_root = merkle_root(run_results.iter().map(|r| r.logs_hash()).collect());
+    (account_root, logs_root)
+}
+
+/// push a block of the txs, the accounts are kept before the block
+fn push_block(
+    store: &mut Store,
+    txs: Vec<Tx>,
+    account_root: [u8; 32],
+    logs_root: [u8; 32],
+) -> AgBlock {
+    push_block_with_aggregate_sig(store, txs, account_root, logs_root, [0u8; 96])
+}
+
+fn push_block_with_aggregate_sig(
+    store: &mut Store,
+    txs: Vec<Tx>,
+    account_root: [u8; 32],
+    logs_root: [u8; 32],
+    aggregate_sig: [u8; 96],
+) -> AgBlock {
+    let tx_root = merkle_root(txs.iter().map(|tx| tx_hash(&tx.as_reader())).collect());
+    let block = AgBlock::new_builder()
+        .number(store.block_count().pack())
+        .tx_root(tx_root.pack())
+        .txs_count((txs.len() as u32).pack())
+        .logs_root(logs_root.pack())
+        .ag_index(AG_INDEX.pack())
+        .prev_account_root(store.account_root().pack())
+        .prev_account_count(store.account_count().pack())
+        .account_root(account_root.pack())
+        .aggregate_sig(aggregate_sig.pack())
+        .build();
+    store.push_block(block.clone(), txs).expect("push block");
+    block
+}
+
+#[test]
+fn test_invalid_challenge() {
+    let (mut store, privkey) = prepare_accounts();
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let txs = vec![sign_tx(&privkey, &rollup_id(), tx)];
+    let (account_root, logs_root) = run_txs(&mut store, &txs);
+    let block = push_block(&mut store, txs.clone(), account_root, logs_root);
+    let (_tx, challenge_cell) = create_challenge(&store, &block, &txs);
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build invalid challenge");
+    verify_tx(&tx, &[challenge_cell]).expect("pass verification");
+}
+
+#[test]
+fn test_invalid_challenge_of_zero_amounts_in_fresh_token() {
+    // no account holds the token, the zero balances aren't written to the state
+    let fresh_token_id = [5u8; 32];
+    let (mut store, privkey) = prepare_accounts();
+    let txs: Vec<Tx> = vec![
+        gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, fresh_token_id, 0, 0),
+        gen_withdraw_request_tx(SENDER_INDEX, 2, fresh_token_id, 0, 0),
+    ]
+    .into_iter()
+    .map(|tx| sign_tx(&privkey, &rollup_id(), tx))
+    .collect();
+    let (account_root, logs_root) = run_txs(&mut store, &txs);
+    let block = push_block(&mut store, txs.clone(), account_root, logs_root);
+    let (_tx, challenge_cell) = create_challenge(&store, &block, &txs);
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build invalid challenge");
+    verify_tx(&tx, &[challenge_cell]).expect("pass verification");
+}
+
+#[test]
+fn test_invalid_challenge_of_bls_block() {
+    let (mut store, _privkey) = prepare_accounts();
+    let secret_key = [7u8; 32];
+    let sender = store
+        .get_account(SENDER_INDEX)
+        .expect("get")
+        .expect("sender")
+        .as_builder()
+        .bls_pubkey(Some(bls::pubkey(&secret_key).expect("pubkey")).pack())
+        .build();
+    store.update_account(sender).expect("update account");
+    store.commit().expect("commit");
+    // each BLS tx adds a pairing to the aggregate verification
+    let txs: Vec<Tx> = (1..=BLS_TXS)
+        .map(|nonce| gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, nonce, CKB_TOKEN_ID, 10, 1))
+        .collect();
+    let signatures: Vec<[u8; 96]> = txs
+        .iter()
+        .map(|tx| {
+            let message = tx_signing_message(&rollup_id(), &raw_tx_hash(&tx.raw().as_reader()));
+            bls::sign(&secret_key, &message).expect("sign")
+        })
+        .collect();
+    let aggregate_sig = bls::aggregate_signatures(&signatures).expect("aggregate");
+    let (account_root, logs_root) = run_txs_with_aggregate_sig(&mut store, &txs, &aggregate_sig);
+    let block = push_block_with_aggregate_sig(
+        &mut store,
+        txs.clone(),
+        account_root,
+        logs_root,
+        aggregate_sig,
+    );
+    let (_tx, challenge_cell) = create_challenge(&store, &block, &txs);
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build invalid challenge");
+    verify_tx(&tx, &[challenge_cell]).expect("pass verification");
+}
+
+#[test]
+fn test_invalid_challenge_of_invalid_block() {
+    let (mut store, privkey) = prepare_accounts();
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let txs = vec![sign_tx(&privkey, &rollup_id(), tx)];
+    let (_account_root, logs_root) = run_txs(&mut store, &txs);
+    let block = push_block(&mut store, txs.clone(), [9u8; 32], logs_root);
+    let (_tx, challenge_cell) = create_challenge(&store, &block, &txs);
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build invalid challenge");
+    let verify_result = verify_tx(&tx, &[challenge_cell]);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::InvalidMerkleProof as i8)
+    );
+}
+
+#[test]
+fn test_invalid_challenge_of_block_larger_than_load_buffer() {
+    let (mut store, privkey) = prepare_accounts();
+    // the contract loads the challenge context and the witnesses by chunks of 4096 bytes
+    let tx = gen_multi_transfer_tx(
+        SENDER_INDEX,
+        vec![(RECEIVER_INDEX, 1); 60],
+        1,
+        CKB_TOKEN_ID,
+        1,
+    );
+    let txs = vec![sign_tx(&privkey, &rollup_id(), tx)];
+    assert!(txs[0].as_slice().len() > 4096);
+    let (account_root, logs_root) = run_txs(&mut store, &txs);
+    let block = push_block(&mut store, txs.clone(), account_root, logs_root);
+    // the challenge cell's capacity covers the block and txs
+    let payment = payment_cell(1, 10_000 * BYTE_SHANNONS);
+    let tx = ChallengeBuilder::new(block, txs, CHALLENGER_INDEX)
+        .build(&provider(&store, vec![payment.clone()]), &builder_config())
+        .expect("build challenge");
+    verify_tx(&tx, &[payment]).expect("pass verification");
+    let challenge_cell = output_cell(&tx, 0);
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build invalid challenge");
+    verify_tx(&tx, &[challenge_cell]).expect("pass verification");
+}
+
+#[test]
+fn test_invalid_challenge_of_transfer_to_contract() {
+    let (mut store, privkey) = prepare_accounts();
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let txs = vec![sign_tx(&privkey, &rollup_id(), tx)];
+    let (account_root, logs_root) = run_txs(&mut store, &txs);
+    // the block commits the state as if the receiver weren't a contract,
+    // the challenge contract can't execute the receiver's contract
+    let receiver = store
+        .get_account(RECEIVER_INDEX)
+        .expect("get")
+        .expect("receiver")
+        .as_builder()
+        .script(
+            AccountScriptOpt::new_builder()
+                .set(Some(AccountScript::default()))
+                .build(),
+        )
+        .build();
+    store.update_account(receiver).expect("update account");
+    let block = push_block(&mut store, txs.clone(), account_root, logs_root);
+    let (_tx, challenge_cell) = create_challenge(&store, &block, &txs);
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build invalid challenge");
+    let verify_result = verify_tx(&tx, &[challenge_cell]);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::InvalidTx as i8)
+    );
+}
+
+#[test]
+fn test_invalid_challenge_with_missing_storage() {
+    let (mut store, privkey) = prepare_accounts();
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let txs = vec![sign_tx(&privkey, &rollup_id(), tx)];
+    let (account_root, logs_root) = run_txs(&mut store, &txs);
+    let block = push_block(&mut store, txs.clone(), account_root, logs_root);
+    let (_tx, challenge_cell) = create_challenge(&store, &block, &txs);
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider(&store, Vec::new()), &builder_config())
+        .expect("build invalid challenge");
+    // drop the storage of the touched accounts
+    let witness: Bytes = tx.witnesses().get(0).expect("witness").unpack();
+    let invalid_challenge = match ChallengeUnlock::from_slice(&witness)
+        .expect("challenge unlock")
+        .to_enum()
+    {
+        ChallengeUnlockUnion::InvalidChallenge(invalid_challenge) => invalid_challenge,
+        unlock => panic!("unexpected unlock: {}", unlock),
+    };
+    let invalid_challenge = invalid_challenge
+        .as_builder()
+        .touched_accounts_storage(StorageKeyValueMapVec::default())
+        .build();
+    let mut witnesses: Vec<Bytes> = tx.witnesses().unpack();
+    witnesses[0] = ChallengeUnlock::new_builder()
+        .set(invalid_challenge)
+        .build()
+        .as_bytes();
+    let tx = tx.as_builder().witnesses(witnesses.pack()).build();
+    let verify_result = verify_tx(&tx, &[challenge_cell]);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::InvalidEncoding as i8)
+    );
+}
@@ -0,0 +1,9 @@
+mod test_bls;
+mod test_call;
+mod test_logs;
+mod test_nonce;
+mod test_signature;
+mod test_simulate;
+mod test_syscalls;
+mod test_transfer;
+mod test_vm;
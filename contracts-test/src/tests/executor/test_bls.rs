@@ -0,0 +1,176 @@
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_executor::{
+    error::{BlockError, Error},
+    executor::Executor,
+    state::{AccountState, State},
+};
+use godwoken_types::{
+    cache::{KVMap, StorageMap, TxWithHash},
+    core::Index,
+    packed::{Account, Tx},
+    prelude::*,
+    signing::{raw_tx_hash, tx_signing_message},
+};
+use godwoken_utils::bls;
+
+const ROLLUP_ID: [u8; 32] = [42u8; 32];
+const AG_INDEX: Index = 0;
+const BLS_SENDER_INDEX: Index = 1;
+const SENDER_INDEX: Index = 2;
+const BLS_SECRET_KEY: [u8; 32] = [7u8; 32];
+
+// known-answer vectors, secret keys are [7; 32] and [9; 32] in little-endian,
+// messages are [0x42; 32] and [0x43; 32]
+const PUBKEYS: [&str; 2] = [
+    "a4cafe0e4602bb74340d45b931591034894f6be4aae24c4e80931d622636bb4da64804903072c655995b423113f41705",
+    "ac91408d197c1166a17d38eaef54449b4f5609e66eb9774f15e2efba1bd3c617eaac32be406c78058902d001884c75ec",
+];
+const SIGNATURES: [&str; 2] = [
+    "a3fe4d5ae419348641547d5d45d973f749294e88f657a2430c9dae95e6e9457bbb1460fa90db03955284c98abaf17dc50fc4863822f8be6ee6fa9f05774aad56f0d19f38496b14c3a9087e6f56fe933f83805e1f331ae4da79f79cd27c393815",
+    "ad367f90b16759e1fa454155759258fe398217fbd8b28b0915bee0817a1d6f603b5ba3c0c3138596842e83475c7ebead0a77a0a01f650a439db8a0dcfbdbe86b116c2f7fc1a6804f553d5b8a7e3355b80c53dfaf2cb6a523ac65ce1c70f67182",
+];
+const AGGREGATE_SIGNATURE: &str = "b2b683e842f6b1a71d745b35c27a48722900123cd67475fbd24657a27cfc87d037e10f8ff9e42358a7eeb044cded18d1073a7ca1d3f64d9c68b656c30d09259748d9d9977d391b4818c12e49165940fdeb716a9b8c0206146ab0379bc28485af";
+
+fn pubkey(i: usize) -> [u8; 48] {
+    let mut buf = [0u8; 48];
+    buf.copy_from_slice(&hex::decode(PUBKEYS[i]).expect("hex"));
+    buf
+}
+
+fn signature(s: &str) -> [u8; 96] {
+    let mut buf = [0u8; 96];
+    buf.copy_from_slice(&hex::decode(s).expect("hex"));
+    buf
+}
+
+/// the aggregator, a sender of the BLS key and a sender of the secp256k1 key
+fn gen_state(pubkey_hash: [u8; 20]) -> State {
+    let bls_pubkey = bls::pubkey(&BLS_SECRET_KEY).expect("pubkey");
+    let accounts = vec![
+        Account::new_builder().index(AG_INDEX.pack()).build(),
+        Account::new_builder()
+            .index(BLS_SENDER_INDEX.pack())
+            .bls_pubkey(Some(bls_pubkey).pack())
+            .build(),
+        Account::new_builder()
+            .index(SENDER_INDEX.pack())
+            .pubkey_hash(pubkey_hash.pack())
+            .build(),
+    ];
+    let mut kv = KVMap::default();
+    kv.insert(CKB_TOKEN_ID, 100);
+    State::new(
+        accounts
+            .iter()
+            .map(|account| AccountState {
+                account: account.as_reader(),
+                kv: kv.clone(),
+                withdraw_kv: KVMap::default(),
+                storage: StorageMap::default(),
+            })
+            .collect(),
+    )
+}
+
+fn bls_sign(tx: &Tx) -> [u8; 96] {
+    let message = tx_signing_message(&ROLLUP_ID, &raw_tx_hash(&tx.raw().as_reader()));
+    bls::sign(&BLS_SECRET_KEY, &message).expect("sign")
+}
+
+fn tx_hashes(txs: &[Tx]) -> Vec<TxWithHash> {
+    txs.iter()
+        .map(|tx| TxWithHash::new(tx.as_reader()))
+        .collect()
+}
+
+#[test]
+fn test_bls_vectors() {
+    let messages = [[0x42u8; 32], [0x43u8; 32]];
+    let signatures = [signature(SIGNATURES[0]), signature(SIGNATURES[1])];
+    for (i, secret_key) in [[7u8; 32], [9u8; 32]].iter().enumerate() {
+        assert_eq!(bls::pubkey(secret_key).expect("pubkey")[..], pubkey(i)[..]);
+        // signatures are deterministic
+        let sig = bls::sign(secret_key, &messages[i]).expect("sign");
+        assert_eq!(sig[..], signatures[i][..]);
+        bls::verify(&pubkey(i), &messages[i], &sig).expect("verify");
+    }
+    let aggregate_sig = bls::aggregate_signatures(&signatures).expect("aggregate");
+    assert_eq!(aggregate_sig[..], signature(AGGREGATE_SIGNATURE)[..]);
+    let items = [(pubkey(0), &messages[0][..]), (pubkey(1), &messages[1][..])];
+    bls::aggregate_verify(&items, &aggregate_sig).expect("verify");
+    // messages are bound to the signers
+    let swapped = [(pubkey(0), &messages[1][..]), (pubkey(1), &messages[0][..])];
+    assert_eq!(
+        bls::aggregate_verify(&swapped, &aggregate_sig),
+        Err(bls::Error::VerifyFailed)
+    );
+    assert_eq!(
+        bls::aggregate_verify(&items[..1], &aggregate_sig),
+        Err(bls::Error::VerifyFailed)
+    );
+    assert_eq!(
+        bls::verify(&pubkey(0), &messages[0], &signatures[1]),
+        Err(bls::Error::VerifyFailed)
+    );
+    assert_eq!(bls::pubkey(&[0u8; 32]), Err(bls::Error::InvalidSecretKey));
+    assert_eq!(
+        bls::verify(&[0u8; 48], &messages[0], &signatures[0]),
+        Err(bls::Error::InvalidPubkey)
+    );
+}
+
+#[test]
+fn test_bls_tx_outside_block() {
+    let (_privkey, pubkey_hash) = gen_key_pair();
+    let mut state = gen_state(pubkey_hash);
+    let executor = Executor::new(ROLLUP_ID);
+    // a tx with empty witness isn't signed without the block's aggregate signature
+    let tx = gen_transfer_tx(BLS_SENDER_INDEX, SENDER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    match executor.run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let (sender, _kv) = state.get_account(BLS_SENDER_INDEX).expect("sender");
+    assert_eq!(Unpack::<u32>::unpack(&sender.nonce()), 0);
+}
+
+#[test]
+fn test_run_block_with_aggregate_signature() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let executor = Executor::new(ROLLUP_ID);
+    let bls_tx = gen_transfer_tx(BLS_SENDER_INDEX, SENDER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let signed_tx = gen_transfer_tx(SENDER_INDEX, BLS_SENDER_INDEX, 1, CKB_TOKEN_ID, 20, 1);
+    let txs = vec![bls_tx.clone(), sign_tx(&privkey, &ROLLUP_ID, signed_tx)];
+    let aggregate_sig = bls::aggregate_signatures(&[bls_sign(&bls_tx)]).expect("aggregate");
+
+    let mut state = gen_state(pubkey_hash);
+    let run_results = executor
+        .run_block(&mut state, tx_hashes(&txs), &aggregate_sig, AG_INDEX)
+        .expect("run block");
+    assert_eq!(run_results.len(), 2);
+    let (_sender, kv) = state.get_account(BLS_SENDER_INDEX).expect("sender");
+    assert_eq!(kv.get(&CKB_TOKEN_ID), Some(&109));
+
+    // no tx runs if the aggregate signature is invalid
+    let wrong_tx = gen_transfer_tx(BLS_SENDER_INDEX, SENDER_INDEX, 1, CKB_TOKEN_ID, 11, 1);
+    let wrong_sig = bls::aggregate_signatures(&[bls_sign(&wrong_tx)]).expect("aggregate");
+    for aggregate_sig in &[wrong_sig, [0u8; 96]] {
+        let mut state = gen_state(pubkey_hash);
+        match executor.run_block(&mut state, tx_hashes(&txs), aggregate_sig, AG_INDEX) {
+            Err(BlockError::InvalidAggregateSignature(Error::InvalidSignature)) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+        let (_sender, kv) = state.get_account(SENDER_INDEX).expect("sender");
+        assert_eq!(kv.get(&CKB_TOKEN_ID), Some(&100));
+    }
+
+    // the aggregate signature must be zeros without BLS txs
+    let mut state = gen_state(pubkey_hash);
+    match executor.run_block(&mut state, tx_hashes(&txs[1..]), &aggregate_sig, AG_INDEX) {
+        Err(BlockError::InvalidAggregateSignature(Error::InvalidSignature)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
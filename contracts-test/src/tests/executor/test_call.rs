@@ -0,0 +1,187 @@
+use crate::tests::utils::constants::CKB_TOKEN_ID;
+use godwoken_executor::{
+    error::Error,
+    execution_context::{ExecutionContext, MAX_CALL_DEPTH},
+    state::{AccountState, State},
+    traits::Contract,
+};
+use godwoken_types::{
+    cache::{KVMap, StorageMap},
+    core::Index,
+    packed::{Account, AccountScript, AccountScriptOpt},
+    prelude::*,
+};
+
+const SENDER_INDEX: Index = 1;
+const CONTRACT_INDEX: Index = 2;
+const TARGET_INDEX: Index = 3;
+pub const KEY: [u8; 32] = [1u8; 32];
+
+/// transfer 1 CKB to the account of `args[1..9]` by a nested call
+pub const OP_FORWARD: u8 = 1;
+/// write the storage and emit a log, then fail
+pub const OP_FAIL: u8 = 2;
+/// call itself until the max depth, then store the depth
+pub const OP_RECURSE: u8 = 3;
+
+/// A contract that runs the op of `args[0]`
+pub struct MockContract;
+
+impl Contract for MockContract {
+    fn call(
+        &self,
+        context: &mut ExecutionContext,
+        args: &[u8],
+        max_cycles: u64,
+    ) -> Result<u64, Error> {
+        let index = context.current_index();
+        match args[0] {
+            OP_FORWARD => {
+                let mut to_index = [0u8; 8];
+                to_index.copy_from_slice(&args[1..9]);
+                let to_index = u64::from_le_bytes(to_index);
+                context.call(to_index, CKB_TOKEN_ID, 1, &[], max_cycles)?;
+            }
+            OP_FAIL => {
+                context.set_storage(index, KEY, b"value".to_vec())?;
+                context.emit_log(b"log".to_vec());
+                return Err(Error::ContractExit(1));
+            }
+            OP_RECURSE => match context.call(index, CKB_TOKEN_ID, 0, args, max_cycles) {
+                Err(Error::CallDepthExceeded) => {
+                    let depth = context.depth() as u8;
+                    context.set_storage(index, KEY, vec![depth])?;
+                }
+                result => {
+                    result?;
+                }
+            },
+            op => panic!("unexpected op: {}", op),
+        }
+        Ok(1)
+    }
+}
+
+pub fn contract_account(index: Index) -> Account {
+    Account::new_builder()
+        .index(index.pack())
+        .script(
+            AccountScriptOpt::new_builder()
+                .set(Some(AccountScript::default()))
+                .build(),
+        )
+        .build()
+}
+
+/// account state with 100 CKB and an empty storage slot `KEY`
+pub fn account_state(account: &Account) -> AccountState {
+    let mut kv = KVMap::default();
+    kv.insert(CKB_TOKEN_ID, 100);
+    let mut storage = StorageMap::default();
+    storage.insert(KEY, Vec::new());
+    AccountState {
+        account: account.as_reader(),
+        kv,
+        withdraw_kv: KVMap::default(),
+        storage,
+    }
+}
+
+/// the sender, the contract and the target account
+fn gen_accounts() -> Vec<Account> {
+    vec![
+        Account::new_builder().index(SENDER_INDEX.pack()).build(),
+        contract_account(CONTRACT_INDEX),
+        Account::new_builder().index(TARGET_INDEX.pack()).build(),
+    ]
+}
+
+/// state of the accounts in `indexes`
+fn prepare_state(accounts: &[Account], indexes: &[Index]) -> State {
+    State::new(
+        accounts
+            .iter()
+            .filter(|account| indexes.contains(&account.index().unpack()))
+            .map(account_state)
+            .collect(),
+    )
+}
+
+fn forward_args(to_index: Index) -> Vec<u8> {
+    let mut args = vec![OP_FORWARD];
+    args.extend_from_slice(&to_index.to_le_bytes());
+    args
+}
+
+#[test]
+fn test_nested_call() {
+    let accounts = gen_accounts();
+    let mut state = prepare_state(&accounts, &[SENDER_INDEX, CONTRACT_INDEX, TARGET_INDEX]);
+    let mut context = ExecutionContext::new(&mut state, SENDER_INDEX, Some(&MockContract));
+    context
+        .call(
+            CONTRACT_INDEX,
+            CKB_TOKEN_ID,
+            10,
+            &forward_args(TARGET_INDEX),
+            1000,
+        )
+        .expect("call");
+    let (_account, kv) = state.get_account(CONTRACT_INDEX).expect("contract");
+    assert_eq!(kv.get(&CKB_TOKEN_ID), Some(&109));
+    let (_account, kv) = state.get_account(TARGET_INDEX).expect("target");
+    assert_eq!(kv.get(&CKB_TOKEN_ID), Some(&101));
+}
+
+#[test]
+fn test_nested_call_to_missing_account() {
+    let accounts = gen_accounts();
+    let mut state = prepare_state(&accounts, &[SENDER_INDEX, CONTRACT_INDEX]);
+    let mut context = ExecutionContext::new(&mut state, SENDER_INDEX, Some(&MockContract));
+    // the missing account fails the outer call instead of the nested call
+    match context.call(
+        CONTRACT_INDEX,
+        CKB_TOKEN_ID,
+        10,
+        &forward_args(TARGET_INDEX),
+        1000,
+    ) {
+        Err(Error::MissingAccount(TARGET_INDEX)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    let (_account, kv) = state.get_account(SENDER_INDEX).expect("sender");
+    assert_eq!(kv.get(&CKB_TOKEN_ID), Some(&100));
+}
+
+#[test]
+fn test_revert_failed_call() {
+    let account = contract_account(CONTRACT_INDEX);
+    let mut state = State::new(vec![account_state(&account)]);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, Some(&MockContract));
+    let checkpoint = context.checkpoint();
+    match context.call(CONTRACT_INDEX, CKB_TOKEN_ID, 10, &[OP_FAIL], 1000) {
+        Err(Error::ContractExit(1)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert_eq!(context.checkpoint(), checkpoint);
+    assert!(context.logs().is_empty());
+    assert_eq!(
+        context.get_storage(CONTRACT_INDEX, &KEY).expect("get"),
+        None
+    );
+}
+
+#[test]
+fn test_max_call_depth() {
+    let account = contract_account(CONTRACT_INDEX);
+    let mut state = State::new(vec![account_state(&account)]);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, Some(&MockContract));
+    context
+        .call(CONTRACT_INDEX, CKB_TOKEN_ID, 0, &[OP_RECURSE], 1000)
+        .expect("call");
+    assert_eq!(context.depth(), 0);
+    assert_eq!(
+        context.get_storage(CONTRACT_INDEX, &KEY).expect("get"),
+        Some(&[MAX_CALL_DEPTH as u8][..])
+    );
+}
@@ -0,0 +1,140 @@
+use super::test_call::{account_state, contract_account};
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_executor::{
+    execution_context::ExecutionContext, executor::Executor, state::State, types::RunResult,
+};
+use godwoken_types::{
+    cache::TxWithHash,
+    core::Index,
+    packed::{Account, Log, LogUnion, LogVec},
+    prelude::*,
+};
+use godwoken_utils::{hash::new_blake2b, mmr::merkle_root};
+
+const ROLLUP_ID: [u8; 32] = [42u8; 32];
+const AG_INDEX: Index = 0;
+const SENDER_INDEX: Index = 1;
+const RECEIVER_INDEX: Index = 2;
+
+fn gen_accounts(sender_pubkey_hash: [u8; 20]) -> Vec<Account> {
+    vec![
+        Account::new_builder().index(AG_INDEX.pack()).build(),
+        Account::new_builder()
+            .index(SENDER_INDEX.pack())
+            .pubkey_hash(sender_pubkey_hash.pack())
+            .build(),
+        Account::new_builder().index(RECEIVER_INDEX.pack()).build(),
+    ]
+}
+
+/// (from_index, to_index, amount) of a transfer log
+fn unpack_transfer_log(log: &Log) -> (Index, Index, u128) {
+    match log.to_enum() {
+        LogUnion::TransferLog(log) => {
+            let token_id: [u8; 32] = log.token_id().unpack();
+            assert_eq!(token_id, CKB_TOKEN_ID);
+            (
+                log.from_index().unpack(),
+                log.to_index().unpack(),
+                log.amount().unpack(),
+            )
+        }
+        log => panic!("unexpected log: {}", log),
+    }
+}
+
+fn logs_hash(logs: &[Log]) -> [u8; 32] {
+    let logs = LogVec::new_builder().set(logs.to_vec()).build();
+    let mut hasher = new_blake2b();
+    hasher.update(logs.as_slice());
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+#[test]
+fn test_transfer_logs() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let mut state = State::new(accounts.iter().map(account_state).collect());
+    let executor = Executor::new(ROLLUP_ID);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let run_result = executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+        .expect("run tx");
+    // the fee is logged before the transfer
+    let logs: Vec<_> = run_result.logs.iter().map(unpack_transfer_log).collect();
+    assert_eq!(
+        logs,
+        vec![
+            (SENDER_INDEX, AG_INDEX, 1),
+            (SENDER_INDEX, RECEIVER_INDEX, 10)
+        ]
+    );
+    assert_eq!(run_result.logs_hash(), logs_hash(&run_result.logs));
+
+    // a failed tx emits no logs
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 2, CKB_TOKEN_ID, 1000, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    assert!(executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+        .is_err());
+}
+
+#[test]
+fn test_contract_logs() {
+    let account = contract_account(SENDER_INDEX);
+    let mut state = State::new(vec![account_state(&account)]);
+    let mut context = ExecutionContext::new(&mut state, SENDER_INDEX, None);
+    context.emit_log(b"first".to_vec());
+    let checkpoint = context.checkpoint();
+    context.emit_log(b"second".to_vec());
+    assert_eq!(context.logs().len(), 2);
+    match context.logs()[1].to_enum() {
+        LogUnion::ContractLog(log) => {
+            let account_index: Index = log.account_index().unpack();
+            assert_eq!(account_index, SENDER_INDEX);
+            assert_eq!(log.data().raw_data(), &b"second"[..]);
+        }
+        log => panic!("unexpected log: {}", log),
+    }
+    // logs are reverted with the state
+    context.revert(checkpoint);
+    assert_eq!(context.logs().len(), 1);
+}
+
+#[test]
+fn test_logs_root() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let mut state = State::new(accounts.iter().map(account_state).collect());
+    let executor = Executor::new(ROLLUP_ID);
+    let run_results: Vec<RunResult> = (1..=2)
+        .map(|nonce| {
+            let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, nonce, CKB_TOKEN_ID, 10, 1);
+            let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+            executor
+                .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+                .expect("run tx")
+        })
+        .collect();
+    // logs don't include the nonce, identical transfers have the same logs hash
+    assert_eq!(run_results[0].logs_hash(), run_results[1].logs_hash());
+    let logs_hashes: Vec<[u8; 32]> = run_results.iter().map(|r| r.logs_hash()).collect();
+    let logs_root = merkle_root(logs_hashes.clone());
+    assert_ne!(logs_root, [0u8; 32]);
+    // the root commits the count of txs
+    assert_ne!(merkle_root(logs_hashes[..1].to_vec()), logs_root);
+    // a tx without logs still has a leaf
+    let empty_hash = RunResult::default().logs_hash();
+    assert_eq!(empty_hash, logs_hash(&[]));
+    assert_ne!(
+        merkle_root(vec![logs_hashes[0], empty_hash, logs_hashes[1]]),
+        logs_root
+    );
+    assert_eq!(merkle_root(Vec::new()), [0u8; 32]);
+}
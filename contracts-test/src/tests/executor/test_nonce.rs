@@ -0,0 +1,71 @@
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+};
+use godwoken_executor::{
+    error::Error,
+    executor::Executor,
+    state::{AccountState, State},
+};
+use godwoken_types::{
+    cache::{KVMap, StorageMap, TxWithHash},
+    core::Index,
+    packed::Account,
+    prelude::*,
+};
+use godwoken_utils::smt::{self, Value, ValueTrait};
+
+const ROLLUP_ID: [u8; 32] = [42u8; 32];
+const SENDER_INDEX: Index = 0;
+const RECEIVER_INDEX: Index = 1;
+
+#[test]
+fn test_consecutive_txs_of_a_sender() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts: Vec<Account> = [SENDER_INDEX, RECEIVER_INDEX]
+        .iter()
+        .map(|index| {
+            Account::new_builder()
+                .index(index.pack())
+                .pubkey_hash(pubkey_hash.pack())
+                .build()
+        })
+        .collect();
+    let mut kv = KVMap::default();
+    kv.insert(CKB_TOKEN_ID, 100);
+    let mut state = State::new(
+        accounts
+            .iter()
+            .map(|account| AccountState {
+                account: account.as_reader(),
+                kv: kv.clone(),
+                withdraw_kv: KVMap::default(),
+                storage: StorageMap::default(),
+            })
+            .collect(),
+    );
+    let executor = Executor::new(ROLLUP_ID);
+    // txs of a sender in the same block run on the same state
+    for nonce in 1..=2 {
+        let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, nonce, CKB_TOKEN_ID, 10, 1);
+        let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+        executor
+            .run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX)
+            .expect("run tx");
+    }
+    // the nonce is committed to the account leaf
+    let (sender, _kv) = state.get_account(SENDER_INDEX).expect("sender");
+    assert_eq!(Unpack::<u32>::unpack(&sender.nonce()), 2);
+    let leaf = (
+        smt::account_index_key(SENDER_INDEX),
+        Value::from(sender.clone()).to_h256(),
+    );
+    assert!(state.merkle_leaves().contains(&leaf));
+    // a tx can't be replayed
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 2, CKB_TOKEN_ID, 10, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    match executor.run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX) {
+        Err(Error::InvalidNonce(3, 2)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
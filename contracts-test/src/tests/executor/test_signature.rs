@@ -0,0 +1,520 @@
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{gen_key_pair, gen_key_rotation_tx, gen_transfer_tx, sign_message, sign_tx},
+};
+use godwoken_executor::{
+    error::{BlockError, Error},
+    executor::Executor,
+    state::{AccountState, State},
+    types::AccountKey,
+};
+use godwoken_types::{
+    cache::{KVMap, StorageMap, TxWithHash},
+    core::Index,
+    packed::{Account, Tx},
+    prelude::*,
+    signing::{raw_tx_hash, tx_signing_message},
+};
+use godwoken_utils::{
+    bls, secp256k1 as gw_secp256k1,
+    signature::{self, verify_signature, SignatureScheme},
+};
+
+const ROLLUP_ID: [u8; 32] = [42u8; 32];
+const SENDER_INDEX: Index = 0;
+const RECEIVER_INDEX: Index = 1;
+
+// known-answer vectors over the message [0x42; 32]
+const MESSAGE: [u8; 32] = [0x42u8; 32];
+// key of the web3.js documentation
+const ETH_ADDRESS: &str = "2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+const ETH_SIGNATURE: &str = "bb50e2d89a4ed70663d080659fe0ad4b9bc3e06c17a227433966cb59ceee020d609d7118c435da78a2f9ce56d13b44222128402b9b96fa3fd3f2bacd9234bd391b";
+// key of RFC 8032 test 1, the signature is pubkey | sig
+const ED25519_PUBKEY_HASH: &str = "41ce516812a5f6182e91f09db263b5ffb046c131";
+const ED25519_SIGNATURE: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511addf3b5189c51feb459b936620288eb6c9b69ea64e9759d0f0f8b089f912d44839265cab067922b1ded4507883bfb3b914a6a06b3632e700c72062ba9f412000a";
+// 2 of 3, the private keys are 1, 2 and 3
+const MULTISIG_PUBKEY_HASH: &str = "ddd799794ee23b7a767974164f4fb2f72bb769ce";
+const MULTISIG_CONFIG: &str = "02030279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f8179802c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee502f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+const MULTISIG_SIGNATURES: [&str; 3] = [
+    "7592aab5d43618dda13fba71e3993cd7517a712d3da49664c06ee1bd3d1f70af076641ec5f630e55b77762d9944b5c6ee07a67a5b4bb91a53c7a808afa6be76e01",
+    "e5740e63bad28081ed7cf654dd6c19029ca03382fc05ab5f5dda81f2c55b845b41a0a45e6d27506dc8fba43f0428ee6f033544aaeb73598b429ec386a050d6eb00",
+    "ec6d499aefd540e90357f1004a136049d1f7df5ad99c44c46e3ed4169e40acb632190c667107183cff4a81d81788751a45beff65300eb740193f679bf5a885cc01",
+];
+
+fn unhex(s: &str) -> Vec<u8> {
+    hex::decode(s).expect("hex")
+}
+
+/// multisig witness of the config and the signatures of `signers`
+fn multisig_witness(signers: &[usize]) -> Vec<u8> {
+    let mut witness = unhex(MULTISIG_CONFIG);
+    for i in signers {
+        witness.extend_from_slice(&unhex(MULTISIG_SIGNATURES[*i]));
+    }
+    witness
+}
+
+fn gen_accounts(sender_pubkey_hash: [u8; 20]) -> Vec<(Account, KVMap)> {
+    let (_privkey, receiver_pubkey_hash) = gen_key_pair();
+    [
+        (SENDER_INDEX, sender_pubkey_hash),
+        (RECEIVER_INDEX, receiver_pubkey_hash),
+    ]
+    .iter()
+    .map(|(index, pubkey_hash)| {
+        let account = Account::new_builder()
+            .index(index.pack())
+            .pubkey_hash(pubkey_hash.pack())
+            .build();
+        let mut kv = KVMap::default();
+        kv.insert(CKB_TOKEN_ID, 100);
+        (account, kv)
+    })
+    .collect()
+}
+
+fn gen_state(accounts: &[(Account, KVMap)]) -> State {
+    State::new(
+        accounts
+            .iter()
+            .map(|(account, kv)| AccountState {
+                account: account.as_reader(),
+                kv: kv.clone(),
+                withdraw_kv: KVMap::default(),
+                storage: StorageMap::default(),
+            })
+            .collect(),
+    )
+}
+
+fn run_tx(accounts: &[(Account, KVMap)], tx: &Tx) -> Result<(), Error> {
+    let mut state = gen_state(accounts);
+    let executor = Executor::new(ROLLUP_ID);
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX)
+        .map(|_| ())
+}
+
+#[test]
+fn test_tx_signed_by_sender() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 15, 3);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    run_tx(&accounts, &tx).expect("run tx");
+}
+
+#[test]
+fn test_tx_signed_by_wrong_key() {
+    let (_privkey, pubkey_hash) = gen_key_pair();
+    let (wrong_privkey, _pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 15, 3);
+    let tx = sign_tx(&wrong_privkey, &ROLLUP_ID, tx);
+    match run_tx(&accounts, &tx) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_tx_signed_with_wrong_rollup_id() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 15, 3);
+    let tx = sign_tx(&privkey, &[0u8; 32], tx);
+    match run_tx(&accounts, &tx) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_tx_with_high_s_signature() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 15, 3);
+    let message = tx_signing_message(&ROLLUP_ID, &raw_tx_hash(&tx.raw().as_reader()));
+    let sig = sign_message(&privkey, &message);
+    // (r, n - s) is also a valid signature of the flipped recovery id
+    let mut signature = secp256k1::Signature::parse_slice(&sig[..64]).expect("parse");
+    signature.s = -signature.s;
+    let mut malleable_sig = [0u8; 65];
+    malleable_sig[..64].copy_from_slice(&signature.serialize());
+    malleable_sig[64] = sig[64] ^ 1;
+    let tx = tx.as_builder().witness(malleable_sig[..].pack()).build();
+    match run_tx(&accounts, &tx) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_key_rotation() {
+    let (old_privkey, old_pubkey_hash) = gen_key_pair();
+    let (new_privkey, new_pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(old_pubkey_hash);
+    let mut state = gen_state(&accounts);
+    let executor = Executor::new(ROLLUP_ID);
+    // rotate key, signed by the old key
+    let new_key = AccountKey {
+        pubkey_hash: new_pubkey_hash,
+        sig_scheme: SignatureScheme::Secp256k1Blake2b as u8,
+        bls_pubkey: None,
+    };
+    let tx = gen_key_rotation_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, new_key, 3);
+    let tx = sign_tx(&old_privkey, &ROLLUP_ID, tx);
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX)
+        .expect("rotate key");
+    let (sender, _kv) = state.get_account(SENDER_INDEX).expect("sender");
+    let pubkey_hash: [u8; 20] = sender.pubkey_hash().unpack();
+    assert_eq!(pubkey_hash, new_pubkey_hash);
+    // the old key is invalid
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 2, CKB_TOKEN_ID, 15, 3);
+    let old_key_tx = sign_tx(&old_privkey, &ROLLUP_ID, tx.clone());
+    match executor.run(
+        &mut state,
+        TxWithHash::new(old_key_tx.as_reader()),
+        RECEIVER_INDEX,
+    ) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+    // signed by the new key
+    let new_key_tx = sign_tx(&new_privkey, &ROLLUP_ID, tx);
+    executor
+        .run(
+            &mut state,
+            TxWithHash::new(new_key_tx.as_reader()),
+            RECEIVER_INDEX,
+        )
+        .expect("run tx");
+}
+
+#[test]
+fn test_key_rotation_is_not_replayable() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (new_privkey, new_pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let mut state = gen_state(&accounts);
+    let executor = Executor::new(ROLLUP_ID);
+    let key = AccountKey {
+        pubkey_hash,
+        sig_scheme: SignatureScheme::Secp256k1Blake2b as u8,
+        bls_pubkey: None,
+    };
+    let new_key = AccountKey {
+        pubkey_hash: new_pubkey_hash,
+        ..key
+    };
+    let rotation_tx = gen_key_rotation_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, new_key, 3);
+    let rotation_tx = sign_tx(&privkey, &ROLLUP_ID, rotation_tx);
+    executor
+        .run(
+            &mut state,
+            TxWithHash::new(rotation_tx.as_reader()),
+            RECEIVER_INDEX,
+        )
+        .expect("rotate key");
+    // rotate back to the old key in the same block
+    let tx = gen_key_rotation_tx(SENDER_INDEX, 2, CKB_TOKEN_ID, key, 3);
+    let tx = sign_tx(&new_privkey, &ROLLUP_ID, tx);
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX)
+        .expect("rotate key back");
+    let (sender, _kv) = state.get_account(SENDER_INDEX).expect("sender");
+    assert_eq!(Unpack::<u32>::unpack(&sender.nonce()), 2);
+    // the first rotation is signed by a valid key again, the nonce rejects it
+    match executor.run(
+        &mut state,
+        TxWithHash::new(rotation_tx.as_reader()),
+        RECEIVER_INDEX,
+    ) {
+        Err(Error::InvalidNonce(3, 1)) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_secp256k1_blake2b_errors() {
+    let scheme = SignatureScheme::Secp256k1Blake2b as u8;
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let sig = sign_message(&privkey, &MESSAGE);
+    verify_signature(scheme, &sig, &MESSAGE, &pubkey_hash).expect("verify");
+    // the specific rejection of the signature is kept
+    let mut high_s = secp256k1::Signature::parse_slice(&sig[..64]).expect("parse");
+    high_s.s = -high_s.s;
+    let mut malleable_sig = [0u8; 65];
+    malleable_sig[..64].copy_from_slice(&high_s.serialize());
+    malleable_sig[64] = sig[64] ^ 1;
+    assert_eq!(
+        verify_signature(scheme, &malleable_sig, &MESSAGE, &pubkey_hash),
+        Err(signature::Error::Secp256k1(gw_secp256k1::Error::HighS))
+    );
+    let mut overflowing_sig = sig;
+    overflowing_sig[32..64].copy_from_slice(&[0xffu8; 32]);
+    assert_eq!(
+        verify_signature(scheme, &overflowing_sig, &MESSAGE, &pubkey_hash),
+        Err(signature::Error::Secp256k1(
+            gw_secp256k1::Error::InvalidSignature
+        ))
+    );
+    assert_eq!(
+        verify_signature(scheme, &sig, &MESSAGE, &[0u8; 20]),
+        Err(signature::Error::IncorrectPubkeyHash)
+    );
+}
+
+#[test]
+fn test_secp256k1_keccak_vector() {
+    let scheme = SignatureScheme::Secp256k1Keccak as u8;
+    let address = unhex(ETH_ADDRESS);
+    let sig = unhex(ETH_SIGNATURE);
+    verify_signature(scheme, &sig, &MESSAGE, &address).expect("verify");
+    // the recovery id without the 27 offset
+    let mut raw_sig = sig.clone();
+    raw_sig[64] -= 27;
+    verify_signature(scheme, &raw_sig, &MESSAGE, &address).expect("verify");
+    // another message recovers another address
+    assert_eq!(
+        verify_signature(scheme, &sig, &[0u8; 32], &address),
+        Err(signature::Error::IncorrectPubkeyHash)
+    );
+    assert_eq!(
+        verify_signature(scheme, &sig, &MESSAGE, &address[..19]),
+        Err(signature::Error::InvalidPubkeyHash)
+    );
+    assert_eq!(
+        verify_signature(scheme, &sig[..64], &MESSAGE, &address),
+        Err(signature::Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_ed25519_vector() {
+    let scheme = SignatureScheme::Ed25519 as u8;
+    let pubkey_hash = unhex(ED25519_PUBKEY_HASH);
+    let sig = unhex(ED25519_SIGNATURE);
+    verify_signature(scheme, &sig, &MESSAGE, &pubkey_hash).expect("verify");
+    assert_eq!(
+        verify_signature(scheme, &sig, &[0u8; 32], &pubkey_hash),
+        Err(signature::Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify_signature(scheme, &sig, &MESSAGE, &[0u8; 20]),
+        Err(signature::Error::IncorrectPubkeyHash)
+    );
+    let mut tampered_sig = sig.clone();
+    tampered_sig[40] ^= 1;
+    assert_eq!(
+        verify_signature(scheme, &tampered_sig, &MESSAGE, &pubkey_hash),
+        Err(signature::Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_multisig_vector() {
+    let scheme = SignatureScheme::Multisig as u8;
+    let pubkey_hash = unhex(MULTISIG_PUBKEY_HASH);
+    // any 2 signers in any order
+    for signers in &[[0, 1], [0, 2], [1, 2], [2, 0]] {
+        verify_signature(scheme, &multisig_witness(signers), &MESSAGE, &pubkey_hash)
+            .expect("verify");
+    }
+    // the same signer twice
+    assert_eq!(
+        verify_signature(scheme, &multisig_witness(&[1, 1]), &MESSAGE, &pubkey_hash),
+        Err(signature::Error::InvalidSignature)
+    );
+    // below the threshold
+    assert_eq!(
+        verify_signature(scheme, &multisig_witness(&[0]), &MESSAGE, &pubkey_hash),
+        Err(signature::Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify_signature(scheme, &multisig_witness(&[0, 1]), &[0u8; 32], &pubkey_hash),
+        Err(signature::Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify_signature(scheme, &multisig_witness(&[0, 1]), &MESSAGE, &[0u8; 20]),
+        Err(signature::Error::IncorrectPubkeyHash)
+    );
+}
+
+#[test]
+fn test_unknown_signature_scheme() {
+    assert_eq!(
+        verify_signature(4, &unhex(ETH_SIGNATURE), &MESSAGE, &unhex(ETH_ADDRESS)),
+        Err(signature::Error::UnknownScheme(4))
+    );
+}
+
+#[test]
+fn test_tx_signed_by_multisig() {
+    let pubkey_hash: [u8; 20] = {
+        let mut buf = [0u8; 20];
+        buf.copy_from_slice(&unhex(MULTISIG_PUBKEY_HASH));
+        buf
+    };
+    let mut accounts = gen_accounts(pubkey_hash);
+    accounts[SENDER_INDEX as usize].0 = accounts[SENDER_INDEX as usize]
+        .0
+        .clone()
+        .as_builder()
+        .sig_scheme((SignatureScheme::Multisig as u8).into())
+        .build();
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 15, 3);
+    let message = tx_signing_message(&ROLLUP_ID, &raw_tx_hash(&tx.raw().as_reader()));
+    let sign_with = |signers: &[u8]| {
+        let mut witness = unhex(MULTISIG_CONFIG);
+        for i in signers {
+            let mut key = [0u8; 32];
+            key[31] = *i;
+            let privkey = secp256k1::SecretKey::parse(&key).expect("privkey");
+            witness.extend_from_slice(&sign_message(&privkey, &message));
+        }
+        tx.clone().as_builder().witness(witness[..].pack()).build()
+    };
+    run_tx(&accounts, &sign_with(&[3, 1])).expect("run tx");
+    match run_tx(&accounts, &sign_with(&[2, 2])) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    // a secp256k1 signature of a multisig member is not accepted
+    let mut key = [0u8; 32];
+    key[31] = 1;
+    let privkey = secp256k1::SecretKey::parse(&key).expect("privkey");
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    match run_tx(&accounts, &tx) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_key_rotation_replaces_bls_pubkey() {
+    let old_secret_key = [7u8; 32];
+    let new_secret_key = [9u8; 32];
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let mut accounts = gen_accounts(pubkey_hash);
+    accounts[SENDER_INDEX as usize].0 = accounts[SENDER_INDEX as usize]
+        .0
+        .clone()
+        .as_builder()
+        .bls_pubkey(Some(bls::pubkey(&old_secret_key).expect("pubkey")).pack())
+        .build();
+    let mut state = gen_state(&accounts);
+    let executor = Executor::new(ROLLUP_ID);
+    let bls_tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 2, CKB_TOKEN_ID, 15, 3);
+    let bls_message = tx_signing_message(&ROLLUP_ID, &raw_tx_hash(&bls_tx.raw().as_reader()));
+    let run_bls_tx = |state: &mut State, secret_key: &[u8; 32]| {
+        let aggregate_sig = bls::sign(secret_key, &bls_message).expect("sign");
+        executor.run_block(
+            state,
+            vec![TxWithHash::new(bls_tx.as_reader())],
+            &aggregate_sig,
+            RECEIVER_INDEX,
+        )
+    };
+
+    // removing the BLS key disables BLS txs
+    let key = AccountKey {
+        pubkey_hash,
+        sig_scheme: SignatureScheme::Secp256k1Blake2b as u8,
+        bls_pubkey: None,
+    };
+    let tx = gen_key_rotation_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, key, 3);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let mut removed_state = gen_state(&accounts);
+    executor
+        .run(
+            &mut removed_state,
+            TxWithHash::new(tx.as_reader()),
+            RECEIVER_INDEX,
+        )
+        .expect("rotate key");
+    let (sender, _kv) = removed_state.get_account(SENDER_INDEX).expect("sender");
+    assert!(sender.bls_pubkey().to_opt().is_none());
+    match run_bls_tx(&mut removed_state, &old_secret_key) {
+        Err(BlockError::InvalidAggregateSignature(Error::InvalidSignature)) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+
+    // a new BLS key replaces the old one
+    let new_bls_pubkey = bls::pubkey(&new_secret_key).expect("pubkey");
+    let key = AccountKey {
+        bls_pubkey: Some(new_bls_pubkey),
+        ..key
+    };
+    let tx = gen_key_rotation_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, key, 3);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX)
+        .expect("rotate key");
+    match run_bls_tx(&mut state, &old_secret_key) {
+        Err(BlockError::InvalidAggregateSignature(Error::InvalidSignature)) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+    run_bls_tx(&mut state, &new_secret_key).expect("run block");
+}
+
+#[test]
+fn test_key_rotation_changes_sig_scheme() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let mut state = gen_state(&accounts);
+    let executor = Executor::new(ROLLUP_ID);
+    let mut multisig_pubkey_hash = [0u8; 20];
+    multisig_pubkey_hash.copy_from_slice(&unhex(MULTISIG_PUBKEY_HASH));
+
+    // an unknown scheme is rejected
+    let key = AccountKey {
+        pubkey_hash: multisig_pubkey_hash,
+        sig_scheme: 4,
+        bls_pubkey: None,
+    };
+    let tx = gen_key_rotation_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, key, 3);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    match executor.run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX) {
+        Err(Error::UnknownSigScheme(4)) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+
+    // rotate to the multisig
+    let key = AccountKey {
+        sig_scheme: SignatureScheme::Multisig as u8,
+        ..key
+    };
+    let tx = gen_key_rotation_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, key, 3);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX)
+        .expect("rotate key");
+    let (sender, _kv) = state.get_account(SENDER_INDEX).expect("sender");
+    assert_eq!(AccountKey::from_account(sender), key);
+
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 2, CKB_TOKEN_ID, 15, 3);
+    let message = tx_signing_message(&ROLLUP_ID, &raw_tx_hash(&tx.raw().as_reader()));
+    // the old secp256k1 key is invalid
+    let old_key_tx = sign_tx(&privkey, &ROLLUP_ID, tx.clone());
+    match executor.run(
+        &mut state,
+        TxWithHash::new(old_key_tx.as_reader()),
+        RECEIVER_INDEX,
+    ) {
+        Err(Error::InvalidSignature) => {}
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+    let mut witness = unhex(MULTISIG_CONFIG);
+    for i in &[1u8, 2] {
+        let mut key = [0u8; 32];
+        key[31] = *i;
+        let privkey = secp256k1::SecretKey::parse(&key).expect("privkey");
+        witness.extend_from_slice(&sign_message(&privkey, &message));
+    }
+    let tx = tx.as_builder().witness(witness[..].pack()).build();
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), RECEIVER_INDEX)
+        .expect("run tx");
+}
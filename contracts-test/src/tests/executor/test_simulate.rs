@@ -0,0 +1,187 @@
+use super::test_call::{account_state, contract_account, KEY};
+use crate::tests::utils::{
+    constants::CKB_TOKEN_ID,
+    shortcut::{
+        gen_key_pair, gen_key_rotation_tx, gen_transfer_tx, gen_withdraw_request_tx, sign_tx,
+    },
+};
+use godwoken_executor::{
+    error::Error,
+    execution_context::ExecutionContext,
+    executor::Executor,
+    state::State,
+    types::{AccountKey, StateDiff},
+};
+use godwoken_types::{
+    cache::TxWithHash,
+    core::Index,
+    packed::{Account, Tx},
+    prelude::*,
+};
+
+const ROLLUP_ID: [u8; 32] = [42u8; 32];
+const AG_INDEX: Index = 0;
+const SENDER_INDEX: Index = 1;
+const RECEIVER_INDEX: Index = 2;
+
+fn gen_accounts(sender_pubkey_hash: [u8; 20]) -> Vec<Account> {
+    vec![
+        Account::new_builder().index(AG_INDEX.pack()).build(),
+        Account::new_builder()
+            .index(SENDER_INDEX.pack())
+            .pubkey_hash(sender_pubkey_hash.pack())
+            .build(),
+        Account::new_builder().index(RECEIVER_INDEX.pack()).build(),
+    ]
+}
+
+fn balance(state: &State, index: Index) -> u128 {
+    let (_account, kv) = state.get_account(index).expect("account");
+    kv.get(&CKB_TOKEN_ID).copied().unwrap_or(0)
+}
+
+fn nonce(state: &State, index: Index) -> u32 {
+    let (account, _kv) = state.get_account(index).expect("account");
+    account.nonce().unpack()
+}
+
+fn simulate(executor: &Executor, state: &mut State, tx: &Tx, verify_signature: bool) -> StateDiff {
+    let result = executor.simulate(
+        state,
+        TxWithHash::new(tx.as_reader()),
+        AG_INDEX,
+        verify_signature,
+    );
+    if let Some(err) = result.error {
+        panic!("simulate tx: {:?}", err);
+    }
+    result.state_diff
+}
+
+#[test]
+fn test_simulate_transfer() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let mut state = State::new(accounts.iter().map(account_state).collect());
+    let executor = Executor::new(ROLLUP_ID);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let result = executor.simulate(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX, true);
+    assert!(result.error.is_none());
+    assert_eq!(result.cycles, 0);
+    assert_eq!(result.logs.len(), 2);
+    let mut expected_diff = StateDiff::default();
+    expected_diff
+        .balances
+        .insert((SENDER_INDEX, CKB_TOKEN_ID), 89);
+    expected_diff.balances.insert((AG_INDEX, CKB_TOKEN_ID), 101);
+    expected_diff
+        .balances
+        .insert((RECEIVER_INDEX, CKB_TOKEN_ID), 110);
+    expected_diff.nonces.insert(SENDER_INDEX, 1);
+    assert_eq!(result.state_diff, expected_diff);
+
+    // the state is unchanged, the tx can still run
+    for index in &[AG_INDEX, SENDER_INDEX, RECEIVER_INDEX] {
+        assert_eq!(balance(&state, *index), 100);
+    }
+    assert_eq!(nonce(&state, SENDER_INDEX), 0);
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+        .expect("run tx");
+    assert_eq!(balance(&state, RECEIVER_INDEX), 110);
+}
+
+#[test]
+fn test_simulate_withdraw_and_key_rotation() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let mut state = State::new(accounts.iter().map(account_state).collect());
+    let executor = Executor::new(ROLLUP_ID);
+
+    let tx = gen_withdraw_request_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let state_diff = simulate(&executor, &mut state, &tx, true);
+    assert_eq!(
+        state_diff.balances.get(&(SENDER_INDEX, CKB_TOKEN_ID)),
+        Some(&89)
+    );
+    assert_eq!(
+        state_diff.withdrawals.into_iter().collect::<Vec<_>>(),
+        vec![((SENDER_INDEX, CKB_TOKEN_ID), 10)]
+    );
+
+    let (_privkey, new_pubkey_hash) = gen_key_pair();
+    let new_key = AccountKey {
+        pubkey_hash: new_pubkey_hash,
+        sig_scheme: 0,
+        bls_pubkey: None,
+    };
+    let tx = gen_key_rotation_tx(SENDER_INDEX, 1, CKB_TOKEN_ID, new_key, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let state_diff = simulate(&executor, &mut state, &tx, true);
+    assert_eq!(
+        state_diff.keys.into_iter().collect::<Vec<_>>(),
+        vec![(SENDER_INDEX, new_key)]
+    );
+    let (sender, _kv) = state.get_account(SENDER_INDEX).expect("sender");
+    let sender_pubkey_hash: [u8; 20] = sender.pubkey_hash().unpack();
+    assert_eq!(sender_pubkey_hash, pubkey_hash);
+}
+
+#[test]
+fn test_simulate_failed_tx() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = gen_accounts(pubkey_hash);
+    let mut state = State::new(accounts.iter().map(account_state).collect());
+    let executor = Executor::new(ROLLUP_ID);
+
+    // the fee is charged before the failure, it's reverted too
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 1000, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let result = executor.simulate(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX, true);
+    match result.error {
+        Some(Error::BalanceNotEnough(99, 1000)) => {}
+        err => panic!("unexpected error: {:?}", err),
+    }
+    assert_eq!(result.state_diff, StateDiff::default());
+    assert!(result.logs.is_empty());
+    assert_eq!(balance(&state, SENDER_INDEX), 100);
+
+    // the signature is only checked on demand
+    let (other_privkey, _pubkey_hash) = gen_key_pair();
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, CKB_TOKEN_ID, 10, 1);
+    let tx = sign_tx(&other_privkey, &ROLLUP_ID, tx);
+    let result = executor.simulate(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX, true);
+    match result.error {
+        Some(Error::InvalidSignature) => {}
+        err => panic!("unexpected error: {:?}", err),
+    }
+    simulate(&executor, &mut state, &tx, false);
+}
+
+#[test]
+fn test_storage_diff() {
+    let account = contract_account(SENDER_INDEX);
+    let mut state = State::new(vec![account_state(&account)]);
+    let mut context = ExecutionContext::new(&mut state, SENDER_INDEX, None);
+    context
+        .set_storage(SENDER_INDEX, KEY, b"value".to_vec())
+        .expect("set storage");
+    let checkpoint = context.checkpoint();
+    assert_eq!(context.state_diff(checkpoint), StateDiff::default());
+    // the diff holds the latest value, an empty value removes the slot
+    context
+        .set_storage(SENDER_INDEX, KEY, b"new value".to_vec())
+        .expect("set storage");
+    context
+        .set_storage(SENDER_INDEX, KEY, Vec::new())
+        .expect("set storage");
+    let state_diff = context.state_diff(checkpoint);
+    assert_eq!(
+        state_diff.storage.into_iter().collect::<Vec<_>>(),
+        vec![((SENDER_INDEX, KEY), Vec::new())]
+    );
+    let state_diff = context.state_diff(0);
+    assert_eq!(state_diff.storage.len(), 1);
+}
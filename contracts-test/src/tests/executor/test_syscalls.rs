@@ -0,0 +1,442 @@
+use super::test_call::{account_state, MockContract, OP_FAIL};
+use crate::tests::utils::constants::CKB_TOKEN_ID;
+use ckb_vm::{
+    registers::{A0, A1, A2, A3, A4, A5, A7},
+    CoreMachine, DefaultCoreMachine, Error as VMError, Memory, SparseMemory, Syscalls,
+};
+use godwoken_executor::{
+    error::Error,
+    execution_context::ExecutionContext,
+    state::{AccountState, State},
+    vm::{
+        L2Syscalls, CALL_FAILED, MAX_ARGS_SIZE, MAX_LOG_SIZE, MAX_VALUE_SIZE, NEW_SLOT_CYCLES,
+        SUCCESS, SYS_CALL, SYS_LOAD_STORAGE, SYS_LOG, SYS_STORE_STORAGE,
+    },
+};
+use godwoken_types::{
+    cache::{KVMap, StorageMap},
+    packed::{Account, AccountScript, AccountScriptOpt},
+    prelude::*,
+};
+use godwoken_utils::smt::{self, compute_root_with_proof, Value, SMT};
+
+type Machine = DefaultCoreMachine<u64, SparseMemory<u64>>;
+
+const CONTRACT_INDEX: u64 = 0;
+const CALLEE_INDEX: u64 = 1;
+const MAX_CYCLES: u64 = 1_000_000;
+const DATA_ADDR: u64 = 0x1000;
+const KEY_ADDR: u64 = 0x2000;
+const SIZE_ADDR: u64 = 0x3000;
+const TOKEN_ID_ADDR: u64 = 0x4000;
+const AMOUNT_ADDR: u64 = 0x5000;
+const KEY: [u8; 32] = [1u8; 32];
+
+fn contract_account() -> Account {
+    Account::new_builder()
+        .index(CONTRACT_INDEX.pack())
+        .script(
+            AccountScriptOpt::new_builder()
+                .set(Some(AccountScript::default()))
+                .build(),
+        )
+        .build()
+}
+
+/// the contract account with an empty storage slot `KEY`
+fn prepare_state(account: &Account) -> State {
+    let mut storage = StorageMap::default();
+    storage.insert(KEY, Vec::new());
+    State::new(vec![AccountState {
+        account: account.as_reader(),
+        kv: KVMap::default(),
+        withdraw_kv: KVMap::default(),
+        storage,
+    }])
+}
+
+fn new_machine() -> Machine {
+    let mut machine = Machine::new_with_max_cycles(MAX_CYCLES);
+    machine
+        .memory_mut()
+        .store_bytes(KEY_ADDR, &KEY)
+        .expect("store key");
+    machine
+        .memory_mut()
+        .store_bytes(TOKEN_ID_ADDR, &CKB_TOKEN_ID)
+        .expect("store token id");
+    machine
+        .memory_mut()
+        .store_bytes(AMOUNT_ADDR, &10u128.to_le_bytes())
+        .expect("store amount");
+    machine
+}
+
+/// run a syscall of the contract with registers set
+fn ecall(
+    context: &mut ExecutionContext,
+    machine: &mut Machine,
+    code: u64,
+    registers: &[(usize, u64)],
+) -> Result<bool, VMError> {
+    for (index, value) in registers {
+        machine.set_register(*index, *value);
+    }
+    machine.set_register(A7, code);
+    L2Syscalls::new(context, &[], MAX_CYCLES).ecall(machine)
+}
+
+#[test]
+fn test_log() {
+    let account = contract_account();
+    let mut state = prepare_state(&account);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, None);
+    let mut machine = new_machine();
+    machine
+        .memory_mut()
+        .store_bytes(DATA_ADDR, b"hello")
+        .expect("store data");
+    let handled = ecall(
+        &mut context,
+        &mut machine,
+        SYS_LOG,
+        &[(A0, DATA_ADDR), (A1, 5)],
+    )
+    .expect("ecall");
+    assert!(handled);
+    assert_eq!(machine.registers()[A0], u64::from(SUCCESS));
+    assert_eq!(context.logs().len(), 1);
+
+    // oversized logs fail before the data is loaded
+    for len in &[MAX_LOG_SIZE + 1, u64::max_value()] {
+        match ecall(
+            &mut context,
+            &mut machine,
+            SYS_LOG,
+            &[(A0, DATA_ADDR), (A1, *len)],
+        ) {
+            Err(VMError::OutOfBound) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+    assert_eq!(context.logs().len(), 1);
+}
+
+#[test]
+fn test_storage() {
+    let account = contract_account();
+    let mut state = prepare_state(&account);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, None);
+    let mut machine = new_machine();
+    let value = b"value";
+    machine
+        .memory_mut()
+        .store_bytes(DATA_ADDR, value)
+        .expect("store data");
+    ecall(
+        &mut context,
+        &mut machine,
+        SYS_STORE_STORAGE,
+        &[(A0, KEY_ADDR), (A1, DATA_ADDR), (A2, value.len() as u64)],
+    )
+    .expect("ecall");
+    assert_eq!(machine.registers()[A0], u64::from(SUCCESS));
+
+    // load the value into a cleared buffer
+    machine
+        .memory_mut()
+        .store_bytes(DATA_ADDR, &[0u8; 5])
+        .expect("clear data");
+    machine
+        .memory_mut()
+        .store64(&SIZE_ADDR, &(value.len() as u64))
+        .expect("store size");
+    ecall(
+        &mut context,
+        &mut machine,
+        SYS_LOAD_STORAGE,
+        &[(A0, DATA_ADDR), (A1, SIZE_ADDR), (A2, 0), (A3, KEY_ADDR)],
+    )
+    .expect("ecall");
+    assert_eq!(machine.registers()[A0], u64::from(SUCCESS));
+    let loaded: Vec<u8> = (0..value.len() as u64)
+        .map(|i| machine.memory_mut().load8(&(DATA_ADDR + i)).expect("load") as u8)
+        .collect();
+    assert_eq!(&loaded[..], &value[..]);
+
+    // oversized values fail before the value is loaded
+    for len in &[MAX_VALUE_SIZE + 1, u64::max_value()] {
+        match ecall(
+            &mut context,
+            &mut machine,
+            SYS_STORE_STORAGE,
+            &[(A0, KEY_ADDR), (A1, DATA_ADDR), (A2, *len)],
+        ) {
+            Err(VMError::OutOfBound) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+    assert_eq!(
+        context.get_storage(CONTRACT_INDEX, &KEY).expect("get"),
+        Some(&value[..])
+    );
+}
+
+#[test]
+fn test_storage_missing_from_state() {
+    let account = contract_account();
+    let mut state = prepare_state(&account);
+    let missing_key = [2u8; 32];
+    // a slot that isn't proven can't be read as empty
+    match state.get_storage(CONTRACT_INDEX, &missing_key) {
+        Err(Error::MissingStorage(CONTRACT_INDEX, key)) if key == missing_key => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert_eq!(state.get_storage(CONTRACT_INDEX, &KEY).expect("get"), None);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, None);
+    let mut machine = new_machine();
+    machine
+        .memory_mut()
+        .store_bytes(KEY_ADDR, &missing_key)
+        .expect("store key");
+    match ecall(
+        &mut context,
+        &mut machine,
+        SYS_LOAD_STORAGE,
+        &[(A0, DATA_ADDR), (A1, SIZE_ADDR), (A2, 0), (A3, KEY_ADDR)],
+    ) {
+        Err(VMError::Unexpected) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_store_new_slot() {
+    let account = contract_account();
+    let mut state = prepare_state(&account);
+    let new_key = [2u8; 32];
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, None);
+    let mut machine = new_machine();
+    machine
+        .memory_mut()
+        .store_bytes(DATA_ADDR, b"value")
+        .expect("store value");
+    // filling the proven empty slot and the missing slot cost the same
+    for key in &[KEY, new_key] {
+        machine
+            .memory_mut()
+            .store_bytes(KEY_ADDR, key)
+            .expect("store key");
+        let cycles = machine.cycles();
+        let handled = ecall(
+            &mut context,
+            &mut machine,
+            SYS_STORE_STORAGE,
+            &[(A0, KEY_ADDR), (A1, DATA_ADDR), (A2, 5)],
+        )
+        .expect("ecall");
+        assert!(handled);
+        assert_eq!(machine.registers()[A0], u64::from(SUCCESS));
+        assert_eq!(machine.cycles() - cycles, NEW_SLOT_CYCLES);
+        assert_eq!(
+            context.get_storage(CONTRACT_INDEX, key).expect("get"),
+            Some(&b"value"[..])
+        );
+    }
+    // overwriting a slot isn't charged
+    let cycles = machine.cycles();
+    ecall(
+        &mut context,
+        &mut machine,
+        SYS_STORE_STORAGE,
+        &[(A0, KEY_ADDR), (A1, DATA_ADDR), (A2, 3)],
+    )
+    .expect("ecall");
+    assert_eq!(machine.cycles(), cycles);
+    // a reverted call removes the new slot
+    context.revert(0);
+    assert_eq!(
+        context.get_storage(CONTRACT_INDEX, &KEY).expect("get"),
+        None
+    );
+    match context.get_storage(CONTRACT_INDEX, &new_key) {
+        Err(Error::MissingStorage(CONTRACT_INDEX, key)) if key == new_key => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    // removing a missing slot doesn't insert it
+    context
+        .set_storage(CONTRACT_INDEX, new_key, Vec::new())
+        .expect("set storage");
+    assert_eq!(context.checkpoint(), 0);
+}
+
+#[test]
+fn test_new_slot_proof() {
+    let account = contract_account();
+    let new_key = [2u8; 32];
+    let mut tree = SMT::default();
+    tree.update(
+        smt::account_index_key(CONTRACT_INDEX),
+        Value::from(account.clone()),
+    )
+    .expect("update");
+    let keys = vec![
+        smt::storage_key(CONTRACT_INDEX, &KEY),
+        smt::storage_key(CONTRACT_INDEX, &new_key),
+        smt::account_index_key(CONTRACT_INDEX),
+    ];
+    let proof = tree.merkle_proof(keys).expect("merkle proof");
+    let leaves_path = proof.leaves_path().to_owned();
+    let branches = proof.proof().to_owned();
+    // like a challenge, the new slot is proven as an empty slot before the tx
+    let mut state = prepare_state(&account);
+    state
+        .update_account_storage(CONTRACT_INDEX, new_key, Vec::new())
+        .expect("update storage");
+    let root =
+        compute_root_with_proof(state.merkle_leaves(), leaves_path.clone(), branches.clone())
+            .expect("compute root");
+    assert_eq!(&root, tree.root());
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, None);
+    context
+        .set_storage(CONTRACT_INDEX, new_key, b"value".to_vec())
+        .expect("set storage");
+    tree.update(
+        smt::storage_key(CONTRACT_INDEX, &new_key),
+        smt::storage_value(b"value"),
+    )
+    .expect("update");
+    let root = compute_root_with_proof(state.merkle_leaves(), leaves_path, branches)
+        .expect("compute root");
+    assert_eq!(&root, tree.root());
+
+    // a slot inserted without a proof can't be committed
+    let proof = tree
+        .merkle_proof(vec![
+            smt::storage_key(CONTRACT_INDEX, &KEY),
+            smt::account_index_key(CONTRACT_INDEX),
+        ])
+        .expect("merkle proof");
+    let mut state = prepare_state(&account);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, None);
+    context
+        .set_storage(CONTRACT_INDEX, new_key, b"value".to_vec())
+        .expect("set storage");
+    match compute_root_with_proof(
+        state.merkle_leaves(),
+        proof.leaves_path().to_owned(),
+        proof.proof().to_owned(),
+    ) {
+        Ok(root) => assert_ne!(&root, tree.root()),
+        Err(_err) => {}
+    }
+}
+
+#[test]
+fn test_call_with_oversized_args() {
+    let account = contract_account();
+    let mut state = prepare_state(&account);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, None);
+    let mut machine = new_machine();
+    for len in &[MAX_ARGS_SIZE + 1, u64::max_value()] {
+        match ecall(
+            &mut context,
+            &mut machine,
+            SYS_CALL,
+            &[
+                (A0, CONTRACT_INDEX),
+                (A1, KEY_ADDR),
+                (A3, DATA_ADDR),
+                (A4, *len),
+            ],
+        ) {
+            Err(VMError::OutOfBound) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+}
+
+#[test]
+fn test_call_failed() {
+    let caller = contract_account();
+    let callee = Account::new_builder()
+        .index(CALLEE_INDEX.pack())
+        .script(
+            AccountScriptOpt::new_builder()
+                .set(Some(AccountScript::default()))
+                .build(),
+        )
+        .build();
+    let mut state = State::new(vec![account_state(&caller), account_state(&callee)]);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, Some(&MockContract));
+    let mut machine = new_machine();
+    machine
+        .memory_mut()
+        .store_bytes(DATA_ADDR, &[OP_FAIL])
+        .expect("store args");
+    let handled = ecall(
+        &mut context,
+        &mut machine,
+        SYS_CALL,
+        &[
+            (A0, CALLEE_INDEX),
+            (A1, TOKEN_ID_ADDR),
+            (A2, AMOUNT_ADDR),
+            (A3, DATA_ADDR),
+            (A4, 1),
+            (A5, 0),
+        ],
+    )
+    .expect("ecall");
+    assert!(handled);
+    // the callee's error is returned to the caller, its changes are reverted
+    assert_eq!(machine.registers()[A0], u64::from(CALL_FAILED));
+    assert_eq!(
+        context
+            .get_balance(CONTRACT_INDEX, &CKB_TOKEN_ID)
+            .expect("get"),
+        100
+    );
+    assert_eq!(
+        context
+            .get_balance(CALLEE_INDEX, &CKB_TOKEN_ID)
+            .expect("get"),
+        100
+    );
+    assert_eq!(context.get_storage(CALLEE_INDEX, &KEY).expect("get"), None);
+    assert!(context.logs().is_empty());
+}
+
+#[test]
+fn test_call_missing_account() {
+    let account = contract_account();
+    let mut state = State::new(vec![account_state(&account)]);
+    let mut context = ExecutionContext::new(&mut state, CONTRACT_INDEX, Some(&MockContract));
+    let mut machine = new_machine();
+    // an account missing from the state aborts the caller instead of failing the call
+    match ecall(
+        &mut context,
+        &mut machine,
+        SYS_CALL,
+        &[
+            (A0, CALLEE_INDEX),
+            (A1, TOKEN_ID_ADDR),
+            (A2, AMOUNT_ADDR),
+            (A3, DATA_ADDR),
+            (A4, 0),
+        ],
+    ) {
+        Err(VMError::Unexpected) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    match context.take_internal_error() {
+        Some(Error::MissingAccount(CALLEE_INDEX)) => {}
+        err => panic!("unexpected error: {:?}", err),
+    }
+    assert_eq!(
+        context
+            .get_balance(CONTRACT_INDEX, &CKB_TOKEN_ID)
+            .expect("get"),
+        100
+    );
+}
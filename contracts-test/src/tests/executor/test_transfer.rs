@@ -0,0 +1,162 @@
+use crate::tests::utils::shortcut::{
+    gen_key_pair, gen_multi_transfer_tx, gen_transfer_tx, sign_tx,
+};
+use godwoken_executor::{
+    error::Error,
+    executor::Executor,
+    state::{AccountState, State},
+};
+use godwoken_types::{
+    cache::{KVMap, StorageMap, TxWithHash},
+    core::Index,
+    packed::{Account, LogUnion},
+    prelude::*,
+};
+use godwoken_utils::smt::Value;
+
+const ROLLUP_ID: [u8; 32] = [42u8; 32];
+const UDT_ID: [u8; 32] = [3u8; 32];
+const AG_INDEX: Index = 0;
+const SENDER_INDEX: Index = 1;
+const RECEIVER_INDEX: Index = 2;
+
+/// the aggregator, the sender and the receiver
+fn prepare_accounts(sender_pubkey_hash: [u8; 20]) -> Vec<Account> {
+    vec![
+        Account::new_builder().index(AG_INDEX.pack()).build(),
+        Account::new_builder()
+            .index(SENDER_INDEX.pack())
+            .pubkey_hash(sender_pubkey_hash.pack())
+            .build(),
+        Account::new_builder().index(RECEIVER_INDEX.pack()).build(),
+    ]
+}
+
+/// each account holds `balance` of the UDT
+fn prepare_state(accounts: &[Account], balance: u128) -> State {
+    let mut kv = KVMap::default();
+    kv.insert(UDT_ID, balance);
+    State::new(
+        accounts
+            .iter()
+            .map(|account| AccountState {
+                account: account.as_reader(),
+                kv: kv.clone(),
+                withdraw_kv: KVMap::default(),
+                storage: StorageMap::default(),
+            })
+            .collect(),
+    )
+}
+
+fn balance(state: &State, index: Index) -> u128 {
+    let (_account, kv) = state.get_account(index).expect("account");
+    kv.get(&UDT_ID).copied().unwrap_or(0)
+}
+
+#[test]
+fn test_udt_balance_above_u64() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = prepare_accounts(pubkey_hash);
+    let initial_balance = u128::from(u64::max_value());
+    let mut state = prepare_state(&accounts, initial_balance);
+    let executor = Executor::new(ROLLUP_ID);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, UDT_ID, 10, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let run_result = executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+        .expect("run tx");
+    assert_eq!(balance(&state, SENDER_INDEX), initial_balance - 11);
+    assert_eq!(balance(&state, RECEIVER_INDEX), initial_balance + 10);
+    assert_eq!(balance(&state, AG_INDEX), initial_balance + 1);
+    match run_result.logs[1].to_enum() {
+        LogUnion::TransferLog(log) => {
+            let amount: u128 = log.amount().unpack();
+            assert_eq!(amount, 10);
+        }
+        log => panic!("unexpected log: {}", log),
+    }
+    // the balance leaf keeps all 16 bytes
+    let leaf_balance: u128 = Value::from(initial_balance + 10).into();
+    assert_eq!(leaf_balance, initial_balance + 10);
+}
+
+#[test]
+fn test_udt_balance_overflow() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = prepare_accounts(pubkey_hash);
+    let mut state = prepare_state(&accounts, u128::max_value() - 5);
+    let executor = Executor::new(ROLLUP_ID);
+    let tx = gen_transfer_tx(SENDER_INDEX, RECEIVER_INDEX, 1, UDT_ID, 10, 1);
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    match executor.run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX) {
+        Err(Error::BalanceOverflow) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert_eq!(balance(&state, SENDER_INDEX), u128::max_value() - 5);
+}
+
+#[test]
+fn test_multi_transfer() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = prepare_accounts(pubkey_hash);
+    let mut state = prepare_state(&accounts, 100);
+    let executor = Executor::new(ROLLUP_ID);
+    let tx = gen_multi_transfer_tx(
+        SENDER_INDEX,
+        vec![(RECEIVER_INDEX, 10), (AG_INDEX, 20)],
+        1,
+        UDT_ID,
+        1,
+    );
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    let run_result = executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+        .expect("run tx");
+    // the fee and a log for each transfer
+    assert_eq!(run_result.logs.len(), 3);
+    assert_eq!(balance(&state, SENDER_INDEX), 69);
+    assert_eq!(balance(&state, RECEIVER_INDEX), 110);
+    assert_eq!(balance(&state, AG_INDEX), 121);
+}
+
+#[test]
+fn test_multi_transfer_rollback() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = prepare_accounts(pubkey_hash);
+    let mut state = prepare_state(&accounts, 100);
+    let executor = Executor::new(ROLLUP_ID);
+    // the first transfer succeeds, the second one exceeds the remaining balance
+    let tx = gen_multi_transfer_tx(
+        SENDER_INDEX,
+        vec![(RECEIVER_INDEX, 10), (AG_INDEX, 90)],
+        1,
+        UDT_ID,
+        1,
+    );
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    match executor.run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX) {
+        Err(Error::BalanceNotEnough(89, 90)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    // all transfers and the fee are reverted
+    for index in &[AG_INDEX, SENDER_INDEX, RECEIVER_INDEX] {
+        assert_eq!(balance(&state, *index), 100);
+    }
+    let (sender, _kv) = state.get_account(SENDER_INDEX).expect("sender");
+    assert_eq!(Unpack::<u32>::unpack(&sender.nonce()), 0);
+
+    // the same tx can be resubmitted with a smaller amount
+    let tx = gen_multi_transfer_tx(
+        SENDER_INDEX,
+        vec![(RECEIVER_INDEX, 10), (AG_INDEX, 89)],
+        1,
+        UDT_ID,
+        1,
+    );
+    let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+    executor
+        .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+        .expect("run tx");
+    assert_eq!(balance(&state, SENDER_INDEX), 0);
+}
@@ -0,0 +1,146 @@
+//! Run the counter contract in ckb-vm, see `contracts/counter`
+
+use crate::tests::{
+    utils::{
+        constants::CKB_TOKEN_ID,
+        shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+    },
+    COUNTER_CONTRACT_BIN, COUNTER_CONTRACT_HASH,
+};
+use godwoken_executor::{
+    error::Error,
+    execution_context::ExecutionContext,
+    executor::Executor,
+    state::{AccountState, State},
+    traits::CodeStore,
+    vm::{VMContract, NEW_SLOT_CYCLES},
+};
+use godwoken_types::{
+    bytes::Bytes,
+    cache::{KVMap, StorageMap, TxWithHash},
+    core::Index,
+    packed::{Account, AccountScript, AccountScriptOpt, LogUnion},
+    prelude::*,
+};
+
+const ROLLUP_ID: [u8; 32] = [42u8; 32];
+const AG_INDEX: Index = 0;
+const SENDER_INDEX: Index = 1;
+const CONTRACT_INDEX: Index = 2;
+/// the counter's storage slot
+const KEY: [u8; 32] = [0u8; 32];
+const MAX_CYCLES: u64 = 10_000_000;
+/// cycles of an ecall
+const ECALL_CYCLES: u64 = 500;
+
+struct CounterCode;
+
+impl CodeStore for CounterCode {
+    fn get_code(&self, code_hash: &[u8; 32]) -> Option<Bytes> {
+        if code_hash == &*COUNTER_CONTRACT_HASH {
+            Some(COUNTER_CONTRACT_BIN.to_vec().into())
+        } else {
+            None
+        }
+    }
+}
+
+/// the aggregator, the sender and the counter contract
+fn prepare_accounts(sender_pubkey_hash: [u8; 20]) -> Vec<Account> {
+    let script = AccountScript::new_builder()
+        .code_hash(COUNTER_CONTRACT_HASH.pack())
+        .build();
+    vec![
+        Account::new_builder().index(AG_INDEX.pack()).build(),
+        Account::new_builder()
+            .index(SENDER_INDEX.pack())
+            .pubkey_hash(sender_pubkey_hash.pack())
+            .build(),
+        Account::new_builder()
+            .index(CONTRACT_INDEX.pack())
+            .script(AccountScriptOpt::new_builder().set(Some(script)).build())
+            .build(),
+    ]
+}
+
+/// each account holds 100 CKB, the counter's slot is proven empty
+fn prepare_state(accounts: &[Account]) -> State {
+    let mut kv = KVMap::default();
+    kv.insert(CKB_TOKEN_ID, 100);
+    let mut storage = StorageMap::default();
+    storage.insert(KEY, Vec::new());
+    State::new(
+        accounts
+            .iter()
+            .map(|account| AccountState {
+                account: account.as_reader(),
+                kv: kv.clone(),
+                withdraw_kv: KVMap::default(),
+                storage: storage.clone(),
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn test_transfer_to_vm_contract() {
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let accounts = prepare_accounts(pubkey_hash);
+    let mut state = prepare_state(&accounts);
+    let executor = Executor::with_contract(ROLLUP_ID, Box::new(VMContract::new(CounterCode)));
+    for nonce in 1..=2u32 {
+        let tx = gen_transfer_tx(SENDER_INDEX, CONTRACT_INDEX, nonce, CKB_TOKEN_ID, 10, 1);
+        let tx = sign_tx(&privkey, &ROLLUP_ID, tx);
+        let run_result = executor
+            .run(&mut state, TxWithHash::new(tx.as_reader()), AG_INDEX)
+            .expect("run tx");
+        let counter = u64::from(nonce).to_le_bytes();
+        assert_eq!(
+            state.get_storage(CONTRACT_INDEX, &KEY).expect("get"),
+            Some(&counter[..])
+        );
+        match run_result.logs.last().expect("log").to_enum() {
+            LogUnion::ContractLog(log) => {
+                let index: Index = log.account_index().unpack();
+                assert_eq!(index, CONTRACT_INDEX);
+                assert_eq!(log.data().raw_data(), &counter[..]);
+            }
+            log => panic!("unexpected log: {}", log),
+        }
+        // the contract loads the args and the slot, stores the slot, logs and exits
+        assert!(run_result.cycles > 5 * ECALL_CYCLES);
+        if nonce == 1 {
+            assert!(run_result.cycles > NEW_SLOT_CYCLES + 5 * ECALL_CYCLES);
+        }
+    }
+    let (_account, kv) = state.get_account(CONTRACT_INDEX).expect("contract");
+    assert_eq!(kv.get(&CKB_TOKEN_ID), Some(&120));
+}
+
+#[test]
+fn test_vm_contract_exit_code() {
+    let accounts = prepare_accounts([0u8; 20]);
+    let mut state = prepare_state(&accounts);
+    let contract = VMContract::new(CounterCode);
+    let mut context = ExecutionContext::new(&mut state, SENDER_INDEX, Some(&contract));
+    match context.call(CONTRACT_INDEX, CKB_TOKEN_ID, 10, &[7u8], MAX_CYCLES) {
+        Err(Error::ContractExit(7)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    // the failed call is reverted
+    assert_eq!(
+        context
+            .get_balance(CONTRACT_INDEX, &CKB_TOKEN_ID)
+            .expect("get"),
+        100
+    );
+    assert_eq!(
+        context.get_storage(CONTRACT_INDEX, &KEY).expect("get"),
+        None
+    );
+    // out of cycles
+    match context.call(CONTRACT_INDEX, CKB_TOKEN_ID, 10, &[], ECALL_CYCLES) {
+        Err(Error::VM(_)) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
@@ -6,6 +6,9 @@ mod test_submit_block;
 #[derive(Debug)]
 #[repr(i8)]
 pub enum Error {
+    InvalidWitness = -11,
     InvalidAggregator = -20,
     InvalidSignature = -23,
+    BalanceOverflow = -42,
+    BlockTooLarge = -43,
 }
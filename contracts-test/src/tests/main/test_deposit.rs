@@ -1,4 +1,5 @@
 use crate::tests::{
+    main::Error,
     utils::{
         constants::CKB_TOKEN_ID,
         contract_state::ContractState,
@@ -6,6 +7,11 @@ use crate::tests::{
     },
     MAX_CYCLES,
 };
+use ckb_tool::{
+    ckb_error::{assert_error_eq, Error as CKBError},
+    ckb_script::ScriptError,
+    ckb_types::core::Cycle,
+};
 use godwoken_types::{
     cache::KVMap,
     core::Index,
@@ -14,17 +20,16 @@ use godwoken_types::{
 };
 use godwoken_utils::smt;
 
-#[test]
-fn test_deposit() {
-    let mut context = ContractState::new();
+const INPUT_CAPACITY: u64 = 12;
 
-    let original_amount = 12u64;
-    let deposit_amount = 42u64;
+/// deposit `deposit_amount` to an account of `balance`
+fn verify_deposit(balance: u128, deposit_amount: u64) -> Result<Cycle, CKBError> {
+    let mut context = ContractState::new();
 
-    // prepare a account
+    // prepare a account, the i128 delta wraps to the u128 balance
     let account = gen_accounts(0, 1).next().unwrap();
     let index: Index = 0;
-    context.update_account(index, CKB_TOKEN_ID, original_amount as i128);
+    context.update_account(index, CKB_TOKEN_ID, balance as i128);
     context.push_account(account.clone());
 
     let (leaves_path, merkle_branches) = context.gen_account_merkle_proof(vec![
@@ -34,7 +39,7 @@ fn test_deposit() {
 
     let global_state = context.get_global_state();
     let mut kv = KVMap::default();
-    kv.insert(CKB_TOKEN_ID, original_amount);
+    kv.insert(CKB_TOKEN_ID, balance);
 
     // deposit money
     context.update_account(index, CKB_TOKEN_ID, deposit_amount as i128);
@@ -65,12 +70,25 @@ fn test_deposit() {
     let mut context = default_context();
     let tx = default_tx_builder()
         .previous_output_data(global_state.as_slice().into())
-        .input_capacity(original_amount)
-        .output_capacity(original_amount + deposit_amount)
+        .input_capacity(INPUT_CAPACITY)
+        .output_capacity(INPUT_CAPACITY + deposit_amount)
         .witnesses(vec![witness.as_slice().into()])
         .outputs_data(vec![new_global_state.as_slice().into()])
         .inject_and_build(&mut context)
         .expect("build tx");
-    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
-    verify_result.expect("pass verification");
+    context.verify_tx(&tx, MAX_CYCLES)
+}
+
+#[test]
+fn test_deposit() {
+    verify_deposit(12, 42).expect("pass verification");
+}
+
+#[test]
+fn test_deposit_overflow() {
+    let verify_result = verify_deposit(u128::MAX, 42);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::BalanceOverflow as i8)
+    );
 }
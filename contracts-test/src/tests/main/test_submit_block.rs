@@ -5,12 +5,15 @@ use crate::tests::{
         constants::CKB_TOKEN_ID,
         contract_state::ContractState,
         shortcut::{
-            default_context, gen_transfer_tx, prepare_accounts, prepare_ag_account, sign_block,
+            default_context, gen_key_pair, gen_key_rotation_tx, gen_multi_transfer_tx,
+            gen_transfer_tx, gen_withdraw_request_tx, prepare_accounts, prepare_ag_account,
+            sign_block,
         },
     },
     MAX_CYCLES,
 };
 use ckb_tool::{ckb_error::assert_error_eq, ckb_script::ScriptError};
+use godwoken_types::{core::MAX_BLOCK_TXS_SIZE, packed::TxKind, prelude::*};
 
 #[test]
 fn test_submit_block() {
@@ -32,7 +35,7 @@ fn test_submit_block() {
     aggregator.push_tx(transfer_tx);
     // generate block and sign
     let mut submit_context = aggregator.gen_submit_block(ag_index);
-    let ag_sig = sign_block(&privkey, &submit_context.block);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
     submit_context.complete_sig(ag_sig);
     // run
     let mut context = default_context();
@@ -44,6 +47,213 @@ fn test_submit_block() {
     verify_result.expect("pass verification");
 }
 
+#[test]
+fn test_submit_block_with_multi_transfer() {
+    let mut contract_state = ContractState::new();
+    // prepare contract acccounts
+    let account_indexes = prepare_accounts(&mut contract_state, vec![50, 0, 0]);
+    // prepare aggregator account
+    let (ag_index, privkey) = prepare_ag_account(&mut contract_state);
+    let mut aggregator = Aggregator::new(contract_state);
+    // txs
+    let multi_transfer_tx = gen_multi_transfer_tx(
+        account_indexes[0],
+        vec![(account_indexes[1], 15), (account_indexes[2], 20)],
+        1,
+        CKB_TOKEN_ID,
+        3,
+    );
+    aggregator.push_tx(multi_transfer_tx);
+    // generate block and sign
+    let mut submit_context = aggregator.gen_submit_block(ag_index);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
+    submit_context.complete_sig(ag_sig);
+    // run
+    let mut context = default_context();
+    let tx = aggregator
+        .complete_submit_block(submit_context)
+        .inject_and_build(&mut context)
+        .expect("tx");
+    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
+    verify_result.expect("pass verification");
+}
+
+#[test]
+fn test_submit_block_with_withdraw_request() {
+    let mut contract_state = ContractState::new();
+    // prepare contract acccounts
+    let account_indexes = prepare_accounts(&mut contract_state, vec![50]);
+    // prepare aggregator account
+    let (ag_index, privkey) = prepare_ag_account(&mut contract_state);
+    let mut aggregator = Aggregator::new(contract_state);
+    // txs
+    let withdraw_request_tx = gen_withdraw_request_tx(account_indexes[0], 1, CKB_TOKEN_ID, 30, 3);
+    aggregator.push_tx(withdraw_request_tx);
+    // generate block and sign
+    let mut submit_context = aggregator.gen_submit_block(ag_index);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
+    submit_context.complete_sig(ag_sig);
+    // run
+    let mut context = default_context();
+    let tx = aggregator
+        .complete_submit_block(submit_context)
+        .inject_and_build(&mut context)
+        .expect("tx");
+    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
+    verify_result.expect("pass verification");
+}
+
+#[test]
+fn test_submit_block_with_key_rotation() {
+    let mut contract_state = ContractState::new();
+    // prepare contract acccounts
+    let account_indexes = prepare_accounts(&mut contract_state, vec![20, 100]);
+    // prepare aggregator account
+    let (ag_index, privkey) = prepare_ag_account(&mut contract_state);
+    let mut aggregator = Aggregator::new(contract_state);
+    // txs
+    let (_new_privkey, new_pubkey_hash) = gen_key_pair();
+    let key_rotation_tx =
+        gen_key_rotation_tx(account_indexes[0], 1, CKB_TOKEN_ID, new_pubkey_hash, 3);
+    aggregator.push_tx(key_rotation_tx);
+    let transfer_tx = gen_transfer_tx(
+        account_indexes[0],
+        account_indexes[1],
+        2,
+        CKB_TOKEN_ID,
+        15,
+        3,
+    );
+    aggregator.push_tx(transfer_tx);
+    // generate block and sign
+    let mut submit_context = aggregator.gen_submit_block(ag_index);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
+    submit_context.complete_sig(ag_sig);
+    // run
+    let mut context = default_context();
+    let tx = aggregator
+        .complete_submit_block(submit_context)
+        .inject_and_build(&mut context)
+        .expect("tx");
+    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
+    verify_result.expect("pass verification");
+}
+
+#[test]
+fn test_submit_block_larger_than_load_buffer() {
+    let mut contract_state = ContractState::new();
+    // prepare contract acccounts
+    let account_indexes = prepare_accounts(&mut contract_state, vec![100, 0]);
+    // prepare aggregator account
+    let (ag_index, privkey) = prepare_ag_account(&mut contract_state);
+    let mut aggregator = Aggregator::new(contract_state);
+    // txs, the witness is loaded by chunks of 4096 bytes
+    let multi_transfer_tx = gen_multi_transfer_tx(
+        account_indexes[0],
+        vec![(account_indexes[1], 1); 60],
+        1,
+        CKB_TOKEN_ID,
+        3,
+    );
+    assert!(multi_transfer_tx.as_slice().len() > 4096);
+    aggregator.push_tx(multi_transfer_tx);
+    // generate block and sign
+    let mut submit_context = aggregator.gen_submit_block(ag_index);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
+    submit_context.complete_sig(ag_sig);
+    // run
+    let mut context = default_context();
+    let tx = aggregator
+        .complete_submit_block(submit_context)
+        .inject_and_build(&mut context)
+        .expect("tx");
+    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
+    verify_result.expect("pass verification");
+}
+
+#[test]
+fn test_submit_too_large_block() {
+    let mut contract_state = ContractState::new();
+    // prepare contract acccounts
+    let account_indexes = prepare_accounts(&mut contract_state, vec![2000, 0]);
+    // prepare aggregator account
+    let (ag_index, privkey) = prepare_ag_account(&mut contract_state);
+    let mut aggregator = Aggregator::new(contract_state);
+    // txs
+    let mut txs_size = 0;
+    for nonce in 1..=5 {
+        let multi_transfer_tx = gen_multi_transfer_tx(
+            account_indexes[0],
+            vec![(account_indexes[1], 1); 200],
+            nonce,
+            CKB_TOKEN_ID,
+            1,
+        );
+        txs_size += multi_transfer_tx.as_slice().len();
+        aggregator.push_tx(multi_transfer_tx);
+    }
+    assert!(txs_size > MAX_BLOCK_TXS_SIZE);
+    // generate block and sign
+    let mut submit_context = aggregator.gen_submit_block(ag_index);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
+    submit_context.complete_sig(ag_sig);
+    // run
+    let mut context = default_context();
+    let tx = aggregator
+        .complete_submit_block(submit_context)
+        .inject_and_build(&mut context)
+        .expect("tx");
+    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::BlockTooLarge as i8)
+    );
+}
+
+#[test]
+fn test_submit_block_with_unknown_tx_kind() {
+    let mut contract_state = ContractState::new();
+    // prepare contract acccounts
+    let account_indexes = prepare_accounts(&mut contract_state, vec![20, 100]);
+    // prepare aggregator account
+    let (ag_index, privkey) = prepare_ag_account(&mut contract_state);
+    let mut aggregator = Aggregator::new(contract_state);
+    // txs
+    let transfer_tx = gen_transfer_tx(
+        account_indexes[0],
+        account_indexes[1],
+        1,
+        CKB_TOKEN_ID,
+        15,
+        3,
+    );
+    aggregator.push_tx(transfer_tx);
+    // generate block, the tx's kind is replaced by an item id out of `TxKind`
+    let mut submit_context = aggregator.gen_submit_block(ag_index);
+    let tx = submit_context.txs[0].clone();
+    let raw = tx.raw();
+    let mut kind = raw.kind().as_bytes().to_vec();
+    kind[..4].copy_from_slice(&(TxKind::ITEM_COUNT as u32).to_le_bytes());
+    let raw = raw
+        .as_builder()
+        .kind(TxKind::new_unchecked(kind.into()))
+        .build();
+    submit_context.txs[0] = tx.as_builder().raw(raw).build();
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
+    submit_context.complete_sig(ag_sig);
+    // run
+    let mut context = default_context();
+    let tx = aggregator
+        .complete_submit_block(submit_context)
+        .inject_and_build(&mut context)
+        .expect("tx");
+    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::InvalidWitness as i8)
+    );
+}
+
 #[test]
 fn test_submit_with_non_ag_account() {
     let mut contract_state = ContractState::new();
@@ -64,7 +274,7 @@ fn test_submit_with_non_ag_account() {
     aggregator.push_tx(transfer_tx);
     // generate block and sign
     let mut submit_context = aggregator.gen_submit_block(account_indexes[0]);
-    let ag_sig = sign_block(&privkey, &submit_context.block);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
     submit_context.complete_sig(ag_sig);
     // run
     let mut context = default_context();
@@ -101,7 +311,7 @@ fn test_with_non_sufficient_balance() {
     aggregator.push_tx(transfer_tx);
     // generate block and sign
     let mut submit_context = aggregator.gen_submit_block(ag_index);
-    let ag_sig = sign_block(&privkey, &submit_context.block);
+    let ag_sig = sign_block(&privkey, &aggregator.rollup_id(), &submit_context.block);
     submit_context.complete_sig(ag_sig);
     // run
     let mut context = default_context();
@@ -150,3 +360,43 @@ fn test_with_wrong_ag_sig() {
         ScriptError::ValidationFailure(Error::InvalidSignature as i8)
     );
 }
+
+#[test]
+fn test_with_ag_sig_of_wrong_key() {
+    let mut contract_state = ContractState::new();
+    // prepare contract acccounts
+    let account_indexes = prepare_accounts(&mut contract_state, vec![20, 100]);
+    // prepare aggregator account
+    let (ag_index, _privkey) = prepare_ag_account(&mut contract_state);
+    let mut aggregator = Aggregator::new(contract_state);
+    // txs
+    let transfer_tx = gen_transfer_tx(
+        account_indexes[0],
+        account_indexes[1],
+        1,
+        CKB_TOKEN_ID,
+        15,
+        3,
+    );
+    aggregator.push_tx(transfer_tx);
+    // generate block and sign with a key that is not the aggregator's
+    let mut submit_context = aggregator.gen_submit_block(ag_index);
+    let (wrong_privkey, _pubkey_hash) = gen_key_pair();
+    let ag_sig = sign_block(
+        &wrong_privkey,
+        &aggregator.rollup_id(),
+        &submit_context.block,
+    );
+    submit_context.complete_sig(ag_sig);
+    // run
+    let mut context = default_context();
+    let tx = aggregator
+        .complete_submit_block(submit_context)
+        .inject_and_build(&mut context)
+        .expect("tx");
+    let verify_result = context.verify_tx(&tx, MAX_CYCLES);
+    assert_error_eq!(
+        verify_result.unwrap_err(),
+        ScriptError::ValidationFailure(Error::InvalidSignature as i8)
+    );
+}
@@ -1,13 +1,20 @@
 #[macro_use]
 mod utils;
+mod aggregator;
+mod challenge;
 mod dummy_lock;
+mod executor;
 mod main;
+mod tx_builder;
+mod types;
 
 use ckb_tool::ckb_types::{bytes::Bytes, packed::CellOutput, prelude::*};
 use lazy_static::lazy_static;
 
 const DUMMY_LOCK_PATH: &str = "../contracts/binaries/dummy-lock";
 const MAIN_CONTRACT_PATH: &str = "../contracts/binaries/godwoken-main";
+const CHALLENGE_CONTRACT_PATH: &str = "../contracts/binaries/godwoken-challenge";
+const COUNTER_CONTRACT_PATH: &str = "../contracts/binaries/counter";
 
 lazy_static! {
     pub static ref DUMMY_LOCK_BIN: Bytes = std::fs::read(DUMMY_LOCK_PATH).expect("read").into();
@@ -16,6 +23,14 @@ lazy_static! {
     pub static ref DUMMY_LOCK_HASH: [u8; 32] = CellOutput::calc_data_hash(&DUMMY_LOCK_BIN).unpack();
     pub static ref MAIN_CONTRACT_HASH: [u8; 32] =
         CellOutput::calc_data_hash(&MAIN_CONTRACT_BIN).unpack();
+    pub static ref CHALLENGE_CONTRACT_BIN: Bytes =
+        std::fs::read(CHALLENGE_CONTRACT_PATH).expect("read").into();
+    pub static ref CHALLENGE_CONTRACT_HASH: [u8; 32] =
+        CellOutput::calc_data_hash(&CHALLENGE_CONTRACT_BIN).unpack();
+    pub static ref COUNTER_CONTRACT_BIN: Bytes =
+        std::fs::read(COUNTER_CONTRACT_PATH).expect("read").into();
+    pub static ref COUNTER_CONTRACT_HASH: [u8; 32] =
+        CellOutput::calc_data_hash(&COUNTER_CONTRACT_BIN).unpack();
 }
 
 pub const MAX_CYCLES: u64 = 30_000_000;
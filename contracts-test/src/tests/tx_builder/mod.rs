@@ -0,0 +1,166 @@
+mod test_challenge;
+mod test_rollup;
+mod test_skeleton;
+
+use super::aggregator::mock_chain::{deploy_tx, rollup_id, MockChain};
+use godwoken_aggregator::{
+    chain::{transaction_hash, ChainFollower},
+    error::Error as AggregatorError,
+    mempool::{Mempool, MempoolConfig},
+    state,
+    store::Store,
+};
+use godwoken_tx_builder::{
+    config::BuilderConfig,
+    constants::BYTE_SHANNONS,
+    error::Error,
+    provider::{AccountEntry, LiveCell, StateProvider},
+};
+use godwoken_types::{core::Index, packed::*, prelude::*};
+use godwoken_utils::smt::H256;
+use std::sync::Arc;
+
+pub const FEE_RATE: u64 = 1000;
+pub const CHALLENGE_CODE_HASH: [u8; 32] = [5u8; 32];
+
+type Follower = ChainFollower<Arc<MockChain>>;
+
+/// Serves the state of an aggregator's store
+pub struct StoreProvider<'a> {
+    pub store: &'a Store,
+    pub rollup_cell: LiveCell,
+    pub payment_cells: Vec<LiveCell>,
+}
+
+impl<'a> StoreProvider<'a> {
+    pub fn new(store: &'a Store, rollup_cell: LiveCell, payment_cells: Vec<LiveCell>) -> Self {
+        StoreProvider {
+            store,
+            rollup_cell,
+            payment_cells,
+        }
+    }
+}
+
+fn provider_error(err: AggregatorError) -> Error {
+    Error::Provider(format!("{:?}", err))
+}
+
+impl<'a> StateProvider for StoreProvider<'a> {
+    fn rollup_cell(&self) -> Result<LiveCell, Error> {
+        Ok(self.rollup_cell.clone())
+    }
+
+    fn account_root(&self) -> Result<[u8; 32], Error> {
+        Ok(self.store.account_root())
+    }
+
+    fn get_account(&self, index: Index) -> Result<Option<AccountEntry>, Error> {
+        let entry = state::load_accounts(self.store, &[index])
+            .map_err(provider_error)?
+            .pop();
+        Ok(entry)
+    }
+
+    fn account_merkle_proof(&self, keys: Vec<H256>) -> Result<SMTProof, Error> {
+        self.store
+            .account_merkle_proof(keys)
+            .map_err(provider_error)
+    }
+
+    fn get_block_hash(&self, number: u64) -> Result<Option<[u8; 32]>, Error> {
+        self.store.get_block_hash(number).map_err(provider_error)
+    }
+
+    fn block_merkle_proof(&self, number: u64) -> Result<Vec<[u8; 32]>, Error> {
+        self.store
+            .block_merkle_proof(number)
+            .map_err(provider_error)
+    }
+
+    fn payment_cells(&self) -> Result<Vec<LiveCell>, Error> {
+        Ok(self.payment_cells.clone())
+    }
+}
+
+pub fn change_lock() -> Script {
+    Script::new_builder().code_hash([3u8; 32].pack()).build()
+}
+
+pub fn builder_config() -> BuilderConfig {
+    BuilderConfig {
+        cell_deps: Vec::new(),
+        fee_rate: FEE_RATE,
+        change_lock: change_lock(),
+        challenge_capacity: 500 * BYTE_SHANNONS,
+        challenge_lock: Script::new_builder().code_hash([4u8; 32].pack()).build(),
+        challenge_code_hash: CHALLENGE_CODE_HASH,
+        challenge_hash_type: 0,
+        withdraw_lock_hash: [6u8; 32],
+    }
+}
+
+/// a cell of the change lock
+pub fn payment_cell(n: u8, capacity: u64) -> LiveCell {
+    LiveCell {
+        out_point: OutPoint::new_builder().tx_hash([n; 32].pack()).build(),
+        output: CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(change_lock())
+            .build(),
+        data: Default::default(),
+    }
+}
+
+/// the cell of an output of a tx
+pub fn output_cell(tx: &Transaction, index: usize) -> LiveCell {
+    let raw = tx.raw();
+    LiveCell {
+        out_point: OutPoint::new_builder()
+            .tx_hash(transaction_hash(tx).pack())
+            .index((index as u32).pack())
+            .build(),
+        output: raw.outputs().get(index).expect("output"),
+        data: raw.outputs_data().get(index).expect("data").unpack(),
+    }
+}
+
+/// total capacity of the tx's outputs
+pub fn outputs_capacity(tx: &Transaction) -> u64 {
+    tx.raw()
+        .outputs()
+        .into_iter()
+        .map(|output| Unpack::<u64>::unpack(&output.capacity()))
+        .sum()
+}
+
+/// a follower and a store synced with the deployed rollup cell
+pub fn setup() -> (Follower, Store, Mempool) {
+    let follower = ChainFollower::new(Arc::new(MockChain::new()), rollup_id(), 0);
+    let mut store = Store::open_temporary().expect("open");
+    let mut mempool = Mempool::new(rollup_id(), MempoolConfig::default());
+    follower
+        .client()
+        .push_block(vec![deploy_tx(1000 * BYTE_SHANNONS)]);
+    follower.sync(&mut store, &mut mempool).expect("sync");
+    (follower, store, mempool)
+}
+
+/// the follower's rollup cell, its data is the store's global state
+pub fn rollup_cell(follower: &Follower, store: &Store) -> LiveCell {
+    let rollup_cell = follower
+        .rollup_cell(store)
+        .expect("get")
+        .expect("rollup cell");
+    LiveCell {
+        out_point: rollup_cell.out_point,
+        output: rollup_cell.output,
+        data: store.get_global_state().expect("get").as_bytes(),
+    }
+}
+
+/// commit the tx in a new layer-1 block and sync
+pub fn submit_tx(follower: &Follower, store: &mut Store, mempool: &mut Mempool, tx: Transaction) {
+    follower.client().push_block(vec![tx]);
+    follower.sync(store, mempool).expect("sync");
+}
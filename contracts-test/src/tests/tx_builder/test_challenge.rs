@@ -0,0 +1,286 @@
+use super::{
+    builder_config, output_cell, outputs_capacity, payment_cell, StoreProvider,
+    CHALLENGE_CODE_HASH, FEE_RATE,
+};
+use crate::tests::{
+    aggregator::{
+        mock_chain::{rollup_id, rollup_type_script},
+        prepare_store,
+    },
+    utils::{constants::CKB_TOKEN_ID, shortcut::gen_transfer_tx},
+};
+use godwoken_aggregator::{chain::load_global_state, store::Store};
+use godwoken_executor::state::{AccountState, State};
+use godwoken_tx_builder::{
+    challenge::{ChallengeBuilder, InvalidChallengeBuilder, WithdrawChallengeBuilder},
+    constants::{
+        BYTE_SHANNONS, CHALLENGE_CELL_WAIT_BLOCKS, SINCE_EPOCH_FLAG, SINCE_RELATIVE_FLAG,
+        WITHDRAW_CHALLENGE_WAIT_EPOCHS,
+    },
+    error::Error,
+    provider::LiveCell,
+    revert_block::RevertBlockBuilder,
+    skeleton::estimate_fee,
+};
+use godwoken_types::{
+    bytes::Bytes,
+    cache::{KVMap, StorageMap},
+    packed::*,
+    prelude::*,
+    signing::tx_hash,
+};
+use godwoken_utils::{
+    hash::block_hash,
+    mmr::{compute_tx_root, merkle_root},
+    smt::{self, compute_root_with_proof},
+};
+
+/// accounts of the aggregator and the challenger, a block of a wrong account root is pushed
+/// to the store, the accounts are kept before the block
+fn prepare_block() -> (Store, AgBlock, Vec<Tx>) {
+    let mut store = prepare_store(vec![([1u8; 20], 5000), ([2u8; 20], 100)]);
+    let txs = vec![gen_transfer_tx(1, 0, 1, CKB_TOKEN_ID, 10, 1)];
+    let tx_root = merkle_root(txs.iter().map(|tx| tx_hash(&tx.as_reader())).collect());
+    let block = AgBlock::new_builder()
+        .tx_root(tx_root.pack())
+        .txs_count(1u32.pack())
+        .prev_account_root(store.account_root().pack())
+        .prev_account_count(store.account_count().pack())
+        .account_root([9u8; 32].pack())
+        .build();
+    store
+        .push_block(block.clone(), txs.clone())
+        .expect("push block");
+    (store, block, txs)
+}
+
+fn rollup_cell(store: &Store) -> LiveCell {
+    LiveCell {
+        out_point: OutPoint::new_builder().tx_hash([7u8; 32].pack()).build(),
+        output: CellOutput::new_builder()
+            .capacity((1000 * BYTE_SHANNONS).pack())
+            .type_(Some(rollup_type_script()).pack())
+            .build(),
+        data: store.get_global_state().expect("get").as_bytes(),
+    }
+}
+
+fn provider(store: &Store) -> StoreProvider {
+    StoreProvider::new(
+        store,
+        rollup_cell(store),
+        vec![payment_cell(1, 1000 * BYTE_SHANNONS)],
+    )
+}
+
+fn create_challenge(store: &Store, block: &AgBlock, txs: &[Tx]) -> LiveCell {
+    let tx = ChallengeBuilder::new(block.clone(), txs.to_vec(), 1)
+        .build(&provider(store), &builder_config())
+        .expect("build challenge");
+    output_cell(&tx, 0)
+}
+
+fn load_unlock(tx: &Transaction, index: usize) -> ChallengeUnlockUnion {
+    let witness: Bytes = tx.witnesses().get(index).expect("witness").unpack();
+    ChallengeUnlock::from_slice(&witness)
+        .expect("challenge unlock")
+        .to_enum()
+}
+
+/// root of the touched accounts with the proof of the response
+fn touched_accounts_root(invalid_challenge: &InvalidChallenge) -> [u8; 32] {
+    let reader = invalid_challenge.as_reader();
+    let state = State::new(
+        reader
+            .touched_accounts()
+            .iter()
+            .zip(reader.touched_accounts_token_kv().iter())
+            .zip(reader.touched_accounts_withdraw_kv().iter())
+            .zip(reader.touched_accounts_storage().iter())
+            .map(|(((account, kv), withdraw_kv), storage)| {
+                let kv: KVMap = kv.unpack();
+                let withdraw_kv: KVMap = withdraw_kv.unpack();
+                let storage: StorageMap = storage.unpack();
+                AccountState {
+                    account,
+                    kv,
+                    withdraw_kv,
+                    storage,
+                }
+            })
+            .collect(),
+    );
+    let proof = reader.touched_accounts_proof();
+    let leaves_path = proof.leaves_path().unpack();
+    let merkle_branches: Vec<(smt::H256, u8)> =
+        Unpack::<Vec<([u8; 32], u8)>>::unpack(&proof.proof())
+            .into_iter()
+            .map(|(node, height)| (node.into(), height))
+            .collect();
+    compute_root_with_proof(state.merkle_leaves(), leaves_path, merkle_branches)
+        .expect("compute root")
+        .into()
+}
+
+#[test]
+fn test_create_challenge() {
+    let (store, block, txs) = prepare_block();
+    let config = builder_config();
+    let tx = ChallengeBuilder::new(block.clone(), txs.clone(), 1)
+        .build(&provider(&store), &config)
+        .expect("build challenge");
+    let challenge_cell = output_cell(&tx, 0);
+    let capacity: u64 = challenge_cell.output.capacity().unpack();
+    assert_eq!(capacity, config.challenge_capacity);
+    let type_script = challenge_cell.output.type_().to_opt().expect("type");
+    let code_hash: [u8; 32] = type_script.code_hash().unpack();
+    assert_eq!(code_hash, CHALLENGE_CODE_HASH);
+    let args: Bytes = type_script.args().unpack();
+    let main_type_hash: [u8; 32] = ChallengeArgs::from_slice(&args)
+        .expect("challenge args")
+        .main_type_hash()
+        .unpack();
+    assert_eq!(main_type_hash, rollup_id());
+    let context = ChallengeContext::from_slice(&challenge_cell.data).expect("context");
+    assert_eq!(context.block().as_slice(), block.as_slice());
+    assert_eq!(Unpack::<u64>::unpack(&context.challenger_index()), 1);
+
+    // the challenge contract checks the txs with the proof in the witness
+    let witness: Bytes = tx.witnesses().get(0).expect("witness").unpack();
+    let proof: Bytes = WitnessArgs::from_slice(&witness)
+        .expect("witness args")
+        .output_type()
+        .to_opt()
+        .expect("proof")
+        .unpack();
+    let txs_proof: Vec<[u8; 32]> = ChallengeProof::from_slice(&proof)
+        .expect("challenge proof")
+        .txs_proof()
+        .into_iter()
+        .map(|item| item.unpack())
+        .collect();
+    let leaves = txs
+        .iter()
+        .enumerate()
+        .map(|(i, tx)| (i, tx_hash(&tx.as_reader())))
+        .collect();
+    let tx_root: [u8; 32] = block.tx_root().unpack();
+    assert_eq!(
+        compute_tx_root(leaves, txs.len() as u32, txs_proof).expect("compute root"),
+        tx_root
+    );
+
+    // blocks out of the rollup can't be challenged
+    let unknown_block = block.as_builder().account_root([8u8; 32].pack()).build();
+    match ChallengeBuilder::new(unknown_block, txs, 1).build(&provider(&store), &config) {
+        Err(Error::MissingBlock(0)) => {}
+        _ => panic!("expect missing block"),
+    }
+}
+
+#[test]
+fn test_revert_block() {
+    let (mut store, block, txs) = prepare_block();
+    let challenge_cell = create_challenge(&store, &block, &txs);
+    let tx = RevertBlockBuilder::new(challenge_cell.clone())
+        .build(&provider(&store), &builder_config())
+        .expect("build revert block");
+    let raw = tx.raw();
+    let since: u64 = raw
+        .inputs()
+        .get(1)
+        .expect("challenge input")
+        .since()
+        .unpack();
+    assert_eq!(since, SINCE_RELATIVE_FLAG | CHALLENGE_CELL_WAIT_BLOCKS);
+    // the challenge cell is kept for the challenge contract
+    assert_eq!(
+        raw.outputs().get(1).expect("challenge output").as_slice(),
+        challenge_cell.output.as_slice()
+    );
+    match load_unlock(&tx, 1) {
+        ChallengeUnlockUnion::RevertBlockWithChallenge(_) => {}
+        _ => panic!("expect revert block with challenge"),
+    }
+
+    // the challenger gets 80% of the aggregator's balance
+    store
+        .update_balance(0, CKB_TOKEN_ID, 0)
+        .expect("update balance");
+    store
+        .update_balance(1, CKB_TOKEN_ID, 4100)
+        .expect("update balance");
+    let global_state = load_global_state(&tx, 0);
+    let account_root: [u8; 32] = global_state.account_root().unpack();
+    assert_eq!(account_root, store.account_root());
+    let reverted_block = AgBlock::new_reverted_block(block.as_reader(), account_root, 1);
+    // the block root of a single block is its hash
+    let block_root: [u8; 32] = global_state.block_root().unpack();
+    assert_eq!(block_root, block_hash(&reverted_block));
+}
+
+#[test]
+fn test_withdraw_challenge() {
+    let (store, block, txs) = prepare_block();
+    let challenge_cell = create_challenge(&store, &block, &txs);
+    let provider = StoreProvider::new(&store, rollup_cell(&store), Vec::new());
+    let tx = WithdrawChallengeBuilder::new(challenge_cell)
+        .build(&provider, &builder_config())
+        .expect("build withdraw challenge");
+    let raw = tx.raw();
+    assert_eq!(raw.inputs().len(), 1);
+    let since: u64 = raw
+        .inputs()
+        .get(0)
+        .expect("challenge input")
+        .since()
+        .unpack();
+    assert_eq!(
+        since,
+        SINCE_RELATIVE_FLAG | SINCE_EPOCH_FLAG | (1 << 40) | WITHDRAW_CHALLENGE_WAIT_EPOCHS
+    );
+    match load_unlock(&tx, 0) {
+        ChallengeUnlockUnion::WithdrawChallenge(_) => {}
+        _ => panic!("expect withdraw challenge"),
+    }
+    // the bond pays the fee, the rest is the change
+    let fee = 500 * BYTE_SHANNONS - outputs_capacity(&tx);
+    assert_eq!(fee, estimate_fee(&tx, FEE_RATE));
+}
+
+#[test]
+fn test_invalid_challenge() {
+    let (store, block, txs) = prepare_block();
+    let challenge_cell = create_challenge(&store, &block, &txs);
+    let provider = StoreProvider::new(&store, rollup_cell(&store), Vec::new());
+    let tx = InvalidChallengeBuilder::new(challenge_cell.clone())
+        .build(&provider, &builder_config())
+        .expect("build invalid challenge");
+    let invalid_challenge = match load_unlock(&tx, 0) {
+        ChallengeUnlockUnion::InvalidChallenge(invalid_challenge) => invalid_challenge,
+        _ => panic!("expect invalid challenge"),
+    };
+    // the aggregator and the sender
+    assert_eq!(invalid_challenge.touched_accounts().len(), 2);
+    assert_eq!(
+        touched_accounts_root(&invalid_challenge),
+        store.account_root()
+    );
+    let fee = 500 * BYTE_SHANNONS - outputs_capacity(&tx);
+    assert_eq!(fee, estimate_fee(&tx, FEE_RATE));
+
+    // the provider must serve the accounts before the block
+    let context = ChallengeContext::from_slice(&challenge_cell.data).expect("context");
+    let other_block = block
+        .as_builder()
+        .prev_account_root([8u8; 32].pack())
+        .build();
+    let other_cell = LiveCell {
+        data: context.as_builder().block(other_block).build().as_bytes(),
+        ..challenge_cell
+    };
+    match InvalidChallengeBuilder::new(other_cell).build(&provider, &builder_config()) {
+        Err(Error::AccountRootMismatch(expected, _)) => assert_eq!(expected, [8u8; 32]),
+        _ => panic!("expect account root mismatch"),
+    }
+}
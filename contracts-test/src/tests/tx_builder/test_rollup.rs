@@ -0,0 +1,185 @@
+use super::{
+    builder_config, outputs_capacity, payment_cell, rollup_cell, setup, submit_tx, Follower,
+    StoreProvider, FEE_RATE,
+};
+use crate::tests::{
+    aggregator::mock_chain::rollup_id,
+    utils::{
+        constants::CKB_TOKEN_ID,
+        shortcut::{gen_key_pair, gen_transfer_tx, sign_tx},
+    },
+};
+use godwoken_aggregator::{
+    chain::{load_action, load_global_state},
+    generator::{Generator, GeneratorConfig},
+    mempool::Mempool,
+    store::Store,
+};
+use godwoken_tx_builder::{
+    constants::BYTE_SHANNONS, deposit::DepositBuilder, error::Error, register::RegisterBuilder,
+    skeleton::estimate_fee, submit_block::SubmitBlockBuilder,
+};
+use godwoken_types::{packed::*, prelude::*};
+
+/// register an account with the builder, the follower checks the new global state
+fn register(
+    follower: &Follower,
+    store: &mut Store,
+    mempool: &mut Mempool,
+    pubkey_hash: [u8; 20],
+    deposit: u64,
+) {
+    let account = Account::new_builder()
+        .pubkey_hash(pubkey_hash.pack())
+        .build();
+    let provider = StoreProvider::new(
+        store,
+        rollup_cell(follower, store),
+        vec![payment_cell(1, 10000 * BYTE_SHANNONS)],
+    );
+    let tx = RegisterBuilder::new(account, deposit)
+        .build(&provider, &builder_config())
+        .expect("build register");
+    submit_tx(follower, store, mempool, tx);
+}
+
+#[test]
+fn test_register_and_deposit() {
+    let (_privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    let config = builder_config();
+    let deposit = 2000 * BYTE_SHANNONS;
+    let account = Account::new_builder()
+        .pubkey_hash(pubkey_hash.pack())
+        .build();
+    let cell = rollup_cell(&follower, &store);
+    let rollup_capacity: u64 = cell.output.capacity().unpack();
+    let payment_cells = vec![
+        payment_cell(1, 1000 * BYTE_SHANNONS),
+        payment_cell(2, 5000 * BYTE_SHANNONS),
+    ];
+    let provider = StoreProvider::new(&store, cell.clone(), payment_cells.clone());
+    let tx = RegisterBuilder::new(account.clone(), deposit)
+        .build(&provider, &config)
+        .expect("build register");
+    let raw = tx.raw();
+    let input = raw.inputs().get(0).expect("rollup input");
+    assert_eq!(
+        input.previous_output().as_slice(),
+        cell.out_point.as_slice()
+    );
+    let capacity: u64 = raw.outputs().get(0).expect("rollup").capacity().unpack();
+    assert_eq!(capacity, rollup_capacity + deposit);
+    // both payment cells pay the deposit
+    assert_eq!(raw.inputs().len(), 3);
+    let fee = rollup_capacity + 6000 * BYTE_SHANNONS - outputs_capacity(&tx);
+    assert_eq!(fee, estimate_fee(&tx, FEE_RATE));
+    match load_action(&tx, 0).expect("action").to_enum() {
+        ActionUnion::Register(register) => {
+            let expected_account = account.as_builder().index(0u64.pack()).build();
+            assert_eq!(register.account().as_slice(), expected_account.as_slice());
+        }
+        _ => panic!("expect register"),
+    }
+    submit_tx(&follower, &mut store, &mut mempool, tx);
+    assert_eq!(store.account_count(), 1);
+    assert_eq!(
+        store.get_balance(0, &CKB_TOKEN_ID).expect("get"),
+        u128::from(deposit)
+    );
+
+    let provider = StoreProvider::new(
+        &store,
+        rollup_cell(&follower, &store),
+        payment_cells.clone(),
+    );
+    let tx = DepositBuilder::new(0, deposit)
+        .build(&provider, &config)
+        .expect("build deposit");
+    submit_tx(&follower, &mut store, &mut mempool, tx);
+    assert_eq!(
+        store.get_balance(0, &CKB_TOKEN_ID).expect("get"),
+        u128::from(2 * deposit)
+    );
+
+    let provider = StoreProvider::new(&store, rollup_cell(&follower, &store), payment_cells);
+    match DepositBuilder::new(1, deposit).build(&provider, &config) {
+        Err(Error::MissingAccount(1)) => {}
+        _ => panic!("expect missing account"),
+    }
+}
+
+#[test]
+fn test_submit_blocks() {
+    let (ag_privkey, ag_pubkey_hash) = gen_key_pair();
+    let (privkey, pubkey_hash) = gen_key_pair();
+    let (follower, mut store, mut mempool) = setup();
+    register(
+        &follower,
+        &mut store,
+        &mut mempool,
+        ag_pubkey_hash,
+        5000 * BYTE_SHANNONS,
+    );
+    register(
+        &follower,
+        &mut store,
+        &mut mempool,
+        pubkey_hash,
+        2000 * BYTE_SHANNONS,
+    );
+    let generator = Generator::new(rollup_id(), 0, ag_privkey, GeneratorConfig::default());
+    let config = builder_config();
+    // the first nonce is 1
+    for nonce in 1..=2u32 {
+        let tx = gen_transfer_tx(1, 0, nonce, CKB_TOKEN_ID, 10, 1);
+        mempool
+            .push_tx(&store, sign_tx(&privkey, &rollup_id(), tx))
+            .expect("push tx");
+        let cell = rollup_cell(&follower, &store);
+        let rollup_capacity: u64 = cell.output.capacity().unpack();
+        let ag_cell = follower.rollup_cell(&store).expect("get").expect("cell");
+        let generated = generator
+            .generate(&mut store, &mut mempool, &ag_cell)
+            .expect("generate");
+        let provider =
+            StoreProvider::new(&store, cell, vec![payment_cell(1, 1000 * BYTE_SHANNONS)]);
+        let tx = SubmitBlockBuilder::new(generated.block.clone(), generated.txs.clone())
+            .build(&provider, &config)
+            .expect("build submit block");
+        // same as the aggregator's block
+        match load_action(&tx, 0).expect("action").to_enum() {
+            ActionUnion::SubmitBlock(submit_block) => {
+                assert_eq!(submit_block.as_slice(), generated.submit_block.as_slice());
+            }
+            _ => panic!("expect submit block"),
+        }
+        assert_eq!(
+            load_global_state(&tx, 0).as_slice(),
+            generated.global_state.as_slice()
+        );
+        let capacity: u64 = tx
+            .raw()
+            .outputs()
+            .get(0)
+            .expect("rollup")
+            .capacity()
+            .unpack();
+        assert_eq!(capacity, rollup_capacity);
+        submit_tx(&follower, &mut store, &mut mempool, tx);
+        assert_eq!(store.block_count(), nonce as u64);
+    }
+
+    // a submitted block can't be submitted again
+    let block = store.get_block(1).expect("get").expect("block");
+    let txs = store.get_block_txs(1).expect("get").expect("txs");
+    let provider = StoreProvider::new(
+        &store,
+        rollup_cell(&follower, &store),
+        vec![payment_cell(1, 1000 * BYTE_SHANNONS)],
+    );
+    match SubmitBlockBuilder::new(block, txs).build(&provider, &config) {
+        Err(Error::InvalidBlockNumber(2, 1)) => {}
+        _ => panic!("expect invalid block number"),
+    }
+}
@@ -0,0 +1,94 @@
+use super::{builder_config, change_lock, outputs_capacity, payment_cell, FEE_RATE};
+use godwoken_tx_builder::{
+    constants::BYTE_SHANNONS,
+    error::Error,
+    skeleton::{estimate_fee, occupied_capacity, TransactionSkeleton},
+};
+use godwoken_types::{bytes::Bytes, packed::*, prelude::*};
+
+fn skeleton_with_output(capacity: u64) -> TransactionSkeleton {
+    let mut skeleton = TransactionSkeleton::new(Vec::new());
+    skeleton.output(
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(change_lock())
+            .build(),
+        Bytes::new(),
+    );
+    skeleton
+}
+
+#[test]
+fn test_occupied_capacity() {
+    let output = CellOutput::new_builder().lock(change_lock()).build();
+    // capacity, code hash and hash type
+    assert_eq!(occupied_capacity(&output, &[]), 41 * BYTE_SHANNONS);
+    let type_script = Script::new_builder()
+        .args(Bytes::from(vec![0u8; 64]).pack())
+        .build();
+    let output = output.as_builder().type_(Some(type_script).pack()).build();
+    assert_eq!(
+        occupied_capacity(&output, &[0u8; 80]),
+        (41 + 97 + 80) * BYTE_SHANNONS
+    );
+}
+
+#[test]
+fn test_complete_with_change() {
+    let payment_cells = vec![
+        payment_cell(1, 150 * BYTE_SHANNONS),
+        payment_cell(2, 150 * BYTE_SHANNONS),
+        payment_cell(3, 1000 * BYTE_SHANNONS),
+    ];
+    let tx = skeleton_with_output(200 * BYTE_SHANNONS)
+        .complete(payment_cells, &builder_config())
+        .expect("complete");
+    let raw = tx.raw();
+    // the first two cells pay the output, the fee and the change
+    assert_eq!(raw.inputs().len(), 2);
+    assert_eq!(raw.outputs().len(), 2);
+    let change = raw.outputs().get(1).expect("change");
+    assert_eq!(change.lock().as_slice(), change_lock().as_slice());
+    let fee = 300 * BYTE_SHANNONS - outputs_capacity(&tx);
+    assert!(fee > 0);
+    assert_eq!(fee, estimate_fee(&tx, FEE_RATE));
+}
+
+#[test]
+fn test_complete_skips_existing_inputs() {
+    let mut skeleton = skeleton_with_output(150 * BYTE_SHANNONS);
+    skeleton.input(&payment_cell(1, 100 * BYTE_SHANNONS), 0);
+    let payment_cells = vec![
+        payment_cell(1, 100 * BYTE_SHANNONS),
+        payment_cell(2, 100 * BYTE_SHANNONS),
+    ];
+    let tx = skeleton
+        .complete(payment_cells, &builder_config())
+        .expect("complete");
+    let inputs: Vec<OutPoint> = tx
+        .raw()
+        .inputs()
+        .into_iter()
+        .map(|input| input.previous_output())
+        .collect();
+    assert_eq!(inputs.len(), 2);
+    assert_eq!(
+        inputs[1].as_slice(),
+        payment_cell(2, 0).out_point.as_slice()
+    );
+}
+
+#[test]
+fn test_insufficient_capacity() {
+    let result = skeleton_with_output(200 * BYTE_SHANNONS).complete(
+        vec![payment_cell(1, 100 * BYTE_SHANNONS)],
+        &builder_config(),
+    );
+    match result {
+        Err(Error::InsufficientCapacity(required, available)) => {
+            assert!(required > 200 * BYTE_SHANNONS);
+            assert_eq!(available, 100 * BYTE_SHANNONS);
+        }
+        _ => panic!("expect insufficient capacity"),
+    }
+}
@@ -0,0 +1,98 @@
+use crate::tests::utils::constants::CKB_TOKEN_ID;
+use godwoken_types::{
+    cache::KVMap,
+    packed::{Byte20, Byte32, Bytes, KeyValueMap, Payment, PaymentUnion, UDTPayment, Uint32},
+    prelude::*,
+};
+
+const UDT_ID: [u8; 32] = [3u8; 32];
+
+#[test]
+fn test_payment_conversion() {
+    // CKB is packed as `Uint64`, UDT as `Uint128`
+    let ckb_payment: Payment = (CKB_TOKEN_ID, u128::from(u64::max_value()))
+        .try_pack()
+        .expect("pack");
+    match ckb_payment.to_enum() {
+        PaymentUnion::Uint64(_) => {}
+        payment => panic!("unexpected payment: {}", payment),
+    }
+    let (token_id, amount): ([u8; 32], u128) = ckb_payment.unpack();
+    assert_eq!(token_id, CKB_TOKEN_ID);
+    assert_eq!(amount, u128::from(u64::max_value()));
+
+    let amount = u128::max_value();
+    let udt_payment: Payment = (UDT_ID, amount).try_pack().expect("pack");
+    let unpacked: ([u8; 32], u128) = udt_payment.unpack();
+    assert_eq!(unpacked, (UDT_ID, amount));
+
+    // a CKB amount never exceeds the cell capacity
+    let result: Result<Payment, _> = (CKB_TOKEN_ID, u128::from(u64::max_value()) + 1).try_pack();
+    assert_eq!(result.map(|_| ()), Err(ConversionError::Overflow));
+}
+
+#[test]
+fn test_kv_map_conversion() {
+    let mut kv = KVMap::default();
+    kv.insert(CKB_TOKEN_ID, 42);
+    kv.insert(UDT_ID, u128::max_value());
+    let packed: KeyValueMap = kv.pack();
+    assert_eq!(packed.len(), 2);
+    let unpacked: KVMap = packed.as_reader().unpack();
+    assert_eq!(unpacked, kv);
+    // round trip through the molecule bytes
+    let packed = KeyValueMap::from_slice(packed.as_slice()).expect("verify");
+    let unpacked: KVMap = packed.as_reader().unpack();
+    assert_eq!(unpacked, kv);
+}
+
+#[test]
+fn test_narrowing_conversion() {
+    let count: Uint32 = 3usize.try_pack().expect("pack");
+    assert_eq!(Unpack::<u32>::unpack(&count), 3);
+    let result: Result<Uint32, _> = (u32::max_value() as usize + 1).try_pack();
+    assert_eq!(result.map(|_| ()), Err(ConversionError::Overflow));
+
+    let hash: Byte32 = [1u8; 32][..].try_pack().expect("pack");
+    assert_eq!(hash.as_slice(), &[1u8; 32][..]);
+    let result: Result<Byte32, _> = [1u8; 31][..].try_pack();
+    assert_eq!(
+        result.map(|_| ()),
+        Err(ConversionError::InvalidLength(32, 31))
+    );
+    let result: Result<Byte20, _> = [1u8; 32][..].try_pack();
+    assert_eq!(
+        result.map(|_| ()),
+        Err(ConversionError::InvalidLength(20, 32))
+    );
+}
+
+#[test]
+fn test_try_unpack() {
+    let count: Uint32 = 3u32.pack();
+    let count: usize = count.try_unpack().expect("unpack");
+    assert_eq!(count, 3);
+
+    let hash: Bytes = [1u8; 32][..].pack();
+    let unpacked: [u8; 32] = hash.try_unpack().expect("unpack");
+    assert_eq!(unpacked, [1u8; 32]);
+    let result: Result<[u8; 20], _> = hash.try_unpack();
+    assert_eq!(result, Err(ConversionError::InvalidLength(20, 32)));
+    let result: Result<[u8; 65], _> = hash.as_reader().try_unpack();
+    assert_eq!(result, Err(ConversionError::InvalidLength(65, 32)));
+
+    let udt_payment: Payment = (UDT_ID, u128::max_value()).try_pack().expect("pack");
+    let unpacked: ([u8; 32], u128) = udt_payment.try_unpack().expect("unpack");
+    assert_eq!(unpacked, (UDT_ID, u128::max_value()));
+    // CKB in a `UDTPayment` is still limited by the cell capacity
+    let ckb_payment = Payment::new_builder()
+        .set(
+            UDTPayment::new_builder()
+                .type_hash(CKB_TOKEN_ID.pack())
+                .amount((u128::from(u64::max_value()) + 1).pack())
+                .build(),
+        )
+        .build();
+    let result: Result<([u8; 32], u128), _> = ckb_payment.try_unpack();
+    assert_eq!(result, Err(ConversionError::Overflow));
+}
@@ -32,6 +32,10 @@ impl Aggregator {
         }
     }
 
+    pub fn rollup_id(&self) -> [u8; 32] {
+        self.contract_state.rollup_id()
+    }
+
     /// push a new user tx to pool
     pub fn push_tx(&mut self, tx: Tx) {
         self.txs_queue.push(tx);
@@ -52,7 +56,7 @@ impl Aggregator {
                 .contract_state
                 .get_account(ag_index)
                 .expect("get aggregator account");
-            let balance: u64 = self
+            let balance: u128 = self
                 .contract_state
                 .get_account_token(ag_index, &CKB_TOKEN_ID)
                 .expect("get");
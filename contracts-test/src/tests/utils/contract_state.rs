@@ -3,7 +3,7 @@ use ckb_merkle_mountain_range::{leaf_index_to_pos, util::MemMMR, Merge};
 use ckb_tool::ckb_hash::{blake2b_256, new_blake2b};
 use godwoken_types::{
     core::{Index, ScriptHashType, TokenID},
-    packed::{Account, AgBlock, GlobalState, Script, Tx},
+    packed::{Account, AgBlock, GlobalState, Script, Tx, TxKindUnion},
     prelude::*,
 };
 use godwoken_utils::smt::{self, Value, SMT};
@@ -58,6 +58,11 @@ impl ContractState {
             .build()
     }
 
+    /// type hash of the main contract, txs and blocks are signed with it
+    pub fn rollup_id(&self) -> [u8; 32] {
+        blake2b_256(self.type_script().as_slice())
+    }
+
     pub fn lock_script(&self) -> Script {
         Script::new_builder()
             .code_hash(self.lock_data_hash.pack())
@@ -87,7 +92,7 @@ impl ContractState {
         self.account_smt.get(&key).map(|v| v.into()).ok()
     }
 
-    pub fn get_account_token(&self, index: Index, token: &TokenID) -> Option<u64> {
+    pub fn get_account_token(&self, index: Index, token: &TokenID) -> Option<u128> {
         let key = smt::token_id_key(index, token);
         self.account_smt.get(&key).map(|v| v.into()).ok()
     }
@@ -139,24 +144,64 @@ impl ContractState {
 
     pub fn update_account(&mut self, index: Index, token_type: [u8; 32], amount: i128) {
         let token_key = smt::token_id_key(index, &token_type);
-        let balance: u64 = self.account_smt.get(&token_key).expect("get").into();
-        let new_balance = (balance as i128 + amount) as u64;
+        let balance: u128 = self.account_smt.get(&token_key).expect("get").into();
+        let new_balance = (balance as i128 + amount) as u128;
         self.account_smt
             .update(token_key, new_balance.into())
             .expect("update");
     }
 
-    pub fn apply_tx(&mut self, tx: &Tx, fee_to: Index) {
-        let (fee_token_type, tx_fee): ([u8; 32], u64) = tx.fee().unpack();
-        let (token_type, amount): ([u8; 32], u64) = tx.amount().unpack();
+    pub fn update_account_withdraw(&mut self, index: Index, token_type: [u8; 32], amount: u128) {
+        let withdraw_key = smt::withdraw_key(index, &token_type);
+        let balance: u128 = self.account_smt.get(&withdraw_key).expect("get").into();
+        self.account_smt
+            .update(withdraw_key, (balance + amount).into())
+            .expect("update");
+    }
 
-        let sender_index: Index = tx.sender_index().unpack();
-        let to_index: Index = tx.to_index().unpack();
+    pub fn apply_tx(&mut self, tx: &Tx, fee_to: Index) {
+        let raw_tx = tx.raw();
+        let (fee_token_type, tx_fee): ([u8; 32], u128) = raw_tx.fee().unpack();
+        let sender_index: Index = raw_tx.sender_index().unpack();
 
-        self.update_account(sender_index, token_type, -((amount + tx_fee) as i128));
-        self.update_account(to_index, token_type, amount as i128);
+        self.update_account(sender_index, fee_token_type, -(tx_fee as i128));
         self.update_account(fee_to, fee_token_type, tx_fee as i128);
 
+        let transfers: Vec<(Index, ([u8; 32], u128))> = match raw_tx.kind().to_enum() {
+            TxKindUnion::Transfer(transfer) => {
+                vec![(transfer.to_index().unpack(), transfer.amount().unpack())]
+            }
+            TxKindUnion::MultiTransfer(multi_transfer) => multi_transfer
+                .transfers()
+                .into_iter()
+                .map(|item| (item.to_index().unpack(), item.amount().unpack()))
+                .collect(),
+            TxKindUnion::WithdrawRequest(withdraw_request) => {
+                let (token_type, amount): ([u8; 32], u128) = withdraw_request.amount().unpack();
+                self.update_account(sender_index, token_type, -(amount as i128));
+                self.update_account_withdraw(sender_index, token_type, amount);
+                Vec::new()
+            }
+            TxKindUnion::KeyRotation(key_rotation) => {
+                let sender_key = smt::account_index_key(sender_index);
+                let sender: Account = self.account_smt.get(&sender_key).expect("get").into();
+                let sender = sender
+                    .as_builder()
+                    .pubkey_hash(key_rotation.new_pubkey_hash())
+                    .sig_scheme(key_rotation.new_sig_scheme())
+                    .bls_pubkey(key_rotation.new_bls_pubkey())
+                    .build();
+                self.account_smt
+                    .update(sender_key, sender.into())
+                    .expect("update");
+                Vec::new()
+            }
+        };
+        for (to_index, (token_type, amount)) in transfers {
+            self.update_account(sender_index, token_type, -(amount as i128));
+            self.update_account(to_index, token_type, amount as i128);
+        }
+
         // increase account's nonce
         let sender_key = smt::account_index_key(sender_index);
         let sender: Account = self.account_smt.get(&sender_key).expect("get").into();
@@ -4,12 +4,13 @@ use crate::tests::utils::{
 };
 use crate::tests::{DUMMY_LOCK_BIN, DUMMY_LOCK_HASH, MAIN_CONTRACT_BIN, MAIN_CONTRACT_HASH};
 use ckb_tool::testtool::{context::Context, tx_builder::TxBuilder};
+use godwoken_executor::types::AccountKey;
 use godwoken_types::prelude::*;
 use godwoken_types::{
     core::{Index, ScriptHashType},
     packed::*,
+    signing::{block_signing_message, raw_tx_hash, tx_signing_message},
 };
-use godwoken_utils::hash::new_blake2b;
 use rand::{thread_rng, Rng};
 
 pub fn default_tx_builder() -> TxBuilder {
@@ -41,12 +42,15 @@ pub fn default_context() -> Context {
     context
 }
 
-pub fn sign_block(privkey: &secp256k1::SecretKey, block: &AgBlock) -> [u8; 65] {
-    let mut hasher = new_blake2b();
-    hasher.update(block.as_slice());
-    let mut block_hash = [0u8; 32];
-    hasher.finalize(&mut block_hash);
-    let msg = secp256k1::Message::parse(&block_hash);
+pub fn gen_key_pair() -> (secp256k1::SecretKey, [u8; 20]) {
+    let mut rng = thread_rng();
+    let privkey = secp256k1::SecretKey::random(&mut rng);
+    let pubkey = secp256k1::PublicKey::from_secret_key(&privkey);
+    (privkey, godwoken_utils::secp256k1::pubkey_hash(&pubkey))
+}
+
+pub fn sign_message(privkey: &secp256k1::SecretKey, message: &[u8; 32]) -> [u8; 65] {
+    let msg = secp256k1::Message::parse(message);
     let (signature, rec_id) = secp256k1::sign(&msg, &privkey);
     let mut sig = [0u8; 65];
     sig[..64].copy_from_slice(&signature.serialize());
@@ -54,6 +58,23 @@ pub fn sign_block(privkey: &secp256k1::SecretKey, block: &AgBlock) -> [u8; 65] {
     sig
 }
 
+pub fn sign_block(
+    privkey: &secp256k1::SecretKey,
+    rollup_id: &[u8; 32],
+    block: &AgBlock,
+) -> [u8; 65] {
+    let message = block_signing_message(rollup_id, &block.as_reader());
+    sign_message(privkey, &message)
+}
+
+/// fill the witness with the signature of raw tx
+pub fn sign_tx(privkey: &secp256k1::SecretKey, rollup_id: &[u8; 32], tx: Tx) -> Tx {
+    let raw_tx_hash = raw_tx_hash(&tx.raw().as_reader());
+    let message = tx_signing_message(rollup_id, &raw_tx_hash);
+    let sig = sign_message(privkey, &message);
+    tx.as_builder().witness(sig[..].pack()).build()
+}
+
 pub fn gen_accounts(start_i: Index, count: usize) -> impl Iterator<Item = Account> {
     (start_i..start_i + count as Index).map(|i| {
         let mut pubkey = [0u8; 20];
@@ -82,20 +103,7 @@ pub fn prepare_accounts(contract_state: &mut ContractState, balances: Vec<u64>)
 
 pub fn prepare_ag_account(contract_state: &mut ContractState) -> (Index, secp256k1::SecretKey) {
     let ag_index = contract_state.account_count();
-    let (privkey, pubkey) = {
-        let mut rng = thread_rng();
-        let privkey = secp256k1::SecretKey::random(&mut rng);
-        let pubkey = secp256k1::PublicKey::from_secret_key(&privkey);
-        (privkey, pubkey)
-    };
-    let pubkey_hash = {
-        let pubkey_bytes = pubkey.serialize_compressed();
-        let mut hasher = new_blake2b();
-        hasher.update(&pubkey_bytes);
-        let mut hash = [0u8; 20];
-        hasher.finalize(&mut hash);
-        hash
-    };
+    let (privkey, pubkey_hash) = gen_key_pair();
     let account_ag = Account::new_builder()
         .index(ag_index.pack())
         .pubkey_hash(pubkey_hash.pack())
@@ -105,6 +113,13 @@ pub fn prepare_ag_account(contract_state: &mut ContractState) -> (Index, secp256
     (ag_index, privkey)
 }
 
+/// pack `amount` of the token, a CKB payment must fit in `Uint64`
+pub fn payment(token_id: [u8; 32], amount: u32) -> Payment {
+    (token_id, u128::from(amount))
+        .try_pack()
+        .expect("pack payment")
+}
+
 pub fn gen_transfer_tx(
     sender: Index,
     to: Index,
@@ -113,11 +128,83 @@ pub fn gen_transfer_tx(
     amount: u32,
     fee: u32,
 ) -> Tx {
-    Tx::new_builder()
-        .sender_index(sender.pack())
+    let transfer = Transfer::new_builder()
         .to_index(to.pack())
-        .fee((token_id, fee as u64).pack())
-        .amount((token_id, amount as u64).pack())
+        .amount(payment(token_id, amount))
+        .build();
+    let raw_tx = RawTx::new_builder()
+        .sender_index(sender.pack())
+        .fee(payment(token_id, fee))
+        .kind(TxKind::new_builder().set(transfer).build())
+        .nonce(nonce.pack())
+        .build();
+    Tx::new_builder().raw(raw_tx).build()
+}
+
+pub fn gen_multi_transfer_tx(
+    sender: Index,
+    transfers: Vec<(Index, u32)>,
+    nonce: u32,
+    token_id: [u8; 32],
+    fee: u32,
+) -> Tx {
+    let transfers = transfers
+        .into_iter()
+        .map(|(to, amount)| {
+            TransferItem::new_builder()
+                .to_index(to.pack())
+                .amount(payment(token_id, amount))
+                .build()
+        })
+        .collect::<Vec<_>>();
+    let multi_transfer = MultiTransfer::new_builder()
+        .transfers(TransferItemVec::new_builder().set(transfers).build())
+        .build();
+    let raw_tx = RawTx::new_builder()
+        .sender_index(sender.pack())
+        .fee(payment(token_id, fee))
+        .kind(TxKind::new_builder().set(multi_transfer).build())
         .nonce(nonce.pack())
-        .build()
+        .build();
+    Tx::new_builder().raw(raw_tx).build()
+}
+
+pub fn gen_withdraw_request_tx(
+    sender: Index,
+    nonce: u32,
+    token_id: [u8; 32],
+    amount: u32,
+    fee: u32,
+) -> Tx {
+    let withdraw_request = WithdrawRequest::new_builder()
+        .amount(payment(token_id, amount))
+        .build();
+    let raw_tx = RawTx::new_builder()
+        .sender_index(sender.pack())
+        .fee(payment(token_id, fee))
+        .kind(TxKind::new_builder().set(withdraw_request).build())
+        .nonce(nonce.pack())
+        .build();
+    Tx::new_builder().raw(raw_tx).build()
+}
+
+pub fn gen_key_rotation_tx(
+    sender: Index,
+    nonce: u32,
+    token_id: [u8; 32],
+    new_key: AccountKey,
+    fee: u32,
+) -> Tx {
+    let key_rotation = KeyRotation::new_builder()
+        .new_pubkey_hash(new_key.pubkey_hash.pack())
+        .new_sig_scheme(new_key.sig_scheme.into())
+        .new_bls_pubkey(new_key.bls_pubkey.pack())
+        .build();
+    let raw_tx = RawTx::new_builder()
+        .sender_index(sender.pack())
+        .fee(payment(token_id, fee))
+        .kind(TxKind::new_builder().set(key_rotation).build())
+        .nonce(nonce.pack())
+        .build();
+    Tx::new_builder().raw(raw_tx).build()
 }
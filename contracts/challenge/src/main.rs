@@ -11,24 +11,39 @@
 //! 4. after `CHALLENGE_PREPARE_TIMEOUT`, the challenge cell can revert the block that described in the ChallengeContext.
 
 use alloc::vec::Vec;
-use ckb_std::{ckb_constants::*, entry, default_alloc, since, syscalls};
-use godwoken_executor::{executor::Executor, state::State};
+use ckb_std::{ckb_constants::*, default_alloc, entry, since, syscalls};
+use godwoken_executor::{
+    error::BlockError,
+    executor::Executor,
+    state::{AccountState, State},
+};
 use godwoken_types::{
-    cache::{KVMap, TxWithHash},
-    core::Index,
+    cache::{KVMap, StorageMap, TxWithHash},
+    core::{Index, WITHDRAW_CHALLENGE_WAIT_EPOCHS},
     packed::*,
     prelude::*,
 };
 use godwoken_utils::{
-    hash::new_blake2b,
-    mmr::compute_tx_root,
-    smt::{self, compute_root_with_proof, Value, ValueTrait},
+    mmr::{compute_tx_root, merkle_root},
+    smt::{self, compute_root_with_proof},
 };
 
 const BUF_LEN: usize = 4096;
 const HASH_LEN: usize = 32;
-/// must wait WITHDRAW_WAIT_EPOCHS epochs before withdraw challnege
-const WITHDRAW_WAIT_EPOCHS: u64 = 6;
+
+/// loads the whole data by chunks of `BUF_LEN`, a syscall loads at most `len` bytes from `offset`
+fn load_data<F: Fn(usize, usize) -> Result<Vec<u8>, SysError>>(
+    syscall: F,
+) -> Result<Vec<u8>, SysError> {
+    let mut data = Vec::new();
+    loop {
+        let chunk = syscall(BUF_LEN, data.len())?;
+        data.extend_from_slice(&chunk);
+        if chunk.len() < BUF_LEN {
+            return Ok(data);
+        }
+    }
+}
 
 #[repr(i8)]
 enum Error {
@@ -37,6 +52,9 @@ enum Error {
     InvalidMerkleProof = -3,
     InvalidSince = -4,
     InvalidProveChallege = -5,
+    InvalidLogsRoot = -6,
+    InvalidAggregateSignature = -7,
+    InvalidTx = -8,
 }
 
 #[no_mangle]
@@ -52,16 +70,21 @@ default_alloc!();
 
 fn contract_entry() -> Result<(), Error> {
     let args = load_challenge_args()?;
-    if let Ok(_) =
-        syscalls::load_cell_by_field(HASH_LEN, 0, 0, Source::GroupOutput, CellField::TypeHash)
-    {
-        // create a challenge cell
-        verify_challenge_context()?;
-        return Ok(());
+    // the revert block tx keeps the challenge cell in the outputs, so an input
+    // of the group means the challenge cell is unlocked
+    match syscalls::load_cell_by_field(HASH_LEN, 0, 0, Source::GroupInput, CellField::TypeHash) {
+        Ok(_) => {}
+        Err(SysError::IndexOutOfBound) => {
+            // create a challenge cell
+            verify_challenge_context()?;
+            return Ok(());
+        }
+        Err(err) => panic!("syscall err {:?}", err),
     }
 
-    // destroy the challenge cell
-    let buf = syscalls::load_witness(BUF_LEN, 0, 0, Source::GroupInput).expect("load witness");
+    // unlock the challenge cell
+    let buf = load_data(|len, offset| syscalls::load_witness(len, offset, 0, Source::GroupInput))
+        .expect("load witness");
     let respond = match ChallengeUnlockReader::verify(&buf, false) {
         Ok(()) => ChallengeUnlock::new_unchecked(buf.into()),
         Err(_) => return Err(Error::InvalidEncoding),
@@ -74,7 +97,7 @@ fn contract_entry() -> Result<(), Error> {
             check_revert_block(args.as_reader())
         }
         ChallengeUnlockUnionReader::InvalidChallenge(invalid_challenge) => {
-            check_invalid_challenge(invalid_challenge)
+            check_invalid_challenge(args.as_reader(), invalid_challenge)
         }
     }
 }
@@ -96,7 +119,7 @@ fn load_challenge_args() -> Result<ChallengeArgs, Error> {
 
 /// Unlock challenge cell by use it in main contract revert block transaction
 /// anyone can unlock by this path, but only the challenger in the ChallengeContext can get reward.
-/// 
+///
 /// For simplify, this cell does not destroyed by the revert block transaction,
 /// owner needs to send another withdraw tx.
 fn check_revert_block<'a>(args: ChallengeArgsReader<'a>) -> Result<(), Error> {
@@ -155,18 +178,19 @@ fn check_withdraw_challenge<'a>(args: ChallengeArgsReader<'a>) -> Result<(), Err
         .extract_lock_value()
         .and_then(|value| value.epoch())
         .ok_or(Error::InvalidSince)?;
-    if withdraw_epoch.number() < WITHDRAW_WAIT_EPOCHS {
+    if withdraw_epoch.number() < WITHDRAW_CHALLENGE_WAIT_EPOCHS {
         return Err(Error::InvalidSince);
     }
     // verify inputs include withdraw lock hash
     let withdraw_lock_hash = args.withdraw_lock_hash();
     find_hash_from_inputs(withdraw_lock_hash.as_slice(), CellField::LockHash)?;
-    return Err(Error::NoUnlockCell);
+    Ok(())
 }
 
+/// find a cell with the hash in all inputs, the challenge cell itself doesn't have it
 fn find_hash_from_inputs(lock_hash: &[u8], field: CellField) -> Result<usize, Error> {
     for i in 0.. {
-        let buf = match syscalls::load_cell_by_field(HASH_LEN, 0, i, Source::GroupInput, field) {
+        let buf = match syscalls::load_cell_by_field(HASH_LEN, 0, i, Source::Input, field) {
             Ok(buf) => buf,
             Err(SysError::ItemMissing) => continue,
             Err(SysError::IndexOutOfBound) => break,
@@ -181,9 +205,13 @@ fn find_hash_from_inputs(lock_hash: &[u8], field: CellField) -> Result<usize, Er
 
 /// Unlock this challenge cell by provide invalid proof
 /// anyone can unlock challenge cell by this path
-fn check_invalid_challenge<'a>(invalid_challenge: InvalidChallengeReader<'a>) -> Result<(), Error> {
+fn check_invalid_challenge<'a>(
+    args: ChallengeArgsReader<'a>,
+    invalid_challenge: InvalidChallengeReader<'a>,
+) -> Result<(), Error> {
     // load challenge context
-    let buf = syscalls::load_cell_data(BUF_LEN, 0, 0, Source::GroupInput).expect("load data");
+    let buf = load_data(|len, offset| syscalls::load_cell_data(len, offset, 0, Source::GroupInput))
+        .expect("load data");
     let context = match ChallengeContextReader::verify(&buf, false) {
         Ok(()) => ChallengeContext::new_unchecked(buf.into()),
         Err(_) => return Err(Error::InvalidEncoding),
@@ -191,14 +219,33 @@ fn check_invalid_challenge<'a>(invalid_challenge: InvalidChallengeReader<'a>) ->
     let context_reader = context.as_reader();
     let block = context_reader.block();
     // initialize state from touched accounts
+    let touched_accounts = invalid_challenge.touched_accounts();
+    let token_kvs = invalid_challenge.touched_accounts_token_kv();
+    let withdraw_kvs = invalid_challenge.touched_accounts_withdraw_kv();
+    let storages = invalid_challenge.touched_accounts_storage();
+    let accounts_count = touched_accounts.len();
+    if token_kvs.len() != accounts_count
+        || withdraw_kvs.len() != accounts_count
+        || storages.len() != accounts_count
+    {
+        return Err(Error::InvalidEncoding);
+    }
     let mut state = State::new(
-        invalid_challenge
-            .touched_accounts()
+        touched_accounts
             .iter()
-            .zip(invalid_challenge.touched_accounts_token_kv().iter())
-            .map(|(account, kv)| {
+            .zip(token_kvs.iter())
+            .zip(withdraw_kvs.iter())
+            .zip(storages.iter())
+            .map(|(((account, kv), withdraw_kv), storage)| {
                 let kv: KVMap = kv.unpack();
-                (account, kv)
+                let withdraw_kv: KVMap = withdraw_kv.unpack();
+                let storage: StorageMap = storage.unpack();
+                AccountState {
+                    account,
+                    kv,
+                    withdraw_kv,
+                    storage,
+                }
             })
             .collect(),
     );
@@ -211,7 +258,7 @@ fn check_invalid_challenge<'a>(invalid_challenge: InvalidChallengeReader<'a>) ->
             .map(|(node, height)| (node.into(), height))
             .collect();
     // verify prev account root
-    let leaves = state_to_merkle_leaves(&state);
+    let leaves = state.merkle_leaves();
     let calculated_root: [u8; 32] =
         compute_root_with_proof(leaves, leaves_path.clone(), merkle_branches.clone())
             .map_err(|_| Error::InvalidMerkleProof)?
@@ -220,18 +267,28 @@ fn check_invalid_challenge<'a>(invalid_challenge: InvalidChallengeReader<'a>) ->
         return Err(Error::InvalidMerkleProof);
     }
     // verify new state
-    let executor = Executor::new();
+    // txs are signed with the main contract's type hash
+    let executor = Executor::new(args.main_type_hash().unpack());
     let ag_index: Index = block.ag_index().unpack();
     let txs = context_reader.txs();
     let tx_with_hashes = build_tx_hashes(&txs);
-    for tx in tx_with_hashes {
-        if executor.run(&mut state, tx, ag_index).is_err() {
-            // errors occured, represents the block is invalid
-            return Ok(());
-        }
+    // txs with empty witness are signed by the block's aggregate signature
+    let aggregate_sig: [u8; 96] = block.aggregate_sig().unpack();
+    // a failed tx represents the block is invalid, including txs calling contract
+    // accounts, contracts can't be executed on-chain
+    let run_results = executor
+        .run_block(&mut state, tx_with_hashes, &aggregate_sig, ag_index)
+        .map_err(|err| match err {
+            BlockError::InvalidAggregateSignature(_) => Error::InvalidAggregateSignature,
+            BlockError::InvalidTx(..) => Error::InvalidTx,
+        })?;
+    let logs_hashes: Vec<_> = run_results.iter().map(|r| r.logs_hash()).collect();
+    // check logs root
+    if &merkle_root(logs_hashes) != block.logs_root().raw_data() {
+        return Err(Error::InvalidLogsRoot);
     }
     // check new account root
-    let leaves: Vec<_> = state_to_merkle_leaves(&state);
+    let leaves: Vec<_> = state.merkle_leaves();
     let calculated_root: [u8; 32] = compute_root_with_proof(leaves, leaves_path, merkle_branches)
         .map_err(|_| Error::InvalidMerkleProof)?
         .into();
@@ -244,13 +301,16 @@ fn check_invalid_challenge<'a>(invalid_challenge: InvalidChallengeReader<'a>) ->
 
 fn verify_challenge_context() -> Result<(), Error> {
     // load challenge context
-    let buf = syscalls::load_cell_data(BUF_LEN, 0, 0, Source::GroupOutput).expect("load data");
+    let buf =
+        load_data(|len, offset| syscalls::load_cell_data(len, offset, 0, Source::GroupOutput))
+            .expect("load data");
     let context = match ChallengeContextReader::verify(&buf, false) {
         Ok(()) => ChallengeContext::new_unchecked(buf.into()),
         Err(_) => return Err(Error::InvalidEncoding),
     };
     // load challenge proof
-    let buf = syscalls::load_witness(BUF_LEN, 0, 0, Source::GroupOutput).expect("load witness");
+    let buf = load_data(|len, offset| syscalls::load_witness(len, offset, 0, Source::GroupOutput))
+        .expect("load witness");
     let wit_args = match WitnessArgsReader::verify(&buf, false) {
         Ok(()) => WitnessArgs::new_unchecked(buf.into()),
         Err(_) => return Err(Error::InvalidEncoding),
@@ -294,31 +354,6 @@ fn verify_challenge_context() -> Result<(), Error> {
     Ok(())
 }
 
-fn state_to_merkle_leaves(state: &State) -> Vec<(smt::H256, smt::H256)> {
-    // verify account and kv
-    let mut leaves: Vec<_> = Vec::with_capacity(state.len() * 2);
-    for (account, kv) in state.iter() {
-        let index: Index = account.index().unpack();
-        for (k, v) in kv {
-            leaves.push((smt::token_id_key(index, k), Value::from(*v).to_h256()));
-        }
-        let value = Value::from(account.clone());
-        leaves.push((smt::account_index_key(index.into()), value.to_h256()));
-    }
-    leaves
-}
-
 fn build_tx_hashes<'a>(txs: &'a TxVecReader<'a>) -> Vec<TxWithHash<'a>> {
-    txs.iter()
-        .map(|tx| {
-            let mut hasher = new_blake2b();
-            hasher.update(tx.as_slice());
-            let mut hash = [0u8; 32];
-            hasher.finalize(&mut hash);
-            TxWithHash {
-                raw: tx,
-                tx_hash: hash.clone(),
-            }
-        })
-        .collect()
+    txs.iter().map(TxWithHash::new).collect()
 }
@@ -0,0 +1,91 @@
+#![no_std]
+#![no_main]
+#![feature(asm)]
+#![feature(lang_items)]
+#![feature(alloc_error_handler)]
+#![feature(panic_info_message)]
+
+//! Contract account of the executor's VM tests
+//! 1. the args `[code]` exits with `code`
+//! 2. otherwise the counter in storage slot `KEY` is incremented and logged
+
+use ckb_std::{default_alloc, entry};
+
+const SUCCESS: u64 = 0;
+const ITEM_MISSING: u64 = 2;
+const SYS_LOAD_ARGS: u64 = 3002;
+const SYS_LOAD_STORAGE: u64 = 3004;
+const SYS_STORE_STORAGE: u64 = 3005;
+const SYS_LOG: u64 = 3007;
+const KEY: [u8; 32] = [0u8; 32];
+
+unsafe fn syscall(mut a0: u64, a1: u64, a2: u64, a3: u64, a7: u64) -> u64 {
+    asm!(
+        "ecall",
+        inout("a0") a0,
+        in("a1") a1,
+        in("a2") a2,
+        in("a3") a3,
+        in("a7") a7,
+    );
+    a0
+}
+
+#[no_mangle]
+fn main() -> i8 {
+    let mut args = [0u8; 8];
+    let mut len = args.len() as u64;
+    let ret = unsafe {
+        syscall(
+            args.as_mut_ptr() as u64,
+            &mut len as *mut u64 as u64,
+            0,
+            0,
+            SYS_LOAD_ARGS,
+        )
+    };
+    if ret != SUCCESS {
+        return -1;
+    }
+    if len == 1 {
+        return args[0] as i8;
+    }
+
+    let mut value = [0u8; 8];
+    let mut len = value.len() as u64;
+    let ret = unsafe {
+        syscall(
+            value.as_mut_ptr() as u64,
+            &mut len as *mut u64 as u64,
+            0,
+            KEY.as_ptr() as u64,
+            SYS_LOAD_STORAGE,
+        )
+    };
+    let counter = match ret {
+        SUCCESS if len == 8 => u64::from_le_bytes(value),
+        ITEM_MISSING => 0,
+        _ => return -2,
+    };
+    let value = (counter + 1).to_le_bytes();
+    let ret = unsafe {
+        syscall(
+            KEY.as_ptr() as u64,
+            value.as_ptr() as u64,
+            value.len() as u64,
+            0,
+            SYS_STORE_STORAGE,
+        )
+    };
+    if ret != SUCCESS {
+        return -3;
+    }
+    let ret = unsafe { syscall(value.as_ptr() as u64, value.len() as u64, 0, 0, SYS_LOG) };
+    if ret != SUCCESS {
+        return -4;
+    }
+    0
+}
+
+entry!(main);
+default_alloc!();
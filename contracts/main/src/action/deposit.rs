@@ -48,7 +48,9 @@ impl<'a> DepositVerifier<'a> {
 
         // update balance
         let balance = kv.get(&CKB_TOKEN_ID).map(|balance| *balance).unwrap_or(0);
-        let new_balance = balance + deposit_capacity;
+        let new_balance = balance
+            .checked_add(u128::from(deposit_capacity))
+            .ok_or(Error::BalanceOverflow)?;
         kv.insert(CKB_TOKEN_ID, new_balance);
 
         // verify new state
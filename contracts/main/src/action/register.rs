@@ -4,7 +4,9 @@ use crate::{
     error::Error,
 };
 use alloc::vec::Vec;
+use core::convert::TryFrom;
 use godwoken_types::{cache::KVMap, packed::*, prelude::*};
+use godwoken_utils::signature::SignatureScheme;
 
 pub struct RegisterVerifier<'a> {
     action: RegisterReader<'a>,
@@ -39,6 +41,10 @@ impl<'a> RegisterVerifier<'a> {
         if account.script().to_opt().is_some() {
             Err(Error::InvalidAccountScript)?;
         }
+        let sig_scheme: u8 = account.sig_scheme().into();
+        if SignatureScheme::try_from(sig_scheme).is_err() {
+            Err(Error::InvalidSignatureScheme)?;
+        }
         if deposit_capacity < NEW_ACCOUNT_REQUIRED_BALANCE {
             Err(Error::InvalidDepositAmount)?;
         }
@@ -2,7 +2,7 @@ use crate::constants::{
     CHALLENGE_CELL_WAIT_BLOCKS, CHALLENGE_CONTRACT_CODE_HASH, CHALLENGE_REWARD_RATE, CKB_TOKEN_ID,
     HASH_SIZE,
 };
-use crate::error::Error;
+use crate::{common, error::Error};
 use alloc::vec;
 use alloc::vec::Vec;
 use ckb_std::{ckb_constants::*, since, syscalls};
@@ -256,13 +256,13 @@ impl<'a> RevertBlockVerifier<'a> {
 fn load_challenge_context(
     challenge_cell_data_hash: &[u8],
 ) -> Result<Option<(usize, ChallengeContext)>, Error> {
-    const BUF_LEN: usize = 4096;
-
     for i in 0.. {
         match syscalls::load_cell_by_field(HASH_SIZE, 0, i, Source::Input, CellField::DataHash) {
             Ok(data_hash) if &data_hash[..] == challenge_cell_data_hash => {
-                let buf =
-                    syscalls::load_cell_data(BUF_LEN, 0, i, Source::Input).expect("load cell data");
+                let buf = common::load_data(|len, offset| {
+                    syscalls::load_cell_data(len, offset, i, Source::Input)
+                })
+                .expect("load cell data");
                 let challenge_context = match ChallengeContextReader::verify(&buf, false) {
                     Ok(()) => ChallengeContext::new_unchecked(buf.into()),
                     Err(_) => return Err(Error::InvalidChallengeContext),
@@ -303,10 +303,10 @@ pub fn calculate_reverted_account_root<'a>(
 ) -> Result<[u8; 32], Error> {
     // calculate reward
     let reward_amount = {
-        let balance: u64 = ag_kv.get(&CKB_TOKEN_ID).map(|b| *b).unwrap_or(0);
+        let balance: u128 = ag_kv.get(&CKB_TOKEN_ID).map(|b| *b).unwrap_or(0);
         balance.saturating_mul(CHALLENGE_REWARD_RATE.0) / CHALLENGE_REWARD_RATE.1
     };
-    let chal_balance: u64 = chal_kv.get(&CKB_TOKEN_ID).map(|b| *b).unwrap_or(0);
+    let chal_balance: u128 = chal_kv.get(&CKB_TOKEN_ID).map(|b| *b).unwrap_or(0);
 
     ag_kv.insert(CKB_TOKEN_ID, 0);
     chal_kv.insert(
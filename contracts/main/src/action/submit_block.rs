@@ -1,12 +1,22 @@
 use crate::error::Error;
-use crate::{common, constants::CKB_TOKEN_ID};
+use crate::{
+    common,
+    constants::{CKB_TOKEN_ID, HASH_SIZE, MAX_BLOCK_TXS_SIZE},
+};
 use alloc::vec;
 use alloc::vec::Vec;
-use godwoken_types::{cache::KVMap, core::Index, packed::*, prelude::*};
+use ckb_std::syscalls;
+use godwoken_types::{
+    cache::KVMap,
+    core::Index,
+    packed::*,
+    prelude::*,
+    signing::{block_signing_message, tx_hash},
+};
 use godwoken_utils::{
     hash::new_blake2b,
     mmr::{compute_block_root, compute_new_block_root, merkle_root},
-    secp256k1::verify_signature,
+    signature::verify_signature,
     smt::{self},
 };
 
@@ -66,20 +76,14 @@ impl<'a> SubmitBlockVerifier<'a> {
         // verify aggregator's signature
         let ag_pubkey_hash = ag_account.pubkey_hash().raw_data();
         let block = self.action.block();
-        let sig_message = {
-            let sig_block = block
-                .to_entity()
-                .as_builder()
-                .ag_sig(Byte65::default())
-                .build();
-            let mut hasher = new_blake2b();
-            hasher.update(sig_block.as_slice());
-            let mut hash = [0u8; 32];
-            hasher.finalize(&mut hash);
-            hash
-        };
+        // the block is signed with the main contract's type hash
+        let script_hash = syscalls::load_script_hash(HASH_SIZE, 0).expect("load script");
+        let mut rollup_id = [0u8; 32];
+        rollup_id.copy_from_slice(&script_hash);
+        let sig_message = block_signing_message(&rollup_id, &block);
         let ag_sig = block.ag_sig().unpack();
-        verify_signature(&ag_sig[..], &sig_message[..], ag_pubkey_hash)
+        let sig_scheme: u8 = ag_account.sig_scheme().into();
+        verify_signature(sig_scheme, &ag_sig[..], &sig_message, ag_pubkey_hash)
             .map_err(|_| Error::InvalidSignature)?;
         Ok(())
     }
@@ -104,18 +108,14 @@ impl<'a> SubmitBlockVerifier<'a> {
         if block.account_root().as_slice() != self.new_state.account_root().as_slice() {
             return Err(Error::InvalidAccountRoot);
         }
-        // verify tx root
+        // a larger block can't be loaded by the challenge contract
         let txs = self.action.txs();
-        let tx_hashes: Vec<[u8; 32]> = txs
-            .iter()
-            .map(|tx| {
-                let mut hasher = new_blake2b();
-                hasher.update(tx.as_slice());
-                let mut hash = [0u8; 32];
-                hasher.finalize(&mut hash);
-                hash
-            })
-            .collect();
+        let txs_size: usize = txs.iter().map(|tx| tx.as_slice().len()).sum();
+        if txs_size > MAX_BLOCK_TXS_SIZE {
+            return Err(Error::BlockTooLarge);
+        }
+        // verify tx root, it commits to full txs including witnesses
+        let tx_hashes: Vec<[u8; 32]> = txs.iter().map(|tx| tx_hash(&tx)).collect();
         let calculated_tx_root = merkle_root(tx_hashes);
         let tx_root = block.tx_root().unpack();
         if tx_root != calculated_tx_root {
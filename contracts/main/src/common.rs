@@ -9,8 +9,22 @@ use godwoken_utils::smt::{self, Value, ValueTrait};
 
 const BUF_LEN: usize = 4096;
 
-pub fn check_aggregator<'a>(account: AccountReader<'a>, balance: u64) -> Result<(), Error> {
-    if balance < AGGREGATOR_REQUIRED_BALANCE {
+/// loads the whole data by chunks of `BUF_LEN`, a syscall loads at most `len` bytes from `offset`
+pub fn load_data<F: Fn(usize, usize) -> Result<Vec<u8>, SysError>>(
+    syscall: F,
+) -> Result<Vec<u8>, SysError> {
+    let mut data = Vec::new();
+    loop {
+        let chunk = syscall(BUF_LEN, data.len())?;
+        data.extend_from_slice(&chunk);
+        if chunk.len() < BUF_LEN {
+            return Ok(data);
+        }
+    }
+}
+
+pub fn check_aggregator<'a>(account: AccountReader<'a>, balance: u128) -> Result<(), Error> {
+    if balance < u128::from(AGGREGATOR_REQUIRED_BALANCE) {
         return Err(Error::InvalidAggregator);
     }
     if account.script().to_opt().is_some() {
@@ -58,7 +72,8 @@ pub fn check_output(type_hash: &[u8], lock_hash: &[u8]) -> Result<(), Error> {
 }
 
 pub fn load_action() -> Result<Action, Error> {
-    let buf = syscalls::load_witness(BUF_LEN, 0, 0, Source::GroupOutput).expect("load witness");
+    let buf = load_data(|len, offset| syscalls::load_witness(len, offset, 0, Source::GroupOutput))
+        .expect("load witness");
     let witness_args = match WitnessArgsReader::verify(&buf, false) {
         Ok(()) => WitnessArgs::new_unchecked(buf.into()),
         Err(_) => return Err(Error::InvalidWitness),
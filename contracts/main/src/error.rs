@@ -26,4 +26,7 @@ pub enum Error {
     InvalidNewAccountRoot = -36,
     InvalidScript = -38,
     InvalidChallengeCell = -39,
+    InvalidSignatureScheme = -40,
+    BalanceOverflow = -42,
+    BlockTooLarge = -43,
 }
@@ -1,12 +1,55 @@
 #[derive(Debug)]
 pub enum Error {
-    ContractCall(u8),
+    /// non-zero exit code of a contract
+    ContractExit(i8),
     MissingAccount(u64),
+    /// account index, key of the storage slot
+    MissingStorage(u64, [u8; 32]),
     /// balance, required_amount
-    BalanceNotEnough(u64, u64),
+    BalanceNotEnough(u128, u128),
     /// expected nonce, tx's nonce
     InvalidNonce(u32, u32),
+    NonceOverflow,
     BalanceOverflow,
     InvalidSignature,
     InvalidMerkleProof,
+    /// the executor has no contract engine
+    ContractNotSupported,
+    /// account index
+    NotContractAccount(u64),
+    /// index of a contract account sending a tx
+    ContractSender(u64),
+    /// code hash
+    MissingContractCode([u8; 32]),
+    CallDepthExceeded,
+    /// tx's version
+    InvalidTxVersion(u8),
+    /// signature scheme of a key rotation
+    UnknownSigScheme(u8),
+    #[cfg(feature = "std")]
+    VM(ckb_vm::Error),
+}
+
+impl Error {
+    /// Errors of the environment rather than the contract: the state lacks
+    /// an account or a storage slot, or the contract code is unavailable.
+    /// They fail the whole tx instead of returning an error code to the caller.
+    pub fn is_internal(&self) -> bool {
+        matches!(
+            self,
+            Error::MissingAccount(_)
+                | Error::MissingStorage(..)
+                | Error::ContractNotSupported
+                | Error::MissingContractCode(_)
+        )
+    }
+}
+
+/// Error of `Executor::run_block`
+#[derive(Debug)]
+pub enum BlockError {
+    /// the aggregate signature doesn't match txs with empty witness
+    InvalidAggregateSignature(Error),
+    /// index of the failed tx
+    InvalidTx(usize, Error),
 }
@@ -1,64 +1,401 @@
-use crate::{error::Error, state::State};
-use godwoken_types::{cache::KVMap, packed::*, prelude::*};
+use crate::{
+    error::Error,
+    state::State,
+    traits::Contract,
+    types::{AccountKey, StateDiff},
+};
+use alloc::vec::Vec;
+use godwoken_types::{bytes::Bytes, cache::KVMap, core::TokenID, packed::*, prelude::*};
 
-const CKB_TOKEN_ID: [u8; 32] = [0u8; 32];
+/// max depth of nested contract calls
+pub const MAX_CALL_DEPTH: usize = 64;
+
+/// A contract call
+#[derive(Debug, Clone, Copy)]
+pub struct CallFrame {
+    pub caller_index: u64,
+    pub contract_index: u64,
+}
+
+/// Previous values of modified state, used to revert a failed call
+enum JournalEntry {
+    Balance {
+        index: u64,
+        token_id: TokenID,
+        value: Option<u128>,
+    },
+    Withdraw {
+        index: u64,
+        token_id: TokenID,
+        value: Option<u128>,
+    },
+    Storage {
+        index: u64,
+        key: [u8; 32],
+        value: Option<Vec<u8>>,
+    },
+    Key {
+        index: u64,
+        value: AccountKey,
+    },
+    Log,
+}
 
 pub struct ExecutionContext<'a> {
     state: &'a mut State,
     sender_index: u64,
+    contract: Option<&'a dyn Contract>,
+    call_stack: Vec<CallFrame>,
+    journal: Vec<JournalEntry>,
+    logs: Vec<Log>,
+    /// raised by a syscall, it fails the tx
+    internal_error: Option<Error>,
 }
 
 impl<'a> ExecutionContext<'a> {
-    pub fn new(state: &'a mut State, sender_index: u64) -> Self {
+    /// `contract` runs contract accounts, calling a contract account fails if it is `None`
+    pub fn new(
+        state: &'a mut State,
+        sender_index: u64,
+        contract: Option<&'a dyn Contract>,
+    ) -> Self {
         ExecutionContext {
             state,
             sender_index,
+            contract,
+            call_stack: Vec::new(),
+            journal: Vec::new(),
+            logs: Vec::new(),
+            internal_error: None,
         }
     }
 
+    /// tx sender
+    pub fn sender_index(&self) -> u64 {
+        self.sender_index
+    }
+
     pub fn sender(&self) -> Result<(&Account, &KVMap), Error> {
+        self.get_account(self.sender_index)
+    }
+
+    /// the caller of current contract, or the tx sender outside of contracts
+    pub fn caller_index(&self) -> u64 {
+        self.call_stack
+            .last()
+            .map(|frame| frame.caller_index)
+            .unwrap_or(self.sender_index)
+    }
+
+    /// the running contract, or the tx sender outside of contracts
+    pub fn current_index(&self) -> u64 {
+        self.call_stack
+            .last()
+            .map(|frame| frame.contract_index)
+            .unwrap_or(self.sender_index)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.call_stack.len()
+    }
+
+    pub fn get_account(&self, index: u64) -> Result<(&Account, &KVMap), Error> {
         self.state
-            .get_account(self.sender_index)
-            .ok_or(Error::MissingAccount(self.sender_index))
+            .get_account(index)
+            .ok_or(Error::MissingAccount(index))
+    }
+
+    pub fn get_balance(&self, index: u64, token_id: &TokenID) -> Result<u128, Error> {
+        let (_account, kv) = self.get_account(index)?;
+        Ok(kv.get(token_id).copied().unwrap_or(0))
+    }
+
+    pub fn get_storage(&self, index: u64, key: &[u8; 32]) -> Result<Option<&[u8]>, Error> {
+        self.state.get_storage(index, key)
     }
 
+    /// a slot missing from the state is inserted, a challenge must prove it as an empty slot
+    pub fn set_storage(&mut self, index: u64, key: [u8; 32], value: Vec<u8>) -> Result<(), Error> {
+        let prev_value = match self.state.get_raw_storage(index, &key) {
+            Ok(prev_value) => Some(prev_value.clone()),
+            Err(Error::MissingStorage(..)) => None,
+            Err(err) => return Err(err),
+        };
+        // removing a missing slot changes nothing
+        if prev_value.is_none() && value.is_empty() {
+            return Ok(());
+        }
+        self.state.update_account_storage(index, key, value)?;
+        self.journal.push(JournalEntry::Storage {
+            index,
+            key,
+            value: prev_value,
+        });
+        Ok(())
+    }
+
+    fn set_balance(&mut self, index: u64, token_id: TokenID, value: u128) -> Result<(), Error> {
+        let (_account, kv) = self.get_account(index)?;
+        let prev_value = kv.get(&token_id).cloned();
+        // an absent token is zero, a new zero key isn't in the proof of the touched accounts
+        if prev_value.is_none() && value == 0 {
+            return Ok(());
+        }
+        self.state.update_account_state(index, token_id, value)?;
+        self.journal.push(JournalEntry::Balance {
+            index,
+            token_id,
+            value: prev_value,
+        });
+        Ok(())
+    }
+
+    /// transfer from the current account
     pub fn transfer<'r>(&mut self, to_index: u64, payment: PaymentReader<'r>) -> Result<(), Error> {
+        let (token_id, amount) = payment.unpack();
+        self.transfer_token(to_index, token_id, amount)
+    }
+
+    /// transfer from the current account
+    pub fn transfer_token(
+        &mut self,
+        to_index: u64,
+        token_type: TokenID,
+        amount: u128,
+    ) -> Result<(), Error> {
+        let from_index = self.current_index();
         // check sender
-        let (_sender, sender_kv) = self.sender()?;
+        let sender_balance = self.get_balance(from_index, &token_type)?;
         // check receiver
-        let (_receiver, receiver_kv) = self
-            .state
-            .get_account(to_index)
-            .ok_or(Error::MissingAccount(to_index))?;
-
-        // get token type and amount
-        let (token_type, amount) = match payment.to_enum() {
-            PaymentUnionReader::Uint32(amount) => {
-                let amount: u64 = Unpack::<u32>::unpack(&amount).into();
-                (CKB_TOKEN_ID, amount)
-            }
-            PaymentUnionReader::UDTPayment(udt_payment) => {
-                let udt_type: [u8; 32] = udt_payment.type_hash().unpack();
-                let amount: u64 = Unpack::<u32>::unpack(&udt_payment.amount()).into();
-                (udt_type, amount)
-            }
-        };
+        let receiver_balance = self.get_balance(to_index, &token_type)?;
         // calculate new balance
-        let sender_balance: u64 = sender_kv.get(&token_type).map(|v| *v).unwrap_or(0);
         if sender_balance < amount {
             return Err(Error::BalanceNotEnough(sender_balance, amount));
         }
         let sender_balance = sender_balance - amount;
-        let receiver_balance: u64 = receiver_kv.get(&token_type).map(|v| *v).unwrap_or(0);
+        // sender may transfer to itself
+        let receiver_balance = if from_index == to_index {
+            sender_balance
+        } else {
+            receiver_balance
+        };
         let receiver_balance = receiver_balance
             .checked_add(amount)
             .ok_or(Error::BalanceOverflow)?;
 
         // update account balance
+        self.set_balance(from_index, token_type, sender_balance)?;
+        self.set_balance(to_index, token_type, receiver_balance)?;
+        let log = TransferLog::new_builder()
+            .from_index(from_index.pack())
+            .to_index(to_index.pack())
+            .token_id(token_type.pack())
+            .amount(amount.pack())
+            .build();
+        self.push_log(Log::new_builder().set(log).build());
+        Ok(())
+    }
+
+    /// move `amount` of the current account's balance to the withdrawing state
+    pub fn withdraw(&mut self, token_type: TokenID, amount: u128) -> Result<(), Error> {
+        let index = self.current_index();
+        let balance = self.get_balance(index, &token_type)?;
+        if balance < amount {
+            return Err(Error::BalanceNotEnough(balance, amount));
+        }
+        let prev_value = self.get_withdraw_balance(index, &token_type)?;
+        let withdraw_balance = prev_value
+            .checked_add(amount)
+            .ok_or(Error::BalanceOverflow)?;
+        self.set_balance(index, token_type, balance - amount)?;
+        let prev_value = self
+            .state
+            .get_account_withdraw(index)
+            .and_then(|kv| kv.get(&token_type))
+            .copied();
+        if prev_value.is_none() && withdraw_balance == 0 {
+            return Ok(());
+        }
         self.state
-            .update_account_state(self.sender_index, token_type, sender_balance)?;
-        self.state
-            .update_account_state(to_index, token_type, receiver_balance)?;
+            .update_account_withdraw(index, token_type, withdraw_balance)?;
+        self.journal.push(JournalEntry::Withdraw {
+            index,
+            token_id: token_type,
+            value: prev_value,
+        });
+        Ok(())
+    }
+
+    /// replace the keys of the current account
+    pub fn rotate_key(&mut self, new_key: AccountKey) -> Result<(), Error> {
+        let index = self.current_index();
+        let prev_value = self.state.set_key(index, new_key)?;
+        self.journal.push(JournalEntry::Key {
+            index,
+            value: prev_value,
+        });
         Ok(())
     }
+
+    pub fn get_withdraw_balance(&self, index: u64, token_id: &TokenID) -> Result<u128, Error> {
+        let kv = self
+            .state
+            .get_account_withdraw(index)
+            .ok_or(Error::MissingAccount(index))?;
+        Ok(kv.get(token_id).copied().unwrap_or(0))
+    }
+
+    /// emit a log from the current account
+    pub fn emit_log(&mut self, data: Vec<u8>) {
+        let account_index = self.current_index();
+        let log = ContractLog::new_builder()
+            .account_index(account_index.pack())
+            .data(Bytes::from(data).pack())
+            .build();
+        self.push_log(Log::new_builder().set(log).build());
+    }
+
+    fn push_log(&mut self, log: Log) {
+        self.logs.push(log);
+        self.journal.push(JournalEntry::Log);
+    }
+
+    /// logs emitted by the tx
+    pub fn logs(&self) -> &[Log] {
+        &self.logs
+    }
+
+    pub fn take_logs(&mut self) -> Vec<Log> {
+        core::mem::take(&mut self.logs)
+    }
+
+    /// Record an internal error, see `Error::is_internal`,
+    /// the contract is aborted and the error is returned by its call
+    pub fn set_internal_error(&mut self, err: Error) {
+        self.internal_error = Some(err);
+    }
+
+    pub fn take_internal_error(&mut self) -> Option<Error> {
+        self.internal_error.take()
+    }
+
+    /// Transfer `amount` to `to_index` and run its contract,
+    /// all changes of the call are reverted on failure.
+    /// Returns the consumed cycles.
+    pub fn call(
+        &mut self,
+        to_index: u64,
+        token_id: TokenID,
+        amount: u128,
+        args: &[u8],
+        max_cycles: u64,
+    ) -> Result<u64, Error> {
+        if self.call_stack.len() >= MAX_CALL_DEPTH {
+            return Err(Error::CallDepthExceeded);
+        }
+        let checkpoint = self.checkpoint();
+        let result = self.call_inner(to_index, token_id, amount, args, max_cycles);
+        if result.is_err() {
+            self.revert(checkpoint);
+        }
+        result
+    }
+
+    fn call_inner(
+        &mut self,
+        to_index: u64,
+        token_id: TokenID,
+        amount: u128,
+        args: &[u8],
+        max_cycles: u64,
+    ) -> Result<u64, Error> {
+        let caller_index = self.current_index();
+        self.transfer_token(to_index, token_id, amount)?;
+        let (receiver, _kv) = self.get_account(to_index)?;
+        if receiver.script().to_opt().is_none() {
+            return Ok(0);
+        }
+        let contract = self.contract.ok_or(Error::ContractNotSupported)?;
+        self.call_stack.push(CallFrame {
+            caller_index,
+            contract_index: to_index,
+        });
+        let result = contract.call(self, args, max_cycles);
+        self.call_stack.pop();
+        result
+    }
+
+    /// Returns a checkpoint that can be reverted to
+    pub fn checkpoint(&self) -> usize {
+        self.journal.len()
+    }
+
+    /// Changes after the checkpoint
+    pub fn state_diff(&self, checkpoint: usize) -> StateDiff {
+        let mut state_diff = StateDiff::default();
+        for entry in &self.journal[checkpoint..] {
+            match entry {
+                JournalEntry::Balance {
+                    index, token_id, ..
+                } => {
+                    let balance = self
+                        .get_balance(*index, token_id)
+                        .expect("journaled account");
+                    state_diff.balances.insert((*index, *token_id), balance);
+                }
+                JournalEntry::Withdraw {
+                    index, token_id, ..
+                } => {
+                    let balance = self
+                        .get_withdraw_balance(*index, token_id)
+                        .expect("journaled account");
+                    state_diff.withdrawals.insert((*index, *token_id), balance);
+                }
+                JournalEntry::Storage { index, key, .. } => {
+                    let value = self
+                        .state
+                        .get_raw_storage(*index, key)
+                        .expect("journaled slot")
+                        .clone();
+                    state_diff.storage.insert((*index, *key), value);
+                }
+                JournalEntry::Key { index, .. } => {
+                    let (account, _kv) = self.get_account(*index).expect("journaled account");
+                    state_diff
+                        .keys
+                        .insert(*index, AccountKey::from_account(account));
+                }
+                JournalEntry::Log => {}
+            }
+        }
+        state_diff
+    }
+
+    /// Revert all changes after the checkpoint
+    pub fn revert(&mut self, checkpoint: usize) {
+        while self.journal.len() > checkpoint {
+            let entry = self.journal.pop().expect("journal entry");
+            let result = match entry {
+                JournalEntry::Balance {
+                    index,
+                    token_id,
+                    value,
+                } => self.state.restore_account_state(index, token_id, value),
+                JournalEntry::Withdraw {
+                    index,
+                    token_id,
+                    value,
+                } => self.state.restore_account_withdraw(index, token_id, value),
+                JournalEntry::Storage { index, key, value } => {
+                    self.state.restore_account_storage(index, key, value)
+                }
+                JournalEntry::Key { index, value } => self.state.set_key(index, value).map(|_| ()),
+                JournalEntry::Log => {
+                    self.logs.pop();
+                    Ok(())
+                }
+            };
+            result.expect("journaled account exists");
+        }
+    }
 }
@@ -1,45 +1,303 @@
-use crate::{error::Error, execution_context::ExecutionContext, state::State};
-use godwoken_types::{cache::TxWithHash, packed::*, prelude::*};
+use crate::{
+    error::{BlockError, Error},
+    execution_context::ExecutionContext,
+    state::State,
+    traits::Contract,
+    types::{AccountKey, RunResult, SimulateResult},
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::convert::TryFrom;
+use godwoken_types::{
+    cache::TxWithHash,
+    core::{RollupID, TX_VERSION},
+    packed::*,
+    prelude::*,
+    signing::tx_signing_message,
+};
+use godwoken_utils::{
+    bls,
+    signature::{self, SignatureScheme},
+};
 
-pub struct Executor;
+/// max cycles of a tx
+pub const MAX_CYCLES: u64 = 70_000_000;
+
+pub struct Executor {
+    rollup_id: RollupID,
+    contract: Option<Box<dyn Contract>>,
+    max_cycles: u64,
+}
 
 impl Executor {
-    pub fn new() -> Self {
-        Executor {}
+    /// `rollup_id` is the main contract's type hash, txs are signed with it
+    pub fn new(rollup_id: RollupID) -> Self {
+        Executor {
+            rollup_id,
+            contract: None,
+            max_cycles: MAX_CYCLES,
+        }
+    }
+
+    /// Executor that runs contract accounts with `contract`
+    pub fn with_contract(rollup_id: RollupID, contract: Box<dyn Contract>) -> Self {
+        Executor {
+            rollup_id,
+            contract: Some(contract),
+            max_cycles: MAX_CYCLES,
+        }
+    }
+
+    pub fn max_cycles(mut self, max_cycles: u64) -> Self {
+        self.max_cycles = max_cycles;
+        self
     }
 
-    fn verify_tx<'a>(&self, sender: &Account, tx: &TxWithHash) -> Result<(), Error> {
+    fn verify_tx(
+        &self,
+        sender: &Account,
+        tx: &TxWithHash,
+        verify_signature: bool,
+    ) -> Result<(), Error> {
+        let raw_tx = tx.tx.raw();
+        let version: u8 = raw_tx.version().into();
+        if version != TX_VERSION {
+            return Err(Error::InvalidTxVersion(version));
+        }
         // check nonce
         let nonce: u32 = sender.nonce().unpack();
-        let tx_nonce = tx.raw.nonce().unpack();
-        if nonce + 1 != tx_nonce {
-            return Err(Error::InvalidNonce(nonce + 1, tx_nonce));
+        let expected_nonce = nonce.checked_add(1).ok_or(Error::NonceOverflow)?;
+        let tx_nonce = raw_tx.nonce().unpack();
+        if expected_nonce != tx_nonce {
+            return Err(Error::InvalidNonce(expected_nonce, tx_nonce));
+        }
+        if !verify_signature {
+            return Ok(());
         }
-        // check signature
+        self.verify_tx_signature(sender, tx)
+    }
+
+    /// Verify the witness of tx against sender's pubkey_hash.
+    /// A tx with empty witness is signed by the BLS key, it's invalid alone and
+    /// only runs in a block by `run_block`.
+    pub fn verify_tx_signature(&self, sender: &Account, tx: &TxWithHash) -> Result<(), Error> {
         let pubkey_hash = sender.pubkey_hash().raw_data();
-        let witness = tx.raw.witness().raw_data();
-        godwoken_utils::secp256k1::verify_signature(&witness, &tx.tx_hash, &pubkey_hash)
+        let witness = tx.tx.witness().raw_data();
+        if witness.is_empty() {
+            return Err(Error::InvalidSignature);
+        }
+        let message = tx_signing_message(&self.rollup_id, &tx.raw_tx_hash);
+        let sig_scheme: u8 = sender.sig_scheme().into();
+        signature::verify_signature(sig_scheme, &witness, &message, &pubkey_hash)
             .map_err(|_| Error::InvalidSignature)?;
         Ok(())
     }
 
-    pub fn run(&self, state: &mut State, tx: TxWithHash, ag_index: u64) -> Result<(), Error> {
-        let sender_index: u64 = tx.raw.sender_index().unpack();
-        let to_index: u64 = tx.raw.to_index().unpack();
+    /// Verify the block's BLS aggregate signature of txs with empty witness.
+    /// `aggregate_sig` is zeros if there is no such tx.
+    fn verify_aggregate_signature(
+        &self,
+        state: &State,
+        txs: &[TxWithHash],
+        aggregate_sig: &[u8; 96],
+    ) -> Result<(), Error> {
+        let mut messages = Vec::new();
+        for tx in txs {
+            if !tx.tx.witness().raw_data().is_empty() {
+                continue;
+            }
+            let sender_index: u64 = tx.tx.raw().sender_index().unpack();
+            let (sender, _kv) = state
+                .get_account(sender_index)
+                .ok_or(Error::MissingAccount(sender_index))?;
+            let bls_pubkey: [u8; 48] = sender
+                .bls_pubkey()
+                .to_opt()
+                .ok_or(Error::InvalidSignature)?
+                .unpack();
+            let message = tx_signing_message(&self.rollup_id, &tx.raw_tx_hash);
+            messages.push((bls_pubkey, message));
+        }
+        if messages.is_empty() {
+            if aggregate_sig != &[0u8; 96] {
+                return Err(Error::InvalidSignature);
+            }
+            return Ok(());
+        }
+        let items: Vec<_> = messages
+            .iter()
+            .map(|(pubkey, message)| (*pubkey, &message[..]))
+            .collect();
+        bls::aggregate_verify(&items, aggregate_sig).map_err(|_| Error::InvalidSignature)
+    }
+
+    /// verify tx and returns the sender's index
+    fn check_tx(
+        &self,
+        state: &State,
+        tx: &TxWithHash,
+        verify_signature: bool,
+    ) -> Result<u64, Error> {
+        let sender_index: u64 = tx.tx.raw().sender_index().unpack();
         let (sender, _kv) = state
             .get_account(sender_index)
             .ok_or(Error::MissingAccount(sender_index))?;
-        self.verify_tx(&sender, &tx)?;
+        self.verify_tx(sender, tx, verify_signature)?;
         if sender.script().to_opt().is_some() {
-            // do not support contract yet
-            return Err(Error::ContractCall(1));
+            // contract account can't send tx
+            return Err(Error::ContractSender(sender_index));
         }
-        let mut context = ExecutionContext::new(state, sender_index);
-        // charge tx fee
-        context.transfer(ag_index, tx.raw.fee())?;
-        // transfer
-        context.transfer(to_index, tx.raw.amount())?;
+        Ok(sender_index)
+    }
+
+    /// Run a tx signed by its witness, use `run_block` for txs signed by BLS keys.
+    pub fn run(
+        &self,
+        state: &mut State,
+        tx: TxWithHash,
+        ag_index: u64,
+    ) -> Result<RunResult, Error> {
+        self.run_tx(state, tx, ag_index, false)
+    }
+
+    /// Run txs of a block, txs with empty witness are verified by `aggregate_sig`
+    /// against the senders' BLS pubkeys before running any tx.
+    /// Txs before the failed one are kept in `state`.
+    pub fn run_block(
+        &self,
+        state: &mut State,
+        txs: Vec<TxWithHash>,
+        aggregate_sig: &[u8; 96],
+        ag_index: u64,
+    ) -> Result<Vec<RunResult>, BlockError> {
+        self.verify_aggregate_signature(state, &txs, aggregate_sig)
+            .map_err(BlockError::InvalidAggregateSignature)?;
+        let mut run_results = Vec::with_capacity(txs.len());
+        for (i, tx) in txs.into_iter().enumerate() {
+            let run_result = self
+                .run_tx(state, tx, ag_index, true)
+                .map_err(|err| BlockError::InvalidTx(i, err))?;
+            run_results.push(run_result);
+        }
+        Ok(run_results)
+    }
+
+    /// `aggregate_verified` represents txs with empty witness are verified by the
+    /// block's aggregate signature
+    fn run_tx(
+        &self,
+        state: &mut State,
+        tx: TxWithHash,
+        ag_index: u64,
+        aggregate_verified: bool,
+    ) -> Result<RunResult, Error> {
+        let verify_signature = !(aggregate_verified && tx.tx.witness().raw_data().is_empty());
+        let sender_index = self.check_tx(state, &tx, verify_signature)?;
+        let contract = self.contract.as_ref().map(|contract| contract.as_ref());
+        let mut context = ExecutionContext::new(state, sender_index, contract);
+        let checkpoint = context.checkpoint();
+        let cycles = match self.execute(&mut context, &tx, ag_index) {
+            Ok(cycles) => cycles,
+            Err(err) => {
+                context.revert(checkpoint);
+                return Err(err);
+            }
+        };
+        let logs = context.take_logs();
         state.inc_nonce(sender_index)?;
-        Ok(())
+        Ok(RunResult { cycles, logs })
+    }
+
+    /// Dry-run a tx, all changes are reverted, `state` is unchanged after the simulation.
+    /// The signature verification is skipped if `verify_signature` is false.
+    pub fn simulate(
+        &self,
+        state: &mut State,
+        tx: TxWithHash,
+        ag_index: u64,
+        verify_signature: bool,
+    ) -> SimulateResult {
+        let sender_index = match self.check_tx(state, &tx, verify_signature) {
+            Ok(sender_index) => sender_index,
+            Err(err) => {
+                return SimulateResult {
+                    error: Some(err),
+                    ..Default::default()
+                }
+            }
+        };
+        let contract = self.contract.as_ref().map(|contract| contract.as_ref());
+        let mut context = ExecutionContext::new(state, sender_index, contract);
+        let checkpoint = context.checkpoint();
+        let result = match self.execute(&mut context, &tx, ag_index) {
+            Ok(cycles) => {
+                let mut state_diff = context.state_diff(checkpoint);
+                let tx_nonce: u32 = tx.tx.raw().nonce().unpack();
+                state_diff.nonces.insert(sender_index, tx_nonce);
+                SimulateResult {
+                    state_diff,
+                    cycles,
+                    logs: context.take_logs(),
+                    error: None,
+                }
+            }
+            Err(err) => SimulateResult {
+                error: Some(err),
+                ..Default::default()
+            },
+        };
+        context.revert(checkpoint);
+        result
+    }
+
+    fn execute(
+        &self,
+        context: &mut ExecutionContext,
+        tx: &TxWithHash,
+        ag_index: u64,
+    ) -> Result<u64, Error> {
+        // charge tx fee
+        let raw_tx = tx.tx.raw();
+        context.transfer(ag_index, raw_tx.fee())?;
+        match raw_tx.kind().to_enum() {
+            TxKindUnionReader::Transfer(transfer) => {
+                // a contract receiver is called with empty args
+                let to_index: u64 = transfer.to_index().unpack();
+                let (token_id, amount) = transfer.amount().unpack();
+                context.call(to_index, token_id, amount, &[], self.max_cycles)
+            }
+            TxKindUnionReader::MultiTransfer(multi_transfer) => {
+                // the whole tx is reverted if any transfer fails
+                let mut cycles = 0u64;
+                for item in multi_transfer.transfers().iter() {
+                    let to_index: u64 = item.to_index().unpack();
+                    let (token_id, amount) = item.amount().unpack();
+                    let max_cycles = self.max_cycles.saturating_sub(cycles);
+                    let used_cycles = context.call(to_index, token_id, amount, &[], max_cycles)?;
+                    cycles = cycles.saturating_add(used_cycles);
+                }
+                Ok(cycles)
+            }
+            TxKindUnionReader::WithdrawRequest(withdraw_request) => {
+                let (token_id, amount) = withdraw_request.amount().unpack();
+                context.withdraw(token_id, amount)?;
+                Ok(0)
+            }
+            TxKindUnionReader::KeyRotation(key_rotation) => {
+                // the tx is signed by the old key, all keys are replaced
+                let sig_scheme: u8 = key_rotation.new_sig_scheme().into();
+                SignatureScheme::try_from(sig_scheme)
+                    .map_err(|_| Error::UnknownSigScheme(sig_scheme))?;
+                let new_key = AccountKey {
+                    pubkey_hash: key_rotation.new_pubkey_hash().unpack(),
+                    sig_scheme,
+                    bls_pubkey: key_rotation
+                        .new_bls_pubkey()
+                        .to_opt()
+                        .map(|pubkey| pubkey.unpack()),
+                };
+                context.rotate_key(new_key)?;
+                Ok(0)
+            }
+        }
     }
 }
@@ -1,31 +1,55 @@
-use crate::error::Error;
-use alloc::vec::Vec;
-use godwoken_types::{cache::KVMap, packed::*, prelude::*};
+use crate::{error::Error, types::AccountKey};
+use alloc::{collections::BTreeSet, vec::Vec};
+use godwoken_types::{
+    cache::{KVMap, StorageMap},
+    core::Index,
+    packed::*,
+    prelude::*,
+};
+use godwoken_utils::smt::{self, Value, ValueTrait, H256};
+
+/// initial state of an account
+pub struct AccountState<'a> {
+    pub account: AccountReader<'a>,
+    /// token balances
+    pub kv: KVMap,
+    /// withdrawing token balances
+    pub withdraw_kv: KVMap,
+    /// touched storage slots, an empty slot is proven with an empty value
+    pub storage: StorageMap,
+}
 
 struct AccountInner {
     account: Account,
     kv: KVMap,
-    nonce: u32,
+    withdraw_kv: KVMap,
+    storage: StorageMap,
 }
 
 /// account states
 pub struct State(Vec<AccountInner>);
 
 impl State {
-    pub fn new<'a>(mut accounts: Vec<(AccountReader<'a>, KVMap)>) -> Self {
-        accounts.sort_unstable_by_key(|(account, _)| {
-            let index: u64 = account.index().unpack();
+    pub fn new<'a>(mut accounts: Vec<AccountState<'a>>) -> Self {
+        accounts.sort_unstable_by_key(|account_state| {
+            let index: u64 = account_state.account.index().unpack();
             index
         });
         State(
             accounts
                 .into_iter()
-                .map(|(account, kv)| {
-                    let nonce: u32 = account.nonce().unpack();
+                .map(|account_state| {
+                    let AccountState {
+                        account,
+                        kv,
+                        withdraw_kv,
+                        storage,
+                    } = account_state;
                     AccountInner {
                         account: account.to_entity(),
                         kv,
-                        nonce,
+                        withdraw_kv,
+                        storage,
                     }
                 })
                 .collect(),
@@ -48,7 +72,7 @@ impl State {
         &mut self,
         index: u64,
         key: [u8; 32],
-        value: u64,
+        value: u128,
     ) -> Result<(), Error> {
         let i = self
             .get_inner_index(index)
@@ -57,16 +81,146 @@ impl State {
         Ok(())
     }
 
+    /// withdrawing balances of an account
+    pub fn get_account_withdraw(&self, index: u64) -> Option<&KVMap> {
+        self.get_inner_index(index)
+            .ok()
+            .and_then(|i| self.0.get(i))
+            .map(|inner| &inner.withdraw_kv)
+    }
+
+    pub fn update_account_withdraw(
+        &mut self,
+        index: u64,
+        key: [u8; 32],
+        value: u128,
+    ) -> Result<(), Error> {
+        let i = self
+            .get_inner_index(index)
+            .map_err(|_| Error::MissingAccount(index))?;
+        self.0[i].withdraw_kv.insert(key, value);
+        Ok(())
+    }
+
+    /// Value of a storage slot, `None` if the slot is empty.
+    /// Fails if the slot isn't in the state, an empty slot must be proven with an empty value.
+    pub fn get_storage(&self, index: u64, key: &[u8; 32]) -> Result<Option<&[u8]>, Error> {
+        let value = self.get_raw_storage(index, key)?;
+        if value.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(value.as_slice()))
+        }
+    }
+
+    /// storage slots of an account, a removed slot has an empty value
+    pub fn get_account_storage(&self, index: u64) -> Option<&StorageMap> {
+        self.get_inner_index(index)
+            .ok()
+            .and_then(|i| self.0.get(i))
+            .map(|inner| &inner.storage)
+    }
+
+    pub fn update_account_storage(
+        &mut self,
+        index: u64,
+        key: [u8; 32],
+        value: Vec<u8>,
+    ) -> Result<(), Error> {
+        let i = self
+            .get_inner_index(index)
+            .map_err(|_| Error::MissingAccount(index))?;
+        self.0[i].storage.insert(key, value);
+        Ok(())
+    }
+
+    /// storage slot of the state, an empty value is an empty slot
+    pub(crate) fn get_raw_storage(&self, index: u64, key: &[u8; 32]) -> Result<&Vec<u8>, Error> {
+        let i = self
+            .get_inner_index(index)
+            .map_err(|_| Error::MissingAccount(index))?;
+        self.0[i]
+            .storage
+            .get(key)
+            .ok_or(Error::MissingStorage(index, *key))
+    }
+
+    pub(crate) fn restore_account_state(
+        &mut self,
+        index: u64,
+        key: [u8; 32],
+        value: Option<u128>,
+    ) -> Result<(), Error> {
+        let i = self
+            .get_inner_index(index)
+            .map_err(|_| Error::MissingAccount(index))?;
+        match value {
+            Some(value) => self.0[i].kv.insert(key, value),
+            None => self.0[i].kv.remove(&key),
+        };
+        Ok(())
+    }
+
+    pub(crate) fn restore_account_withdraw(
+        &mut self,
+        index: u64,
+        key: [u8; 32],
+        value: Option<u128>,
+    ) -> Result<(), Error> {
+        let i = self
+            .get_inner_index(index)
+            .map_err(|_| Error::MissingAccount(index))?;
+        match value {
+            Some(value) => self.0[i].withdraw_kv.insert(key, value),
+            None => self.0[i].withdraw_kv.remove(&key),
+        };
+        Ok(())
+    }
+
+    pub(crate) fn restore_account_storage(
+        &mut self,
+        index: u64,
+        key: [u8; 32],
+        value: Option<Vec<u8>>,
+    ) -> Result<(), Error> {
+        let i = self
+            .get_inner_index(index)
+            .map_err(|_| Error::MissingAccount(index))?;
+        match value {
+            Some(value) => self.0[i].storage.insert(key, value),
+            None => self.0[i].storage.remove(&key),
+        };
+        Ok(())
+    }
+
     pub fn inc_nonce(&mut self, index: u64) -> Result<(), Error> {
         let i = self
             .get_inner_index(index)
             .map_err(|_| Error::MissingAccount(index))?;
-        let nonce = self.0[i].nonce;
-        let new_nonce: u32 = nonce.checked_add(1).expect("no overflow");
-        self.0[i].nonce = new_nonce;
+        let account = &self.0[i].account;
+        let nonce: u32 = account.nonce().unpack();
+        let new_nonce: u32 = nonce.checked_add(1).ok_or(Error::NonceOverflow)?;
+        self.0[i].account = account.clone().as_builder().nonce(new_nonce.pack()).build();
         Ok(())
     }
 
+    /// set keys of an account, returns the previous keys
+    pub(crate) fn set_key(&mut self, index: u64, key: AccountKey) -> Result<AccountKey, Error> {
+        let i = self
+            .get_inner_index(index)
+            .map_err(|_| Error::MissingAccount(index))?;
+        let account = &self.0[i].account;
+        let prev_key = AccountKey::from_account(account);
+        self.0[i].account = account
+            .clone()
+            .as_builder()
+            .pubkey_hash(key.pubkey_hash.pack())
+            .sig_scheme(key.sig_scheme.into())
+            .bls_pubkey(key.bls_pubkey.pack())
+            .build();
+        Ok(prev_key)
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -74,4 +228,48 @@ impl State {
     pub fn iter(&self) -> impl Iterator<Item = (&Account, &KVMap)> {
         self.0.iter().map(|inner| (&inner.account, &inner.kv))
     }
+
+    /// SMT leaves of accounts, token kv, withdrawing kv and storage slots,
+    /// used to verify the state with a merkle proof
+    pub fn merkle_leaves(&self) -> Vec<(H256, H256)> {
+        let mut leaves: Vec<_> = Vec::with_capacity(self.0.len() * 2);
+        for inner in &self.0 {
+            let index: u64 = inner.account.index().unpack();
+            for (k, v) in &inner.kv {
+                leaves.push((smt::token_id_key(index, k), Value::from(*v).to_h256()));
+            }
+            for (k, v) in &inner.withdraw_kv {
+                leaves.push((smt::withdraw_key(index, k), Value::from(*v).to_h256()));
+            }
+            for (k, v) in &inner.storage {
+                leaves.push((smt::storage_key(index, k), smt::storage_value(v).to_h256()));
+            }
+            let value = Value::from(inner.account.clone());
+            leaves.push((smt::account_index_key(index), value.to_h256()));
+        }
+        leaves
+    }
+}
+
+/// indexes of accounts touched by txs, in ascending order:
+/// the aggregator, the senders and the receivers of the txs
+pub fn touched_accounts(txs: &[Tx], ag_index: Index) -> Vec<Index> {
+    let mut indexes = BTreeSet::new();
+    indexes.insert(ag_index);
+    for tx in txs {
+        let raw_tx = tx.raw();
+        indexes.insert(raw_tx.sender_index().unpack());
+        match raw_tx.kind().to_enum() {
+            TxKindUnion::Transfer(transfer) => {
+                indexes.insert(transfer.to_index().unpack());
+            }
+            TxKindUnion::MultiTransfer(multi_transfer) => {
+                for item in multi_transfer.transfers().into_iter() {
+                    indexes.insert(item.to_index().unpack());
+                }
+            }
+            TxKindUnion::WithdrawRequest(_) | TxKindUnion::KeyRotation(_) => {}
+        }
+    }
+    indexes.into_iter().collect()
 }
@@ -1,6 +1,17 @@
 use crate::{error::Error, execution_context::ExecutionContext};
-use godwoken_types::cache::TxWithHash;
+use godwoken_types::bytes::Bytes;
 
 pub trait Contract {
-    fn call(&mut self, context: &mut ExecutionContext, tx: &TxWithHash) -> Result<(), Error>;
+    /// Run the contract of `context.current_index()`, returns the consumed cycles
+    fn call(
+        &self,
+        context: &mut ExecutionContext,
+        args: &[u8],
+        max_cycles: u64,
+    ) -> Result<u64, Error>;
+}
+
+/// Provides contract code by `AccountScript.code_hash`
+pub trait CodeStore {
+    fn get_code(&self, code_hash: &[u8; 32]) -> Option<Bytes>;
 }
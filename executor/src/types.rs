@@ -1 +1,71 @@
+use crate::error::Error;
+use alloc::{collections::BTreeMap, vec::Vec};
+use godwoken_types::{core::TokenID, packed::*, prelude::*};
+use godwoken_utils::hash::new_blake2b;
 
+/// Execution result of a tx
+#[derive(Debug, Default)]
+pub struct RunResult {
+    /// consumed cycles of contracts
+    pub cycles: u64,
+    /// logs emitted by the tx
+    pub logs: Vec<Log>,
+}
+
+impl RunResult {
+    /// hash of the tx's logs, block's `logs_root` is the merkle root of these hashes
+    pub fn logs_hash(&self) -> [u8; 32] {
+        let logs = LogVec::new_builder().set(self.logs.clone()).build();
+        let mut hasher = new_blake2b();
+        hasher.update(logs.as_slice());
+        let mut hash = [0u8; 32];
+        hasher.finalize(&mut hash);
+        hash
+    }
+}
+
+/// Keys of an account, replaced together by a `KeyRotation` tx
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountKey {
+    pub pubkey_hash: [u8; 20],
+    pub sig_scheme: u8,
+    pub bls_pubkey: Option<[u8; 48]>,
+}
+
+impl AccountKey {
+    pub fn from_account(account: &Account) -> Self {
+        AccountKey {
+            pubkey_hash: account.pubkey_hash().unpack(),
+            sig_scheme: account.sig_scheme().into(),
+            bls_pubkey: account.bls_pubkey().to_opt().map(|pubkey| pubkey.unpack()),
+        }
+    }
+}
+
+/// State changes of a tx
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StateDiff {
+    /// (account index, token id) -> new balance
+    pub balances: BTreeMap<(u64, TokenID), u128>,
+    /// (account index, token id) -> new withdrawing balance
+    pub withdrawals: BTreeMap<(u64, TokenID), u128>,
+    /// (account index, storage key) -> new value, an empty value represents a removed slot
+    pub storage: BTreeMap<(u64, [u8; 32]), Vec<u8>>,
+    /// account index -> new nonce
+    pub nonces: BTreeMap<u64, u32>,
+    /// account index -> new keys
+    pub keys: BTreeMap<u64, AccountKey>,
+}
+
+/// Result of `Executor::simulate`
+#[derive(Debug, Default)]
+pub struct SimulateResult {
+    /// would-be state changes, empty if the tx failed
+    pub state_diff: StateDiff,
+    /// consumed cycles of contracts, 0 if the tx failed
+    pub cycles: u64,
+    /// logs emitted by the tx
+    pub logs: Vec<Log>,
+    /// the tx failed with this error
+    pub error: Option<Error>,
+}
@@ -0,0 +1,114 @@
+//! Run contract accounts in ckb-vm
+//!
+//! Contracts share the same tech stack as CKB scripts, the differences are
+//! the syscalls, see `syscalls` for the godwoken specific ones.
+
+mod syscalls;
+
+pub use syscalls::*;
+
+use crate::{
+    error::Error,
+    execution_context::ExecutionContext,
+    traits::{CodeStore, Contract},
+};
+use ckb_vm::{
+    instructions::{extract_opcode, insts, Instruction},
+    Bytes, DefaultCoreMachine, DefaultMachineBuilder, SparseMemory,
+};
+use godwoken_types::prelude::*;
+
+/// cycles of an instruction, the same as CKB scripts
+pub fn instruction_cycles(i: Instruction) -> u64 {
+    match extract_opcode(i) {
+        insts::OP_JALR => 3,
+        insts::OP_LD => 2,
+        insts::OP_LW => 3,
+        insts::OP_LH => 3,
+        insts::OP_LB => 3,
+        insts::OP_LWU => 3,
+        insts::OP_LHU => 3,
+        insts::OP_LBU => 3,
+        insts::OP_SB => 3,
+        insts::OP_SH => 3,
+        insts::OP_SW => 3,
+        insts::OP_SD => 2,
+        insts::OP_BEQ => 3,
+        insts::OP_BGE => 3,
+        insts::OP_BGEU => 3,
+        insts::OP_BLT => 3,
+        insts::OP_BLTU => 3,
+        insts::OP_BNE => 3,
+        insts::OP_EBREAK => 500,
+        insts::OP_ECALL => 500,
+        insts::OP_JAL => 3,
+        insts::OP_MUL => 5,
+        insts::OP_MULW => 5,
+        insts::OP_MULH => 5,
+        insts::OP_MULHU => 5,
+        insts::OP_MULHSU => 5,
+        insts::OP_DIV => 32,
+        insts::OP_DIVW => 32,
+        insts::OP_DIVU => 32,
+        insts::OP_DIVUW => 32,
+        insts::OP_REM => 32,
+        insts::OP_REMW => 32,
+        insts::OP_REMU => 32,
+        insts::OP_REMUW => 32,
+        _ => 1,
+    }
+}
+
+/// Load contract code by `AccountScript.code_hash` and run it in ckb-vm
+pub struct VMContract<CS> {
+    code_store: CS,
+}
+
+impl<CS: CodeStore> VMContract<CS> {
+    pub fn new(code_store: CS) -> Self {
+        VMContract { code_store }
+    }
+}
+
+impl<CS: CodeStore> Contract for VMContract<CS> {
+    fn call(
+        &self,
+        context: &mut ExecutionContext,
+        args: &[u8],
+        max_cycles: u64,
+    ) -> Result<u64, Error> {
+        let contract_index = context.current_index();
+        let (account, _kv) = context.get_account(contract_index)?;
+        let script = account
+            .script()
+            .to_opt()
+            .ok_or(Error::NotContractAccount(contract_index))?;
+        let code_hash: [u8; 32] = script.code_hash().unpack();
+        let code = self
+            .code_store
+            .get_code(&code_hash)
+            .ok_or(Error::MissingContractCode(code_hash))?;
+        let run_result = {
+            let syscalls = L2Syscalls::new(context, args, max_cycles);
+            let core_machine =
+                DefaultCoreMachine::<u64, SparseMemory<u64>>::new_with_max_cycles(max_cycles);
+            let mut machine = DefaultMachineBuilder::new(core_machine)
+                .instruction_cycle_func(Box::new(instruction_cycles))
+                .syscall(Box::new(syscalls))
+                .build();
+            machine
+                .load_program(&Bytes::from(code.to_vec()), &[])
+                .map_err(Error::VM)?;
+            machine.run().map(|exit_code| (exit_code, machine.cycles()))
+        };
+        // a syscall aborts the VM on internal errors
+        if let Some(err) = context.take_internal_error() {
+            return Err(err);
+        }
+        let (exit_code, cycles) = run_result.map_err(Error::VM)?;
+        if exit_code != 0 {
+            return Err(Error::ContractExit(exit_code));
+        }
+        Ok(cycles)
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{error::Error, execution_context::ExecutionContext};
+use ckb_vm::{
+    registers::{A0, A1, A2, A3, A4, A5, A7},
+    Error as VMError, Memory, Register, SupportMachine, Syscalls,
+};
+use std::cmp;
+
+pub const SUCCESS: u8 = 0;
+pub const INDEX_OUT_OF_BOUND: u8 = 1;
+pub const ITEM_MISSING: u8 = 2;
+/// the callee failed, all changes of the call are reverted
+pub const CALL_FAILED: u8 = 3;
+
+/// max size of a storage value
+pub const MAX_VALUE_SIZE: u64 = 16 * 1024;
+/// max size of the args of a call
+pub const MAX_ARGS_SIZE: u64 = 16 * 1024;
+/// max size of a log
+pub const MAX_LOG_SIZE: u64 = 4 * 1024;
+/// cycles charged for filling an empty storage slot, it grows the account's state
+pub const NEW_SLOT_CYCLES: u64 = 10_000;
+
+/// load caller's account index
+pub const SYS_LOAD_CALLER: u64 = 3001;
+/// load tx args
+pub const SYS_LOAD_ARGS: u64 = 3002;
+/// load balance of an account as a little-endian u128, A3: account index, A4: token id ptr,
+/// the account must be touched by the tx
+pub const SYS_LOAD_BALANCE: u64 = 3003;
+/// load a value from contract's storage, A3: key ptr
+pub const SYS_LOAD_STORAGE: u64 = 3004;
+/// store a value to contract's storage, A0: key ptr, A1: value ptr, A2: value len,
+/// an empty value removes the slot, the value is limited by `MAX_VALUE_SIZE`.
+/// Filling an empty slot costs `NEW_SLOT_CYCLES`.
+pub const SYS_STORE_STORAGE: u64 = 3005;
+/// call another account and transfer tokens to it,
+/// A0: callee index, A1: token id ptr, A2: amount ptr (a little-endian u128),
+/// A3: args ptr, A4: args len, A5: max cycles of the callee, 0 means all remaining cycles.
+/// The args are limited by `MAX_ARGS_SIZE`.
+/// A failed callee consumes all the cycles given to it.
+/// The callee and the accounts it touches must be declared by the tx.
+pub const SYS_CALL: u64 = 3006;
+/// emit a log, A0: data ptr, A1: data len, the data is limited by `MAX_LOG_SIZE`
+pub const SYS_LOG: u64 = 3007;
+
+/// Syscalls of a contract account, the load syscalls follow the CKB partial
+/// loading convention: A0 is the buffer address, A1 is the address of buffer
+/// length and A2 is the offset.
+pub struct L2Syscalls<'a, 'b> {
+    context: &'a mut ExecutionContext<'b>,
+    contract_index: u64,
+    args: &'a [u8],
+    max_cycles: u64,
+}
+
+impl<'a, 'b> L2Syscalls<'a, 'b> {
+    pub fn new(context: &'a mut ExecutionContext<'b>, args: &'a [u8], max_cycles: u64) -> Self {
+        let contract_index = context.current_index();
+        L2Syscalls {
+            context,
+            contract_index,
+            args,
+            max_cycles,
+        }
+    }
+
+    /// abort the VM, `err` is returned by the contract call
+    fn internal_error(&mut self, err: Error) -> VMError {
+        self.context.set_internal_error(err);
+        VMError::Unexpected
+    }
+}
+
+impl<'a, 'b, Mac: SupportMachine> Syscalls<Mac> for L2Syscalls<'a, 'b> {
+    fn initialize(&mut self, _machine: &mut Mac) -> Result<(), VMError> {
+        Ok(())
+    }
+
+    fn ecall(&mut self, machine: &mut Mac) -> Result<bool, VMError> {
+        let code = machine.registers()[A7].to_u64();
+        match code {
+            SYS_LOAD_CALLER => {
+                let caller = self.context.caller_index().to_le_bytes();
+                store_data(machine, &caller)?;
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+            }
+            SYS_LOAD_ARGS => {
+                store_data(machine, self.args)?;
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+            }
+            SYS_LOAD_BALANCE => {
+                let index = machine.registers()[A3].to_u64();
+                let token_id_addr = machine.registers()[A4].to_u64();
+                let token_id = load_hash(machine, token_id_addr)?;
+                let balance = match self.context.get_balance(index, &token_id) {
+                    Ok(balance) => balance,
+                    Err(err) => return Err(self.internal_error(err)),
+                };
+                store_data(machine, &balance.to_le_bytes())?;
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+            }
+            SYS_LOAD_STORAGE => {
+                let key_addr = machine.registers()[A3].to_u64();
+                let key = load_hash(machine, key_addr)?;
+                match self.context.get_storage(self.contract_index, &key) {
+                    Ok(Some(value)) => {
+                        store_data(machine, value)?;
+                        machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+                    }
+                    Ok(None) => machine.set_register(A0, Mac::REG::from_u8(ITEM_MISSING)),
+                    Err(err) => return Err(self.internal_error(err)),
+                }
+            }
+            SYS_STORE_STORAGE => {
+                let key_addr = machine.registers()[A0].to_u64();
+                let value_addr = machine.registers()[A1].to_u64();
+                let value_len = machine.registers()[A2].to_u64();
+                let key = load_hash(machine, key_addr)?;
+                let value = load_bytes(machine, value_addr, value_len, MAX_VALUE_SIZE)?;
+                // a slot missing from the state is empty, it costs the same as a proven empty slot
+                let empty_slot = match self.context.get_storage(self.contract_index, &key) {
+                    Ok(prev_value) => prev_value.is_none(),
+                    Err(Error::MissingStorage(..)) => true,
+                    Err(err) => return Err(self.internal_error(err)),
+                };
+                if empty_slot && !value.is_empty() {
+                    machine.add_cycles(NEW_SLOT_CYCLES)?;
+                }
+                if let Err(err) = self.context.set_storage(self.contract_index, key, value) {
+                    return Err(self.internal_error(err));
+                }
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+            }
+            SYS_CALL => {
+                let callee_index = machine.registers()[A0].to_u64();
+                let token_id_addr = machine.registers()[A1].to_u64();
+                let amount_addr = machine.registers()[A2].to_u64();
+                let args_addr = machine.registers()[A3].to_u64();
+                let args_len = machine.registers()[A4].to_u64();
+                let callee_cycles = machine.registers()[A5].to_u64();
+                let token_id = load_hash(machine, token_id_addr)?;
+                let amount = load_amount(machine, amount_addr)?;
+                let args = load_bytes(machine, args_addr, args_len, MAX_ARGS_SIZE)?;
+                let remaining_cycles = self.max_cycles.saturating_sub(machine.cycles());
+                let callee_cycles = if callee_cycles == 0 {
+                    remaining_cycles
+                } else {
+                    cmp::min(callee_cycles, remaining_cycles)
+                };
+                let result =
+                    self.context
+                        .call(callee_index, token_id, amount, &args, callee_cycles);
+                match result {
+                    Ok(used_cycles) => {
+                        machine.add_cycles(used_cycles)?;
+                        machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+                    }
+                    Err(err) if err.is_internal() => return Err(self.internal_error(err)),
+                    Err(_) => {
+                        machine.add_cycles(callee_cycles)?;
+                        machine.set_register(A0, Mac::REG::from_u8(CALL_FAILED));
+                    }
+                }
+            }
+            SYS_LOG => {
+                let data_addr = machine.registers()[A0].to_u64();
+                let data_len = machine.registers()[A1].to_u64();
+                let data = load_bytes(machine, data_addr, data_len, MAX_LOG_SIZE)?;
+                self.context.emit_log(data);
+                machine.set_register(A0, Mac::REG::from_u8(SUCCESS));
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// load `len` bytes from the VM memory, fails before allocating if `len` exceeds `max_len`
+fn load_bytes<Mac: SupportMachine>(
+    machine: &mut Mac,
+    addr: u64,
+    len: u64,
+    max_len: u64,
+) -> Result<Vec<u8>, VMError> {
+    if len > max_len {
+        return Err(VMError::OutOfBound);
+    }
+    let mut buf = Vec::with_capacity(len as usize);
+    for i in 0..len {
+        let addr = addr.checked_add(i).ok_or(VMError::OutOfBound)?;
+        let byte = machine.memory_mut().load8(&Mac::REG::from_u64(addr))?;
+        buf.push(byte.to_u8());
+    }
+    Ok(buf)
+}
+
+fn load_hash<Mac: SupportMachine>(machine: &mut Mac, addr: u64) -> Result<[u8; 32], VMError> {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&load_bytes(machine, addr, 32, 32)?);
+    Ok(hash)
+}
+
+fn load_amount<Mac: SupportMachine>(machine: &mut Mac, addr: u64) -> Result<u128, VMError> {
+    let mut amount = [0u8; 16];
+    amount.copy_from_slice(&load_bytes(machine, addr, 16, 16)?);
+    Ok(u128::from_le_bytes(amount))
+}
+
+fn store_data<Mac: SupportMachine>(machine: &mut Mac, data: &[u8]) -> Result<u64, VMError> {
+    let addr = machine.registers()[A0].to_u64();
+    let size_addr = machine.registers()[A1].clone();
+    let data_len = data.len() as u64;
+    let offset = cmp::min(data_len, machine.registers()[A2].to_u64());
+
+    let size = machine.memory_mut().load64(&size_addr)?.to_u64();
+    let full_size = data_len - offset;
+    let real_size = cmp::min(size, full_size);
+    machine
+        .memory_mut()
+        .store64(&size_addr, &Mac::REG::from_u64(full_size))?;
+    machine
+        .memory_mut()
+        .store_bytes(addr, &data[offset as usize..(offset + real_size) as usize])?;
+    Ok(real_size)
+}
@@ -0,0 +1,246 @@
+//! Txs of the challenge contract
+//!
+//! `ChallengeBuilder` creates a challenge cell of a block. The challenge cell is unlocked by:
+//!
+//! * `WithdrawChallengeBuilder`, the challenger withdraws the bond after
+//!   `WITHDRAW_CHALLENGE_WAIT_EPOCHS`
+//! * `RevertBlockBuilder`, the challenge cell reverts the block in the main contract
+//! * `InvalidChallengeBuilder`, the block is proved valid and the bond is collected
+
+use crate::{
+    common::{check_account_root, load_rollup_cell, rollup_id},
+    config::BuilderConfig,
+    constants::{SINCE_EPOCH_FLAG, SINCE_RELATIVE_FLAG, WITHDRAW_CHALLENGE_WAIT_EPOCHS},
+    error::Error,
+    provider::{AccountEntry, LiveCell, StateProvider},
+    skeleton::{occupied_capacity, TransactionSkeleton},
+    witness::{challenge_unlock_witness, output_type_witness, pack_proof_items},
+};
+use godwoken_executor::state::touched_accounts;
+use godwoken_types::{core::Index, packed::*, prelude::*, signing::tx_hash};
+use godwoken_utils::{
+    hash::block_hash,
+    mmr::merkle_proof,
+    smt::{self, H256},
+};
+
+/// length of the epoch fraction in a since, the since waits for whole epochs
+const SINCE_EPOCH_LENGTH: u64 = 1 << 40;
+
+/// the `ChallengeContext` in the data of a challenge cell
+pub(crate) fn load_challenge_context(challenge_cell: &LiveCell) -> Result<ChallengeContext, Error> {
+    ChallengeContext::from_slice(&challenge_cell.data).map_err(|_| Error::InvalidChallengeCell)
+}
+
+/// Creates a challenge cell of a block, the challenger gets the reward if the block is reverted
+pub struct ChallengeBuilder {
+    block: AgBlock,
+    txs: Vec<Tx>,
+    challenger_index: Index,
+}
+
+impl ChallengeBuilder {
+    pub fn new(block: AgBlock, txs: Vec<Tx>, challenger_index: Index) -> Self {
+        ChallengeBuilder {
+            block,
+            txs,
+            challenger_index,
+        }
+    }
+
+    pub fn build<P: StateProvider>(
+        &self,
+        provider: &P,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        let (rollup_cell, _global_state) = load_rollup_cell(provider)?;
+        let number: u64 = self.block.number().unpack();
+        if provider.get_block_hash(number)? != Some(block_hash(&self.block)) {
+            return Err(Error::MissingBlock(number));
+        }
+        let (context, proof) =
+            challenge_context(self.block.clone(), self.txs.clone(), self.challenger_index)?;
+        let type_script = challenge_type_script(
+            config.challenge_code_hash,
+            config.challenge_hash_type,
+            rollup_id(&rollup_cell)?,
+            config.withdraw_lock_hash,
+        );
+        let output = CellOutput::new_builder()
+            .lock(config.challenge_lock.clone())
+            .type_(Some(type_script).pack())
+            .build();
+        // the bond covers at least the block and txs in the data
+        let capacity = config
+            .challenge_capacity
+            .max(occupied_capacity(&output, context.as_slice()));
+        let output = output.as_builder().capacity(capacity.pack()).build();
+
+        let mut skeleton = TransactionSkeleton::new(config.cell_deps.clone());
+        skeleton.output(output, context.as_bytes());
+        // the proof is in the witness of the challenge cell's output
+        skeleton.witness(output_type_witness(proof.as_bytes()));
+        skeleton.complete(provider.payment_cells()?, config)
+    }
+}
+
+/// Withdraws the bond of a challenge cell to the change lock,
+/// a cell of the `withdraw_lock_hash` must unlock the tx.
+pub struct WithdrawChallengeBuilder {
+    challenge_cell: LiveCell,
+}
+
+impl WithdrawChallengeBuilder {
+    pub fn new(challenge_cell: LiveCell) -> Self {
+        WithdrawChallengeBuilder { challenge_cell }
+    }
+
+    pub fn build<P: StateProvider>(
+        &self,
+        provider: &P,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        let since = SINCE_RELATIVE_FLAG
+            | SINCE_EPOCH_FLAG
+            | SINCE_EPOCH_LENGTH
+            | WITHDRAW_CHALLENGE_WAIT_EPOCHS;
+        let mut skeleton = TransactionSkeleton::new(config.cell_deps.clone());
+        skeleton.input(&self.challenge_cell, since);
+        skeleton.witness(challenge_unlock_witness(WithdrawChallenge::default()));
+        skeleton.complete(provider.payment_cells()?, config)
+    }
+}
+
+/// Proves the challenged block valid, the bond of the challenge cell goes to the change lock.
+///
+/// The challenge contract re-executes the block's txs on the touched accounts,
+/// the provider serves accounts before the block.
+pub struct InvalidChallengeBuilder {
+    challenge_cell: LiveCell,
+}
+
+impl InvalidChallengeBuilder {
+    pub fn new(challenge_cell: LiveCell) -> Self {
+        InvalidChallengeBuilder { challenge_cell }
+    }
+
+    pub fn build<P: StateProvider>(
+        &self,
+        provider: &P,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        let context = load_challenge_context(&self.challenge_cell)?;
+        let block = context.block();
+        let txs: Vec<Tx> = context.txs().into_iter().collect();
+        check_account_root(provider, block.prev_account_root().unpack())?;
+
+        // missing accounts are skipped, the block can't touch them
+        let mut accounts: Vec<AccountEntry> = Vec::new();
+        for index in touched_accounts(&txs, block.ag_index().unpack()) {
+            if let Some(entry) = provider.get_account(index)? {
+                accounts.push(entry);
+            }
+        }
+        let proof = provider.account_merkle_proof(touched_accounts_keys(&accounts))?;
+        let unlock = invalid_challenge(&accounts, proof);
+
+        let mut skeleton = TransactionSkeleton::new(config.cell_deps.clone());
+        skeleton.input(&self.challenge_cell, 0);
+        skeleton.witness(challenge_unlock_witness(unlock));
+        skeleton.complete(provider.payment_cells()?, config)
+    }
+}
+
+/// the `ChallengeContext` of a block and the proof of its txs for the challenge contract
+pub fn challenge_context(
+    block: AgBlock,
+    txs: Vec<Tx>,
+    challenger_index: Index,
+) -> Result<(ChallengeContext, ChallengeProof), Error> {
+    let tx_hashes: Vec<[u8; 32]> = txs.iter().map(|tx| tx_hash(&tx.as_reader())).collect();
+    let indices = (0..tx_hashes.len() as u64).collect();
+    let txs_proof = merkle_proof(tx_hashes, indices)?;
+    let context = ChallengeContext::new_builder()
+        .block(block)
+        .txs(TxVec::new_builder().set(txs).build())
+        .challenger_index(challenger_index.pack())
+        .build();
+    let proof = ChallengeProof::new_builder()
+        .txs_proof(pack_proof_items(txs_proof))
+        .build();
+    Ok((context, proof))
+}
+
+/// type script of the rollup's challenge cells
+pub fn challenge_type_script(
+    code_hash: [u8; 32],
+    hash_type: u8,
+    rollup_id: [u8; 32],
+    withdraw_lock_hash: [u8; 32],
+) -> Script {
+    let args = ChallengeArgs::new_builder()
+        .main_type_hash(rollup_id.pack())
+        .withdraw_lock_hash(withdraw_lock_hash.pack())
+        .build();
+    Script::new_builder()
+        .code_hash(code_hash.pack())
+        .hash_type(hash_type.into())
+        .args(args.as_bytes().pack())
+        .build()
+}
+
+/// SMT keys of the accounts with all their tokens and storage slots
+pub fn touched_accounts_keys(accounts: &[AccountEntry]) -> Vec<H256> {
+    let mut keys = Vec::new();
+    for entry in accounts {
+        let index: Index = entry.account.index().unpack();
+        keys.extend(
+            entry
+                .kv
+                .keys()
+                .map(|token_id| smt::token_id_key(index, token_id)),
+        );
+        keys.extend(
+            entry
+                .withdraw_kv
+                .keys()
+                .map(|token_id| smt::withdraw_key(index, token_id)),
+        );
+        keys.extend(entry.storage.keys().map(|key| smt::storage_key(index, key)));
+        keys.push(smt::account_index_key(index));
+    }
+    keys
+}
+
+/// the `InvalidChallenge` unlock of the touched accounts before the block,
+/// `proof` is the merkle proof of `touched_accounts_keys`
+pub fn invalid_challenge(accounts: &[AccountEntry], proof: SMTProof) -> InvalidChallenge {
+    InvalidChallenge::new_builder()
+        .touched_accounts(
+            AccountVec::new_builder()
+                .set(accounts.iter().map(|entry| entry.account.clone()).collect())
+                .build(),
+        )
+        .touched_accounts_token_kv(
+            KeyValueMapVec::new_builder()
+                .set(accounts.iter().map(|entry| entry.kv.pack()).collect())
+                .build(),
+        )
+        .touched_accounts_withdraw_kv(
+            KeyValueMapVec::new_builder()
+                .set(
+                    accounts
+                        .iter()
+                        .map(|entry| entry.withdraw_kv.pack())
+                        .collect(),
+                )
+                .build(),
+        )
+        .touched_accounts_storage(
+            StorageKeyValueMapVec::new_builder()
+                .set(accounts.iter().map(|entry| entry.storage.pack()).collect())
+                .build(),
+        )
+        .touched_accounts_proof(proof)
+        .build()
+}
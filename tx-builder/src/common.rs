@@ -0,0 +1,90 @@
+use crate::{
+    constants::CKB_TOKEN_ID,
+    error::Error,
+    provider::{AccountEntry, LiveCell, StateProvider},
+};
+use godwoken_types::{cache::KVMap, core::Index, packed::*, prelude::*};
+use godwoken_utils::{
+    hash::blake2b_256,
+    smt::{self, Value, ValueTrait, H256},
+};
+
+/// the rollup cell and its global state
+pub fn load_rollup_cell<P: StateProvider>(provider: &P) -> Result<(LiveCell, GlobalState), Error> {
+    let rollup_cell = provider.rollup_cell()?;
+    let global_state =
+        GlobalState::from_slice(&rollup_cell.data).map_err(|_| Error::InvalidRollupCell)?;
+    Ok((rollup_cell, global_state))
+}
+
+/// the main type hash of the rollup cell, it's the `RollupID`
+pub fn rollup_id(rollup_cell: &LiveCell) -> Result<[u8; 32], Error> {
+    let type_script = rollup_cell
+        .output
+        .type_()
+        .to_opt()
+        .ok_or(Error::InvalidRollupCell)?;
+    Ok(blake2b_256(type_script.as_slice()))
+}
+
+/// the accounts of the provider must be at `account_root`
+pub fn check_account_root<P: StateProvider>(
+    provider: &P,
+    account_root: [u8; 32],
+) -> Result<(), Error> {
+    let provider_root = provider.account_root()?;
+    if provider_root != account_root {
+        return Err(Error::AccountRootMismatch(account_root, provider_root));
+    }
+    Ok(())
+}
+
+/// an account and its CKB balance
+pub fn load_account<P: StateProvider>(
+    provider: &P,
+    index: Index,
+) -> Result<(Account, KVMap), Error> {
+    let AccountEntry { account, kv, .. } = provider
+        .get_account(index)?
+        .ok_or(Error::MissingAccount(index))?;
+    let mut ckb_kv = KVMap::default();
+    ckb_kv.insert(
+        CKB_TOKEN_ID,
+        kv.get(&CKB_TOKEN_ID).copied().unwrap_or_default(),
+    );
+    Ok((account, ckb_kv))
+}
+
+/// SMT keys of an account and its tokens
+pub fn account_keys(index: Index, kv: &KVMap) -> Vec<H256> {
+    let mut keys = vec![smt::account_index_key(index)];
+    keys.extend(kv.keys().map(|token_id| smt::token_id_key(index, token_id)));
+    keys
+}
+
+/// SMT leaves of an account and its tokens, a missing account is a zero leaf
+pub fn account_leaves(index: Index, account: Option<&Account>, kv: &KVMap) -> Vec<(H256, H256)> {
+    let mut leaves: Vec<_> = kv
+        .iter()
+        .map(|(k, v)| (smt::token_id_key(index, k), Value::from(*v).to_h256()))
+        .collect();
+    let value = account
+        .map(|account| Value::from(account.clone()))
+        .unwrap_or_else(Value::zero);
+    leaves.push((smt::account_index_key(index), value.to_h256()));
+    leaves
+}
+
+/// root of the leaves with an SMT proof
+pub fn compute_account_root(
+    leaves: Vec<(H256, H256)>,
+    proof: &SMTProof,
+) -> Result<[u8; 32], Error> {
+    let leaves_path = proof.leaves_path().unpack();
+    let merkle_branches: Vec<(H256, u8)> = Unpack::<Vec<([u8; 32], u8)>>::unpack(&proof.proof())
+        .into_iter()
+        .map(|(node, height)| (node.into(), height))
+        .collect();
+    let root = smt::compute_root_with_proof(leaves, leaves_path, merkle_branches)?;
+    Ok(root.into())
+}
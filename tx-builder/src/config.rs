@@ -0,0 +1,21 @@
+use godwoken_types::packed::{CellDep, Script};
+
+/// Settings shared by the builders
+#[derive(Debug, Clone)]
+pub struct BuilderConfig {
+    /// cell deps of the txs, e.g. the main contract's and the challenge contract's code
+    pub cell_deps: Vec<CellDep>,
+    /// fee rate in shannons per 1000 bytes
+    pub fee_rate: u64,
+    /// lock of the payment cells and the change output
+    pub change_lock: Script,
+    /// capacity of a challenge cell, it's the bond of the challenge
+    pub challenge_capacity: u64,
+    /// lock of the challenge cells
+    pub challenge_lock: Script,
+    /// code hash of the challenge contract
+    pub challenge_code_hash: [u8; 32],
+    pub challenge_hash_type: u8,
+    /// lock hash of the cell which withdraws a challenge cell
+    pub withdraw_lock_hash: [u8; 32],
+}
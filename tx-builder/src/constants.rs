@@ -0,0 +1,13 @@
+pub use godwoken_types::core::{
+    CHALLENGE_CELL_WAIT_BLOCKS, CHALLENGE_REWARD_RATE, CKB_TOKEN_ID, WITHDRAW_CHALLENGE_WAIT_EPOCHS,
+};
+
+/// shannons of a CKByte, a cell's capacity covers its occupied bytes
+pub const BYTE_SHANNONS: u64 = 100_000_000;
+/// serialized size of the witness which unlocks the payment cells,
+/// a `WitnessArgs` with a 65 bytes signature in `lock`
+pub const SIGNATURE_WITNESS_SIZE: usize = 85;
+/// flag of a relative since
+pub const SINCE_RELATIVE_FLAG: u64 = 1 << 63;
+/// metric flag of a since in epochs, a since without the flag is in block numbers
+pub const SINCE_EPOCH_FLAG: u64 = 1 << 61;
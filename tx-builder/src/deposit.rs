@@ -0,0 +1,74 @@
+use crate::{
+    common::{
+        account_keys, account_leaves, check_account_root, compute_account_root, load_account,
+        load_rollup_cell,
+    },
+    config::BuilderConfig,
+    constants::CKB_TOKEN_ID,
+    error::Error,
+    provider::StateProvider,
+    skeleton::TransactionSkeleton,
+    witness::action_witness,
+};
+use godwoken_types::{core::Index, packed::*, prelude::*};
+
+/// Deposits capacity to the CKB balance of a registered account
+pub struct DepositBuilder {
+    index: Index,
+    deposit_capacity: u64,
+}
+
+impl DepositBuilder {
+    pub fn new(index: Index, deposit_capacity: u64) -> Self {
+        DepositBuilder {
+            index,
+            deposit_capacity,
+        }
+    }
+
+    pub fn build<P: StateProvider>(
+        &self,
+        provider: &P,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        let (rollup_cell, global_state) = load_rollup_cell(provider)?;
+        check_account_root(provider, global_state.account_root().unpack())?;
+        let (account, kv) = load_account(provider, self.index)?;
+        let proof = provider.account_merkle_proof(account_keys(self.index, &kv))?;
+
+        let mut new_kv = kv.clone();
+        let balance = new_kv.entry(CKB_TOKEN_ID).or_default();
+        *balance = balance
+            .checked_add(u128::from(self.deposit_capacity))
+            .ok_or(Error::CapacityOverflow)?;
+        let new_account_root =
+            compute_account_root(account_leaves(self.index, Some(&account), &new_kv), &proof)?;
+        let new_global_state = global_state
+            .as_builder()
+            .account_root(new_account_root.pack())
+            .build();
+        let deposit = Deposit::new_builder()
+            .account(account)
+            .token_kv(kv.pack())
+            .proof(proof)
+            .build();
+
+        let rollup_capacity: u64 = rollup_cell.output.capacity().unpack();
+        let output_capacity = rollup_capacity
+            .checked_add(self.deposit_capacity)
+            .ok_or(Error::CapacityOverflow)?;
+        let mut skeleton = TransactionSkeleton::new(config.cell_deps.clone());
+        skeleton.input(&rollup_cell, 0);
+        skeleton.output(
+            rollup_cell
+                .output
+                .clone()
+                .as_builder()
+                .capacity(output_capacity.pack())
+                .build(),
+            new_global_state.as_bytes(),
+        );
+        skeleton.witness(action_witness(deposit));
+        skeleton.complete(provider.payment_cells()?, config)
+    }
+}
@@ -0,0 +1,38 @@
+use godwoken_types::core::Index;
+use godwoken_utils::mmr;
+use sparse_merkle_tree::error::Error as SMTError;
+
+#[derive(Debug)]
+pub enum Error {
+    SMT(SMTError),
+    MMR(mmr::Error),
+    /// error of the state provider
+    Provider(String),
+    /// account index
+    MissingAccount(Index),
+    /// block number
+    MissingBlock(u64),
+    /// the rollup cell has no type script or its data isn't a `GlobalState`
+    InvalidRollupCell,
+    /// the challenge cell's data isn't a `ChallengeContext`
+    InvalidChallengeCell,
+    /// expected account root, account root of the provider
+    AccountRootMismatch([u8; 32], [u8; 32]),
+    /// block count of the global state, block's number
+    InvalidBlockNumber(u64, u64),
+    /// required capacity, capacity of the inputs
+    InsufficientCapacity(u64, u64),
+    CapacityOverflow,
+}
+
+impl From<SMTError> for Error {
+    fn from(err: SMTError) -> Self {
+        Error::SMT(err)
+    }
+}
+
+impl From<mmr::Error> for Error {
+    fn from(err: mmr::Error) -> Self {
+        Error::MMR(err)
+    }
+}
@@ -0,0 +1,20 @@
+//! Builders of the rollup's layer-1 txs
+//!
+//! Each action of the main contract and the challenge contract has a typed builder.
+//! A builder loads accounts, merkle proofs and the rollup cell from a `StateProvider`,
+//! assembles the inputs, outputs, cell deps and witnesses of the action,
+//! then pays the capacity and the fee with the provider's payment cells.
+//! The returned txs aren't signed, the payment cells are unlocked by the caller.
+
+pub mod challenge;
+mod common;
+pub mod config;
+pub mod constants;
+pub mod deposit;
+pub mod error;
+pub mod provider;
+pub mod register;
+pub mod revert_block;
+pub mod skeleton;
+pub mod submit_block;
+pub mod witness;
@@ -0,0 +1,48 @@
+use crate::error::Error;
+use godwoken_types::{
+    bytes::Bytes,
+    cache::{KVMap, StorageMap},
+    core::Index,
+    packed::*,
+};
+use godwoken_utils::smt::H256;
+
+/// A live cell on layer 1
+#[derive(Debug, Clone)]
+pub struct LiveCell {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub data: Bytes,
+}
+
+/// State of an account with all its tokens and storage slots
+#[derive(Debug, Clone)]
+pub struct AccountEntry {
+    pub account: Account,
+    pub kv: KVMap,
+    pub withdraw_kv: KVMap,
+    pub storage: StorageMap,
+}
+
+/// Provides the rollup's state to the builders.
+///
+/// Accounts are served at `account_root`, it's the rollup cell's account root for
+/// `Register`, `Deposit` and `SubmitBlock`. `RevertBlock` and `InvalidChallenge` prove
+/// accounts before the challenged block, the provider must serve the block's
+/// `prev_account_root` for them, e.g. an aggregator resets its store to that root.
+pub trait StateProvider {
+    /// the live rollup cell, its data is the `GlobalState`
+    fn rollup_cell(&self) -> Result<LiveCell, Error>;
+    /// root of the served accounts
+    fn account_root(&self) -> Result<[u8; 32], Error>;
+    /// state of an account, `None` if it isn't registered
+    fn get_account(&self, index: Index) -> Result<Option<AccountEntry>, Error>;
+    /// merkle proof of SMT keys against `account_root`
+    fn account_merkle_proof(&self, keys: Vec<H256>) -> Result<SMTProof, Error>;
+    /// hash of a block committed in the rollup cell's block root
+    fn get_block_hash(&self, number: u64) -> Result<Option<[u8; 32]>, Error>;
+    /// merkle proof of a block against the rollup cell's block root
+    fn block_merkle_proof(&self, number: u64) -> Result<Vec<[u8; 32]>, Error>;
+    /// cells locked by the change lock, they pay the capacity and the fee
+    fn payment_cells(&self) -> Result<Vec<LiveCell>, Error>;
+}
@@ -0,0 +1,80 @@
+use crate::{
+    common::{
+        account_keys, account_leaves, check_account_root, compute_account_root, load_rollup_cell,
+    },
+    config::BuilderConfig,
+    constants::CKB_TOKEN_ID,
+    error::Error,
+    provider::StateProvider,
+    skeleton::TransactionSkeleton,
+    witness::action_witness,
+};
+use godwoken_types::{cache::KVMap, core::Index, packed::*, prelude::*};
+
+/// Registers a new account, the deposited capacity is the account's CKB balance
+pub struct RegisterBuilder {
+    account: Account,
+    deposit_capacity: u64,
+}
+
+impl RegisterBuilder {
+    /// The account's index is assigned from the global state's account count,
+    /// `deposit_capacity` should be at least the main contract's `NEW_ACCOUNT_REQUIRED_BALANCE`.
+    pub fn new(account: Account, deposit_capacity: u64) -> Self {
+        RegisterBuilder {
+            account,
+            deposit_capacity,
+        }
+    }
+
+    pub fn build<P: StateProvider>(
+        &self,
+        provider: &P,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        let (rollup_cell, global_state) = load_rollup_cell(provider)?;
+        let old_account_root: [u8; 32] = global_state.account_root().unpack();
+        check_account_root(provider, old_account_root)?;
+        let index: Index = global_state.account_count().unpack();
+        let account = self
+            .account
+            .clone()
+            .as_builder()
+            .index(index.pack())
+            .build();
+        let mut kv = KVMap::default();
+        kv.insert(CKB_TOKEN_ID, u128::from(self.deposit_capacity));
+
+        // non-inclusion proof of the account
+        let proof = provider.account_merkle_proof(account_keys(index, &kv))?;
+        let new_account_root =
+            compute_account_root(account_leaves(index, Some(&account), &kv), &proof)?;
+        let new_global_state = global_state
+            .as_builder()
+            .account_root(new_account_root.pack())
+            .account_count((index + 1).pack())
+            .build();
+        let register = Register::new_builder()
+            .account(account)
+            .proof(proof)
+            .build();
+
+        let rollup_capacity: u64 = rollup_cell.output.capacity().unpack();
+        let output_capacity = rollup_capacity
+            .checked_add(self.deposit_capacity)
+            .ok_or(Error::CapacityOverflow)?;
+        let mut skeleton = TransactionSkeleton::new(config.cell_deps.clone());
+        skeleton.input(&rollup_cell, 0);
+        skeleton.output(
+            rollup_cell
+                .output
+                .clone()
+                .as_builder()
+                .capacity(output_capacity.pack())
+                .build(),
+            new_global_state.as_bytes(),
+        );
+        skeleton.witness(action_witness(register));
+        skeleton.complete(provider.payment_cells()?, config)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    challenge::load_challenge_context,
+    common::{
+        account_keys, account_leaves, check_account_root, compute_account_root, load_account,
+        load_rollup_cell,
+    },
+    config::BuilderConfig,
+    constants::{
+        CHALLENGE_CELL_WAIT_BLOCKS, CHALLENGE_REWARD_RATE, CKB_TOKEN_ID, SINCE_RELATIVE_FLAG,
+    },
+    error::Error,
+    provider::{LiveCell, StateProvider},
+    skeleton::TransactionSkeleton,
+    witness::{action_witness, challenge_unlock_witness, pack_proof_items},
+};
+use godwoken_types::{core::Index, packed::*, prelude::*};
+use godwoken_utils::{
+    hash::{blake2b_256, block_hash},
+    mmr::compute_block_root,
+};
+
+/// Reverts the challenged block with a challenge cell, it unlocks the challenge cell
+/// with `RevertBlockWithChallenge`.
+///
+/// The main contract only reverts the last block and the challenge cell must wait for
+/// `CHALLENGE_CELL_WAIT_BLOCKS`. The provider serves accounts before the block.
+pub struct RevertBlockBuilder {
+    challenge_cell: LiveCell,
+}
+
+impl RevertBlockBuilder {
+    pub fn new(challenge_cell: LiveCell) -> Self {
+        RevertBlockBuilder { challenge_cell }
+    }
+
+    pub fn build<P: StateProvider>(
+        &self,
+        provider: &P,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        let (rollup_cell, global_state) = load_rollup_cell(provider)?;
+        let context = load_challenge_context(&self.challenge_cell)?;
+        let block = context.block();
+        let number: u64 = block.number().unpack();
+        let block_count: u64 = global_state.block_count().unpack();
+        if number + 1 != block_count {
+            return Err(Error::InvalidBlockNumber(block_count, number));
+        }
+        check_account_root(provider, block.prev_account_root().unpack())?;
+
+        // accounts before the block
+        let ag_index: Index = block.ag_index().unpack();
+        let challenger_index: Index = context.challenger_index().unpack();
+        let (ag_account, ag_kv) = load_account(provider, ag_index)?;
+        let (challenger_account, challenger_kv) = load_account(provider, challenger_index)?;
+        let mut keys = account_keys(ag_index, &ag_kv);
+        keys.extend(account_keys(challenger_index, &challenger_kv));
+        let accounts_proof = provider.account_merkle_proof(keys)?;
+
+        // the aggregator's balance is the reward of the challenger, same as the main contract
+        let ag_balance = ag_kv[&CKB_TOKEN_ID];
+        let reward = ag_balance.saturating_mul(CHALLENGE_REWARD_RATE.0) / CHALLENGE_REWARD_RATE.1;
+        let mut reverted_ag_kv = ag_kv.clone();
+        reverted_ag_kv.insert(CKB_TOKEN_ID, 0);
+        let mut reverted_challenger_kv = challenger_kv.clone();
+        reverted_challenger_kv.insert(
+            CKB_TOKEN_ID,
+            challenger_kv[&CKB_TOKEN_ID]
+                .checked_add(reward)
+                .ok_or(Error::CapacityOverflow)?,
+        );
+        let mut leaves = account_leaves(ag_index, Some(&ag_account), &reverted_ag_kv);
+        leaves.extend(account_leaves(
+            challenger_index,
+            Some(&challenger_account),
+            &reverted_challenger_kv,
+        ));
+        let reverted_account_root = compute_account_root(leaves, &accounts_proof)?;
+        let reverted_block =
+            AgBlock::new_reverted_block(block.as_reader(), reverted_account_root, challenger_index);
+        let block_proof = provider.block_merkle_proof(number)?;
+        let reverted_block_root = compute_block_root(
+            vec![(number as usize, block_hash(&reverted_block))],
+            number + 1,
+            block_proof.clone(),
+        )?;
+        let new_global_state = global_state
+            .as_builder()
+            .account_root(reverted_account_root.pack())
+            .block_root(reverted_block_root.pack())
+            .build();
+        let revert_block = RevertBlock::new_builder()
+            .challenge_cell_data_hash(blake2b_256(&self.challenge_cell.data).pack())
+            .block_proof(pack_proof_items(block_proof))
+            .ag_account(ag_account)
+            .challenger_account(challenger_account)
+            .aggregator_token_kv(ag_kv.pack())
+            .challenger_token_kv(challenger_kv.pack())
+            .accounts_proof(accounts_proof)
+            .build();
+
+        // the challenge contract requires the challenge cell in the outputs
+        let mut skeleton = TransactionSkeleton::new(config.cell_deps.clone());
+        skeleton.input(&rollup_cell, 0);
+        skeleton.input(
+            &self.challenge_cell,
+            SINCE_RELATIVE_FLAG | CHALLENGE_CELL_WAIT_BLOCKS,
+        );
+        skeleton.output(rollup_cell.output.clone(), new_global_state.as_bytes());
+        skeleton.output(
+            self.challenge_cell.output.clone(),
+            self.challenge_cell.data.clone(),
+        );
+        skeleton.witness(action_witness(revert_block));
+        skeleton.witness(challenge_unlock_witness(RevertBlockWithChallenge::default()));
+        skeleton.complete(provider.payment_cells()?, config)
+    }
+}
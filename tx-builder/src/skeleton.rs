@@ -0,0 +1,170 @@
+//! Capacity and fee of the txs
+//!
+//! A builder assembles a `TransactionSkeleton` of its action, `complete` then adds
+//! payment cells as inputs until they cover the outputs, the fee and a change output.
+
+use crate::{
+    config::BuilderConfig,
+    constants::{BYTE_SHANNONS, SIGNATURE_WITNESS_SIZE},
+    error::Error,
+    provider::LiveCell,
+};
+use godwoken_types::{bytes::Bytes, packed::*, prelude::*};
+
+/// a tx in a block is prefixed by its offset
+const TX_OFFSET_SIZE: usize = 4;
+
+/// A tx under construction, the capacities of the inputs are tracked to balance the tx
+#[derive(Debug, Clone, Default)]
+pub struct TransactionSkeleton {
+    pub cell_deps: Vec<CellDep>,
+    /// inputs and the capacity of their cells
+    pub inputs: Vec<(CellInput, u64)>,
+    /// outputs and their data
+    pub outputs: Vec<(CellOutput, Bytes)>,
+    pub witnesses: Vec<Bytes>,
+}
+
+impl TransactionSkeleton {
+    pub fn new(cell_deps: Vec<CellDep>) -> Self {
+        TransactionSkeleton {
+            cell_deps,
+            ..Default::default()
+        }
+    }
+
+    pub fn input(&mut self, cell: &LiveCell, since: u64) {
+        let input = CellInput::new_builder()
+            .since(since.pack())
+            .previous_output(cell.out_point.clone())
+            .build();
+        self.inputs.push((input, cell.output.capacity().unpack()));
+    }
+
+    pub fn output(&mut self, output: CellOutput, data: Bytes) {
+        self.outputs.push((output, data));
+    }
+
+    pub fn witness(&mut self, witness: Bytes) {
+        self.witnesses.push(witness);
+    }
+
+    pub fn transaction(&self) -> Transaction {
+        let raw = RawTransaction::new_builder()
+            .cell_deps(self.cell_deps.clone().pack())
+            .inputs(
+                self.inputs
+                    .iter()
+                    .map(|(input, _capacity)| input.clone())
+                    .collect::<Vec<_>>()
+                    .pack(),
+            )
+            .outputs(
+                self.outputs
+                    .iter()
+                    .map(|(output, _data)| output.clone())
+                    .collect::<Vec<_>>()
+                    .pack(),
+            )
+            .outputs_data(
+                self.outputs
+                    .iter()
+                    .map(|(_output, data)| data.pack())
+                    .collect::<Vec<_>>()
+                    .pack(),
+            )
+            .build();
+        Transaction::new_builder()
+            .raw(raw)
+            .witnesses(
+                self.witnesses
+                    .iter()
+                    .map(|witness| witness.pack())
+                    .collect::<Vec<_>>()
+                    .pack(),
+            )
+            .build()
+    }
+
+    /// Pay the outputs and the fee with `payment_cells` in order,
+    /// the rest of the inputs' capacity goes to a change output of `config.change_lock`.
+    pub fn complete(
+        self,
+        payment_cells: Vec<LiveCell>,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        self.complete_with_lock(payment_cells, &config.change_lock, config.fee_rate)
+    }
+
+    /// same as `complete` with the change lock and the fee rate of a wallet
+    pub fn complete_with_lock(
+        mut self,
+        payment_cells: Vec<LiveCell>,
+        change_lock: &Script,
+        fee_rate: u64,
+    ) -> Result<Transaction, Error> {
+        let change_output = CellOutput::new_builder().lock(change_lock.clone()).build();
+        let change_capacity = occupied_capacity(&change_output, &[]);
+        let mut payment_cells = payment_cells.into_iter();
+        loop {
+            let input_capacity = sum_capacity(self.inputs.iter().map(|(_, capacity)| *capacity))?;
+            let output_capacity = sum_capacity(
+                self.outputs
+                    .iter()
+                    .map(|(output, _data)| output.capacity().unpack()),
+            )?;
+            // the fee covers the change output
+            let mut skeleton = self.clone();
+            skeleton.output(change_output.clone(), Bytes::new());
+            let fee = estimate_fee(&skeleton.transaction(), fee_rate);
+            let required = sum_capacity(vec![output_capacity, fee, change_capacity])?;
+            if input_capacity >= required {
+                let change = input_capacity - output_capacity - fee;
+                self.output(
+                    change_output.as_builder().capacity(change.pack()).build(),
+                    Bytes::new(),
+                );
+                return Ok(self.transaction());
+            }
+            let cell = payment_cells
+                .next()
+                .ok_or(Error::InsufficientCapacity(required, input_capacity))?;
+            if self.contains(&cell.out_point) {
+                continue;
+            }
+            self.input(&cell, 0);
+        }
+    }
+
+    fn contains(&self, out_point: &OutPoint) -> bool {
+        self.inputs
+            .iter()
+            .any(|(input, _)| input.previous_output().as_slice() == out_point.as_slice())
+    }
+}
+
+/// capacity of the bytes occupied by a cell
+pub fn occupied_capacity(output: &CellOutput, data: &[u8]) -> u64 {
+    fn script_size(script: &Script) -> usize {
+        let args: Bytes = script.args().unpack();
+        // code hash, hash type and args
+        32 + 1 + args.len()
+    }
+    let mut size = 8 + script_size(&output.lock()) + data.len();
+    if let Some(type_script) = output.type_().to_opt() {
+        size += script_size(&type_script);
+    }
+    size as u64 * BYTE_SHANNONS
+}
+
+/// fee of a tx with the witness of the payment cells' signature
+pub fn estimate_fee(tx: &Transaction, fee_rate: u64) -> u64 {
+    let size = (tx.as_slice().len() + TX_OFFSET_SIZE + SIGNATURE_WITNESS_SIZE) as u64;
+    (size * fee_rate + 999) / 1000
+}
+
+fn sum_capacity<I: IntoIterator<Item = u64>>(capacities: I) -> Result<u64, Error> {
+    capacities.into_iter().try_fold(0u64, |sum, capacity| {
+        sum.checked_add(capacity).ok_or(Error::CapacityOverflow)
+    })
+}
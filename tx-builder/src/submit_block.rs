@@ -0,0 +1,79 @@
+use crate::{
+    common::{account_keys, check_account_root, load_account, load_rollup_cell},
+    config::BuilderConfig,
+    error::Error,
+    provider::StateProvider,
+    skeleton::TransactionSkeleton,
+    witness::{action_witness, pack_proof_items},
+};
+use godwoken_types::{core::Index, packed::*, prelude::*};
+use godwoken_utils::{hash::block_hash, mmr::compute_new_block_root};
+
+/// Submits a signed block of the aggregator
+pub struct SubmitBlockBuilder {
+    block: AgBlock,
+    txs: Vec<Tx>,
+}
+
+impl SubmitBlockBuilder {
+    pub fn new(block: AgBlock, txs: Vec<Tx>) -> Self {
+        SubmitBlockBuilder { block, txs }
+    }
+
+    pub fn build<P: StateProvider>(
+        &self,
+        provider: &P,
+        config: &BuilderConfig,
+    ) -> Result<Transaction, Error> {
+        let (rollup_cell, global_state) = load_rollup_cell(provider)?;
+        let number: u64 = self.block.number().unpack();
+        let block_count: u64 = global_state.block_count().unpack();
+        if number != block_count {
+            return Err(Error::InvalidBlockNumber(block_count, number));
+        }
+        check_account_root(provider, global_state.account_root().unpack())?;
+        let ag_index: Index = self.block.ag_index().unpack();
+        let (ag_account, kv) = load_account(provider, ag_index)?;
+        let account_proof = provider.account_merkle_proof(account_keys(ag_index, &kv))?;
+
+        // the new block is appended after the last block
+        let (last_block_hash, block_proof) = if number == 0 {
+            ([0u8; 32], Vec::new())
+        } else {
+            let last_block_hash = provider
+                .get_block_hash(number - 1)?
+                .ok_or(Error::MissingBlock(number - 1))?;
+            (last_block_hash, provider.block_merkle_proof(number - 1)?)
+        };
+        let new_block_root = compute_new_block_root(
+            last_block_hash,
+            number.saturating_sub(1),
+            block_hash(&self.block),
+            number,
+            number + 1,
+            block_proof.clone(),
+        )?;
+        let new_global_state = global_state
+            .as_builder()
+            .account_root(self.block.account_root())
+            .block_root(new_block_root.pack())
+            .block_count((number + 1).pack())
+            .build();
+        let submit_block = SubmitBlock::new_builder()
+            .txs(TxVec::new_builder().set(self.txs.clone()).build())
+            .block(self.block.clone())
+            .last_block_hash(last_block_hash.pack())
+            .block_proof(pack_proof_items(block_proof))
+            .ag_account(ag_account)
+            .token_kv(kv.pack())
+            .account_proof(account_proof)
+            .build();
+
+        // the rollup cell's capacity is unchanged
+        let mut skeleton = TransactionSkeleton::new(config.cell_deps.clone());
+        skeleton.input(&rollup_cell, 0);
+        skeleton.output(rollup_cell.output.clone(), new_global_state.as_bytes());
+        skeleton.witness(action_witness(submit_block));
+        skeleton.complete(provider.payment_cells()?, config)
+    }
+}
@@ -0,0 +1,36 @@
+//! Witnesses of the rollup cell and the challenge cell
+//!
+//! The action of the main contract and the proof of a new challenge cell are put in
+//! the `output_type` of a `WitnessArgs`, a challenge cell is unlocked by a `ChallengeUnlock`.
+
+use godwoken_types::{bytes::Bytes, packed::*, prelude::*};
+
+pub fn pack_proof_items(items: Vec<[u8; 32]>) -> Byte32Vec {
+    items
+        .into_iter()
+        .map(|item| item.pack())
+        .collect::<Vec<_>>()
+        .pack()
+}
+
+/// a `WitnessArgs` with `data` in `output_type`
+pub fn output_type_witness(data: Bytes) -> Bytes {
+    WitnessArgs::new_builder()
+        .output_type(BytesOpt::new_builder().set(Some(data.pack())).build())
+        .build()
+        .as_bytes()
+}
+
+/// witness of the rollup cell, the action is in `output_type`
+pub fn action_witness<A: Into<ActionUnion>>(action: A) -> Bytes {
+    let action = Action::new_builder().set(action).build();
+    output_type_witness(action.as_bytes())
+}
+
+/// witness of the challenge cell's input
+pub fn challenge_unlock_witness<U: Into<ChallengeUnlockUnion>>(unlock: U) -> Bytes {
+    ChallengeUnlock::new_builder()
+        .set(unlock)
+        .build()
+        .as_bytes()
+}
@@ -1,8 +1,29 @@
-use crate::{collections::BTreeMap, packed::*};
+use crate::{
+    collections::BTreeMap,
+    packed::*,
+    signing::{raw_tx_hash, tx_hash},
+    vec::Vec,
+};
 
-pub type KVMap = BTreeMap<[u8; 32], u64>;
+/// token balances
+pub type KVMap = BTreeMap<[u8; 32], u128>;
+/// contract storage, an empty value represents a missing slot
+pub type StorageMap = BTreeMap<[u8; 32], Vec<u8>>;
 
 pub struct TxWithHash<'a> {
-    pub raw: TxReader<'a>,
+    pub tx: TxReader<'a>,
+    /// hash of the full tx, committed by the block's `tx_root`
     pub tx_hash: [u8; 32],
+    /// hash of the raw tx, signed by the sender
+    pub raw_tx_hash: [u8; 32],
+}
+
+impl<'a> TxWithHash<'a> {
+    pub fn new(tx: TxReader<'a>) -> Self {
+        TxWithHash {
+            tx_hash: tx_hash(&tx),
+            raw_tx_hash: raw_tx_hash(&tx.raw()),
+            tx,
+        }
+    }
 }
@@ -10,11 +10,13 @@ impl Pack<packed::Byte32> for [u8; 32] {
 
 impl<'r> Unpack<[u8; 32]> for packed::Byte32Reader<'r> {
     fn unpack(&self) -> [u8; 32] {
-        let ptr = self.as_slice().as_ptr() as *const [u8; 32];
-        unsafe { *ptr }
+        let mut buf = [0u8; 32];
+        buf.copy_from_slice(self.as_slice());
+        buf
     }
 }
 impl_conversion_for_entity_unpack!([u8; 32], Byte32);
+impl_try_pack_for_byte_array!(Byte32, 32);
 
 impl Pack<packed::ProposalShortId> for [u8; 10] {
     fn pack(&self) -> packed::ProposalShortId {
@@ -25,8 +27,9 @@ impl Pack<packed::ProposalShortId> for [u8; 10] {
 
 impl<'r> Unpack<[u8; 10]> for packed::ProposalShortIdReader<'r> {
     fn unpack(&self) -> [u8; 10] {
-        let ptr = self.as_slice().as_ptr() as *const [u8; 10];
-        unsafe { *ptr }
+        let mut buf = [0u8; 10];
+        buf.copy_from_slice(self.as_slice());
+        buf
     }
 }
 impl_conversion_for_entity_unpack!([u8; 10], ProposalShortId);
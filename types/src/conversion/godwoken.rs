@@ -1,6 +1,12 @@
-use crate::{bytes::Bytes, cache::KVMap, packed, prelude::*, vec::Vec};
-
-const CKB_TOKEN_ID: [u8; 32] = [0u8; 32];
+use crate::{
+    bytes::Bytes,
+    cache::{KVMap, StorageMap},
+    core::CKB_TOKEN_ID,
+    packed,
+    prelude::*,
+    vec::Vec,
+};
+use core::convert::TryFrom;
 
 impl Pack<packed::Byte20> for [u8; 20] {
     fn pack(&self) -> packed::Byte20 {
@@ -10,11 +16,13 @@ impl Pack<packed::Byte20> for [u8; 20] {
 
 impl<'r> Unpack<[u8; 20]> for packed::Byte20Reader<'r> {
     fn unpack(&self) -> [u8; 20] {
-        let ptr = self.as_slice().as_ptr() as *const [u8; 20];
-        unsafe { *ptr }
+        let mut buf = [0u8; 20];
+        buf.copy_from_slice(self.as_slice());
+        buf
     }
 }
 impl_conversion_for_entity_unpack!([u8; 20], Byte20);
+impl_try_pack_for_byte_array!(Byte20, 20);
 
 impl Pack<packed::Byte65> for [u8; 65] {
     fn pack(&self) -> packed::Byte65 {
@@ -24,11 +32,46 @@ impl Pack<packed::Byte65> for [u8; 65] {
 
 impl<'r> Unpack<[u8; 65]> for packed::Byte65Reader<'r> {
     fn unpack(&self) -> [u8; 65] {
-        let ptr = self.as_slice().as_ptr() as *const [u8; 65];
-        unsafe { *ptr }
+        let mut buf = [0u8; 65];
+        buf.copy_from_slice(self.as_slice());
+        buf
     }
 }
 impl_conversion_for_entity_unpack!([u8; 65], Byte65);
+impl_try_pack_for_byte_array!(Byte65, 65);
+
+impl Pack<packed::Byte48> for [u8; 48] {
+    fn pack(&self) -> packed::Byte48 {
+        packed::Byte48::from_slice(&self[..]).expect("impossible: fail to pack [u8; 48]")
+    }
+}
+
+impl<'r> Unpack<[u8; 48]> for packed::Byte48Reader<'r> {
+    fn unpack(&self) -> [u8; 48] {
+        let mut buf = [0u8; 48];
+        buf.copy_from_slice(self.as_slice());
+        buf
+    }
+}
+impl_conversion_for_entity_unpack!([u8; 48], Byte48);
+impl_try_pack_for_byte_array!(Byte48, 48);
+impl_conversion_for_option_pack!([u8; 48], Byte48Opt);
+
+impl Pack<packed::Byte96> for [u8; 96] {
+    fn pack(&self) -> packed::Byte96 {
+        packed::Byte96::from_slice(&self[..]).expect("impossible: fail to pack [u8; 96]")
+    }
+}
+
+impl<'r> Unpack<[u8; 96]> for packed::Byte96Reader<'r> {
+    fn unpack(&self) -> [u8; 96] {
+        let mut buf = [0u8; 96];
+        buf.copy_from_slice(self.as_slice());
+        buf
+    }
+}
+impl_conversion_for_entity_unpack!([u8; 96], Byte96);
+impl_try_pack_for_byte_array!(Byte96, 96);
 
 impl Pack<packed::KeyValueMap> for KVMap {
     fn pack(&self) -> packed::KeyValueMap {
@@ -50,13 +93,39 @@ impl<'r> Unpack<KVMap> for packed::KeyValueMapReader<'r> {
         for i in 0..self.item_count() {
             let kv_pair = self.get(i).unwrap();
             let key: [u8; 32] = kv_pair.key().unpack();
-            let value: u64 = kv_pair.value().unpack();
+            let value: u128 = kv_pair.value().unpack();
             kv_map.insert(key, value);
         }
         kv_map
     }
 }
 
+impl Pack<packed::StorageKeyValueMap> for StorageMap {
+    fn pack(&self) -> packed::StorageKeyValueMap {
+        let mut builder = packed::StorageKeyValueMapBuilder::default();
+        for (k, v) in self.iter() {
+            let kv_pair = packed::StorageKeyValueBuilder::default()
+                .key(k.pack())
+                .value(Bytes::from(v.clone()).pack())
+                .build();
+            builder = builder.push(kv_pair);
+        }
+        builder.build()
+    }
+}
+
+impl<'r> Unpack<StorageMap> for packed::StorageKeyValueMapReader<'r> {
+    fn unpack(&self) -> StorageMap {
+        let mut storage = StorageMap::default();
+        for kv_pair in self.iter() {
+            let key: [u8; 32] = kv_pair.key().unpack();
+            let value = kv_pair.value().raw_data().to_vec();
+            storage.insert(key, value);
+        }
+        storage
+    }
+}
+
 impl Pack<packed::SMTBranch> for ([u8; 32], u8) {
     fn pack(&self) -> packed::SMTBranch {
         let node: packed::Byte32 = self.0.pack();
@@ -95,36 +164,49 @@ impl<'r> Unpack<Vec<u8>> for packed::TreePathReader<'r> {
 impl_conversion_for_entity_unpack!(Vec<u8>, TreePath);
 impl_conversion_for_vector!(Vec<u8>, TreePathVec, TreePathVecReader);
 
-impl Pack<packed::Payment> for ([u8; 32], u64) {
-    fn pack(&self) -> packed::Payment {
-        let inner = if self.0 == CKB_TOKEN_ID {
-            packed::PaymentUnion::Uint32((self.1 as u32).pack())
-        } else {
-            packed::PaymentUnion::UDTPayment(
-                packed::UDTPayment::new_builder()
-                    .type_hash(self.0.pack())
-                    .amount((self.1 as u32).pack())
-                    .build(),
-            )
-        };
-        packed::Payment::new_builder().set(inner).build()
-    }
-}
-
-impl<'r> Unpack<([u8; 32], u64)> for packed::PaymentReader<'r> {
-    fn unpack(&self) -> ([u8; 32], u64) {
+impl<'r> Unpack<([u8; 32], u128)> for packed::PaymentReader<'r> {
+    fn unpack(&self) -> ([u8; 32], u128) {
         match self.to_enum() {
-            packed::PaymentUnionReader::Uint32(amount) => {
-                let amount: u32 = amount.unpack();
+            packed::PaymentUnionReader::Uint64(amount) => {
+                let amount: u64 = amount.unpack();
                 (CKB_TOKEN_ID, amount.into())
             }
             packed::PaymentUnionReader::UDTPayment(udt_payment) => {
                 let udt_type: [u8; 32] = udt_payment.type_hash().unpack();
-                let amount: u32 = udt_payment.amount().unpack();
-                (udt_type, amount.into())
+                let amount: u128 = udt_payment.amount().unpack();
+                (udt_type, amount)
             }
         }
     }
 }
 
-impl_conversion_for_entity_unpack!(([u8; 32], u64), Payment);
+impl_conversion_for_entity_unpack!(([u8; 32], u128), Payment);
+
+/// a CKB amount is in the range of `u64` whatever the encoding
+impl<'r> TryUnpack<([u8; 32], u128)> for packed::PaymentReader<'r> {
+    fn try_unpack(&self) -> Result<([u8; 32], u128), ConversionError> {
+        let (token_id, amount) = self.unpack();
+        if token_id == CKB_TOKEN_ID && amount > u128::from(u64::max_value()) {
+            return Err(ConversionError::Overflow);
+        }
+        Ok((token_id, amount))
+    }
+}
+impl_conversion_for_entity_try_unpack!(([u8; 32], u128), Payment);
+
+impl TryPack<packed::Payment> for ([u8; 32], u128) {
+    fn try_pack(&self) -> Result<packed::Payment, ConversionError> {
+        let inner = if self.0 == CKB_TOKEN_ID {
+            let amount = u64::try_from(self.1).map_err(|_| ConversionError::Overflow)?;
+            packed::PaymentUnion::Uint64(amount.pack())
+        } else {
+            packed::PaymentUnion::UDTPayment(
+                packed::UDTPayment::new_builder()
+                    .type_hash(self.0.pack())
+                    .amount(self.1.pack())
+                    .build(),
+            )
+        };
+        Ok(packed::Payment::new_builder().set(inner).build())
+    }
+}
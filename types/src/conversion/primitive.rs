@@ -1,4 +1,5 @@
 use crate::{bytes::Bytes, packed, prelude::*};
+use core::convert::TryFrom;
 
 #[cfg(not(feature = "std"))]
 use alloc::{borrow::ToOwned, str, string::String, vec::Vec};
@@ -23,35 +24,36 @@ impl Pack<packed::Uint128> for u128 {
     }
 }
 
-impl Pack<packed::Uint32> for usize {
-    fn pack(&self) -> packed::Uint32 {
-        (*self as u32).pack()
+impl TryPack<packed::Uint32> for usize {
+    fn try_pack(&self) -> Result<packed::Uint32, ConversionError> {
+        let value = u32::try_from(*self).map_err(|_| ConversionError::Overflow)?;
+        Ok(value.pack())
     }
 }
 
 impl<'r> Unpack<u32> for packed::Uint32Reader<'r> {
-    #[allow(clippy::cast_ptr_alignment)]
     fn unpack(&self) -> u32 {
-        let le = self.as_slice().as_ptr() as *const u32;
-        u32::from_le(unsafe { *le })
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(self.as_slice());
+        u32::from_le_bytes(buf)
     }
 }
 impl_conversion_for_entity_unpack!(u32, Uint32);
 
 impl<'r> Unpack<u64> for packed::Uint64Reader<'r> {
-    #[allow(clippy::cast_ptr_alignment)]
     fn unpack(&self) -> u64 {
-        let le = self.as_slice().as_ptr() as *const u64;
-        u64::from_le(unsafe { *le })
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(self.as_slice());
+        u64::from_le_bytes(buf)
     }
 }
 impl_conversion_for_entity_unpack!(u64, Uint64);
 
 impl<'r> Unpack<u128> for packed::Uint128Reader<'r> {
-    #[allow(clippy::cast_ptr_alignment)]
     fn unpack(&self) -> u128 {
-        let le = self.as_slice().as_ptr() as *const u128;
-        u128::from_le(unsafe { *le })
+        let mut buf = [0u8; 16];
+        buf.copy_from_slice(self.as_slice());
+        u128::from_le_bytes(buf)
     }
 }
 impl_conversion_for_entity_unpack!(u128, Uint128);
@@ -64,6 +66,14 @@ impl<'r> Unpack<usize> for packed::Uint32Reader<'r> {
 }
 impl_conversion_for_entity_unpack!(usize, Uint32);
 
+impl<'r> TryUnpack<usize> for packed::Uint32Reader<'r> {
+    fn try_unpack(&self) -> Result<usize, ConversionError> {
+        let x: u32 = self.unpack();
+        usize::try_from(x).map_err(|_| ConversionError::Overflow)
+    }
+}
+impl_conversion_for_entity_try_unpack!(usize, Uint32);
+
 impl Pack<packed::Bytes> for [u8] {
     fn pack(&self) -> packed::Bytes {
         let len = self.len();
@@ -81,6 +91,11 @@ impl<'r> Unpack<Vec<u8>> for packed::BytesReader<'r> {
 }
 impl_conversion_for_entity_unpack!(Vec<u8>, Bytes);
 
+// hashes, pubkey hashes and signatures in `Bytes`, e.g. script args and witnesses
+impl_try_unpack_for_bytes!(20);
+impl_try_unpack_for_bytes!(32);
+impl_try_unpack_for_bytes!(65);
+
 impl Pack<packed::Bytes> for str {
     fn pack(&self) -> packed::Bytes {
         self.as_bytes().pack()
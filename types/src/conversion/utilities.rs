@@ -10,6 +10,46 @@ macro_rules! impl_conversion_for_entity_unpack {
     };
 }
 
+macro_rules! impl_conversion_for_entity_try_unpack {
+    ($original:ty, $entity:ident) => {
+        impl TryUnpack<$original> for packed::$entity {
+            fn try_unpack(&self) -> Result<$original, ConversionError> {
+                self.as_reader().try_unpack()
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_pack_for_byte_array {
+    ($entity:ident, $len:expr) => {
+        impl TryPack<packed::$entity> for [u8] {
+            fn try_pack(&self) -> Result<packed::$entity, ConversionError> {
+                if self.len() != $len {
+                    return Err(ConversionError::InvalidLength($len, self.len()));
+                }
+                Ok(packed::$entity::new_unchecked(Bytes::from(self.to_vec())))
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_unpack_for_bytes {
+    ($len:expr) => {
+        impl<'r> TryUnpack<[u8; $len]> for packed::BytesReader<'r> {
+            fn try_unpack(&self) -> Result<[u8; $len], ConversionError> {
+                let data = self.raw_data();
+                if data.len() != $len {
+                    return Err(ConversionError::InvalidLength($len, data.len()));
+                }
+                let mut buf = [0u8; $len];
+                buf.copy_from_slice(data);
+                Ok(buf)
+            }
+        }
+        impl_conversion_for_entity_try_unpack!([u8; $len], Bytes);
+    };
+}
+
 macro_rules! impl_conversion_for_option_pack {
     ($original:ty, $entity:ident) => {
         impl Pack<packed::$entity> for Option<$original> {
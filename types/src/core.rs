@@ -20,3 +20,20 @@ impl Into<packed::Byte> for ScriptHashType {
 
 pub type TokenID = [u8; 32];
 pub type Index = u64;
+/// identifies a Godwoken deployment, the main contract's type hash
+pub type RollupID = [u8; 32];
+
+/// current version of `Tx`
+pub const TX_VERSION: u8 = 0;
+/// CKB token id
+pub const CKB_TOKEN_ID: TokenID = [0u8; 32];
+/// reward rate of a successful challenge, the rest of the aggregator's balance is burnt
+pub const CHALLENGE_REWARD_RATE: (u128, u128) = (8, 10);
+/// blocks a challenge cell waits before it reverts a block,
+/// the aggregator must invalidate the challenge during this time
+pub const CHALLENGE_CELL_WAIT_BLOCKS: u64 = 100;
+/// epochs a challenge cell waits before the challenger withdraws it
+pub const WITHDRAW_CHALLENGE_WAIT_EPOCHS: u64 = 6;
+/// max total size of the txs in a block, the challenge contract keeps a block's txs
+/// and their proofs in its heap
+pub const MAX_BLOCK_TXS_SIZE: usize = 64 * 1024;
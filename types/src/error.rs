@@ -0,0 +1,8 @@
+/// Errors of `TryPack` and `TryUnpack`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    /// the value exceeds the range of the target type
+    Overflow,
+    /// expected length, actual length
+    InvalidLength(usize, usize),
+}
@@ -13,11 +13,13 @@ impl AgBlock {
             .number(number.pack())
             .tx_root([0u8; 32].pack())
             .txs_count(0u32.pack())
+            .logs_root([0u8; 32].pack())
             .prev_account_root(prev_account_root.pack())
             .prev_account_count(prev_account_count.pack())
             .account_root(account_root.pack())
             .ag_sig([0u8; 65].pack())
             .ag_index(challenger_index.pack())
+            .aggregate_sig([0u8; 96].pack())
             .build()
     }
 }
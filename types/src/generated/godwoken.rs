@@ -418,8 +418,8 @@ impl molecule::prelude::Builder for Byte20Builder {
     }
 }
 #[derive(Clone)]
-pub struct Byte65(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Byte65 {
+pub struct Byte48(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Byte48 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -428,32 +428,31 @@ impl ::core::fmt::LowerHex for Byte65 {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Byte65 {
+impl ::core::fmt::Debug for Byte48 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Byte65 {
+impl ::core::fmt::Display for Byte48 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for Byte65 {
+impl ::core::default::Default for Byte48 {
     fn default() -> Self {
         let v: Vec<u8> = vec![
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        Byte65::new_unchecked(v.into())
+        Byte48::new_unchecked(v.into())
     }
 }
-impl Byte65 {
-    pub const TOTAL_SIZE: usize = 65;
+impl Byte48 {
+    pub const TOTAL_SIZE: usize = 48;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 65;
+    pub const ITEM_COUNT: usize = 48;
     pub fn nth0(&self) -> Byte {
         Byte::new_unchecked(self.0.slice(0, 1))
     }
@@ -598,69 +597,18 @@ impl Byte65 {
     pub fn nth47(&self) -> Byte {
         Byte::new_unchecked(self.0.slice(47, 48))
     }
-    pub fn nth48(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(48, 49))
-    }
-    pub fn nth49(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(49, 50))
-    }
-    pub fn nth50(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(50, 51))
-    }
-    pub fn nth51(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(51, 52))
-    }
-    pub fn nth52(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(52, 53))
-    }
-    pub fn nth53(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(53, 54))
-    }
-    pub fn nth54(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(54, 55))
-    }
-    pub fn nth55(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(55, 56))
-    }
-    pub fn nth56(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(56, 57))
-    }
-    pub fn nth57(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(57, 58))
-    }
-    pub fn nth58(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(58, 59))
-    }
-    pub fn nth59(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(59, 60))
-    }
-    pub fn nth60(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(60, 61))
-    }
-    pub fn nth61(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(61, 62))
-    }
-    pub fn nth62(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(62, 63))
-    }
-    pub fn nth63(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(63, 64))
-    }
-    pub fn nth64(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(64, 65))
-    }
     pub fn raw_data(&self) -> molecule::bytes::Bytes {
         self.as_bytes()
     }
-    pub fn as_reader<'r>(&'r self) -> Byte65Reader<'r> {
-        Byte65Reader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> Byte48Reader<'r> {
+        Byte48Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Byte65 {
-    type Builder = Byte65Builder;
-    const NAME: &'static str = "Byte65";
+impl molecule::prelude::Entity for Byte48 {
+    type Builder = Byte48Builder;
+    const NAME: &'static str = "Byte48";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Byte65(data)
+        Byte48(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -669,10 +617,10 @@ impl molecule::prelude::Entity for Byte65 {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        Byte65Reader::from_slice(slice).map(|reader| reader.to_entity())
+        Byte48Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        Byte65Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Byte48Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -727,29 +675,12 @@ impl molecule::prelude::Entity for Byte65 {
             self.nth45(),
             self.nth46(),
             self.nth47(),
-            self.nth48(),
-            self.nth49(),
-            self.nth50(),
-            self.nth51(),
-            self.nth52(),
-            self.nth53(),
-            self.nth54(),
-            self.nth55(),
-            self.nth56(),
-            self.nth57(),
-            self.nth58(),
-            self.nth59(),
-            self.nth60(),
-            self.nth61(),
-            self.nth62(),
-            self.nth63(),
-            self.nth64(),
         ])
     }
 }
 #[derive(Clone, Copy)]
-pub struct Byte65Reader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for Byte65Reader<'r> {
+pub struct Byte48Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Byte48Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -758,22 +689,22 @@ impl<'r> ::core::fmt::LowerHex for Byte65Reader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for Byte65Reader<'r> {
+impl<'r> ::core::fmt::Debug for Byte48Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for Byte65Reader<'r> {
+impl<'r> ::core::fmt::Display for Byte48Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> Byte65Reader<'r> {
-    pub const TOTAL_SIZE: usize = 65;
+impl<'r> Byte48Reader<'r> {
+    pub const TOTAL_SIZE: usize = 48;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 65;
+    pub const ITEM_COUNT: usize = 48;
     pub fn nth0(&self) -> ByteReader<'r> {
         ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
@@ -918,69 +849,18 @@ impl<'r> Byte65Reader<'r> {
     pub fn nth47(&self) -> ByteReader<'r> {
         ByteReader::new_unchecked(&self.as_slice()[47..48])
     }
-    pub fn nth48(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[48..49])
-    }
-    pub fn nth49(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[49..50])
-    }
-    pub fn nth50(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[50..51])
-    }
-    pub fn nth51(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[51..52])
-    }
-    pub fn nth52(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[52..53])
-    }
-    pub fn nth53(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[53..54])
-    }
-    pub fn nth54(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[54..55])
-    }
-    pub fn nth55(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[55..56])
-    }
-    pub fn nth56(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[56..57])
-    }
-    pub fn nth57(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[57..58])
-    }
-    pub fn nth58(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[58..59])
-    }
-    pub fn nth59(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[59..60])
-    }
-    pub fn nth60(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[60..61])
-    }
-    pub fn nth61(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[61..62])
-    }
-    pub fn nth62(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[62..63])
-    }
-    pub fn nth63(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[63..64])
-    }
-    pub fn nth64(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[64..65])
-    }
     pub fn raw_data(&self) -> &'r [u8] {
         self.as_slice()
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for Byte65Reader<'r> {
-    type Entity = Byte65;
-    const NAME: &'static str = "Byte65Reader";
+impl<'r> molecule::prelude::Reader<'r> for Byte48Reader<'r> {
+    type Entity = Byte48;
+    const NAME: &'static str = "Byte48Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        Byte65Reader(slice)
+        Byte48Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -994,32 +874,15 @@ impl<'r> molecule::prelude::Reader<'r> for Byte65Reader<'r> {
         Ok(())
     }
 }
-pub struct Byte65Builder(pub(crate) [Byte; 65]);
-impl ::core::fmt::Debug for Byte65Builder {
+pub struct Byte48Builder(pub(crate) [Byte; 48]);
+impl ::core::fmt::Debug for Byte48Builder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:?})", Self::NAME, &self.0[..])
     }
 }
-impl ::core::default::Default for Byte65Builder {
+impl ::core::default::Default for Byte48Builder {
     fn default() -> Self {
-        Byte65Builder([
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
+        Byte48Builder([
             Byte::default(),
             Byte::default(),
             Byte::default(),
@@ -1071,11 +934,11 @@ impl ::core::default::Default for Byte65Builder {
         ])
     }
 }
-impl Byte65Builder {
-    pub const TOTAL_SIZE: usize = 65;
+impl Byte48Builder {
+    pub const TOTAL_SIZE: usize = 48;
     pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 65;
-    pub fn set(mut self, v: [Byte; 65]) -> Self {
+    pub const ITEM_COUNT: usize = 48;
+    pub fn set(mut self, v: [Byte; 48]) -> Self {
         self.0 = v;
         self
     }
@@ -1271,78 +1134,10 @@ impl Byte65Builder {
         self.0[47] = v;
         self
     }
-    pub fn nth48(mut self, v: Byte) -> Self {
-        self.0[48] = v;
-        self
-    }
-    pub fn nth49(mut self, v: Byte) -> Self {
-        self.0[49] = v;
-        self
-    }
-    pub fn nth50(mut self, v: Byte) -> Self {
-        self.0[50] = v;
-        self
-    }
-    pub fn nth51(mut self, v: Byte) -> Self {
-        self.0[51] = v;
-        self
-    }
-    pub fn nth52(mut self, v: Byte) -> Self {
-        self.0[52] = v;
-        self
-    }
-    pub fn nth53(mut self, v: Byte) -> Self {
-        self.0[53] = v;
-        self
-    }
-    pub fn nth54(mut self, v: Byte) -> Self {
-        self.0[54] = v;
-        self
-    }
-    pub fn nth55(mut self, v: Byte) -> Self {
-        self.0[55] = v;
-        self
-    }
-    pub fn nth56(mut self, v: Byte) -> Self {
-        self.0[56] = v;
-        self
-    }
-    pub fn nth57(mut self, v: Byte) -> Self {
-        self.0[57] = v;
-        self
-    }
-    pub fn nth58(mut self, v: Byte) -> Self {
-        self.0[58] = v;
-        self
-    }
-    pub fn nth59(mut self, v: Byte) -> Self {
-        self.0[59] = v;
-        self
-    }
-    pub fn nth60(mut self, v: Byte) -> Self {
-        self.0[60] = v;
-        self
-    }
-    pub fn nth61(mut self, v: Byte) -> Self {
-        self.0[61] = v;
-        self
-    }
-    pub fn nth62(mut self, v: Byte) -> Self {
-        self.0[62] = v;
-        self
-    }
-    pub fn nth63(mut self, v: Byte) -> Self {
-        self.0[63] = v;
-        self
-    }
-    pub fn nth64(mut self, v: Byte) -> Self {
-        self.0[64] = v;
-        self
-    }
 }
-impl molecule::prelude::Builder for Byte65Builder {
-    type Entity = Byte65;
-    const NAME: &'static str = "Byte65Builder";
+impl molecule::prelude::Builder for Byte48Builder {
+    type Entity = Byte48;
+    const NAME: &'static str = "Byte48Builder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
@@ -1395,35 +1190,18 @@ impl molecule::prelude::Builder for Byte65Builder {
         writer.write_all(self.0[45].as_slice())?;
         writer.write_all(self.0[46].as_slice())?;
         writer.write_all(self.0[47].as_slice())?;
-        writer.write_all(self.0[48].as_slice())?;
-        writer.write_all(self.0[49].as_slice())?;
-        writer.write_all(self.0[50].as_slice())?;
-        writer.write_all(self.0[51].as_slice())?;
-        writer.write_all(self.0[52].as_slice())?;
-        writer.write_all(self.0[53].as_slice())?;
-        writer.write_all(self.0[54].as_slice())?;
-        writer.write_all(self.0[55].as_slice())?;
-        writer.write_all(self.0[56].as_slice())?;
-        writer.write_all(self.0[57].as_slice())?;
-        writer.write_all(self.0[58].as_slice())?;
-        writer.write_all(self.0[59].as_slice())?;
-        writer.write_all(self.0[60].as_slice())?;
-        writer.write_all(self.0[61].as_slice())?;
-        writer.write_all(self.0[62].as_slice())?;
-        writer.write_all(self.0[63].as_slice())?;
-        writer.write_all(self.0[64].as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Byte65::new_unchecked(inner.into())
+        Byte48::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct GlobalState(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for GlobalState {
+pub struct Byte65(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Byte65 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1432,275 +1210,239 @@ impl ::core::fmt::LowerHex for GlobalState {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for GlobalState {
+impl ::core::fmt::Debug for Byte65 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for GlobalState {
+impl ::core::fmt::Display for Byte65 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "account_root", self.account_root())?;
-        write!(f, ", {}: {}", "block_root", self.block_root())?;
-        write!(f, ", {}: {}", "account_count", self.account_count())?;
-        write!(f, ", {}: {}", "block_count", self.block_count())?;
-        write!(f, " }}")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for GlobalState {
+impl ::core::default::Default for Byte65 {
     fn default() -> Self {
         let v: Vec<u8> = vec![
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
         ];
-        GlobalState::new_unchecked(v.into())
+        Byte65::new_unchecked(v.into())
     }
 }
-impl GlobalState {
-    pub const TOTAL_SIZE: usize = 80;
-    pub const FIELD_SIZE: [usize; 4] = [32, 32, 8, 8];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn account_root(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0, 32))
+impl Byte65 {
+    pub const TOTAL_SIZE: usize = 65;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 65;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0, 1))
     }
-    pub fn block_root(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(32, 64))
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1, 2))
     }
-    pub fn account_count(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(64, 72))
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2, 3))
     }
-    pub fn block_count(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(72, 80))
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3, 4))
     }
-    pub fn as_reader<'r>(&'r self) -> GlobalStateReader<'r> {
-        GlobalStateReader::new_unchecked(self.as_slice())
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4, 5))
     }
-}
-impl molecule::prelude::Entity for GlobalState {
-    type Builder = GlobalStateBuilder;
-    const NAME: &'static str = "GlobalState";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        GlobalState(data)
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5, 6))
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6, 7))
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7, 8))
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GlobalStateReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8, 9))
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        GlobalStateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9, 10))
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10, 11))
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .account_root(self.account_root())
-            .block_root(self.block_root())
-            .account_count(self.account_count())
-            .block_count(self.block_count())
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11, 12))
     }
-}
-#[derive(Clone, Copy)]
-pub struct GlobalStateReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for GlobalStateReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12, 13))
     }
-}
-impl<'r> ::core::fmt::Debug for GlobalStateReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13, 14))
     }
-}
-impl<'r> ::core::fmt::Display for GlobalStateReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "account_root", self.account_root())?;
-        write!(f, ", {}: {}", "block_root", self.block_root())?;
-        write!(f, ", {}: {}", "account_count", self.account_count())?;
-        write!(f, ", {}: {}", "block_count", self.block_count())?;
-        write!(f, " }}")
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14, 15))
     }
-}
-impl<'r> GlobalStateReader<'r> {
-    pub const TOTAL_SIZE: usize = 80;
-    pub const FIELD_SIZE: [usize; 4] = [32, 32, 8, 8];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn account_root(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15, 16))
     }
-    pub fn block_root(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16, 17))
     }
-    pub fn account_count(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[64..72])
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17, 18))
     }
-    pub fn block_count(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[72..80])
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18, 19))
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for GlobalStateReader<'r> {
-    type Entity = GlobalState;
-    const NAME: &'static str = "GlobalStateReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19, 20))
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        GlobalStateReader(slice)
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20, 21))
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21, 22))
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
-        }
-        Ok(())
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22, 23))
     }
-}
-#[derive(Debug, Default)]
-pub struct GlobalStateBuilder {
-    pub(crate) account_root: Byte32,
-    pub(crate) block_root: Byte32,
-    pub(crate) account_count: Uint64,
-    pub(crate) block_count: Uint64,
-}
-impl GlobalStateBuilder {
-    pub const TOTAL_SIZE: usize = 80;
-    pub const FIELD_SIZE: [usize; 4] = [32, 32, 8, 8];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn account_root(mut self, v: Byte32) -> Self {
-        self.account_root = v;
-        self
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23, 24))
     }
-    pub fn block_root(mut self, v: Byte32) -> Self {
-        self.block_root = v;
-        self
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24, 25))
     }
-    pub fn account_count(mut self, v: Uint64) -> Self {
-        self.account_count = v;
-        self
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25, 26))
     }
-    pub fn block_count(mut self, v: Uint64) -> Self {
-        self.block_count = v;
-        self
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26, 27))
     }
-}
-impl molecule::prelude::Builder for GlobalStateBuilder {
-    type Entity = GlobalState;
-    const NAME: &'static str = "GlobalStateBuilder";
-    fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27, 28))
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(self.account_root.as_slice())?;
-        writer.write_all(self.block_root.as_slice())?;
-        writer.write_all(self.account_count.as_slice())?;
-        writer.write_all(self.block_count.as_slice())?;
-        Ok(())
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28, 29))
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        GlobalState::new_unchecked(inner.into())
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29, 30))
     }
-}
-#[derive(Clone)]
-pub struct AccountScript(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for AccountScript {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30, 31))
     }
-}
-impl ::core::fmt::Debug for AccountScript {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31, 32))
     }
-}
-impl ::core::fmt::Display for AccountScript {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "code_hash", self.code_hash())?;
-        write!(f, ", {}: {}", "args", self.args())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+    pub fn nth32(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32, 33))
     }
-}
-impl ::core::default::Default for AccountScript {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        AccountScript::new_unchecked(v.into())
+    pub fn nth33(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(33, 34))
     }
-}
-impl AccountScript {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth34(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(34, 35))
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn nth35(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(35, 36))
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth36(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(36, 37))
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn nth37(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(37, 38))
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn nth38(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(38, 39))
     }
-    pub fn code_hash(&self) -> Byte32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start, end))
+    pub fn nth39(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(39, 40))
     }
-    pub fn args(&self) -> Bytes {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[2][..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start, end))
-        } else {
-            Bytes::new_unchecked(self.0.slice_from(start))
-        }
+    pub fn nth40(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(40, 41))
     }
-    pub fn as_reader<'r>(&'r self) -> AccountScriptReader<'r> {
-        AccountScriptReader::new_unchecked(self.as_slice())
+    pub fn nth41(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(41, 42))
+    }
+    pub fn nth42(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(42, 43))
+    }
+    pub fn nth43(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(43, 44))
+    }
+    pub fn nth44(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(44, 45))
+    }
+    pub fn nth45(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(45, 46))
+    }
+    pub fn nth46(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(46, 47))
+    }
+    pub fn nth47(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(47, 48))
+    }
+    pub fn nth48(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(48, 49))
+    }
+    pub fn nth49(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(49, 50))
+    }
+    pub fn nth50(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(50, 51))
+    }
+    pub fn nth51(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(51, 52))
+    }
+    pub fn nth52(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(52, 53))
+    }
+    pub fn nth53(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(53, 54))
+    }
+    pub fn nth54(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(54, 55))
+    }
+    pub fn nth55(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(55, 56))
+    }
+    pub fn nth56(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(56, 57))
+    }
+    pub fn nth57(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(57, 58))
+    }
+    pub fn nth58(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(58, 59))
+    }
+    pub fn nth59(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(59, 60))
+    }
+    pub fn nth60(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(60, 61))
+    }
+    pub fn nth61(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(61, 62))
+    }
+    pub fn nth62(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(62, 63))
+    }
+    pub fn nth63(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(63, 64))
+    }
+    pub fn nth64(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(64, 65))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> Byte65Reader<'r> {
+        Byte65Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for AccountScript {
-    type Builder = AccountScriptBuilder;
-    const NAME: &'static str = "AccountScript";
+impl molecule::prelude::Entity for Byte65 {
+    type Builder = Byte65Builder;
+    const NAME: &'static str = "Byte65";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AccountScript(data)
+        Byte65(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1709,23 +1451,87 @@ impl molecule::prelude::Entity for AccountScript {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountScriptReader::from_slice(slice).map(|reader| reader.to_entity())
+        Byte65Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountScriptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Byte65Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .code_hash(self.code_hash())
-            .args(self.args())
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+            self.nth32(),
+            self.nth33(),
+            self.nth34(),
+            self.nth35(),
+            self.nth36(),
+            self.nth37(),
+            self.nth38(),
+            self.nth39(),
+            self.nth40(),
+            self.nth41(),
+            self.nth42(),
+            self.nth43(),
+            self.nth44(),
+            self.nth45(),
+            self.nth46(),
+            self.nth47(),
+            self.nth48(),
+            self.nth49(),
+            self.nth50(),
+            self.nth51(),
+            self.nth52(),
+            self.nth53(),
+            self.nth54(),
+            self.nth55(),
+            self.nth56(),
+            self.nth57(),
+            self.nth58(),
+            self.nth59(),
+            self.nth60(),
+            self.nth61(),
+            self.nth62(),
+            self.nth63(),
+            self.nth64(),
+        ])
     }
 }
 #[derive(Clone, Copy)]
-pub struct AccountScriptReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AccountScriptReader<'r> {
+pub struct Byte65Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Byte65Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1734,441 +1540,229 @@ impl<'r> ::core::fmt::LowerHex for AccountScriptReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for AccountScriptReader<'r> {
+impl<'r> ::core::fmt::Debug for Byte65Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for AccountScriptReader<'r> {
+impl<'r> ::core::fmt::Display for Byte65Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "code_hash", self.code_hash())?;
-        write!(f, ", {}: {}", "args", self.args())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> AccountScriptReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl<'r> Byte65Reader<'r> {
+    pub const TOTAL_SIZE: usize = 65;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 65;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
     }
-    pub fn code_hash(&self) -> Byte32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
     }
-    pub fn args(&self) -> BytesReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[2][..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
-        }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for AccountScriptReader<'r> {
-    type Entity = AccountScript;
-    const NAME: &'static str = "AccountScriptReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        AccountScriptReader(slice)
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        let field_count = offset_first / 4 - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
-        if slice_len < header_size {
-            return ve!(Self, HeaderIsBroken, header_size, slice_len);
-        }
-        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
-        let mut offsets: Vec<usize> = ptr[..field_count]
-            .iter()
-            .map(|x| molecule::unpack_number(&x[..]) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Ok(())
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
     }
-}
-#[derive(Debug, Default)]
-pub struct AccountScriptBuilder {
-    pub(crate) code_hash: Byte32,
-    pub(crate) args: Bytes,
-}
-impl AccountScriptBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn code_hash(mut self, v: Byte32) -> Self {
-        self.code_hash = v;
-        self
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
     }
-    pub fn args(mut self, v: Bytes) -> Self {
-        self.args = v;
-        self
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
     }
-}
-impl molecule::prelude::Builder for AccountScriptBuilder {
-    type Entity = AccountScript;
-    const NAME: &'static str = "AccountScriptBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.code_hash.as_slice().len()
-            + self.args.as_slice().len()
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.args.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.code_hash.as_slice())?;
-        writer.write_all(self.args.as_slice())?;
-        Ok(())
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AccountScript::new_unchecked(inner.into())
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
     }
-}
-#[derive(Clone)]
-pub struct AccountScriptOpt(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for AccountScriptOpt {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
     }
-}
-impl ::core::fmt::Debug for AccountScriptOpt {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
     }
-}
-impl ::core::fmt::Display for AccountScriptOpt {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        if let Some(v) = self.to_opt() {
-            write!(f, "{}(Some({}))", Self::NAME, v)
-        } else {
-            write!(f, "{}(None)", Self::NAME)
-        }
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
     }
-}
-impl ::core::default::Default for AccountScriptOpt {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![];
-        AccountScriptOpt::new_unchecked(v.into())
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
     }
-}
-impl AccountScriptOpt {
-    pub fn is_none(&self) -> bool {
-        self.0.is_empty()
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
     }
-    pub fn is_some(&self) -> bool {
-        !self.0.is_empty()
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
     }
-    pub fn to_opt(&self) -> Option<AccountScript> {
-        if self.is_none() {
-            None
-        } else {
-            Some(AccountScript::new_unchecked(self.0.clone()))
-        }
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
     }
-    pub fn as_reader<'r>(&'r self) -> AccountScriptOptReader<'r> {
-        AccountScriptOptReader::new_unchecked(self.as_slice())
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
     }
-}
-impl molecule::prelude::Entity for AccountScriptOpt {
-    type Builder = AccountScriptOptBuilder;
-    const NAME: &'static str = "AccountScriptOpt";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AccountScriptOpt(data)
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountScriptOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountScriptOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_opt())
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
     }
-}
-#[derive(Clone, Copy)]
-pub struct AccountScriptOptReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AccountScriptOptReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
     }
-}
-impl<'r> ::core::fmt::Debug for AccountScriptOptReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth32(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
     }
-}
-impl<'r> ::core::fmt::Display for AccountScriptOptReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        if let Some(v) = self.to_opt() {
-            write!(f, "{}(Some({}))", Self::NAME, v)
-        } else {
-            write!(f, "{}(None)", Self::NAME)
-        }
+    pub fn nth33(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[33..34])
     }
-}
-impl<'r> AccountScriptOptReader<'r> {
-    pub fn is_none(&self) -> bool {
-        self.0.is_empty()
+    pub fn nth34(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[34..35])
     }
-    pub fn is_some(&self) -> bool {
-        !self.0.is_empty()
+    pub fn nth35(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[35..36])
     }
-    pub fn to_opt(&self) -> Option<AccountScriptReader<'r>> {
-        if self.is_none() {
-            None
-        } else {
-            Some(AccountScriptReader::new_unchecked(self.as_slice()))
-        }
+    pub fn nth36(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[36..37])
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for AccountScriptOptReader<'r> {
-    type Entity = AccountScriptOpt;
-    const NAME: &'static str = "AccountScriptOptReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth37(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[37..38])
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        AccountScriptOptReader(slice)
+    pub fn nth38(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[38..39])
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth39(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[39..40])
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        if !slice.is_empty() {
-            AccountScriptReader::verify(&slice[..], compatible)?;
-        }
-        Ok(())
+    pub fn nth40(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[40..41])
     }
-}
-#[derive(Debug, Default)]
-pub struct AccountScriptOptBuilder(pub(crate) Option<AccountScript>);
-impl AccountScriptOptBuilder {
-    pub fn set(mut self, v: Option<AccountScript>) -> Self {
-        self.0 = v;
-        self
+    pub fn nth41(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[41..42])
     }
-}
-impl molecule::prelude::Builder for AccountScriptOptBuilder {
-    type Entity = AccountScriptOpt;
-    const NAME: &'static str = "AccountScriptOptBuilder";
-    fn expected_length(&self) -> usize {
-        self.0
-            .as_ref()
-            .map(|ref inner| inner.as_slice().len())
-            .unwrap_or(0)
+    pub fn nth42(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[42..43])
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        self.0
-            .as_ref()
-            .map(|ref inner| writer.write_all(inner.as_slice()))
-            .unwrap_or(Ok(()))
+    pub fn nth43(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[43..44])
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AccountScriptOpt::new_unchecked(inner.into())
+    pub fn nth44(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[44..45])
     }
-}
-#[derive(Clone)]
-pub struct KeyValue(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for KeyValue {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth45(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[45..46])
     }
-}
-impl ::core::fmt::Debug for KeyValue {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth46(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[46..47])
     }
-}
-impl ::core::fmt::Display for KeyValue {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "key", self.key())?;
-        write!(f, ", {}: {}", "value", self.value())?;
-        write!(f, " }}")
+    pub fn nth47(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[47..48])
     }
-}
-impl ::core::default::Default for KeyValue {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        KeyValue::new_unchecked(v.into())
+    pub fn nth48(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[48..49])
     }
-}
-impl KeyValue {
-    pub const TOTAL_SIZE: usize = 40;
-    pub const FIELD_SIZE: [usize; 2] = [32, 8];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn key(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0, 32))
+    pub fn nth49(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[49..50])
     }
-    pub fn value(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(32, 40))
+    pub fn nth50(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[50..51])
     }
-    pub fn as_reader<'r>(&'r self) -> KeyValueReader<'r> {
-        KeyValueReader::new_unchecked(self.as_slice())
+    pub fn nth51(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[51..52])
     }
-}
-impl molecule::prelude::Entity for KeyValue {
-    type Builder = KeyValueBuilder;
-    const NAME: &'static str = "KeyValue";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        KeyValue(data)
+    pub fn nth52(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[52..53])
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth53(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[53..54])
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth54(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[54..55])
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        KeyValueReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth55(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[55..56])
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        KeyValueReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth56(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[56..57])
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth57(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[57..58])
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().key(self.key()).value(self.value())
+    pub fn nth58(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[58..59])
     }
-}
-#[derive(Clone, Copy)]
-pub struct KeyValueReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for KeyValueReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth59(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[59..60])
     }
-}
-impl<'r> ::core::fmt::Debug for KeyValueReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth60(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[60..61])
     }
-}
-impl<'r> ::core::fmt::Display for KeyValueReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "key", self.key())?;
-        write!(f, ", {}: {}", "value", self.value())?;
-        write!(f, " }}")
+    pub fn nth61(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[61..62])
     }
-}
-impl<'r> KeyValueReader<'r> {
-    pub const TOTAL_SIZE: usize = 40;
-    pub const FIELD_SIZE: [usize; 2] = [32, 8];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn key(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    pub fn nth62(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[62..63])
+    }
+    pub fn nth63(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[63..64])
+    }
+    pub fn nth64(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[64..65])
     }
-    pub fn value(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[32..40])
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for KeyValueReader<'r> {
-    type Entity = KeyValue;
-    const NAME: &'static str = "KeyValueReader";
+impl<'r> molecule::prelude::Reader<'r> for Byte65Reader<'r> {
+    type Entity = Byte65;
+    const NAME: &'static str = "Byte65Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        KeyValueReader(slice)
+        Byte65Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -2182,548 +1776,436 @@ impl<'r> molecule::prelude::Reader<'r> for KeyValueReader<'r> {
         Ok(())
     }
 }
-#[derive(Debug, Default)]
-pub struct KeyValueBuilder {
-    pub(crate) key: Byte32,
-    pub(crate) value: Uint64,
-}
-impl KeyValueBuilder {
-    pub const TOTAL_SIZE: usize = 40;
-    pub const FIELD_SIZE: [usize; 2] = [32, 8];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn key(mut self, v: Byte32) -> Self {
-        self.key = v;
-        self
-    }
-    pub fn value(mut self, v: Uint64) -> Self {
-        self.value = v;
-        self
-    }
-}
-impl molecule::prelude::Builder for KeyValueBuilder {
-    type Entity = KeyValue;
-    const NAME: &'static str = "KeyValueBuilder";
-    fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
-    }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(self.key.as_slice())?;
-        writer.write_all(self.value.as_slice())?;
-        Ok(())
-    }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        KeyValue::new_unchecked(inner.into())
-    }
-}
-#[derive(Clone)]
-pub struct KeyValueMap(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for KeyValueMap {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl ::core::fmt::Debug for KeyValueMap {
+pub struct Byte65Builder(pub(crate) [Byte; 65]);
+impl ::core::fmt::Debug for Byte65Builder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
     }
 }
-impl ::core::fmt::Display for KeyValueMap {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
-    }
-}
-impl ::core::default::Default for KeyValueMap {
+impl ::core::default::Default for Byte65Builder {
     fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0];
-        KeyValueMap::new_unchecked(v.into())
+        Byte65Builder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
     }
 }
-impl KeyValueMap {
-    pub const ITEM_SIZE: usize = 40;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.item_count() + 1)
-    }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl Byte65Builder {
+    pub const TOTAL_SIZE: usize = 65;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 65;
+    pub fn set(mut self, v: [Byte; 65]) -> Self {
+        self.0 = v;
+        self
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
     }
-    pub fn get(&self, idx: usize) -> Option<KeyValue> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
     }
-    pub fn get_unchecked(&self, idx: usize) -> KeyValue {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        KeyValue::new_unchecked(self.0.slice(start, end))
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
     }
-    pub fn as_reader<'r>(&'r self) -> KeyValueMapReader<'r> {
-        KeyValueMapReader::new_unchecked(self.as_slice())
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
     }
-}
-impl molecule::prelude::Entity for KeyValueMap {
-    type Builder = KeyValueMapBuilder;
-    const NAME: &'static str = "KeyValueMap";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        KeyValueMap(data)
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        KeyValueMapReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        KeyValueMapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
     }
-}
-#[derive(Clone, Copy)]
-pub struct KeyValueMapReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for KeyValueMapReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
     }
-}
-impl<'r> ::core::fmt::Debug for KeyValueMapReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
     }
-}
-impl<'r> ::core::fmt::Display for KeyValueMapReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
     }
-}
-impl<'r> KeyValueMapReader<'r> {
-    pub const ITEM_SIZE: usize = 40;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
     }
-    pub fn get(&self, idx: usize) -> Option<KeyValueReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
     }
-    pub fn get_unchecked(&self, idx: usize) -> KeyValueReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        KeyValueReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for KeyValueMapReader<'r> {
-    type Entity = KeyValueMap;
-    const NAME: &'static str = "KeyValueMapReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        KeyValueMapReader(slice)
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        Ok(())
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
     }
-}
-#[derive(Debug, Default)]
-pub struct KeyValueMapBuilder(pub(crate) Vec<KeyValue>);
-impl KeyValueMapBuilder {
-    pub const ITEM_SIZE: usize = 40;
-    pub fn set(mut self, v: Vec<KeyValue>) -> Self {
-        self.0 = v;
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
         self
     }
-    pub fn push(mut self, v: KeyValue) -> Self {
-        self.0.push(v);
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = KeyValue>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
         self
     }
-}
-impl molecule::prelude::Builder for KeyValueMapBuilder {
-    type Entity = KeyValueMap;
-    const NAME: &'static str = "KeyValueMapBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
-        }
-        Ok(())
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
+        self
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        KeyValueMap::new_unchecked(inner.into())
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
     }
-}
-pub struct KeyValueMapIterator(KeyValueMap, usize, usize);
-impl ::core::iter::Iterator for KeyValueMapIterator {
-    type Item = KeyValue;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
     }
-}
-impl ::core::iter::ExactSizeIterator for KeyValueMapIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth32(mut self, v: Byte) -> Self {
+        self.0[32] = v;
+        self
     }
-}
-impl ::core::iter::IntoIterator for KeyValueMap {
-    type Item = KeyValue;
-    type IntoIter = KeyValueMapIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        KeyValueMapIterator(self, 0, len)
+    pub fn nth33(mut self, v: Byte) -> Self {
+        self.0[33] = v;
+        self
     }
-}
-impl<'r> KeyValueMapReader<'r> {
-    pub fn iter<'t>(&'t self) -> KeyValueMapReaderIterator<'t, 'r> {
-        KeyValueMapReaderIterator(&self, 0, self.len())
+    pub fn nth34(mut self, v: Byte) -> Self {
+        self.0[34] = v;
+        self
     }
-}
-pub struct KeyValueMapReaderIterator<'t, 'r>(&'t KeyValueMapReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for KeyValueMapReaderIterator<'t, 'r> {
-    type Item = KeyValueReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth35(mut self, v: Byte) -> Self {
+        self.0[35] = v;
+        self
     }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for KeyValueMapReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth36(mut self, v: Byte) -> Self {
+        self.0[36] = v;
+        self
     }
-}
-#[derive(Clone)]
-pub struct TreePath(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TreePath {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth37(mut self, v: Byte) -> Self {
+        self.0[37] = v;
+        self
     }
-}
-impl ::core::fmt::Debug for TreePath {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth38(mut self, v: Byte) -> Self {
+        self.0[38] = v;
+        self
     }
-}
-impl ::core::fmt::Display for TreePath {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    pub fn nth39(mut self, v: Byte) -> Self {
+        self.0[39] = v;
+        self
     }
-}
-impl ::core::default::Default for TreePath {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0];
-        TreePath::new_unchecked(v.into())
+    pub fn nth40(mut self, v: Byte) -> Self {
+        self.0[40] = v;
+        self
     }
-}
-impl TreePath {
-    pub const ITEM_SIZE: usize = 1;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    pub fn nth41(mut self, v: Byte) -> Self {
+        self.0[41] = v;
+        self
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth42(mut self, v: Byte) -> Self {
+        self.0[42] = v;
+        self
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth43(mut self, v: Byte) -> Self {
+        self.0[43] = v;
+        self
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth44(mut self, v: Byte) -> Self {
+        self.0[44] = v;
+        self
     }
-    pub fn get(&self, idx: usize) -> Option<Byte> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth45(mut self, v: Byte) -> Self {
+        self.0[45] = v;
+        self
     }
-    pub fn get_unchecked(&self, idx: usize) -> Byte {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        Byte::new_unchecked(self.0.slice(start, end))
+    pub fn nth46(mut self, v: Byte) -> Self {
+        self.0[46] = v;
+        self
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.0.slice_from(molecule::NUMBER_SIZE)
+    pub fn nth47(mut self, v: Byte) -> Self {
+        self.0[47] = v;
+        self
     }
-    pub fn as_reader<'r>(&'r self) -> TreePathReader<'r> {
-        TreePathReader::new_unchecked(self.as_slice())
+    pub fn nth48(mut self, v: Byte) -> Self {
+        self.0[48] = v;
+        self
     }
-}
-impl molecule::prelude::Entity for TreePath {
-    type Builder = TreePathBuilder;
-    const NAME: &'static str = "TreePath";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TreePath(data)
+    pub fn nth49(mut self, v: Byte) -> Self {
+        self.0[49] = v;
+        self
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth50(mut self, v: Byte) -> Self {
+        self.0[50] = v;
+        self
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth51(mut self, v: Byte) -> Self {
+        self.0[51] = v;
+        self
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TreePathReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth52(mut self, v: Byte) -> Self {
+        self.0[52] = v;
+        self
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TreePathReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth53(mut self, v: Byte) -> Self {
+        self.0[53] = v;
+        self
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth54(mut self, v: Byte) -> Self {
+        self.0[54] = v;
+        self
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+    pub fn nth55(mut self, v: Byte) -> Self {
+        self.0[55] = v;
+        self
     }
-}
-#[derive(Clone, Copy)]
-pub struct TreePathReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TreePathReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl<'r> ::core::fmt::Debug for TreePathReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
-    }
-}
-impl<'r> ::core::fmt::Display for TreePathReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
-    }
-}
-impl<'r> TreePathReader<'r> {
-    pub const ITEM_SIZE: usize = 1;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.item_count() + 1)
-    }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn len(&self) -> usize {
-        self.item_count()
-    }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-    pub fn get(&self, idx: usize) -> Option<ByteReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
-    }
-    pub fn get_unchecked(&self, idx: usize) -> ByteReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth56(mut self, v: Byte) -> Self {
+        self.0[56] = v;
+        self
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        &self.as_slice()[molecule::NUMBER_SIZE..]
+    pub fn nth57(mut self, v: Byte) -> Self {
+        self.0[57] = v;
+        self
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for TreePathReader<'r> {
-    type Entity = TreePath;
-    const NAME: &'static str = "TreePathReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth58(mut self, v: Byte) -> Self {
+        self.0[58] = v;
+        self
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        TreePathReader(slice)
+    pub fn nth59(mut self, v: Byte) -> Self {
+        self.0[59] = v;
+        self
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth60(mut self, v: Byte) -> Self {
+        self.0[60] = v;
+        self
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        Ok(())
+    pub fn nth61(mut self, v: Byte) -> Self {
+        self.0[61] = v;
+        self
     }
-}
-#[derive(Debug, Default)]
-pub struct TreePathBuilder(pub(crate) Vec<Byte>);
-impl TreePathBuilder {
-    pub const ITEM_SIZE: usize = 1;
-    pub fn set(mut self, v: Vec<Byte>) -> Self {
-        self.0 = v;
+    pub fn nth62(mut self, v: Byte) -> Self {
+        self.0[62] = v;
         self
     }
-    pub fn push(mut self, v: Byte) -> Self {
-        self.0.push(v);
+    pub fn nth63(mut self, v: Byte) -> Self {
+        self.0[63] = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = Byte>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn nth64(mut self, v: Byte) -> Self {
+        self.0[64] = v;
         self
     }
 }
-impl molecule::prelude::Builder for TreePathBuilder {
-    type Entity = TreePath;
-    const NAME: &'static str = "TreePathBuilder";
+impl molecule::prelude::Builder for Byte65Builder {
+    type Entity = Byte65;
+    const NAME: &'static str = "Byte65Builder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+        Self::TOTAL_SIZE
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
-        }
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        writer.write_all(self.0[32].as_slice())?;
+        writer.write_all(self.0[33].as_slice())?;
+        writer.write_all(self.0[34].as_slice())?;
+        writer.write_all(self.0[35].as_slice())?;
+        writer.write_all(self.0[36].as_slice())?;
+        writer.write_all(self.0[37].as_slice())?;
+        writer.write_all(self.0[38].as_slice())?;
+        writer.write_all(self.0[39].as_slice())?;
+        writer.write_all(self.0[40].as_slice())?;
+        writer.write_all(self.0[41].as_slice())?;
+        writer.write_all(self.0[42].as_slice())?;
+        writer.write_all(self.0[43].as_slice())?;
+        writer.write_all(self.0[44].as_slice())?;
+        writer.write_all(self.0[45].as_slice())?;
+        writer.write_all(self.0[46].as_slice())?;
+        writer.write_all(self.0[47].as_slice())?;
+        writer.write_all(self.0[48].as_slice())?;
+        writer.write_all(self.0[49].as_slice())?;
+        writer.write_all(self.0[50].as_slice())?;
+        writer.write_all(self.0[51].as_slice())?;
+        writer.write_all(self.0[52].as_slice())?;
+        writer.write_all(self.0[53].as_slice())?;
+        writer.write_all(self.0[54].as_slice())?;
+        writer.write_all(self.0[55].as_slice())?;
+        writer.write_all(self.0[56].as_slice())?;
+        writer.write_all(self.0[57].as_slice())?;
+        writer.write_all(self.0[58].as_slice())?;
+        writer.write_all(self.0[59].as_slice())?;
+        writer.write_all(self.0[60].as_slice())?;
+        writer.write_all(self.0[61].as_slice())?;
+        writer.write_all(self.0[62].as_slice())?;
+        writer.write_all(self.0[63].as_slice())?;
+        writer.write_all(self.0[64].as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TreePath::new_unchecked(inner.into())
-    }
-}
-pub struct TreePathIterator(TreePath, usize, usize);
-impl ::core::iter::Iterator for TreePathIterator {
-    type Item = Byte;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for TreePathIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for TreePath {
-    type Item = Byte;
-    type IntoIter = TreePathIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        TreePathIterator(self, 0, len)
+        Byte65::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct TreePathVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TreePathVec {
+pub struct Byte96(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Byte96 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2732,568 +2214,333 @@ impl ::core::fmt::LowerHex for TreePathVec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TreePathVec {
+impl ::core::fmt::Debug for Byte96 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TreePathVec {
+impl ::core::fmt::Display for Byte96 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for TreePathVec {
+impl ::core::default::Default for Byte96 {
     fn default() -> Self {
-        let v: Vec<u8> = vec![4, 0, 0, 0];
-        TreePathVec::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        Byte96::new_unchecked(v.into())
     }
 }
-impl TreePathVec {
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn item_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+impl Byte96 {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 96;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0, 1))
     }
-    pub fn item_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1, 2))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2, 3))
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3, 4))
     }
-    pub fn get(&self, idx: usize) -> Option<TreePath> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4, 5))
     }
-    pub fn get_unchecked(&self, idx: usize) -> TreePath {
-        let offsets = self.item_offsets();
-        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
-        if idx == self.len() - 1 {
-            TreePath::new_unchecked(self.0.slice_from(start))
-        } else {
-            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            TreePath::new_unchecked(self.0.slice(start, end))
-        }
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5, 6))
     }
-    pub fn as_reader<'r>(&'r self) -> TreePathVecReader<'r> {
-        TreePathVecReader::new_unchecked(self.as_slice())
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6, 7))
     }
-}
-impl molecule::prelude::Entity for TreePathVec {
-    type Builder = TreePathVecBuilder;
-    const NAME: &'static str = "TreePathVec";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TreePathVec(data)
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7, 8))
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8, 9))
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9, 10))
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TreePathVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10, 11))
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TreePathVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11, 12))
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12, 13))
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13, 14))
     }
-}
-#[derive(Clone, Copy)]
-pub struct TreePathVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TreePathVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14, 15))
     }
-}
-impl<'r> ::core::fmt::Debug for TreePathVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15, 16))
     }
-}
-impl<'r> ::core::fmt::Display for TreePathVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16, 17))
     }
-}
-impl<'r> TreePathVecReader<'r> {
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17, 18))
     }
-    pub fn item_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18, 19))
     }
-    pub fn item_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19, 20))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20, 21))
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21, 22))
     }
-    pub fn get(&self, idx: usize) -> Option<TreePathReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22, 23))
     }
-    pub fn get_unchecked(&self, idx: usize) -> TreePathReader<'r> {
-        let offsets = self.item_offsets();
-        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
-        if idx == self.len() - 1 {
-            TreePathReader::new_unchecked(&self.as_slice()[start..])
-        } else {
-            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            TreePathReader::new_unchecked(&self.as_slice()[start..end])
-        }
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23, 24))
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for TreePathVecReader<'r> {
-    type Entity = TreePathVec;
-    const NAME: &'static str = "TreePathVecReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24, 25))
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        TreePathVecReader(slice)
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25, 26))
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26, 27))
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(
-                Self,
-                TotalSizeNotMatch,
-                molecule::NUMBER_SIZE * 2,
-                slice_len
-            );
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        let item_count = offset_first / 4 - 1;
-        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
-        if slice_len < header_size {
-            return ve!(Self, HeaderIsBroken, header_size, slice_len);
-        }
-        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
-        let mut offsets: Vec<usize> = ptr[..item_count]
-            .iter()
-            .map(|x| molecule::unpack_number(&x[..]) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        for pair in offsets.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            TreePathReader::verify(&slice[start..end], compatible)?;
-        }
-        Ok(())
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27, 28))
     }
-}
-#[derive(Debug, Default)]
-pub struct TreePathVecBuilder(pub(crate) Vec<TreePath>);
-impl TreePathVecBuilder {
-    pub fn set(mut self, v: Vec<TreePath>) -> Self {
-        self.0 = v;
-        self
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28, 29))
     }
-    pub fn push(mut self, v: TreePath) -> Self {
-        self.0.push(v);
-        self
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29, 30))
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = TreePath>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
-        self
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30, 31))
     }
-}
-impl molecule::prelude::Builder for TreePathVecBuilder {
-    type Entity = TreePathVec;
-    const NAME: &'static str = "TreePathVecBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.0.len() + 1)
-            + self
-                .0
-                .iter()
-                .map(|inner| inner.as_slice().len())
-                .sum::<usize>()
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31, 32))
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let item_count = self.0.len();
-        if item_count == 0 {
-            writer.write_all(&molecule::pack_number(
-                molecule::NUMBER_SIZE as molecule::Number,
-            ))?;
-        } else {
-            let (total_size, offsets) = self.0.iter().fold(
-                (
-                    molecule::NUMBER_SIZE * (item_count + 1),
-                    Vec::with_capacity(item_count),
-                ),
-                |(start, mut offsets), inner| {
-                    offsets.push(start);
-                    (start + inner.as_slice().len(), offsets)
-                },
-            );
-            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-            for offset in offsets.into_iter() {
-                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-            }
-            for inner in self.0.iter() {
-                writer.write_all(inner.as_slice())?;
-            }
-        }
-        Ok(())
+    pub fn nth32(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32, 33))
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TreePathVec::new_unchecked(inner.into())
+    pub fn nth33(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(33, 34))
     }
-}
-pub struct TreePathVecIterator(TreePathVec, usize, usize);
-impl ::core::iter::Iterator for TreePathVecIterator {
-    type Item = TreePath;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth34(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(34, 35))
     }
-}
-impl ::core::iter::ExactSizeIterator for TreePathVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth35(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(35, 36))
     }
-}
-impl ::core::iter::IntoIterator for TreePathVec {
-    type Item = TreePath;
-    type IntoIter = TreePathVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        TreePathVecIterator(self, 0, len)
+    pub fn nth36(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(36, 37))
     }
-}
-impl<'r> TreePathVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> TreePathVecReaderIterator<'t, 'r> {
-        TreePathVecReaderIterator(&self, 0, self.len())
+    pub fn nth37(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(37, 38))
     }
-}
-pub struct TreePathVecReaderIterator<'t, 'r>(&'t TreePathVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for TreePathVecReaderIterator<'t, 'r> {
-    type Item = TreePathReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth38(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(38, 39))
     }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for TreePathVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth39(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(39, 40))
     }
-}
-#[derive(Clone)]
-pub struct SMTBranch(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SMTBranch {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth40(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(40, 41))
     }
-}
-impl ::core::fmt::Debug for SMTBranch {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth41(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(41, 42))
     }
-}
-impl ::core::fmt::Display for SMTBranch {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "node", self.node())?;
-        write!(f, ", {}: {}", "height", self.height())?;
-        write!(f, " }}")
+    pub fn nth42(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(42, 43))
     }
-}
-impl ::core::default::Default for SMTBranch {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0,
-        ];
-        SMTBranch::new_unchecked(v.into())
+    pub fn nth43(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(43, 44))
     }
-}
-impl SMTBranch {
-    pub const TOTAL_SIZE: usize = 33;
-    pub const FIELD_SIZE: [usize; 2] = [32, 1];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn node(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0, 32))
+    pub fn nth44(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(44, 45))
     }
-    pub fn height(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(32, 33))
+    pub fn nth45(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(45, 46))
     }
-    pub fn as_reader<'r>(&'r self) -> SMTBranchReader<'r> {
-        SMTBranchReader::new_unchecked(self.as_slice())
+    pub fn nth46(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(46, 47))
     }
-}
-impl molecule::prelude::Entity for SMTBranch {
-    type Builder = SMTBranchBuilder;
-    const NAME: &'static str = "SMTBranch";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SMTBranch(data)
+    pub fn nth47(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(47, 48))
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth48(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(48, 49))
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth49(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(49, 50))
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SMTBranchReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth50(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(50, 51))
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SMTBranchReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth51(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(51, 52))
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth52(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(52, 53))
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().node(self.node()).height(self.height())
+    pub fn nth53(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(53, 54))
     }
-}
-#[derive(Clone, Copy)]
-pub struct SMTBranchReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SMTBranchReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth54(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(54, 55))
     }
-}
-impl<'r> ::core::fmt::Debug for SMTBranchReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth55(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(55, 56))
     }
-}
-impl<'r> ::core::fmt::Display for SMTBranchReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "node", self.node())?;
-        write!(f, ", {}: {}", "height", self.height())?;
-        write!(f, " }}")
+    pub fn nth56(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(56, 57))
     }
-}
-impl<'r> SMTBranchReader<'r> {
-    pub const TOTAL_SIZE: usize = 33;
-    pub const FIELD_SIZE: [usize; 2] = [32, 1];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn node(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    pub fn nth57(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(57, 58))
     }
-    pub fn height(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    pub fn nth58(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(58, 59))
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for SMTBranchReader<'r> {
-    type Entity = SMTBranch;
-    const NAME: &'static str = "SMTBranchReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth59(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(59, 60))
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        SMTBranchReader(slice)
+    pub fn nth60(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(60, 61))
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth61(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(61, 62))
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
-        }
-        Ok(())
+    pub fn nth62(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(62, 63))
     }
-}
-#[derive(Debug, Default)]
-pub struct SMTBranchBuilder {
-    pub(crate) node: Byte32,
-    pub(crate) height: Byte,
-}
-impl SMTBranchBuilder {
-    pub const TOTAL_SIZE: usize = 33;
-    pub const FIELD_SIZE: [usize; 2] = [32, 1];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn node(mut self, v: Byte32) -> Self {
-        self.node = v;
-        self
+    pub fn nth63(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(63, 64))
     }
-    pub fn height(mut self, v: Byte) -> Self {
-        self.height = v;
-        self
+    pub fn nth64(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(64, 65))
     }
-}
-impl molecule::prelude::Builder for SMTBranchBuilder {
-    type Entity = SMTBranch;
-    const NAME: &'static str = "SMTBranchBuilder";
-    fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+    pub fn nth65(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(65, 66))
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(self.node.as_slice())?;
-        writer.write_all(self.height.as_slice())?;
-        Ok(())
+    pub fn nth66(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(66, 67))
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SMTBranch::new_unchecked(inner.into())
+    pub fn nth67(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(67, 68))
     }
-}
-#[derive(Clone)]
-pub struct SMTBranchVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SMTBranchVec {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth68(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(68, 69))
     }
-}
-impl ::core::fmt::Debug for SMTBranchVec {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth69(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(69, 70))
     }
-}
-impl ::core::fmt::Display for SMTBranchVec {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+    pub fn nth70(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(70, 71))
     }
-}
-impl ::core::default::Default for SMTBranchVec {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0];
-        SMTBranchVec::new_unchecked(v.into())
+    pub fn nth71(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(71, 72))
     }
-}
-impl SMTBranchVec {
-    pub const ITEM_SIZE: usize = 33;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    pub fn nth72(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(72, 73))
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth73(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(73, 74))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth74(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(74, 75))
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth75(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(75, 76))
     }
-    pub fn get(&self, idx: usize) -> Option<SMTBranch> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth76(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(76, 77))
     }
-    pub fn get_unchecked(&self, idx: usize) -> SMTBranch {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        SMTBranch::new_unchecked(self.0.slice(start, end))
+    pub fn nth77(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(77, 78))
     }
-    pub fn as_reader<'r>(&'r self) -> SMTBranchVecReader<'r> {
-        SMTBranchVecReader::new_unchecked(self.as_slice())
+    pub fn nth78(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(78, 79))
+    }
+    pub fn nth79(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(79, 80))
+    }
+    pub fn nth80(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(80, 81))
+    }
+    pub fn nth81(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(81, 82))
+    }
+    pub fn nth82(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(82, 83))
+    }
+    pub fn nth83(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(83, 84))
+    }
+    pub fn nth84(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(84, 85))
+    }
+    pub fn nth85(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(85, 86))
+    }
+    pub fn nth86(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(86, 87))
+    }
+    pub fn nth87(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(87, 88))
+    }
+    pub fn nth88(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(88, 89))
+    }
+    pub fn nth89(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(89, 90))
+    }
+    pub fn nth90(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(90, 91))
+    }
+    pub fn nth91(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(91, 92))
+    }
+    pub fn nth92(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(92, 93))
+    }
+    pub fn nth93(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(93, 94))
+    }
+    pub fn nth94(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(94, 95))
+    }
+    pub fn nth95(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(95, 96))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> Byte96Reader<'r> {
+        Byte96Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SMTBranchVec {
-    type Builder = SMTBranchVecBuilder;
-    const NAME: &'static str = "SMTBranchVec";
+impl molecule::prelude::Entity for Byte96 {
+    type Builder = Byte96Builder;
+    const NAME: &'static str = "Byte96";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SMTBranchVec(data)
+        Byte96(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3302,21 +2549,118 @@ impl molecule::prelude::Entity for SMTBranchVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SMTBranchVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        Byte96Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SMTBranchVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Byte96Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+            self.nth32(),
+            self.nth33(),
+            self.nth34(),
+            self.nth35(),
+            self.nth36(),
+            self.nth37(),
+            self.nth38(),
+            self.nth39(),
+            self.nth40(),
+            self.nth41(),
+            self.nth42(),
+            self.nth43(),
+            self.nth44(),
+            self.nth45(),
+            self.nth46(),
+            self.nth47(),
+            self.nth48(),
+            self.nth49(),
+            self.nth50(),
+            self.nth51(),
+            self.nth52(),
+            self.nth53(),
+            self.nth54(),
+            self.nth55(),
+            self.nth56(),
+            self.nth57(),
+            self.nth58(),
+            self.nth59(),
+            self.nth60(),
+            self.nth61(),
+            self.nth62(),
+            self.nth63(),
+            self.nth64(),
+            self.nth65(),
+            self.nth66(),
+            self.nth67(),
+            self.nth68(),
+            self.nth69(),
+            self.nth70(),
+            self.nth71(),
+            self.nth72(),
+            self.nth73(),
+            self.nth74(),
+            self.nth75(),
+            self.nth76(),
+            self.nth77(),
+            self.nth78(),
+            self.nth79(),
+            self.nth80(),
+            self.nth81(),
+            self.nth82(),
+            self.nth83(),
+            self.nth84(),
+            self.nth85(),
+            self.nth86(),
+            self.nth87(),
+            self.nth88(),
+            self.nth89(),
+            self.nth90(),
+            self.nth91(),
+            self.nth92(),
+            self.nth93(),
+            self.nth94(),
+            self.nth95(),
+        ])
     }
 }
 #[derive(Clone, Copy)]
-pub struct SMTBranchVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SMTBranchVecReader<'r> {
+pub struct Byte96Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Byte96Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3325,1229 +2669,951 @@ impl<'r> ::core::fmt::LowerHex for SMTBranchVecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SMTBranchVecReader<'r> {
+impl<'r> ::core::fmt::Debug for Byte96Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SMTBranchVecReader<'r> {
+impl<'r> ::core::fmt::Display for Byte96Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> SMTBranchVecReader<'r> {
-    pub const ITEM_SIZE: usize = 33;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.item_count() + 1)
-    }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl<'r> Byte96Reader<'r> {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 96;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
     }
-    pub fn get(&self, idx: usize) -> Option<SMTBranchReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
     }
-    pub fn get_unchecked(&self, idx: usize) -> SMTBranchReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        SMTBranchReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for SMTBranchVecReader<'r> {
-    type Entity = SMTBranchVec;
-    const NAME: &'static str = "SMTBranchVecReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        SMTBranchVecReader(slice)
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        Ok(())
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
     }
-}
-#[derive(Debug, Default)]
-pub struct SMTBranchVecBuilder(pub(crate) Vec<SMTBranch>);
-impl SMTBranchVecBuilder {
-    pub const ITEM_SIZE: usize = 33;
-    pub fn set(mut self, v: Vec<SMTBranch>) -> Self {
-        self.0 = v;
-        self
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
     }
-    pub fn push(mut self, v: SMTBranch) -> Self {
-        self.0.push(v);
-        self
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = SMTBranch>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
-        self
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
     }
-}
-impl molecule::prelude::Builder for SMTBranchVecBuilder {
-    type Entity = SMTBranchVec;
-    const NAME: &'static str = "SMTBranchVecBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
-        }
-        Ok(())
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SMTBranchVec::new_unchecked(inner.into())
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
     }
-}
-pub struct SMTBranchVecIterator(SMTBranchVec, usize, usize);
-impl ::core::iter::Iterator for SMTBranchVecIterator {
-    type Item = SMTBranch;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
     }
-}
-impl ::core::iter::ExactSizeIterator for SMTBranchVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
     }
-}
-impl ::core::iter::IntoIterator for SMTBranchVec {
-    type Item = SMTBranch;
-    type IntoIter = SMTBranchVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        SMTBranchVecIterator(self, 0, len)
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
     }
-}
-impl<'r> SMTBranchVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> SMTBranchVecReaderIterator<'t, 'r> {
-        SMTBranchVecReaderIterator(&self, 0, self.len())
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
     }
-}
-pub struct SMTBranchVecReaderIterator<'t, 'r>(&'t SMTBranchVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for SMTBranchVecReaderIterator<'t, 'r> {
-    type Item = SMTBranchReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
     }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SMTBranchVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
     }
-}
-#[derive(Clone)]
-pub struct SMTProof(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SMTProof {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
     }
-}
-impl ::core::fmt::Debug for SMTProof {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
     }
-}
-impl ::core::fmt::Display for SMTProof {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "leaves_path", self.leaves_path())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
     }
-}
-impl ::core::default::Default for SMTProof {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        SMTProof::new_unchecked(v.into())
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
     }
-}
-impl SMTProof {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
     }
-    pub fn leaves_path(&self) -> TreePathVec {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        TreePathVec::new_unchecked(self.0.slice(start, end))
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
     }
-    pub fn proof(&self) -> SMTBranchVec {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[2][..]) as usize;
-            SMTBranchVec::new_unchecked(self.0.slice(start, end))
-        } else {
-            SMTBranchVec::new_unchecked(self.0.slice_from(start))
-        }
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
     }
-    pub fn as_reader<'r>(&'r self) -> SMTProofReader<'r> {
-        SMTProofReader::new_unchecked(self.as_slice())
+    pub fn nth32(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
     }
-}
-impl molecule::prelude::Entity for SMTProof {
-    type Builder = SMTProofBuilder;
-    const NAME: &'static str = "SMTProof";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SMTProof(data)
+    pub fn nth33(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[33..34])
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth34(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[34..35])
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth35(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[35..36])
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SMTProofReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth36(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[36..37])
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SMTProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth37(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[37..38])
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth38(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[38..39])
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .leaves_path(self.leaves_path())
-            .proof(self.proof())
+    pub fn nth39(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[39..40])
     }
-}
-#[derive(Clone, Copy)]
-pub struct SMTProofReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SMTProofReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth40(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[40..41])
     }
-}
-impl<'r> ::core::fmt::Debug for SMTProofReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth41(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[41..42])
     }
-}
-impl<'r> ::core::fmt::Display for SMTProofReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "leaves_path", self.leaves_path())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+    pub fn nth42(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[42..43])
     }
-}
-impl<'r> SMTProofReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth43(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[43..44])
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn nth44(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[44..45])
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth45(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[45..46])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn nth46(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[46..47])
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn nth47(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[47..48])
     }
-    pub fn leaves_path(&self) -> TreePathVecReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        TreePathVecReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth48(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[48..49])
     }
-    pub fn proof(&self) -> SMTBranchVecReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[2][..]) as usize;
-            SMTBranchVecReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            SMTBranchVecReader::new_unchecked(&self.as_slice()[start..])
-        }
+    pub fn nth49(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[49..50])
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for SMTProofReader<'r> {
-    type Entity = SMTProof;
-    const NAME: &'static str = "SMTProofReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth50(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[50..51])
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        SMTProofReader(slice)
+    pub fn nth51(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[51..52])
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth52(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[52..53])
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        let field_count = offset_first / 4 - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
-        if slice_len < header_size {
-            return ve!(Self, HeaderIsBroken, header_size, slice_len);
-        }
-        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
-        let mut offsets: Vec<usize> = ptr[..field_count]
-            .iter()
-            .map(|x| molecule::unpack_number(&x[..]) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        TreePathVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        SMTBranchVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Ok(())
+    pub fn nth53(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[53..54])
     }
-}
-#[derive(Debug, Default)]
-pub struct SMTProofBuilder {
-    pub(crate) leaves_path: TreePathVec,
-    pub(crate) proof: SMTBranchVec,
-}
-impl SMTProofBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn leaves_path(mut self, v: TreePathVec) -> Self {
-        self.leaves_path = v;
-        self
+    pub fn nth54(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[54..55])
     }
-    pub fn proof(mut self, v: SMTBranchVec) -> Self {
-        self.proof = v;
-        self
+    pub fn nth55(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[55..56])
     }
-}
-impl molecule::prelude::Builder for SMTProofBuilder {
-    type Entity = SMTProof;
-    const NAME: &'static str = "SMTProofBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.leaves_path.as_slice().len()
-            + self.proof.as_slice().len()
+    pub fn nth56(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[56..57])
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.leaves_path.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.proof.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.leaves_path.as_slice())?;
-        writer.write_all(self.proof.as_slice())?;
-        Ok(())
+    pub fn nth57(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[57..58])
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SMTProof::new_unchecked(inner.into())
+    pub fn nth58(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[58..59])
     }
-}
-#[derive(Clone)]
-pub struct Account(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Account {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth59(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[59..60])
     }
-}
-impl ::core::fmt::Debug for Account {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth60(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[60..61])
     }
-}
-impl ::core::fmt::Display for Account {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "index", self.index())?;
-        write!(f, ", {}: {}", "script", self.script())?;
-        write!(f, ", {}: {}", "nonce", self.nonce())?;
-        write!(f, ", {}: {}", "pubkey_hash", self.pubkey_hash())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+    pub fn nth61(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[61..62])
     }
-}
-impl ::core::default::Default for Account {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            52, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        Account::new_unchecked(v.into())
+    pub fn nth62(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[62..63])
     }
-}
-impl Account {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth63(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[63..64])
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn nth64(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[64..65])
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth65(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[65..66])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn nth66(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[66..67])
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn nth67(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[67..68])
     }
-    pub fn index(&self) -> Uint64 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start, end))
+    pub fn nth68(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[68..69])
     }
-    pub fn script(&self) -> AccountScriptOpt {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        AccountScriptOpt::new_unchecked(self.0.slice(start, end))
+    pub fn nth69(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[69..70])
     }
-    pub fn nonce(&self) -> Uint32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start, end))
+    pub fn nth70(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[70..71])
     }
-    pub fn pubkey_hash(&self) -> Byte20 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[4][..]) as usize;
-            Byte20::new_unchecked(self.0.slice(start, end))
-        } else {
-            Byte20::new_unchecked(self.0.slice_from(start))
-        }
+    pub fn nth71(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[71..72])
     }
-    pub fn as_reader<'r>(&'r self) -> AccountReader<'r> {
-        AccountReader::new_unchecked(self.as_slice())
+    pub fn nth72(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[72..73])
     }
-}
-impl molecule::prelude::Entity for Account {
-    type Builder = AccountBuilder;
-    const NAME: &'static str = "Account";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Account(data)
+    pub fn nth73(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[73..74])
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth74(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[74..75])
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth75(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[75..76])
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth76(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[76..77])
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth77(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[77..78])
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth78(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[78..79])
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .index(self.index())
-            .script(self.script())
-            .nonce(self.nonce())
-            .pubkey_hash(self.pubkey_hash())
+    pub fn nth79(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[79..80])
     }
-}
-#[derive(Clone, Copy)]
-pub struct AccountReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AccountReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth80(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[80..81])
     }
-}
-impl<'r> ::core::fmt::Debug for AccountReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth81(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[81..82])
     }
-}
-impl<'r> ::core::fmt::Display for AccountReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "index", self.index())?;
-        write!(f, ", {}: {}", "script", self.script())?;
-        write!(f, ", {}: {}", "nonce", self.nonce())?;
-        write!(f, ", {}: {}", "pubkey_hash", self.pubkey_hash())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+    pub fn nth82(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[82..83])
     }
-}
-impl<'r> AccountReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth83(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[83..84])
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn nth84(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[84..85])
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn nth85(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[85..86])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn nth86(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[86..87])
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn nth87(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[87..88])
     }
-    pub fn index(&self) -> Uint64Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth88(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[88..89])
     }
-    pub fn script(&self) -> AccountScriptOptReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        AccountScriptOptReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth89(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[89..90])
     }
-    pub fn nonce(&self) -> Uint32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth90(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[90..91])
     }
-    pub fn pubkey_hash(&self) -> Byte20Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[4][..]) as usize;
-            Byte20Reader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            Byte20Reader::new_unchecked(&self.as_slice()[start..])
-        }
+    pub fn nth91(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[91..92])
+    }
+    pub fn nth92(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[92..93])
+    }
+    pub fn nth93(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[93..94])
+    }
+    pub fn nth94(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[94..95])
+    }
+    pub fn nth95(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[95..96])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AccountReader<'r> {
-    type Entity = Account;
-    const NAME: &'static str = "AccountReader";
+impl<'r> molecule::prelude::Reader<'r> for Byte96Reader<'r> {
+    type Entity = Byte96;
+    const NAME: &'static str = "Byte96Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AccountReader(slice)
+        Byte96Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        let field_count = offset_first / 4 - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
-        if slice_len < header_size {
-            return ve!(Self, HeaderIsBroken, header_size, slice_len);
-        }
-        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
-        let mut offsets: Vec<usize> = ptr[..field_count]
-            .iter()
-            .map(|x| molecule::unpack_number(&x[..]) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        AccountScriptOptReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Byte20Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
-#[derive(Debug, Default)]
-pub struct AccountBuilder {
-    pub(crate) index: Uint64,
-    pub(crate) script: AccountScriptOpt,
-    pub(crate) nonce: Uint32,
-    pub(crate) pubkey_hash: Byte20,
+pub struct Byte96Builder(pub(crate) [Byte; 96]);
+impl ::core::fmt::Debug for Byte96Builder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
 }
-impl AccountBuilder {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn index(mut self, v: Uint64) -> Self {
-        self.index = v;
+impl ::core::default::Default for Byte96Builder {
+    fn default() -> Self {
+        Byte96Builder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl Byte96Builder {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 96;
+    pub fn set(mut self, v: [Byte; 96]) -> Self {
+        self.0 = v;
         self
     }
-    pub fn script(mut self, v: AccountScriptOpt) -> Self {
-        self.script = v;
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
         self
     }
-    pub fn nonce(mut self, v: Uint32) -> Self {
-        self.nonce = v;
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
         self
     }
-    pub fn pubkey_hash(mut self, v: Byte20) -> Self {
-        self.pubkey_hash = v;
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
         self
     }
-}
-impl molecule::prelude::Builder for AccountBuilder {
-    type Entity = Account;
-    const NAME: &'static str = "AccountBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.index.as_slice().len()
-            + self.script.as_slice().len()
-            + self.nonce.as_slice().len()
-            + self.pubkey_hash.as_slice().len()
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.index.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.script.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.nonce.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.pubkey_hash.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.index.as_slice())?;
-        writer.write_all(self.script.as_slice())?;
-        writer.write_all(self.nonce.as_slice())?;
-        writer.write_all(self.pubkey_hash.as_slice())?;
-        Ok(())
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Account::new_unchecked(inner.into())
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
     }
-}
-#[derive(Clone)]
-pub struct Payment(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Payment {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
     }
-}
-impl ::core::fmt::Debug for Payment {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
     }
-}
-impl ::core::fmt::Display for Payment {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
     }
-}
-impl ::core::default::Default for Payment {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0];
-        Payment::new_unchecked(v.into())
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
     }
-}
-impl Payment {
-    pub const ITEM_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
     }
-    pub fn to_enum(&self) -> PaymentUnion {
-        let inner = self.0.slice_from(molecule::NUMBER_SIZE);
-        match self.item_id() {
-            0 => Uint32::new_unchecked(inner).into(),
-            1 => UDTPayment::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
-        }
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
     }
-    pub fn as_reader<'r>(&'r self) -> PaymentReader<'r> {
-        PaymentReader::new_unchecked(self.as_slice())
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
     }
-}
-impl molecule::prelude::Entity for Payment {
-    type Builder = PaymentBuilder;
-    const NAME: &'static str = "Payment";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Payment(data)
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        PaymentReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        PaymentReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
     }
-}
-#[derive(Clone, Copy)]
-pub struct PaymentReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for PaymentReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
     }
-}
-impl<'r> ::core::fmt::Debug for PaymentReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
     }
-}
-impl<'r> ::core::fmt::Display for PaymentReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
     }
-}
-impl<'r> PaymentReader<'r> {
-    pub const ITEM_COUNT: usize = 2;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
     }
-    pub fn to_enum(&self) -> PaymentUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => Uint32Reader::new_unchecked(inner).into(),
-            1 => UDTPaymentReader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
-        }
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for PaymentReader<'r> {
-    type Entity = Payment;
-    const NAME: &'static str = "PaymentReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
+        self
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        PaymentReader(slice)
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
+        self
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
+        self
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => Uint32Reader::verify(inner_slice, compatible),
-            1 => UDTPaymentReader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEM_COUNT, item_id),
-        }?;
-        Ok(())
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
     }
-}
-#[derive(Debug, Default)]
-pub struct PaymentBuilder(pub(crate) PaymentUnion);
-impl PaymentBuilder {
-    pub const ITEM_COUNT: usize = 2;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<PaymentUnion>,
-    {
-        self.0 = v.into();
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
         self
     }
-}
-impl molecule::prelude::Builder for PaymentBuilder {
-    type Entity = Payment;
-    const NAME: &'static str = "PaymentBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Payment::new_unchecked(inner.into())
+    pub fn nth32(mut self, v: Byte) -> Self {
+        self.0[32] = v;
+        self
     }
-}
-#[derive(Debug, Clone)]
-pub enum PaymentUnion {
-    Uint32(Uint32),
-    UDTPayment(UDTPayment),
-}
-#[derive(Debug, Clone, Copy)]
-pub enum PaymentUnionReader<'r> {
-    Uint32(Uint32Reader<'r>),
-    UDTPayment(UDTPaymentReader<'r>),
-}
-impl ::core::default::Default for PaymentUnion {
-    fn default() -> Self {
-        PaymentUnion::Uint32(::core::default::Default::default())
+    pub fn nth33(mut self, v: Byte) -> Self {
+        self.0[33] = v;
+        self
     }
-}
-impl ::core::fmt::Display for PaymentUnion {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            PaymentUnion::Uint32(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Uint32::NAME, item)
-            }
-            PaymentUnion::UDTPayment(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, UDTPayment::NAME, item)
-            }
-        }
+    pub fn nth34(mut self, v: Byte) -> Self {
+        self.0[34] = v;
+        self
     }
-}
-impl<'r> ::core::fmt::Display for PaymentUnionReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            PaymentUnionReader::Uint32(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Uint32::NAME, item)
-            }
-            PaymentUnionReader::UDTPayment(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, UDTPayment::NAME, item)
-            }
-        }
+    pub fn nth35(mut self, v: Byte) -> Self {
+        self.0[35] = v;
+        self
     }
-}
-impl PaymentUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            PaymentUnion::Uint32(ref item) => write!(f, "{}", item),
-            PaymentUnion::UDTPayment(ref item) => write!(f, "{}", item),
-        }
+    pub fn nth36(mut self, v: Byte) -> Self {
+        self.0[36] = v;
+        self
     }
-}
-impl<'r> PaymentUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            PaymentUnionReader::Uint32(ref item) => write!(f, "{}", item),
-            PaymentUnionReader::UDTPayment(ref item) => write!(f, "{}", item),
-        }
+    pub fn nth37(mut self, v: Byte) -> Self {
+        self.0[37] = v;
+        self
     }
-}
-impl ::core::convert::From<Uint32> for PaymentUnion {
-    fn from(item: Uint32) -> Self {
-        PaymentUnion::Uint32(item)
+    pub fn nth38(mut self, v: Byte) -> Self {
+        self.0[38] = v;
+        self
     }
-}
-impl ::core::convert::From<UDTPayment> for PaymentUnion {
-    fn from(item: UDTPayment) -> Self {
-        PaymentUnion::UDTPayment(item)
+    pub fn nth39(mut self, v: Byte) -> Self {
+        self.0[39] = v;
+        self
     }
-}
-impl<'r> ::core::convert::From<Uint32Reader<'r>> for PaymentUnionReader<'r> {
-    fn from(item: Uint32Reader<'r>) -> Self {
-        PaymentUnionReader::Uint32(item)
+    pub fn nth40(mut self, v: Byte) -> Self {
+        self.0[40] = v;
+        self
     }
-}
-impl<'r> ::core::convert::From<UDTPaymentReader<'r>> for PaymentUnionReader<'r> {
-    fn from(item: UDTPaymentReader<'r>) -> Self {
-        PaymentUnionReader::UDTPayment(item)
+    pub fn nth41(mut self, v: Byte) -> Self {
+        self.0[41] = v;
+        self
     }
-}
-impl PaymentUnion {
-    pub const NAME: &'static str = "PaymentUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            PaymentUnion::Uint32(item) => item.as_bytes(),
-            PaymentUnion::UDTPayment(item) => item.as_bytes(),
-        }
+    pub fn nth42(mut self, v: Byte) -> Self {
+        self.0[42] = v;
+        self
     }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            PaymentUnion::Uint32(item) => item.as_slice(),
-            PaymentUnion::UDTPayment(item) => item.as_slice(),
-        }
+    pub fn nth43(mut self, v: Byte) -> Self {
+        self.0[43] = v;
+        self
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            PaymentUnion::Uint32(_) => 0,
-            PaymentUnion::UDTPayment(_) => 1,
-        }
+    pub fn nth44(mut self, v: Byte) -> Self {
+        self.0[44] = v;
+        self
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            PaymentUnion::Uint32(_) => "Uint32",
-            PaymentUnion::UDTPayment(_) => "UDTPayment",
-        }
+    pub fn nth45(mut self, v: Byte) -> Self {
+        self.0[45] = v;
+        self
     }
-    pub fn as_reader<'r>(&'r self) -> PaymentUnionReader<'r> {
-        match self {
-            PaymentUnion::Uint32(item) => item.as_reader().into(),
-            PaymentUnion::UDTPayment(item) => item.as_reader().into(),
-        }
+    pub fn nth46(mut self, v: Byte) -> Self {
+        self.0[46] = v;
+        self
     }
-}
-impl<'r> PaymentUnionReader<'r> {
-    pub const NAME: &'r str = "PaymentUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            PaymentUnionReader::Uint32(item) => item.as_slice(),
-            PaymentUnionReader::UDTPayment(item) => item.as_slice(),
-        }
+    pub fn nth47(mut self, v: Byte) -> Self {
+        self.0[47] = v;
+        self
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            PaymentUnionReader::Uint32(_) => 0,
-            PaymentUnionReader::UDTPayment(_) => 1,
-        }
+    pub fn nth48(mut self, v: Byte) -> Self {
+        self.0[48] = v;
+        self
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            PaymentUnionReader::Uint32(_) => "Uint32",
-            PaymentUnionReader::UDTPayment(_) => "UDTPayment",
-        }
+    pub fn nth49(mut self, v: Byte) -> Self {
+        self.0[49] = v;
+        self
     }
-}
-#[derive(Clone)]
-pub struct UDTPayment(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for UDTPayment {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth50(mut self, v: Byte) -> Self {
+        self.0[50] = v;
+        self
     }
-}
-impl ::core::fmt::Debug for UDTPayment {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth51(mut self, v: Byte) -> Self {
+        self.0[51] = v;
+        self
     }
-}
-impl ::core::fmt::Display for UDTPayment {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "type_hash", self.type_hash())?;
-        write!(f, ", {}: {}", "amount", self.amount())?;
-        write!(f, " }}")
+    pub fn nth52(mut self, v: Byte) -> Self {
+        self.0[52] = v;
+        self
     }
-}
-impl ::core::default::Default for UDTPayment {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0,
-        ];
-        UDTPayment::new_unchecked(v.into())
+    pub fn nth53(mut self, v: Byte) -> Self {
+        self.0[53] = v;
+        self
     }
-}
-impl UDTPayment {
-    pub const TOTAL_SIZE: usize = 36;
-    pub const FIELD_SIZE: [usize; 2] = [32, 4];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn type_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0, 32))
+    pub fn nth54(mut self, v: Byte) -> Self {
+        self.0[54] = v;
+        self
     }
-    pub fn amount(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(32, 36))
+    pub fn nth55(mut self, v: Byte) -> Self {
+        self.0[55] = v;
+        self
     }
-    pub fn as_reader<'r>(&'r self) -> UDTPaymentReader<'r> {
-        UDTPaymentReader::new_unchecked(self.as_slice())
+    pub fn nth56(mut self, v: Byte) -> Self {
+        self.0[56] = v;
+        self
     }
-}
-impl molecule::prelude::Entity for UDTPayment {
-    type Builder = UDTPaymentBuilder;
-    const NAME: &'static str = "UDTPayment";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        UDTPayment(data)
+    pub fn nth57(mut self, v: Byte) -> Self {
+        self.0[57] = v;
+        self
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth58(mut self, v: Byte) -> Self {
+        self.0[58] = v;
+        self
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth59(mut self, v: Byte) -> Self {
+        self.0[59] = v;
+        self
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        UDTPaymentReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth60(mut self, v: Byte) -> Self {
+        self.0[60] = v;
+        self
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        UDTPaymentReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth61(mut self, v: Byte) -> Self {
+        self.0[61] = v;
+        self
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth62(mut self, v: Byte) -> Self {
+        self.0[62] = v;
+        self
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .type_hash(self.type_hash())
-            .amount(self.amount())
+    pub fn nth63(mut self, v: Byte) -> Self {
+        self.0[63] = v;
+        self
     }
-}
-#[derive(Clone, Copy)]
-pub struct UDTPaymentReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for UDTPaymentReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth64(mut self, v: Byte) -> Self {
+        self.0[64] = v;
+        self
     }
-}
-impl<'r> ::core::fmt::Debug for UDTPaymentReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth65(mut self, v: Byte) -> Self {
+        self.0[65] = v;
+        self
     }
-}
-impl<'r> ::core::fmt::Display for UDTPaymentReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "type_hash", self.type_hash())?;
-        write!(f, ", {}: {}", "amount", self.amount())?;
-        write!(f, " }}")
+    pub fn nth66(mut self, v: Byte) -> Self {
+        self.0[66] = v;
+        self
     }
-}
-impl<'r> UDTPaymentReader<'r> {
-    pub const TOTAL_SIZE: usize = 36;
-    pub const FIELD_SIZE: [usize; 2] = [32, 4];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn type_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    pub fn nth67(mut self, v: Byte) -> Self {
+        self.0[67] = v;
+        self
     }
-    pub fn amount(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[32..36])
+    pub fn nth68(mut self, v: Byte) -> Self {
+        self.0[68] = v;
+        self
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for UDTPaymentReader<'r> {
-    type Entity = UDTPayment;
-    const NAME: &'static str = "UDTPaymentReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
+    pub fn nth69(mut self, v: Byte) -> Self {
+        self.0[69] = v;
+        self
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        UDTPaymentReader(slice)
+    pub fn nth70(mut self, v: Byte) -> Self {
+        self.0[70] = v;
+        self
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth71(mut self, v: Byte) -> Self {
+        self.0[71] = v;
+        self
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
-        }
-        Ok(())
+    pub fn nth72(mut self, v: Byte) -> Self {
+        self.0[72] = v;
+        self
     }
-}
-#[derive(Debug, Default)]
-pub struct UDTPaymentBuilder {
-    pub(crate) type_hash: Byte32,
-    pub(crate) amount: Uint32,
-}
-impl UDTPaymentBuilder {
-    pub const TOTAL_SIZE: usize = 36;
-    pub const FIELD_SIZE: [usize; 2] = [32, 4];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn type_hash(mut self, v: Byte32) -> Self {
-        self.type_hash = v;
+    pub fn nth73(mut self, v: Byte) -> Self {
+        self.0[73] = v;
         self
     }
-    pub fn amount(mut self, v: Uint32) -> Self {
-        self.amount = v;
+    pub fn nth74(mut self, v: Byte) -> Self {
+        self.0[74] = v;
+        self
+    }
+    pub fn nth75(mut self, v: Byte) -> Self {
+        self.0[75] = v;
+        self
+    }
+    pub fn nth76(mut self, v: Byte) -> Self {
+        self.0[76] = v;
+        self
+    }
+    pub fn nth77(mut self, v: Byte) -> Self {
+        self.0[77] = v;
+        self
+    }
+    pub fn nth78(mut self, v: Byte) -> Self {
+        self.0[78] = v;
+        self
+    }
+    pub fn nth79(mut self, v: Byte) -> Self {
+        self.0[79] = v;
+        self
+    }
+    pub fn nth80(mut self, v: Byte) -> Self {
+        self.0[80] = v;
+        self
+    }
+    pub fn nth81(mut self, v: Byte) -> Self {
+        self.0[81] = v;
+        self
+    }
+    pub fn nth82(mut self, v: Byte) -> Self {
+        self.0[82] = v;
+        self
+    }
+    pub fn nth83(mut self, v: Byte) -> Self {
+        self.0[83] = v;
+        self
+    }
+    pub fn nth84(mut self, v: Byte) -> Self {
+        self.0[84] = v;
+        self
+    }
+    pub fn nth85(mut self, v: Byte) -> Self {
+        self.0[85] = v;
+        self
+    }
+    pub fn nth86(mut self, v: Byte) -> Self {
+        self.0[86] = v;
+        self
+    }
+    pub fn nth87(mut self, v: Byte) -> Self {
+        self.0[87] = v;
+        self
+    }
+    pub fn nth88(mut self, v: Byte) -> Self {
+        self.0[88] = v;
+        self
+    }
+    pub fn nth89(mut self, v: Byte) -> Self {
+        self.0[89] = v;
+        self
+    }
+    pub fn nth90(mut self, v: Byte) -> Self {
+        self.0[90] = v;
+        self
+    }
+    pub fn nth91(mut self, v: Byte) -> Self {
+        self.0[91] = v;
+        self
+    }
+    pub fn nth92(mut self, v: Byte) -> Self {
+        self.0[92] = v;
+        self
+    }
+    pub fn nth93(mut self, v: Byte) -> Self {
+        self.0[93] = v;
+        self
+    }
+    pub fn nth94(mut self, v: Byte) -> Self {
+        self.0[94] = v;
+        self
+    }
+    pub fn nth95(mut self, v: Byte) -> Self {
+        self.0[95] = v;
         self
     }
 }
-impl molecule::prelude::Builder for UDTPaymentBuilder {
-    type Entity = UDTPayment;
-    const NAME: &'static str = "UDTPaymentBuilder";
+impl molecule::prelude::Builder for Byte96Builder {
+    type Entity = Byte96;
+    const NAME: &'static str = "Byte96Builder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(self.type_hash.as_slice())?;
-        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        writer.write_all(self.0[32].as_slice())?;
+        writer.write_all(self.0[33].as_slice())?;
+        writer.write_all(self.0[34].as_slice())?;
+        writer.write_all(self.0[35].as_slice())?;
+        writer.write_all(self.0[36].as_slice())?;
+        writer.write_all(self.0[37].as_slice())?;
+        writer.write_all(self.0[38].as_slice())?;
+        writer.write_all(self.0[39].as_slice())?;
+        writer.write_all(self.0[40].as_slice())?;
+        writer.write_all(self.0[41].as_slice())?;
+        writer.write_all(self.0[42].as_slice())?;
+        writer.write_all(self.0[43].as_slice())?;
+        writer.write_all(self.0[44].as_slice())?;
+        writer.write_all(self.0[45].as_slice())?;
+        writer.write_all(self.0[46].as_slice())?;
+        writer.write_all(self.0[47].as_slice())?;
+        writer.write_all(self.0[48].as_slice())?;
+        writer.write_all(self.0[49].as_slice())?;
+        writer.write_all(self.0[50].as_slice())?;
+        writer.write_all(self.0[51].as_slice())?;
+        writer.write_all(self.0[52].as_slice())?;
+        writer.write_all(self.0[53].as_slice())?;
+        writer.write_all(self.0[54].as_slice())?;
+        writer.write_all(self.0[55].as_slice())?;
+        writer.write_all(self.0[56].as_slice())?;
+        writer.write_all(self.0[57].as_slice())?;
+        writer.write_all(self.0[58].as_slice())?;
+        writer.write_all(self.0[59].as_slice())?;
+        writer.write_all(self.0[60].as_slice())?;
+        writer.write_all(self.0[61].as_slice())?;
+        writer.write_all(self.0[62].as_slice())?;
+        writer.write_all(self.0[63].as_slice())?;
+        writer.write_all(self.0[64].as_slice())?;
+        writer.write_all(self.0[65].as_slice())?;
+        writer.write_all(self.0[66].as_slice())?;
+        writer.write_all(self.0[67].as_slice())?;
+        writer.write_all(self.0[68].as_slice())?;
+        writer.write_all(self.0[69].as_slice())?;
+        writer.write_all(self.0[70].as_slice())?;
+        writer.write_all(self.0[71].as_slice())?;
+        writer.write_all(self.0[72].as_slice())?;
+        writer.write_all(self.0[73].as_slice())?;
+        writer.write_all(self.0[74].as_slice())?;
+        writer.write_all(self.0[75].as_slice())?;
+        writer.write_all(self.0[76].as_slice())?;
+        writer.write_all(self.0[77].as_slice())?;
+        writer.write_all(self.0[78].as_slice())?;
+        writer.write_all(self.0[79].as_slice())?;
+        writer.write_all(self.0[80].as_slice())?;
+        writer.write_all(self.0[81].as_slice())?;
+        writer.write_all(self.0[82].as_slice())?;
+        writer.write_all(self.0[83].as_slice())?;
+        writer.write_all(self.0[84].as_slice())?;
+        writer.write_all(self.0[85].as_slice())?;
+        writer.write_all(self.0[86].as_slice())?;
+        writer.write_all(self.0[87].as_slice())?;
+        writer.write_all(self.0[88].as_slice())?;
+        writer.write_all(self.0[89].as_slice())?;
+        writer.write_all(self.0[90].as_slice())?;
+        writer.write_all(self.0[91].as_slice())?;
+        writer.write_all(self.0[92].as_slice())?;
+        writer.write_all(self.0[93].as_slice())?;
+        writer.write_all(self.0[94].as_slice())?;
+        writer.write_all(self.0[95].as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        UDTPayment::new_unchecked(inner.into())
+        Byte96::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Tx(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Tx {
+pub struct Byte48Opt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Byte48Opt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4556,114 +3622,49 @@ impl ::core::fmt::LowerHex for Tx {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Tx {
+impl ::core::fmt::Debug for Byte48Opt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Tx {
+impl ::core::fmt::Display for Byte48Opt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "sender_index", self.sender_index())?;
-        write!(f, ", {}: {}", "to_index", self.to_index())?;
-        write!(f, ", {}: {}", "nonce", self.nonce())?;
-        write!(f, ", {}: {}", "amount", self.amount())?;
-        write!(f, ", {}: {}", "fee", self.fee())?;
-        write!(f, ", {}: {}", "args", self.args())?;
-        write!(f, ", {}: {}", "witness", self.witness())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
         }
-        write!(f, " }}")
     }
 }
-impl ::core::default::Default for Tx {
+impl ::core::default::Default for Byte48Opt {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            76, 0, 0, 0, 32, 0, 0, 0, 40, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 68, 0, 0,
-            0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        Tx::new_unchecked(v.into())
+        let v: Vec<u8> = vec![];
+        Byte48Opt::new_unchecked(v.into())
     }
 }
-impl Tx {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn sender_index(&self) -> Uint64 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn to_index(&self) -> Uint64 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn nonce(&self) -> Uint32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn amount(&self) -> Payment {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        let end = molecule::unpack_number(&offsets[4][..]) as usize;
-        Payment::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn fee(&self) -> Payment {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[4][..]) as usize;
-        let end = molecule::unpack_number(&offsets[5][..]) as usize;
-        Payment::new_unchecked(self.0.slice(start, end))
+impl Byte48Opt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn args(&self) -> Bytes {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[5][..]) as usize;
-        let end = molecule::unpack_number(&offsets[6][..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start, end))
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn witness(&self) -> Bytes {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[6][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[7][..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start, end))
+    pub fn to_opt(&self) -> Option<Byte48> {
+        if self.is_none() {
+            None
         } else {
-            Bytes::new_unchecked(self.0.slice_from(start))
+            Some(Byte48::new_unchecked(self.0.clone()))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> TxReader<'r> {
-        TxReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> Byte48OptReader<'r> {
+        Byte48OptReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Tx {
-    type Builder = TxBuilder;
-    const NAME: &'static str = "Tx";
+impl molecule::prelude::Entity for Byte48Opt {
+    type Builder = Byte48OptBuilder;
+    const NAME: &'static str = "Byte48Opt";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Tx(data)
+        Byte48Opt(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4672,28 +3673,21 @@ impl molecule::prelude::Entity for Tx {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TxReader::from_slice(slice).map(|reader| reader.to_entity())
+        Byte48OptReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TxReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        Byte48OptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .sender_index(self.sender_index())
-            .to_index(self.to_index())
-            .nonce(self.nonce())
-            .amount(self.amount())
-            .fee(self.fee())
-            .args(self.args())
-            .witness(self.witness())
+        Self::new_builder().set(self.to_opt())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TxReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TxReader<'r> {
+pub struct Byte48OptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Byte48OptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4702,251 +3696,87 @@ impl<'r> ::core::fmt::LowerHex for TxReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TxReader<'r> {
+impl<'r> ::core::fmt::Debug for Byte48OptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TxReader<'r> {
+impl<'r> ::core::fmt::Display for Byte48OptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "sender_index", self.sender_index())?;
-        write!(f, ", {}: {}", "to_index", self.to_index())?;
-        write!(f, ", {}: {}", "nonce", self.nonce())?;
-        write!(f, ", {}: {}", "amount", self.amount())?;
-        write!(f, ", {}: {}", "fee", self.fee())?;
-        write!(f, ", {}: {}", "args", self.args())?;
-        write!(f, ", {}: {}", "witness", self.witness())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl<'r> TxReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
         } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+            write!(f, "{}(None)", Self::NAME)
         }
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn sender_index(&self) -> Uint64Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn to_index(&self) -> Uint64Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn nonce(&self) -> Uint32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn amount(&self) -> PaymentReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        let end = molecule::unpack_number(&offsets[4][..]) as usize;
-        PaymentReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn fee(&self) -> PaymentReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[4][..]) as usize;
-        let end = molecule::unpack_number(&offsets[5][..]) as usize;
-        PaymentReader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> Byte48OptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn args(&self) -> BytesReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[5][..]) as usize;
-        let end = molecule::unpack_number(&offsets[6][..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn witness(&self) -> BytesReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[6][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[7][..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn to_opt(&self) -> Option<Byte48Reader<'r>> {
+        if self.is_none() {
+            None
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            Some(Byte48Reader::new_unchecked(self.as_slice()))
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TxReader<'r> {
-    type Entity = Tx;
-    const NAME: &'static str = "TxReader";
+impl<'r> molecule::prelude::Reader<'r> for Byte48OptReader<'r> {
+    type Entity = Byte48Opt;
+    const NAME: &'static str = "Byte48OptReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TxReader(slice)
+        Byte48OptReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
     fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        let field_count = offset_first / 4 - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
-        if slice_len < header_size {
-            return ve!(Self, HeaderIsBroken, header_size, slice_len);
-        }
-        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
-        let mut offsets: Vec<usize> = ptr[..field_count]
-            .iter()
-            .map(|x| molecule::unpack_number(&x[..]) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if !slice.is_empty() {
+            Byte48Reader::verify(&slice[..], compatible)?;
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        PaymentReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        PaymentReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        BytesReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        BytesReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TxBuilder {
-    pub(crate) sender_index: Uint64,
-    pub(crate) to_index: Uint64,
-    pub(crate) nonce: Uint32,
-    pub(crate) amount: Payment,
-    pub(crate) fee: Payment,
-    pub(crate) args: Bytes,
-    pub(crate) witness: Bytes,
-}
-impl TxBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn sender_index(mut self, v: Uint64) -> Self {
-        self.sender_index = v;
-        self
-    }
-    pub fn to_index(mut self, v: Uint64) -> Self {
-        self.to_index = v;
-        self
-    }
-    pub fn nonce(mut self, v: Uint32) -> Self {
-        self.nonce = v;
-        self
-    }
-    pub fn amount(mut self, v: Payment) -> Self {
-        self.amount = v;
-        self
-    }
-    pub fn fee(mut self, v: Payment) -> Self {
-        self.fee = v;
-        self
-    }
-    pub fn args(mut self, v: Bytes) -> Self {
-        self.args = v;
-        self
-    }
-    pub fn witness(mut self, v: Bytes) -> Self {
-        self.witness = v;
+pub struct Byte48OptBuilder(pub(crate) Option<Byte48>);
+impl Byte48OptBuilder {
+    pub fn set(mut self, v: Option<Byte48>) -> Self {
+        self.0 = v;
         self
     }
 }
-impl molecule::prelude::Builder for TxBuilder {
-    type Entity = Tx;
-    const NAME: &'static str = "TxBuilder";
+impl molecule::prelude::Builder for Byte48OptBuilder {
+    type Entity = Byte48Opt;
+    const NAME: &'static str = "Byte48OptBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.sender_index.as_slice().len()
-            + self.to_index.as_slice().len()
-            + self.nonce.as_slice().len()
-            + self.amount.as_slice().len()
-            + self.fee.as_slice().len()
-            + self.args.as_slice().len()
-            + self.witness.as_slice().len()
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.sender_index.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.to_index.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.nonce.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.amount.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.fee.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.args.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.witness.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.sender_index.as_slice())?;
-        writer.write_all(self.to_index.as_slice())?;
-        writer.write_all(self.nonce.as_slice())?;
-        writer.write_all(self.amount.as_slice())?;
-        writer.write_all(self.fee.as_slice())?;
-        writer.write_all(self.args.as_slice())?;
-        writer.write_all(self.witness.as_slice())?;
-        Ok(())
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Tx::new_unchecked(inner.into())
+        Byte48Opt::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct TxVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TxVec {
+pub struct GlobalState(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GlobalState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4955,76 +3785,56 @@ impl ::core::fmt::LowerHex for TxVec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TxVec {
+impl ::core::fmt::Debug for GlobalState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TxVec {
+impl ::core::fmt::Display for GlobalState {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account_root", self.account_root())?;
+        write!(f, ", {}: {}", "block_root", self.block_root())?;
+        write!(f, ", {}: {}", "account_count", self.account_count())?;
+        write!(f, ", {}: {}", "block_count", self.block_count())?;
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for TxVec {
+impl ::core::default::Default for GlobalState {
     fn default() -> Self {
-        let v: Vec<u8> = vec![4, 0, 0, 0];
-        TxVec::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        GlobalState::new_unchecked(v.into())
     }
 }
-impl TxVec {
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn item_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+impl GlobalState {
+    pub const TOTAL_SIZE: usize = 80;
+    pub const FIELD_SIZE: [usize; 4] = [32, 32, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn account_root(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0, 32))
     }
-    pub fn item_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    pub fn block_root(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32, 64))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
-    }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-    pub fn get(&self, idx: usize) -> Option<Tx> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn account_count(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(64, 72))
     }
-    pub fn get_unchecked(&self, idx: usize) -> Tx {
-        let offsets = self.item_offsets();
-        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
-        if idx == self.len() - 1 {
-            Tx::new_unchecked(self.0.slice_from(start))
-        } else {
-            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            Tx::new_unchecked(self.0.slice(start, end))
-        }
+    pub fn block_count(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(72, 80))
     }
-    pub fn as_reader<'r>(&'r self) -> TxVecReader<'r> {
-        TxVecReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GlobalStateReader<'r> {
+        GlobalStateReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for TxVec {
-    type Builder = TxVecBuilder;
-    const NAME: &'static str = "TxVec";
+impl molecule::prelude::Entity for GlobalState {
+    type Builder = GlobalStateBuilder;
+    const NAME: &'static str = "GlobalState";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TxVec(data)
+        GlobalState(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5033,21 +3843,25 @@ impl molecule::prelude::Entity for TxVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TxVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        GlobalStateReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TxVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GlobalStateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .account_root(self.account_root())
+            .block_root(self.block_root())
+            .account_count(self.account_count())
+            .block_count(self.block_count())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TxVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TxVecReader<'r> {
+pub struct GlobalStateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GlobalStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5056,236 +3870,110 @@ impl<'r> ::core::fmt::LowerHex for TxVecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TxVecReader<'r> {
+impl<'r> ::core::fmt::Debug for GlobalStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TxVecReader<'r> {
+impl<'r> ::core::fmt::Display for GlobalStateReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account_root", self.account_root())?;
+        write!(f, ", {}: {}", "block_root", self.block_root())?;
+        write!(f, ", {}: {}", "account_count", self.account_count())?;
+        write!(f, ", {}: {}", "block_count", self.block_count())?;
+        write!(f, " }}")
     }
 }
-impl<'r> TxVecReader<'r> {
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn item_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn item_offsets(&self) -> &[[u8; 4]] {
-        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
-    }
-    pub fn len(&self) -> usize {
-        self.item_count()
+impl<'r> GlobalStateReader<'r> {
+    pub const TOTAL_SIZE: usize = 80;
+    pub const FIELD_SIZE: [usize; 4] = [32, 32, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn account_root(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn block_root(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
     }
-    pub fn get(&self, idx: usize) -> Option<TxReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn account_count(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[64..72])
     }
-    pub fn get_unchecked(&self, idx: usize) -> TxReader<'r> {
-        let offsets = self.item_offsets();
-        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
-        if idx == self.len() - 1 {
-            TxReader::new_unchecked(&self.as_slice()[start..])
-        } else {
-            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            TxReader::new_unchecked(&self.as_slice()[start..end])
-        }
+    pub fn block_count(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[72..80])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TxVecReader<'r> {
-    type Entity = TxVec;
-    const NAME: &'static str = "TxVecReader";
+impl<'r> molecule::prelude::Reader<'r> for GlobalStateReader<'r> {
+    type Entity = GlobalState;
+    const NAME: &'static str = "GlobalStateReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TxVecReader(slice)
+        GlobalStateReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(
-                Self,
-                TotalSizeNotMatch,
-                molecule::NUMBER_SIZE * 2,
-                slice_len
-            );
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        let item_count = offset_first / 4 - 1;
-        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
-        if slice_len < header_size {
-            return ve!(Self, HeaderIsBroken, header_size, slice_len);
-        }
-        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
-        let mut offsets: Vec<usize> = ptr[..item_count]
-            .iter()
-            .map(|x| molecule::unpack_number(&x[..]) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        for pair in offsets.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            TxReader::verify(&slice[start..end], compatible)?;
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TxVecBuilder(pub(crate) Vec<Tx>);
-impl TxVecBuilder {
-    pub fn set(mut self, v: Vec<Tx>) -> Self {
-        self.0 = v;
+pub struct GlobalStateBuilder {
+    pub(crate) account_root: Byte32,
+    pub(crate) block_root: Byte32,
+    pub(crate) account_count: Uint64,
+    pub(crate) block_count: Uint64,
+}
+impl GlobalStateBuilder {
+    pub const TOTAL_SIZE: usize = 80;
+    pub const FIELD_SIZE: [usize; 4] = [32, 32, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn account_root(mut self, v: Byte32) -> Self {
+        self.account_root = v;
         self
     }
-    pub fn push(mut self, v: Tx) -> Self {
-        self.0.push(v);
+    pub fn block_root(mut self, v: Byte32) -> Self {
+        self.block_root = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = Tx>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn account_count(mut self, v: Uint64) -> Self {
+        self.account_count = v;
+        self
+    }
+    pub fn block_count(mut self, v: Uint64) -> Self {
+        self.block_count = v;
         self
     }
 }
-impl molecule::prelude::Builder for TxVecBuilder {
-    type Entity = TxVec;
-    const NAME: &'static str = "TxVecBuilder";
+impl molecule::prelude::Builder for GlobalStateBuilder {
+    type Entity = GlobalState;
+    const NAME: &'static str = "GlobalStateBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.0.len() + 1)
-            + self
-                .0
-                .iter()
-                .map(|inner| inner.as_slice().len())
-                .sum::<usize>()
+        Self::TOTAL_SIZE
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let item_count = self.0.len();
-        if item_count == 0 {
-            writer.write_all(&molecule::pack_number(
-                molecule::NUMBER_SIZE as molecule::Number,
-            ))?;
-        } else {
-            let (total_size, offsets) = self.0.iter().fold(
-                (
-                    molecule::NUMBER_SIZE * (item_count + 1),
-                    Vec::with_capacity(item_count),
-                ),
-                |(start, mut offsets), inner| {
-                    offsets.push(start);
-                    (start + inner.as_slice().len(), offsets)
-                },
-            );
-            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-            for offset in offsets.into_iter() {
-                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-            }
-            for inner in self.0.iter() {
-                writer.write_all(inner.as_slice())?;
-            }
-        }
+        writer.write_all(self.account_root.as_slice())?;
+        writer.write_all(self.block_root.as_slice())?;
+        writer.write_all(self.account_count.as_slice())?;
+        writer.write_all(self.block_count.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TxVec::new_unchecked(inner.into())
-    }
-}
-pub struct TxVecIterator(TxVec, usize, usize);
-impl ::core::iter::Iterator for TxVecIterator {
-    type Item = Tx;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for TxVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for TxVec {
-    type Item = Tx;
-    type IntoIter = TxVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        TxVecIterator(self, 0, len)
-    }
-}
-impl<'r> TxVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> TxVecReaderIterator<'t, 'r> {
-        TxVecReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct TxVecReaderIterator<'t, 'r>(&'t TxVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for TxVecReaderIterator<'t, 'r> {
-    type Item = TxReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for TxVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+        GlobalState::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct AgBlock(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for AgBlock {
+pub struct AccountScript(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AccountScript {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5294,27 +3982,16 @@ impl ::core::fmt::LowerHex for AgBlock {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for AgBlock {
+impl ::core::fmt::Debug for AccountScript {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for AgBlock {
+impl ::core::fmt::Display for AccountScript {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "number", self.number())?;
-        write!(f, ", {}: {}", "tx_root", self.tx_root())?;
-        write!(f, ", {}: {}", "txs_count", self.txs_count())?;
-        write!(f, ", {}: {}", "prev_account_root", self.prev_account_root())?;
-        write!(
-            f,
-            ", {}: {}",
-            "prev_account_count",
-            self.prev_account_count()
-        )?;
-        write!(f, ", {}: {}", "account_root", self.account_root())?;
-        write!(f, ", {}: {}", "ag_sig", self.ag_sig())?;
-        write!(f, ", {}: {}", "ag_index", self.ag_index())?;
+        write!(f, "{}: {}", "code_hash", self.code_hash())?;
+        write!(f, ", {}: {}", "args", self.args())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -5322,23 +3999,17 @@ impl ::core::fmt::Display for AgBlock {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for AgBlock {
+impl ::core::default::Default for AccountScript {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            225, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 112, 0, 0, 0, 120, 0,
-            0, 0, 152, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        AgBlock::new_unchecked(v.into())
+        AccountScript::new_unchecked(v.into())
     }
 }
-impl AgBlock {
-    pub const FIELD_COUNT: usize = 8;
+impl AccountScript {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -5358,67 +4029,31 @@ impl AgBlock {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn number(&self) -> Uint64 {
+    pub fn code_hash(&self) -> Byte32 {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn tx_root(&self) -> Byte32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn txs_count(&self) -> Uint32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn prev_account_root(&self) -> Byte32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        let end = molecule::unpack_number(&offsets[4][..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn prev_account_count(&self) -> Uint64 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[4][..]) as usize;
-        let end = molecule::unpack_number(&offsets[5][..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn account_root(&self) -> Byte32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[5][..]) as usize;
-        let end = molecule::unpack_number(&offsets[6][..]) as usize;
         Byte32::new_unchecked(self.0.slice(start, end))
     }
-    pub fn ag_sig(&self) -> Byte65 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[6][..]) as usize;
-        let end = molecule::unpack_number(&offsets[7][..]) as usize;
-        Byte65::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn ag_index(&self) -> Uint64 {
+    pub fn args(&self) -> Bytes {
         let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[7][..]) as usize;
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[8][..]) as usize;
-            Uint64::new_unchecked(self.0.slice(start, end))
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start, end))
         } else {
-            Uint64::new_unchecked(self.0.slice_from(start))
+            Bytes::new_unchecked(self.0.slice_from(start))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AgBlockReader<'r> {
-        AgBlockReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> AccountScriptReader<'r> {
+        AccountScriptReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for AgBlock {
-    type Builder = AgBlockBuilder;
-    const NAME: &'static str = "AgBlock";
+impl molecule::prelude::Entity for AccountScript {
+    type Builder = AccountScriptBuilder;
+    const NAME: &'static str = "AccountScript";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AgBlock(data)
+        AccountScript(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5427,29 +4062,23 @@ impl molecule::prelude::Entity for AgBlock {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AgBlockReader::from_slice(slice).map(|reader| reader.to_entity())
+        AccountScriptReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AgBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        AccountScriptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .number(self.number())
-            .tx_root(self.tx_root())
-            .txs_count(self.txs_count())
-            .prev_account_root(self.prev_account_root())
-            .prev_account_count(self.prev_account_count())
-            .account_root(self.account_root())
-            .ag_sig(self.ag_sig())
-            .ag_index(self.ag_index())
+            .code_hash(self.code_hash())
+            .args(self.args())
     }
 }
 #[derive(Clone, Copy)]
-pub struct AgBlockReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AgBlockReader<'r> {
+pub struct AccountScriptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AccountScriptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5458,27 +4087,16 @@ impl<'r> ::core::fmt::LowerHex for AgBlockReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for AgBlockReader<'r> {
+impl<'r> ::core::fmt::Debug for AccountScriptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for AgBlockReader<'r> {
+impl<'r> ::core::fmt::Display for AccountScriptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "number", self.number())?;
-        write!(f, ", {}: {}", "tx_root", self.tx_root())?;
-        write!(f, ", {}: {}", "txs_count", self.txs_count())?;
-        write!(f, ", {}: {}", "prev_account_root", self.prev_account_root())?;
-        write!(
-            f,
-            ", {}: {}",
-            "prev_account_count",
-            self.prev_account_count()
-        )?;
-        write!(f, ", {}: {}", "account_root", self.account_root())?;
-        write!(f, ", {}: {}", "ag_sig", self.ag_sig())?;
-        write!(f, ", {}: {}", "ag_index", self.ag_index())?;
+        write!(f, "{}: {}", "code_hash", self.code_hash())?;
+        write!(f, ", {}: {}", "args", self.args())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -5486,8 +4104,8 @@ impl<'r> ::core::fmt::Display for AgBlockReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> AgBlockReader<'r> {
-    pub const FIELD_COUNT: usize = 8;
+impl<'r> AccountScriptReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -5507,67 +4125,31 @@ impl<'r> AgBlockReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn number(&self) -> Uint64Reader<'r> {
+    pub fn code_hash(&self) -> Byte32Reader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn tx_root(&self) -> Byte32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn txs_count(&self) -> Uint32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn prev_account_root(&self) -> Byte32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        let end = molecule::unpack_number(&offsets[4][..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn prev_account_count(&self) -> Uint64Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[4][..]) as usize;
-        let end = molecule::unpack_number(&offsets[5][..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn account_root(&self) -> Byte32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[5][..]) as usize;
-        let end = molecule::unpack_number(&offsets[6][..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn ag_sig(&self) -> Byte65Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[6][..]) as usize;
-        let end = molecule::unpack_number(&offsets[7][..]) as usize;
-        Byte65Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn ag_index(&self) -> Uint64Reader<'r> {
+    pub fn args(&self) -> BytesReader<'r> {
         let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[7][..]) as usize;
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[8][..]) as usize;
-            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+            BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AgBlockReader<'r> {
-    type Entity = AgBlock;
-    const NAME: &'static str = "AgBlockReader";
+impl<'r> molecule::prelude::Reader<'r> for AccountScriptReader<'r> {
+    type Entity = AccountScript;
+    const NAME: &'static str = "AccountScriptReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AgBlockReader(slice)
+        AccountScriptReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -5611,120 +4193,8329 @@ impl<'r> molecule::prelude::Reader<'r> for AgBlockReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        Byte65Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct AgBlockBuilder {
-    pub(crate) number: Uint64,
-    pub(crate) tx_root: Byte32,
-    pub(crate) txs_count: Uint32,
-    pub(crate) prev_account_root: Byte32,
-    pub(crate) prev_account_count: Uint64,
-    pub(crate) account_root: Byte32,
-    pub(crate) ag_sig: Byte65,
-    pub(crate) ag_index: Uint64,
+pub struct AccountScriptBuilder {
+    pub(crate) code_hash: Byte32,
+    pub(crate) args: Bytes,
 }
-impl AgBlockBuilder {
-    pub const FIELD_COUNT: usize = 8;
-    pub fn number(mut self, v: Uint64) -> Self {
-        self.number = v;
+impl AccountScriptBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn code_hash(mut self, v: Byte32) -> Self {
+        self.code_hash = v;
         self
     }
-    pub fn tx_root(mut self, v: Byte32) -> Self {
-        self.tx_root = v;
+    pub fn args(mut self, v: Bytes) -> Self {
+        self.args = v;
         self
     }
-    pub fn txs_count(mut self, v: Uint32) -> Self {
-        self.txs_count = v;
-        self
+}
+impl molecule::prelude::Builder for AccountScriptBuilder {
+    type Entity = AccountScript;
+    const NAME: &'static str = "AccountScriptBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.code_hash.as_slice().len()
+            + self.args.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.args.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.code_hash.as_slice())?;
+        writer.write_all(self.args.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AccountScript::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct AccountScriptOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AccountScriptOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for AccountScriptOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for AccountScriptOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for AccountScriptOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        AccountScriptOpt::new_unchecked(v.into())
+    }
+}
+impl AccountScriptOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<AccountScript> {
+        if self.is_none() {
+            None
+        } else {
+            Some(AccountScript::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AccountScriptOptReader<'r> {
+        AccountScriptOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for AccountScriptOpt {
+    type Builder = AccountScriptOptBuilder;
+    const NAME: &'static str = "AccountScriptOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        AccountScriptOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AccountScriptOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AccountScriptOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AccountScriptOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AccountScriptOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AccountScriptOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AccountScriptOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> AccountScriptOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<AccountScriptReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(AccountScriptReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AccountScriptOptReader<'r> {
+    type Entity = AccountScriptOpt;
+    const NAME: &'static str = "AccountScriptOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AccountScriptOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            AccountScriptReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AccountScriptOptBuilder(pub(crate) Option<AccountScript>);
+impl AccountScriptOptBuilder {
+    pub fn set(mut self, v: Option<AccountScript>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AccountScriptOptBuilder {
+    type Entity = AccountScriptOpt;
+    const NAME: &'static str = "AccountScriptOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AccountScriptOpt::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct KeyValue(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for KeyValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for KeyValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for KeyValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "key", self.key())?;
+        write!(f, ", {}: {}", "value", self.value())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for KeyValue {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        KeyValue::new_unchecked(v.into())
+    }
+}
+impl KeyValue {
+    pub const TOTAL_SIZE: usize = 48;
+    pub const FIELD_SIZE: [usize; 2] = [32, 16];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn key(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0, 32))
+    }
+    pub fn value(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(32, 48))
+    }
+    pub fn as_reader<'r>(&'r self) -> KeyValueReader<'r> {
+        KeyValueReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for KeyValue {
+    type Builder = KeyValueBuilder;
+    const NAME: &'static str = "KeyValue";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        KeyValue(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        KeyValueReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        KeyValueReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().key(self.key()).value(self.value())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct KeyValueReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for KeyValueReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for KeyValueReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for KeyValueReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "key", self.key())?;
+        write!(f, ", {}: {}", "value", self.value())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> KeyValueReader<'r> {
+    pub const TOTAL_SIZE: usize = 48;
+    pub const FIELD_SIZE: [usize; 2] = [32, 16];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn key(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn value(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[32..48])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for KeyValueReader<'r> {
+    type Entity = KeyValue;
+    const NAME: &'static str = "KeyValueReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        KeyValueReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct KeyValueBuilder {
+    pub(crate) key: Byte32,
+    pub(crate) value: Uint128,
+}
+impl KeyValueBuilder {
+    pub const TOTAL_SIZE: usize = 48;
+    pub const FIELD_SIZE: [usize; 2] = [32, 16];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn key(mut self, v: Byte32) -> Self {
+        self.key = v;
+        self
+    }
+    pub fn value(mut self, v: Uint128) -> Self {
+        self.value = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for KeyValueBuilder {
+    type Entity = KeyValue;
+    const NAME: &'static str = "KeyValueBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.key.as_slice())?;
+        writer.write_all(self.value.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        KeyValue::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct KeyValueMap(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for KeyValueMap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for KeyValueMap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for KeyValueMap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for KeyValueMap {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        KeyValueMap::new_unchecked(v.into())
+    }
+}
+impl KeyValueMap {
+    pub const ITEM_SIZE: usize = 48;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<KeyValue> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> KeyValue {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        KeyValue::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn as_reader<'r>(&'r self) -> KeyValueMapReader<'r> {
+        KeyValueMapReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for KeyValueMap {
+    type Builder = KeyValueMapBuilder;
+    const NAME: &'static str = "KeyValueMap";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        KeyValueMap(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        KeyValueMapReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        KeyValueMapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct KeyValueMapReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for KeyValueMapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for KeyValueMapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for KeyValueMapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> KeyValueMapReader<'r> {
+    pub const ITEM_SIZE: usize = 48;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<KeyValueReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> KeyValueReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        KeyValueReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for KeyValueMapReader<'r> {
+    type Entity = KeyValueMap;
+    const NAME: &'static str = "KeyValueMapReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        KeyValueMapReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct KeyValueMapBuilder(pub(crate) Vec<KeyValue>);
+impl KeyValueMapBuilder {
+    pub const ITEM_SIZE: usize = 48;
+    pub fn set(mut self, v: Vec<KeyValue>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: KeyValue) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = KeyValue>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for KeyValueMapBuilder {
+    type Entity = KeyValueMap;
+    const NAME: &'static str = "KeyValueMapBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        KeyValueMap::new_unchecked(inner.into())
+    }
+}
+pub struct KeyValueMapIterator(KeyValueMap, usize, usize);
+impl ::core::iter::Iterator for KeyValueMapIterator {
+    type Item = KeyValue;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for KeyValueMapIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for KeyValueMap {
+    type Item = KeyValue;
+    type IntoIter = KeyValueMapIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        KeyValueMapIterator(self, 0, len)
+    }
+}
+impl<'r> KeyValueMapReader<'r> {
+    pub fn iter<'t>(&'t self) -> KeyValueMapReaderIterator<'t, 'r> {
+        KeyValueMapReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct KeyValueMapReaderIterator<'t, 'r>(&'t KeyValueMapReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for KeyValueMapReaderIterator<'t, 'r> {
+    type Item = KeyValueReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for KeyValueMapReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct StorageKeyValue(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for StorageKeyValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for StorageKeyValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for StorageKeyValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "key", self.key())?;
+        write!(f, ", {}: {}", "value", self.value())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for StorageKeyValue {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        StorageKeyValue::new_unchecked(v.into())
+    }
+}
+impl StorageKeyValue {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn key(&self) -> Byte32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn value(&self) -> Bytes {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start, end))
+        } else {
+            Bytes::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> StorageKeyValueReader<'r> {
+        StorageKeyValueReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for StorageKeyValue {
+    type Builder = StorageKeyValueBuilder;
+    const NAME: &'static str = "StorageKeyValue";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        StorageKeyValue(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StorageKeyValueReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StorageKeyValueReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().key(self.key()).value(self.value())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct StorageKeyValueReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for StorageKeyValueReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for StorageKeyValueReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for StorageKeyValueReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "key", self.key())?;
+        write!(f, ", {}: {}", "value", self.value())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> StorageKeyValueReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn key(&self) -> Byte32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn value(&self) -> BytesReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for StorageKeyValueReader<'r> {
+    type Entity = StorageKeyValue;
+    const NAME: &'static str = "StorageKeyValueReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        StorageKeyValueReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct StorageKeyValueBuilder {
+    pub(crate) key: Byte32,
+    pub(crate) value: Bytes,
+}
+impl StorageKeyValueBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn key(mut self, v: Byte32) -> Self {
+        self.key = v;
+        self
+    }
+    pub fn value(mut self, v: Bytes) -> Self {
+        self.value = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for StorageKeyValueBuilder {
+    type Entity = StorageKeyValue;
+    const NAME: &'static str = "StorageKeyValueBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.key.as_slice().len()
+            + self.value.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.key.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.value.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.key.as_slice())?;
+        writer.write_all(self.value.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        StorageKeyValue::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct StorageKeyValueMap(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for StorageKeyValueMap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for StorageKeyValueMap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for StorageKeyValueMap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for StorageKeyValueMap {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        StorageKeyValueMap::new_unchecked(v.into())
+    }
+}
+impl StorageKeyValueMap {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<StorageKeyValue> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> StorageKeyValue {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            StorageKeyValue::new_unchecked(self.0.slice_from(start))
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            StorageKeyValue::new_unchecked(self.0.slice(start, end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> StorageKeyValueMapReader<'r> {
+        StorageKeyValueMapReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for StorageKeyValueMap {
+    type Builder = StorageKeyValueMapBuilder;
+    const NAME: &'static str = "StorageKeyValueMap";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        StorageKeyValueMap(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StorageKeyValueMapReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StorageKeyValueMapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct StorageKeyValueMapReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for StorageKeyValueMapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for StorageKeyValueMapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for StorageKeyValueMapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> StorageKeyValueMapReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<StorageKeyValueReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> StorageKeyValueReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            StorageKeyValueReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            StorageKeyValueReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for StorageKeyValueMapReader<'r> {
+    type Entity = StorageKeyValueMap;
+    const NAME: &'static str = "StorageKeyValueMapReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        StorageKeyValueMapReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..item_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            StorageKeyValueReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct StorageKeyValueMapBuilder(pub(crate) Vec<StorageKeyValue>);
+impl StorageKeyValueMapBuilder {
+    pub fn set(mut self, v: Vec<StorageKeyValue>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: StorageKeyValue) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = StorageKeyValue>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for StorageKeyValueMapBuilder {
+    type Entity = StorageKeyValueMap;
+    const NAME: &'static str = "StorageKeyValueMapBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        StorageKeyValueMap::new_unchecked(inner.into())
+    }
+}
+pub struct StorageKeyValueMapIterator(StorageKeyValueMap, usize, usize);
+impl ::core::iter::Iterator for StorageKeyValueMapIterator {
+    type Item = StorageKeyValue;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for StorageKeyValueMapIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for StorageKeyValueMap {
+    type Item = StorageKeyValue;
+    type IntoIter = StorageKeyValueMapIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        StorageKeyValueMapIterator(self, 0, len)
+    }
+}
+impl<'r> StorageKeyValueMapReader<'r> {
+    pub fn iter<'t>(&'t self) -> StorageKeyValueMapReaderIterator<'t, 'r> {
+        StorageKeyValueMapReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct StorageKeyValueMapReaderIterator<'t, 'r>(&'t StorageKeyValueMapReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for StorageKeyValueMapReaderIterator<'t, 'r> {
+    type Item = StorageKeyValueReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for StorageKeyValueMapReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct TreePath(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TreePath {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TreePath {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TreePath {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for TreePath {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        TreePath::new_unchecked(v.into())
+    }
+}
+impl TreePath {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Byte> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Byte {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Byte::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.0.slice_from(molecule::NUMBER_SIZE)
+    }
+    pub fn as_reader<'r>(&'r self) -> TreePathReader<'r> {
+        TreePathReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TreePath {
+    type Builder = TreePathBuilder;
+    const NAME: &'static str = "TreePath";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TreePath(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TreePathReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TreePathReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TreePathReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TreePathReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TreePathReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TreePathReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> TreePathReader<'r> {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ByteReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ByteReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        &self.as_slice()[molecule::NUMBER_SIZE..]
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TreePathReader<'r> {
+    type Entity = TreePath;
+    const NAME: &'static str = "TreePathReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TreePathReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TreePathBuilder(pub(crate) Vec<Byte>);
+impl TreePathBuilder {
+    pub const ITEM_SIZE: usize = 1;
+    pub fn set(mut self, v: Vec<Byte>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Byte) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Byte>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for TreePathBuilder {
+    type Entity = TreePath;
+    const NAME: &'static str = "TreePathBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TreePath::new_unchecked(inner.into())
+    }
+}
+pub struct TreePathIterator(TreePath, usize, usize);
+impl ::core::iter::Iterator for TreePathIterator {
+    type Item = Byte;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for TreePathIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for TreePath {
+    type Item = Byte;
+    type IntoIter = TreePathIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        TreePathIterator(self, 0, len)
+    }
+}
+#[derive(Clone)]
+pub struct TreePathVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TreePathVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TreePathVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TreePathVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for TreePathVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        TreePathVec::new_unchecked(v.into())
+    }
+}
+impl TreePathVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<TreePath> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> TreePath {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            TreePath::new_unchecked(self.0.slice_from(start))
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            TreePath::new_unchecked(self.0.slice(start, end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TreePathVecReader<'r> {
+        TreePathVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TreePathVec {
+    type Builder = TreePathVecBuilder;
+    const NAME: &'static str = "TreePathVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TreePathVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TreePathVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TreePathVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TreePathVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TreePathVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TreePathVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TreePathVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> TreePathVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<TreePathReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> TreePathReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            TreePathReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            TreePathReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TreePathVecReader<'r> {
+    type Entity = TreePathVec;
+    const NAME: &'static str = "TreePathVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TreePathVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..item_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            TreePathReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TreePathVecBuilder(pub(crate) Vec<TreePath>);
+impl TreePathVecBuilder {
+    pub fn set(mut self, v: Vec<TreePath>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: TreePath) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = TreePath>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for TreePathVecBuilder {
+    type Entity = TreePathVec;
+    const NAME: &'static str = "TreePathVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TreePathVec::new_unchecked(inner.into())
+    }
+}
+pub struct TreePathVecIterator(TreePathVec, usize, usize);
+impl ::core::iter::Iterator for TreePathVecIterator {
+    type Item = TreePath;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for TreePathVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for TreePathVec {
+    type Item = TreePath;
+    type IntoIter = TreePathVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        TreePathVecIterator(self, 0, len)
+    }
+}
+impl<'r> TreePathVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> TreePathVecReaderIterator<'t, 'r> {
+        TreePathVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct TreePathVecReaderIterator<'t, 'r>(&'t TreePathVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for TreePathVecReaderIterator<'t, 'r> {
+    type Item = TreePathReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for TreePathVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct SMTBranch(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SMTBranch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SMTBranch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SMTBranch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "node", self.node())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SMTBranch {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        SMTBranch::new_unchecked(v.into())
+    }
+}
+impl SMTBranch {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZE: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn node(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0, 32))
+    }
+    pub fn height(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32, 33))
+    }
+    pub fn as_reader<'r>(&'r self) -> SMTBranchReader<'r> {
+        SMTBranchReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SMTBranch {
+    type Builder = SMTBranchBuilder;
+    const NAME: &'static str = "SMTBranch";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SMTBranch(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SMTBranchReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SMTBranchReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().node(self.node()).height(self.height())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SMTBranchReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SMTBranchReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SMTBranchReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SMTBranchReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "node", self.node())?;
+        write!(f, ", {}: {}", "height", self.height())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> SMTBranchReader<'r> {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZE: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn node(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn height(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SMTBranchReader<'r> {
+    type Entity = SMTBranch;
+    const NAME: &'static str = "SMTBranchReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SMTBranchReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SMTBranchBuilder {
+    pub(crate) node: Byte32,
+    pub(crate) height: Byte,
+}
+impl SMTBranchBuilder {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZE: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn node(mut self, v: Byte32) -> Self {
+        self.node = v;
+        self
+    }
+    pub fn height(mut self, v: Byte) -> Self {
+        self.height = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SMTBranchBuilder {
+    type Entity = SMTBranch;
+    const NAME: &'static str = "SMTBranchBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.node.as_slice())?;
+        writer.write_all(self.height.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SMTBranch::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SMTBranchVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SMTBranchVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SMTBranchVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SMTBranchVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for SMTBranchVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        SMTBranchVec::new_unchecked(v.into())
+    }
+}
+impl SMTBranchVec {
+    pub const ITEM_SIZE: usize = 33;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SMTBranch> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SMTBranch {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        SMTBranch::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn as_reader<'r>(&'r self) -> SMTBranchVecReader<'r> {
+        SMTBranchVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SMTBranchVec {
+    type Builder = SMTBranchVecBuilder;
+    const NAME: &'static str = "SMTBranchVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SMTBranchVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SMTBranchVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SMTBranchVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SMTBranchVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SMTBranchVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SMTBranchVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SMTBranchVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> SMTBranchVecReader<'r> {
+    pub const ITEM_SIZE: usize = 33;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.item_count() + 1)
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SMTBranchReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SMTBranchReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        SMTBranchReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SMTBranchVecReader<'r> {
+    type Entity = SMTBranchVec;
+    const NAME: &'static str = "SMTBranchVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SMTBranchVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SMTBranchVecBuilder(pub(crate) Vec<SMTBranch>);
+impl SMTBranchVecBuilder {
+    pub const ITEM_SIZE: usize = 33;
+    pub fn set(mut self, v: Vec<SMTBranch>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: SMTBranch) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = SMTBranch>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for SMTBranchVecBuilder {
+    type Entity = SMTBranchVec;
+    const NAME: &'static str = "SMTBranchVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SMTBranchVec::new_unchecked(inner.into())
+    }
+}
+pub struct SMTBranchVecIterator(SMTBranchVec, usize, usize);
+impl ::core::iter::Iterator for SMTBranchVecIterator {
+    type Item = SMTBranch;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for SMTBranchVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for SMTBranchVec {
+    type Item = SMTBranch;
+    type IntoIter = SMTBranchVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        SMTBranchVecIterator(self, 0, len)
+    }
+}
+impl<'r> SMTBranchVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> SMTBranchVecReaderIterator<'t, 'r> {
+        SMTBranchVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct SMTBranchVecReaderIterator<'t, 'r>(&'t SMTBranchVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for SMTBranchVecReaderIterator<'t, 'r> {
+    type Item = SMTBranchReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SMTBranchVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct SMTProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SMTProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SMTProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SMTProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "leaves_path", self.leaves_path())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SMTProof {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        SMTProof::new_unchecked(v.into())
+    }
+}
+impl SMTProof {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn leaves_path(&self) -> TreePathVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        TreePathVec::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn proof(&self) -> SMTBranchVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            SMTBranchVec::new_unchecked(self.0.slice(start, end))
+        } else {
+            SMTBranchVec::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SMTProofReader<'r> {
+        SMTProofReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SMTProof {
+    type Builder = SMTProofBuilder;
+    const NAME: &'static str = "SMTProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SMTProof(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SMTProofReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SMTProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .leaves_path(self.leaves_path())
+            .proof(self.proof())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SMTProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SMTProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SMTProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SMTProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "leaves_path", self.leaves_path())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SMTProofReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn leaves_path(&self) -> TreePathVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        TreePathVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof(&self) -> SMTBranchVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            SMTBranchVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SMTBranchVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SMTProofReader<'r> {
+    type Entity = SMTProof;
+    const NAME: &'static str = "SMTProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SMTProofReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        TreePathVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SMTBranchVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SMTProofBuilder {
+    pub(crate) leaves_path: TreePathVec,
+    pub(crate) proof: SMTBranchVec,
+}
+impl SMTProofBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn leaves_path(mut self, v: TreePathVec) -> Self {
+        self.leaves_path = v;
+        self
+    }
+    pub fn proof(mut self, v: SMTBranchVec) -> Self {
+        self.proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SMTProofBuilder {
+    type Entity = SMTProof;
+    const NAME: &'static str = "SMTProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.leaves_path.as_slice().len()
+            + self.proof.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.leaves_path.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.leaves_path.as_slice())?;
+        writer.write_all(self.proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SMTProof::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Account(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Account {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Account {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Account {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "index", self.index())?;
+        write!(f, ", {}: {}", "script", self.script())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(f, ", {}: {}", "pubkey_hash", self.pubkey_hash())?;
+        write!(f, ", {}: {}", "sig_scheme", self.sig_scheme())?;
+        write!(f, ", {}: {}", "bls_pubkey", self.bls_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Account {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            61, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ];
+        Account::new_unchecked(v.into())
+    }
+}
+impl Account {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn index(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn script(&self) -> AccountScriptOpt {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        AccountScriptOpt::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn nonce(&self) -> Uint32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn pubkey_hash(&self) -> Byte20 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        Byte20::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn sig_scheme(&self) -> Byte {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        Byte::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn bls_pubkey(&self) -> Byte48Opt {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[6][..]) as usize;
+            Byte48Opt::new_unchecked(self.0.slice(start, end))
+        } else {
+            Byte48Opt::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AccountReader<'r> {
+        AccountReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Account {
+    type Builder = AccountBuilder;
+    const NAME: &'static str = "Account";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Account(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AccountReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AccountReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .index(self.index())
+            .script(self.script())
+            .nonce(self.nonce())
+            .pubkey_hash(self.pubkey_hash())
+            .sig_scheme(self.sig_scheme())
+            .bls_pubkey(self.bls_pubkey())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AccountReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AccountReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AccountReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AccountReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "index", self.index())?;
+        write!(f, ", {}: {}", "script", self.script())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(f, ", {}: {}", "pubkey_hash", self.pubkey_hash())?;
+        write!(f, ", {}: {}", "sig_scheme", self.sig_scheme())?;
+        write!(f, ", {}: {}", "bls_pubkey", self.bls_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> AccountReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn index(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn script(&self) -> AccountScriptOptReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        AccountScriptOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn nonce(&self) -> Uint32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pubkey_hash(&self) -> Byte20Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        Byte20Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sig_scheme(&self) -> ByteReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bls_pubkey(&self) -> Byte48OptReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[6][..]) as usize;
+            Byte48OptReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte48OptReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AccountReader<'r> {
+    type Entity = Account;
+    const NAME: &'static str = "AccountReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AccountReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        AccountScriptOptReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte20Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte48OptReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AccountBuilder {
+    pub(crate) index: Uint64,
+    pub(crate) script: AccountScriptOpt,
+    pub(crate) nonce: Uint32,
+    pub(crate) pubkey_hash: Byte20,
+    pub(crate) sig_scheme: Byte,
+    pub(crate) bls_pubkey: Byte48Opt,
+}
+impl AccountBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn index(mut self, v: Uint64) -> Self {
+        self.index = v;
+        self
+    }
+    pub fn script(mut self, v: AccountScriptOpt) -> Self {
+        self.script = v;
+        self
+    }
+    pub fn nonce(mut self, v: Uint32) -> Self {
+        self.nonce = v;
+        self
+    }
+    pub fn pubkey_hash(mut self, v: Byte20) -> Self {
+        self.pubkey_hash = v;
+        self
+    }
+    pub fn sig_scheme(mut self, v: Byte) -> Self {
+        self.sig_scheme = v;
+        self
+    }
+    pub fn bls_pubkey(mut self, v: Byte48Opt) -> Self {
+        self.bls_pubkey = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AccountBuilder {
+    type Entity = Account;
+    const NAME: &'static str = "AccountBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.index.as_slice().len()
+            + self.script.as_slice().len()
+            + self.nonce.as_slice().len()
+            + self.pubkey_hash.as_slice().len()
+            + self.sig_scheme.as_slice().len()
+            + self.bls_pubkey.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.script.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pubkey_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_scheme.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bls_pubkey.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.index.as_slice())?;
+        writer.write_all(self.script.as_slice())?;
+        writer.write_all(self.nonce.as_slice())?;
+        writer.write_all(self.pubkey_hash.as_slice())?;
+        writer.write_all(self.sig_scheme.as_slice())?;
+        writer.write_all(self.bls_pubkey.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Account::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Payment(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Payment {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Payment {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Payment {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for Payment {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        Payment::new_unchecked(v.into())
+    }
+}
+impl Payment {
+    pub const ITEM_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> PaymentUnion {
+        let inner = self.0.slice_from(molecule::NUMBER_SIZE);
+        match self.item_id() {
+            0 => Uint64::new_unchecked(inner).into(),
+            1 => UDTPayment::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> PaymentReader<'r> {
+        PaymentReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Payment {
+    type Builder = PaymentBuilder;
+    const NAME: &'static str = "Payment";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Payment(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        PaymentReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        PaymentReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct PaymentReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for PaymentReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for PaymentReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for PaymentReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> PaymentReader<'r> {
+    pub const ITEM_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> PaymentUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => Uint64Reader::new_unchecked(inner).into(),
+            1 => UDTPaymentReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for PaymentReader<'r> {
+    type Entity = Payment;
+    const NAME: &'static str = "PaymentReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        PaymentReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => Uint64Reader::verify(inner_slice, compatible),
+            1 => UDTPaymentReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEM_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct PaymentBuilder(pub(crate) PaymentUnion);
+impl PaymentBuilder {
+    pub const ITEM_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<PaymentUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for PaymentBuilder {
+    type Entity = Payment;
+    const NAME: &'static str = "PaymentBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Payment::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum PaymentUnion {
+    Uint64(Uint64),
+    UDTPayment(UDTPayment),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum PaymentUnionReader<'r> {
+    Uint64(Uint64Reader<'r>),
+    UDTPayment(UDTPaymentReader<'r>),
+}
+impl ::core::default::Default for PaymentUnion {
+    fn default() -> Self {
+        PaymentUnion::Uint64(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for PaymentUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            PaymentUnion::Uint64(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Uint64::NAME, item)
+            }
+            PaymentUnion::UDTPayment(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, UDTPayment::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for PaymentUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            PaymentUnionReader::Uint64(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Uint64::NAME, item)
+            }
+            PaymentUnionReader::UDTPayment(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, UDTPayment::NAME, item)
+            }
+        }
+    }
+}
+impl PaymentUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            PaymentUnion::Uint64(ref item) => write!(f, "{}", item),
+            PaymentUnion::UDTPayment(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> PaymentUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            PaymentUnionReader::Uint64(ref item) => write!(f, "{}", item),
+            PaymentUnionReader::UDTPayment(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<Uint64> for PaymentUnion {
+    fn from(item: Uint64) -> Self {
+        PaymentUnion::Uint64(item)
+    }
+}
+impl ::core::convert::From<UDTPayment> for PaymentUnion {
+    fn from(item: UDTPayment) -> Self {
+        PaymentUnion::UDTPayment(item)
+    }
+}
+impl<'r> ::core::convert::From<Uint64Reader<'r>> for PaymentUnionReader<'r> {
+    fn from(item: Uint64Reader<'r>) -> Self {
+        PaymentUnionReader::Uint64(item)
+    }
+}
+impl<'r> ::core::convert::From<UDTPaymentReader<'r>> for PaymentUnionReader<'r> {
+    fn from(item: UDTPaymentReader<'r>) -> Self {
+        PaymentUnionReader::UDTPayment(item)
+    }
+}
+impl PaymentUnion {
+    pub const NAME: &'static str = "PaymentUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            PaymentUnion::Uint64(item) => item.as_bytes(),
+            PaymentUnion::UDTPayment(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            PaymentUnion::Uint64(item) => item.as_slice(),
+            PaymentUnion::UDTPayment(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            PaymentUnion::Uint64(_) => 0,
+            PaymentUnion::UDTPayment(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            PaymentUnion::Uint64(_) => "Uint64",
+            PaymentUnion::UDTPayment(_) => "UDTPayment",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> PaymentUnionReader<'r> {
+        match self {
+            PaymentUnion::Uint64(item) => item.as_reader().into(),
+            PaymentUnion::UDTPayment(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> PaymentUnionReader<'r> {
+    pub const NAME: &'r str = "PaymentUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            PaymentUnionReader::Uint64(item) => item.as_slice(),
+            PaymentUnionReader::UDTPayment(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            PaymentUnionReader::Uint64(_) => 0,
+            PaymentUnionReader::UDTPayment(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            PaymentUnionReader::Uint64(_) => "Uint64",
+            PaymentUnionReader::UDTPayment(_) => "UDTPayment",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct UDTPayment(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for UDTPayment {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for UDTPayment {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for UDTPayment {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_hash", self.type_hash())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for UDTPayment {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        UDTPayment::new_unchecked(v.into())
+    }
+}
+impl UDTPayment {
+    pub const TOTAL_SIZE: usize = 48;
+    pub const FIELD_SIZE: [usize; 2] = [32, 16];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn type_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0, 32))
+    }
+    pub fn amount(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(32, 48))
+    }
+    pub fn as_reader<'r>(&'r self) -> UDTPaymentReader<'r> {
+        UDTPaymentReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for UDTPayment {
+    type Builder = UDTPaymentBuilder;
+    const NAME: &'static str = "UDTPayment";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        UDTPayment(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        UDTPaymentReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        UDTPaymentReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .type_hash(self.type_hash())
+            .amount(self.amount())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct UDTPaymentReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for UDTPaymentReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for UDTPaymentReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for UDTPaymentReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_hash", self.type_hash())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> UDTPaymentReader<'r> {
+    pub const TOTAL_SIZE: usize = 48;
+    pub const FIELD_SIZE: [usize; 2] = [32, 16];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn type_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn amount(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[32..48])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for UDTPaymentReader<'r> {
+    type Entity = UDTPayment;
+    const NAME: &'static str = "UDTPaymentReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        UDTPaymentReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct UDTPaymentBuilder {
+    pub(crate) type_hash: Byte32,
+    pub(crate) amount: Uint128,
+}
+impl UDTPaymentBuilder {
+    pub const TOTAL_SIZE: usize = 48;
+    pub const FIELD_SIZE: [usize; 2] = [32, 16];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn type_hash(mut self, v: Byte32) -> Self {
+        self.type_hash = v;
+        self
+    }
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for UDTPaymentBuilder {
+    type Entity = UDTPayment;
+    const NAME: &'static str = "UDTPaymentBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.type_hash.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        UDTPayment::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Transfer(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Transfer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Transfer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Transfer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "to_index", self.to_index())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Transfer {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            32, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        Transfer::new_unchecked(v.into())
+    }
+}
+impl Transfer {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn to_index(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn amount(&self) -> Payment {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            Payment::new_unchecked(self.0.slice(start, end))
+        } else {
+            Payment::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TransferReader<'r> {
+        TransferReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Transfer {
+    type Builder = TransferBuilder;
+    const NAME: &'static str = "Transfer";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Transfer(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .to_index(self.to_index())
+            .amount(self.amount())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TransferReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransferReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TransferReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TransferReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "to_index", self.to_index())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TransferReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn to_index(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> PaymentReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            PaymentReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            PaymentReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TransferReader<'r> {
+    type Entity = Transfer;
+    const NAME: &'static str = "TransferReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TransferReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        PaymentReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TransferBuilder {
+    pub(crate) to_index: Uint64,
+    pub(crate) amount: Payment,
+}
+impl TransferBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn to_index(mut self, v: Uint64) -> Self {
+        self.to_index = v;
+        self
+    }
+    pub fn amount(mut self, v: Payment) -> Self {
+        self.amount = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TransferBuilder {
+    type Entity = Transfer;
+    const NAME: &'static str = "TransferBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.to_index.as_slice().len()
+            + self.amount.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.to_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.to_index.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Transfer::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TransferItem(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransferItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TransferItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TransferItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "to_index", self.to_index())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for TransferItem {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            32, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        TransferItem::new_unchecked(v.into())
+    }
+}
+impl TransferItem {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn to_index(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn amount(&self) -> Payment {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            Payment::new_unchecked(self.0.slice(start, end))
+        } else {
+            Payment::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TransferItemReader<'r> {
+        TransferItemReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TransferItem {
+    type Builder = TransferItemBuilder;
+    const NAME: &'static str = "TransferItem";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TransferItem(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferItemReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferItemReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .to_index(self.to_index())
+            .amount(self.amount())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TransferItemReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransferItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TransferItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TransferItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "to_index", self.to_index())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TransferItemReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn to_index(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> PaymentReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            PaymentReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            PaymentReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TransferItemReader<'r> {
+    type Entity = TransferItem;
+    const NAME: &'static str = "TransferItemReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TransferItemReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        PaymentReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TransferItemBuilder {
+    pub(crate) to_index: Uint64,
+    pub(crate) amount: Payment,
+}
+impl TransferItemBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn to_index(mut self, v: Uint64) -> Self {
+        self.to_index = v;
+        self
+    }
+    pub fn amount(mut self, v: Payment) -> Self {
+        self.amount = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TransferItemBuilder {
+    type Entity = TransferItem;
+    const NAME: &'static str = "TransferItemBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.to_index.as_slice().len()
+            + self.amount.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.to_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.to_index.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TransferItem::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TransferItemVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransferItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TransferItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TransferItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for TransferItemVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        TransferItemVec::new_unchecked(v.into())
+    }
+}
+impl TransferItemVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<TransferItem> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> TransferItem {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            TransferItem::new_unchecked(self.0.slice_from(start))
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            TransferItem::new_unchecked(self.0.slice(start, end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TransferItemVecReader<'r> {
+        TransferItemVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TransferItemVec {
+    type Builder = TransferItemVecBuilder;
+    const NAME: &'static str = "TransferItemVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TransferItemVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferItemVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferItemVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TransferItemVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransferItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TransferItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TransferItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> TransferItemVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<TransferItemReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> TransferItemReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            TransferItemReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            TransferItemReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TransferItemVecReader<'r> {
+    type Entity = TransferItemVec;
+    const NAME: &'static str = "TransferItemVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TransferItemVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..item_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            TransferItemReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TransferItemVecBuilder(pub(crate) Vec<TransferItem>);
+impl TransferItemVecBuilder {
+    pub fn set(mut self, v: Vec<TransferItem>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: TransferItem) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = TransferItem>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for TransferItemVecBuilder {
+    type Entity = TransferItemVec;
+    const NAME: &'static str = "TransferItemVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TransferItemVec::new_unchecked(inner.into())
+    }
+}
+pub struct TransferItemVecIterator(TransferItemVec, usize, usize);
+impl ::core::iter::Iterator for TransferItemVecIterator {
+    type Item = TransferItem;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for TransferItemVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for TransferItemVec {
+    type Item = TransferItem;
+    type IntoIter = TransferItemVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        TransferItemVecIterator(self, 0, len)
+    }
+}
+impl<'r> TransferItemVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> TransferItemVecReaderIterator<'t, 'r> {
+        TransferItemVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct TransferItemVecReaderIterator<'t, 'r>(&'t TransferItemVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for TransferItemVecReaderIterator<'t, 'r> {
+    type Item = TransferItemReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for TransferItemVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct MultiTransfer(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MultiTransfer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for MultiTransfer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for MultiTransfer {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "transfers", self.transfers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for MultiTransfer {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0];
+        MultiTransfer::new_unchecked(v.into())
+    }
+}
+impl MultiTransfer {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn transfers(&self) -> TransferItemVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[1][..]) as usize;
+            TransferItemVec::new_unchecked(self.0.slice(start, end))
+        } else {
+            TransferItemVec::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> MultiTransferReader<'r> {
+        MultiTransferReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for MultiTransfer {
+    type Builder = MultiTransferBuilder;
+    const NAME: &'static str = "MultiTransfer";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        MultiTransfer(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MultiTransferReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MultiTransferReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().transfers(self.transfers())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct MultiTransferReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MultiTransferReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for MultiTransferReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for MultiTransferReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "transfers", self.transfers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> MultiTransferReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn transfers(&self) -> TransferItemVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[1][..]) as usize;
+            TransferItemVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            TransferItemVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for MultiTransferReader<'r> {
+    type Entity = MultiTransfer;
+    const NAME: &'static str = "MultiTransferReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        MultiTransferReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        TransferItemVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct MultiTransferBuilder {
+    pub(crate) transfers: TransferItemVec,
+}
+impl MultiTransferBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn transfers(mut self, v: TransferItemVec) -> Self {
+        self.transfers = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for MultiTransferBuilder {
+    type Entity = MultiTransfer;
+    const NAME: &'static str = "MultiTransferBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.transfers.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.transfers.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.transfers.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        MultiTransfer::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawRequest(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawRequest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawRequest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawRequest {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "amount", self.amount())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for WithdrawRequest {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![20, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        WithdrawRequest::new_unchecked(v.into())
+    }
+}
+impl WithdrawRequest {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn amount(&self) -> Payment {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[1][..]) as usize;
+            Payment::new_unchecked(self.0.slice(start, end))
+        } else {
+            Payment::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawRequestReader<'r> {
+        WithdrawRequestReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawRequest {
+    type Builder = WithdrawRequestBuilder;
+    const NAME: &'static str = "WithdrawRequest";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawRequest(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawRequestReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawRequestReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().amount(self.amount())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawRequestReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawRequestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawRequestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawRequestReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "amount", self.amount())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> WithdrawRequestReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn amount(&self) -> PaymentReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[1][..]) as usize;
+            PaymentReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            PaymentReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawRequestReader<'r> {
+    type Entity = WithdrawRequest;
+    const NAME: &'static str = "WithdrawRequestReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawRequestReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        PaymentReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct WithdrawRequestBuilder {
+    pub(crate) amount: Payment,
+}
+impl WithdrawRequestBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn amount(mut self, v: Payment) -> Self {
+        self.amount = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for WithdrawRequestBuilder {
+    type Entity = WithdrawRequest;
+    const NAME: &'static str = "WithdrawRequestBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.amount.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.amount.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        WithdrawRequest::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct KeyRotation(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for KeyRotation {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for KeyRotation {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for KeyRotation {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "new_pubkey_hash", self.new_pubkey_hash())?;
+        write!(f, ", {}: {}", "new_sig_scheme", self.new_sig_scheme())?;
+        write!(f, ", {}: {}", "new_bls_pubkey", self.new_bls_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for KeyRotation {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            37, 0, 0, 0, 16, 0, 0, 0, 36, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        KeyRotation::new_unchecked(v.into())
+    }
+}
+impl KeyRotation {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn new_pubkey_hash(&self) -> Byte20 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Byte20::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn new_sig_scheme(&self) -> Byte {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        Byte::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn new_bls_pubkey(&self) -> Byte48Opt {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            Byte48Opt::new_unchecked(self.0.slice(start, end))
+        } else {
+            Byte48Opt::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> KeyRotationReader<'r> {
+        KeyRotationReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for KeyRotation {
+    type Builder = KeyRotationBuilder;
+    const NAME: &'static str = "KeyRotation";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        KeyRotation(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        KeyRotationReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        KeyRotationReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .new_pubkey_hash(self.new_pubkey_hash())
+            .new_sig_scheme(self.new_sig_scheme())
+            .new_bls_pubkey(self.new_bls_pubkey())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct KeyRotationReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for KeyRotationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for KeyRotationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for KeyRotationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "new_pubkey_hash", self.new_pubkey_hash())?;
+        write!(f, ", {}: {}", "new_sig_scheme", self.new_sig_scheme())?;
+        write!(f, ", {}: {}", "new_bls_pubkey", self.new_bls_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> KeyRotationReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn new_pubkey_hash(&self) -> Byte20Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Byte20Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_sig_scheme(&self) -> ByteReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_bls_pubkey(&self) -> Byte48OptReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            Byte48OptReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte48OptReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for KeyRotationReader<'r> {
+    type Entity = KeyRotation;
+    const NAME: &'static str = "KeyRotationReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        KeyRotationReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte20Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte48OptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct KeyRotationBuilder {
+    pub(crate) new_pubkey_hash: Byte20,
+    pub(crate) new_sig_scheme: Byte,
+    pub(crate) new_bls_pubkey: Byte48Opt,
+}
+impl KeyRotationBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn new_pubkey_hash(mut self, v: Byte20) -> Self {
+        self.new_pubkey_hash = v;
+        self
+    }
+    pub fn new_sig_scheme(mut self, v: Byte) -> Self {
+        self.new_sig_scheme = v;
+        self
+    }
+    pub fn new_bls_pubkey(mut self, v: Byte48Opt) -> Self {
+        self.new_bls_pubkey = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for KeyRotationBuilder {
+    type Entity = KeyRotation;
+    const NAME: &'static str = "KeyRotationBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.new_pubkey_hash.as_slice().len()
+            + self.new_sig_scheme.as_slice().len()
+            + self.new_bls_pubkey.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.new_pubkey_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_sig_scheme.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_bls_pubkey.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.new_pubkey_hash.as_slice())?;
+        writer.write_all(self.new_sig_scheme.as_slice())?;
+        writer.write_all(self.new_bls_pubkey.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        KeyRotation::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TxKind(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TxKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TxKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TxKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for TxKind {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 32, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        TxKind::new_unchecked(v.into())
+    }
+}
+impl TxKind {
+    pub const ITEM_COUNT: usize = 4;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> TxKindUnion {
+        let inner = self.0.slice_from(molecule::NUMBER_SIZE);
+        match self.item_id() {
+            0 => Transfer::new_unchecked(inner).into(),
+            1 => MultiTransfer::new_unchecked(inner).into(),
+            2 => WithdrawRequest::new_unchecked(inner).into(),
+            3 => KeyRotation::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TxKindReader<'r> {
+        TxKindReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TxKind {
+    type Builder = TxKindBuilder;
+    const NAME: &'static str = "TxKind";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TxKind(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TxKindReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TxKindReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TxKindReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TxKindReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TxKindReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TxKindReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> TxKindReader<'r> {
+    pub const ITEM_COUNT: usize = 4;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> TxKindUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => TransferReader::new_unchecked(inner).into(),
+            1 => MultiTransferReader::new_unchecked(inner).into(),
+            2 => WithdrawRequestReader::new_unchecked(inner).into(),
+            3 => KeyRotationReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TxKindReader<'r> {
+    type Entity = TxKind;
+    const NAME: &'static str = "TxKindReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TxKindReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => TransferReader::verify(inner_slice, compatible),
+            1 => MultiTransferReader::verify(inner_slice, compatible),
+            2 => WithdrawRequestReader::verify(inner_slice, compatible),
+            3 => KeyRotationReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEM_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TxKindBuilder(pub(crate) TxKindUnion);
+impl TxKindBuilder {
+    pub const ITEM_COUNT: usize = 4;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<TxKindUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for TxKindBuilder {
+    type Entity = TxKind;
+    const NAME: &'static str = "TxKindBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TxKind::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum TxKindUnion {
+    Transfer(Transfer),
+    MultiTransfer(MultiTransfer),
+    WithdrawRequest(WithdrawRequest),
+    KeyRotation(KeyRotation),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum TxKindUnionReader<'r> {
+    Transfer(TransferReader<'r>),
+    MultiTransfer(MultiTransferReader<'r>),
+    WithdrawRequest(WithdrawRequestReader<'r>),
+    KeyRotation(KeyRotationReader<'r>),
+}
+impl ::core::default::Default for TxKindUnion {
+    fn default() -> Self {
+        TxKindUnion::Transfer(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for TxKindUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TxKindUnion::Transfer(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Transfer::NAME, item)
+            }
+            TxKindUnion::MultiTransfer(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, MultiTransfer::NAME, item)
+            }
+            TxKindUnion::WithdrawRequest(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, WithdrawRequest::NAME, item)
+            }
+            TxKindUnion::KeyRotation(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, KeyRotation::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for TxKindUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TxKindUnionReader::Transfer(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Transfer::NAME, item)
+            }
+            TxKindUnionReader::MultiTransfer(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, MultiTransfer::NAME, item)
+            }
+            TxKindUnionReader::WithdrawRequest(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, WithdrawRequest::NAME, item)
+            }
+            TxKindUnionReader::KeyRotation(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, KeyRotation::NAME, item)
+            }
+        }
+    }
+}
+impl TxKindUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TxKindUnion::Transfer(ref item) => write!(f, "{}", item),
+            TxKindUnion::MultiTransfer(ref item) => write!(f, "{}", item),
+            TxKindUnion::WithdrawRequest(ref item) => write!(f, "{}", item),
+            TxKindUnion::KeyRotation(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> TxKindUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            TxKindUnionReader::Transfer(ref item) => write!(f, "{}", item),
+            TxKindUnionReader::MultiTransfer(ref item) => write!(f, "{}", item),
+            TxKindUnionReader::WithdrawRequest(ref item) => write!(f, "{}", item),
+            TxKindUnionReader::KeyRotation(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<Transfer> for TxKindUnion {
+    fn from(item: Transfer) -> Self {
+        TxKindUnion::Transfer(item)
+    }
+}
+impl ::core::convert::From<MultiTransfer> for TxKindUnion {
+    fn from(item: MultiTransfer) -> Self {
+        TxKindUnion::MultiTransfer(item)
+    }
+}
+impl ::core::convert::From<WithdrawRequest> for TxKindUnion {
+    fn from(item: WithdrawRequest) -> Self {
+        TxKindUnion::WithdrawRequest(item)
+    }
+}
+impl ::core::convert::From<KeyRotation> for TxKindUnion {
+    fn from(item: KeyRotation) -> Self {
+        TxKindUnion::KeyRotation(item)
+    }
+}
+impl<'r> ::core::convert::From<TransferReader<'r>> for TxKindUnionReader<'r> {
+    fn from(item: TransferReader<'r>) -> Self {
+        TxKindUnionReader::Transfer(item)
+    }
+}
+impl<'r> ::core::convert::From<MultiTransferReader<'r>> for TxKindUnionReader<'r> {
+    fn from(item: MultiTransferReader<'r>) -> Self {
+        TxKindUnionReader::MultiTransfer(item)
+    }
+}
+impl<'r> ::core::convert::From<WithdrawRequestReader<'r>> for TxKindUnionReader<'r> {
+    fn from(item: WithdrawRequestReader<'r>) -> Self {
+        TxKindUnionReader::WithdrawRequest(item)
+    }
+}
+impl<'r> ::core::convert::From<KeyRotationReader<'r>> for TxKindUnionReader<'r> {
+    fn from(item: KeyRotationReader<'r>) -> Self {
+        TxKindUnionReader::KeyRotation(item)
+    }
+}
+impl TxKindUnion {
+    pub const NAME: &'static str = "TxKindUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            TxKindUnion::Transfer(item) => item.as_bytes(),
+            TxKindUnion::MultiTransfer(item) => item.as_bytes(),
+            TxKindUnion::WithdrawRequest(item) => item.as_bytes(),
+            TxKindUnion::KeyRotation(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            TxKindUnion::Transfer(item) => item.as_slice(),
+            TxKindUnion::MultiTransfer(item) => item.as_slice(),
+            TxKindUnion::WithdrawRequest(item) => item.as_slice(),
+            TxKindUnion::KeyRotation(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            TxKindUnion::Transfer(_) => 0,
+            TxKindUnion::MultiTransfer(_) => 1,
+            TxKindUnion::WithdrawRequest(_) => 2,
+            TxKindUnion::KeyRotation(_) => 3,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            TxKindUnion::Transfer(_) => "Transfer",
+            TxKindUnion::MultiTransfer(_) => "MultiTransfer",
+            TxKindUnion::WithdrawRequest(_) => "WithdrawRequest",
+            TxKindUnion::KeyRotation(_) => "KeyRotation",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TxKindUnionReader<'r> {
+        match self {
+            TxKindUnion::Transfer(item) => item.as_reader().into(),
+            TxKindUnion::MultiTransfer(item) => item.as_reader().into(),
+            TxKindUnion::WithdrawRequest(item) => item.as_reader().into(),
+            TxKindUnion::KeyRotation(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> TxKindUnionReader<'r> {
+    pub const NAME: &'r str = "TxKindUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            TxKindUnionReader::Transfer(item) => item.as_slice(),
+            TxKindUnionReader::MultiTransfer(item) => item.as_slice(),
+            TxKindUnionReader::WithdrawRequest(item) => item.as_slice(),
+            TxKindUnionReader::KeyRotation(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            TxKindUnionReader::Transfer(_) => 0,
+            TxKindUnionReader::MultiTransfer(_) => 1,
+            TxKindUnionReader::WithdrawRequest(_) => 2,
+            TxKindUnionReader::KeyRotation(_) => 3,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            TxKindUnionReader::Transfer(_) => "Transfer",
+            TxKindUnionReader::MultiTransfer(_) => "MultiTransfer",
+            TxKindUnionReader::WithdrawRequest(_) => "WithdrawRequest",
+            TxKindUnionReader::KeyRotation(_) => "KeyRotation",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct RawTx(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RawTx {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RawTx {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RawTx {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "sender_index", self.sender_index())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(f, ", {}: {}", "fee", self.fee())?;
+        write!(f, ", {}: {}", "kind", self.kind())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RawTx {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            85, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0, 0, 33, 0, 0, 0, 37, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0,
+            0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ];
+        RawTx::new_unchecked(v.into())
+    }
+}
+impl RawTx {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> Byte {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Byte::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn sender_index(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn nonce(&self) -> Uint32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn fee(&self) -> Payment {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        Payment::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn kind(&self) -> TxKind {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[5][..]) as usize;
+            TxKind::new_unchecked(self.0.slice(start, end))
+        } else {
+            TxKind::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RawTxReader<'r> {
+        RawTxReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RawTx {
+    type Builder = RawTxBuilder;
+    const NAME: &'static str = "RawTx";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RawTx(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RawTxReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RawTxReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .version(self.version())
+            .sender_index(self.sender_index())
+            .nonce(self.nonce())
+            .fee(self.fee())
+            .kind(self.kind())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RawTxReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RawTxReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RawTxReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RawTxReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "sender_index", self.sender_index())?;
+        write!(f, ", {}: {}", "nonce", self.nonce())?;
+        write!(f, ", {}: {}", "fee", self.fee())?;
+        write!(f, ", {}: {}", "kind", self.kind())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RawTxReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> ByteReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sender_index(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn nonce(&self) -> Uint32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee(&self) -> PaymentReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        PaymentReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn kind(&self) -> TxKindReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[5][..]) as usize;
+            TxKindReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            TxKindReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RawTxReader<'r> {
+    type Entity = RawTx;
+    const NAME: &'static str = "RawTxReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RawTxReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        PaymentReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        TxKindReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RawTxBuilder {
+    pub(crate) version: Byte,
+    pub(crate) sender_index: Uint64,
+    pub(crate) nonce: Uint32,
+    pub(crate) fee: Payment,
+    pub(crate) kind: TxKind,
+}
+impl RawTxBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn version(mut self, v: Byte) -> Self {
+        self.version = v;
+        self
+    }
+    pub fn sender_index(mut self, v: Uint64) -> Self {
+        self.sender_index = v;
+        self
+    }
+    pub fn nonce(mut self, v: Uint32) -> Self {
+        self.nonce = v;
+        self
+    }
+    pub fn fee(mut self, v: Payment) -> Self {
+        self.fee = v;
+        self
+    }
+    pub fn kind(mut self, v: TxKind) -> Self {
+        self.kind = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RawTxBuilder {
+    type Entity = RawTx;
+    const NAME: &'static str = "RawTxBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.version.as_slice().len()
+            + self.sender_index.as_slice().len()
+            + self.nonce.as_slice().len()
+            + self.fee.as_slice().len()
+            + self.kind.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.version.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sender_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.nonce.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.kind.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.version.as_slice())?;
+        writer.write_all(self.sender_index.as_slice())?;
+        writer.write_all(self.nonce.as_slice())?;
+        writer.write_all(self.fee.as_slice())?;
+        writer.write_all(self.kind.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RawTx::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Tx(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Tx {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Tx {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Tx {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "raw", self.raw())?;
+        write!(f, ", {}: {}", "witness", self.witness())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Tx {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            101, 0, 0, 0, 12, 0, 0, 0, 97, 0, 0, 0, 85, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0, 0, 33, 0,
+            0, 0, 37, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        Tx::new_unchecked(v.into())
+    }
+}
+impl Tx {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn raw(&self) -> RawTx {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        RawTx::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn witness(&self) -> Bytes {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start, end))
+        } else {
+            Bytes::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TxReader<'r> {
+        TxReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Tx {
+    type Builder = TxBuilder;
+    const NAME: &'static str = "Tx";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Tx(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TxReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TxReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().raw(self.raw()).witness(self.witness())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TxReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TxReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TxReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TxReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "raw", self.raw())?;
+        write!(f, ", {}: {}", "witness", self.witness())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TxReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn raw(&self) -> RawTxReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        RawTxReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn witness(&self) -> BytesReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TxReader<'r> {
+    type Entity = Tx;
+    const NAME: &'static str = "TxReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TxReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        RawTxReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TxBuilder {
+    pub(crate) raw: RawTx,
+    pub(crate) witness: Bytes,
+}
+impl TxBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn raw(mut self, v: RawTx) -> Self {
+        self.raw = v;
+        self
+    }
+    pub fn witness(mut self, v: Bytes) -> Self {
+        self.witness = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TxBuilder {
+    type Entity = Tx;
+    const NAME: &'static str = "TxBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.raw.as_slice().len()
+            + self.witness.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.raw.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.witness.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.raw.as_slice())?;
+        writer.write_all(self.witness.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Tx::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TxVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TxVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TxVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TxVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for TxVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        TxVec::new_unchecked(v.into())
+    }
+}
+impl TxVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Tx> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Tx {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            Tx::new_unchecked(self.0.slice_from(start))
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            Tx::new_unchecked(self.0.slice(start, end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TxVecReader<'r> {
+        TxVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TxVec {
+    type Builder = TxVecBuilder;
+    const NAME: &'static str = "TxVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TxVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TxVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TxVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TxVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TxVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TxVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TxVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> TxVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<TxReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> TxReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            TxReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            TxReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TxVecReader<'r> {
+    type Entity = TxVec;
+    const NAME: &'static str = "TxVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TxVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..item_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            TxReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TxVecBuilder(pub(crate) Vec<Tx>);
+impl TxVecBuilder {
+    pub fn set(mut self, v: Vec<Tx>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Tx) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Tx>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for TxVecBuilder {
+    type Entity = TxVec;
+    const NAME: &'static str = "TxVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TxVec::new_unchecked(inner.into())
+    }
+}
+pub struct TxVecIterator(TxVec, usize, usize);
+impl ::core::iter::Iterator for TxVecIterator {
+    type Item = Tx;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for TxVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for TxVec {
+    type Item = Tx;
+    type IntoIter = TxVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        TxVecIterator(self, 0, len)
+    }
+}
+impl<'r> TxVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> TxVecReaderIterator<'t, 'r> {
+        TxVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct TxVecReaderIterator<'t, 'r>(&'t TxVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for TxVecReaderIterator<'t, 'r> {
+    type Item = TxReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for TxVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct TransferLog(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransferLog {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TransferLog {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TransferLog {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "from_index", self.from_index())?;
+        write!(f, ", {}: {}", "to_index", self.to_index())?;
+        write!(f, ", {}: {}", "token_id", self.token_id())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for TransferLog {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ];
+        TransferLog::new_unchecked(v.into())
+    }
+}
+impl TransferLog {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZE: [usize; 4] = [8, 8, 32, 16];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn from_index(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(0, 8))
+    }
+    pub fn to_index(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(8, 16))
+    }
+    pub fn token_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(16, 48))
+    }
+    pub fn amount(&self) -> Uint128 {
+        Uint128::new_unchecked(self.0.slice(48, 64))
+    }
+    pub fn as_reader<'r>(&'r self) -> TransferLogReader<'r> {
+        TransferLogReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TransferLog {
+    type Builder = TransferLogBuilder;
+    const NAME: &'static str = "TransferLog";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TransferLog(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferLogReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransferLogReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .from_index(self.from_index())
+            .to_index(self.to_index())
+            .token_id(self.token_id())
+            .amount(self.amount())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TransferLogReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransferLogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TransferLogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TransferLogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "from_index", self.from_index())?;
+        write!(f, ", {}: {}", "to_index", self.to_index())?;
+        write!(f, ", {}: {}", "token_id", self.token_id())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> TransferLogReader<'r> {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZE: [usize; 4] = [8, 8, 32, 16];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn from_index(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
+    }
+    pub fn to_index(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[8..16])
+    }
+    pub fn token_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[16..48])
+    }
+    pub fn amount(&self) -> Uint128Reader<'r> {
+        Uint128Reader::new_unchecked(&self.as_slice()[48..64])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TransferLogReader<'r> {
+    type Entity = TransferLog;
+    const NAME: &'static str = "TransferLogReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TransferLogReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TransferLogBuilder {
+    pub(crate) from_index: Uint64,
+    pub(crate) to_index: Uint64,
+    pub(crate) token_id: Byte32,
+    pub(crate) amount: Uint128,
+}
+impl TransferLogBuilder {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZE: [usize; 4] = [8, 8, 32, 16];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn from_index(mut self, v: Uint64) -> Self {
+        self.from_index = v;
+        self
+    }
+    pub fn to_index(mut self, v: Uint64) -> Self {
+        self.to_index = v;
+        self
+    }
+    pub fn token_id(mut self, v: Byte32) -> Self {
+        self.token_id = v;
+        self
+    }
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TransferLogBuilder {
+    type Entity = TransferLog;
+    const NAME: &'static str = "TransferLogBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.from_index.as_slice())?;
+        writer.write_all(self.to_index.as_slice())?;
+        writer.write_all(self.token_id.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TransferLog::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ContractLog(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ContractLog {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ContractLog {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ContractLog {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account_index", self.account_index())?;
+        write!(f, ", {}: {}", "data", self.data())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ContractLog {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ContractLog::new_unchecked(v.into())
+    }
+}
+impl ContractLog {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn account_index(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn data(&self) -> Bytes {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start, end))
+        } else {
+            Bytes::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ContractLogReader<'r> {
+        ContractLogReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ContractLog {
+    type Builder = ContractLogBuilder;
+    const NAME: &'static str = "ContractLog";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ContractLog(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ContractLogReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ContractLogReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .account_index(self.account_index())
+            .data(self.data())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ContractLogReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ContractLogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ContractLogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ContractLogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account_index", self.account_index())?;
+        write!(f, ", {}: {}", "data", self.data())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ContractLogReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn account_index(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn data(&self) -> BytesReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ContractLogReader<'r> {
+    type Entity = ContractLog;
+    const NAME: &'static str = "ContractLogReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ContractLogReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ContractLogBuilder {
+    pub(crate) account_index: Uint64,
+    pub(crate) data: Bytes,
+}
+impl ContractLogBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn account_index(mut self, v: Uint64) -> Self {
+        self.account_index = v;
+        self
+    }
+    pub fn data(mut self, v: Bytes) -> Self {
+        self.data = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ContractLogBuilder {
+    type Entity = ContractLog;
+    const NAME: &'static str = "ContractLogBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.account_index.as_slice().len()
+            + self.data.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.account_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.data.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.account_index.as_slice())?;
+        writer.write_all(self.data.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ContractLog::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Log(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Log {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Log {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Log {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for Log {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        Log::new_unchecked(v.into())
+    }
+}
+impl Log {
+    pub const ITEM_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> LogUnion {
+        let inner = self.0.slice_from(molecule::NUMBER_SIZE);
+        match self.item_id() {
+            0 => TransferLog::new_unchecked(inner).into(),
+            1 => ContractLog::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LogReader<'r> {
+        LogReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Log {
+    type Builder = LogBuilder;
+    const NAME: &'static str = "Log";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Log(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LogReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LogReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LogReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LogReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> LogReader<'r> {
+    pub const ITEM_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> LogUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => TransferLogReader::new_unchecked(inner).into(),
+            1 => ContractLogReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LogReader<'r> {
+    type Entity = Log;
+    const NAME: &'static str = "LogReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LogReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => TransferLogReader::verify(inner_slice, compatible),
+            1 => ContractLogReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEM_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct LogBuilder(pub(crate) LogUnion);
+impl LogBuilder {
+    pub const ITEM_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<LogUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for LogBuilder {
+    type Entity = Log;
+    const NAME: &'static str = "LogBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Log::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum LogUnion {
+    TransferLog(TransferLog),
+    ContractLog(ContractLog),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum LogUnionReader<'r> {
+    TransferLog(TransferLogReader<'r>),
+    ContractLog(ContractLogReader<'r>),
+}
+impl ::core::default::Default for LogUnion {
+    fn default() -> Self {
+        LogUnion::TransferLog(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for LogUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LogUnion::TransferLog(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, TransferLog::NAME, item)
+            }
+            LogUnion::ContractLog(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ContractLog::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for LogUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LogUnionReader::TransferLog(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, TransferLog::NAME, item)
+            }
+            LogUnionReader::ContractLog(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ContractLog::NAME, item)
+            }
+        }
+    }
+}
+impl LogUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LogUnion::TransferLog(ref item) => write!(f, "{}", item),
+            LogUnion::ContractLog(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> LogUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            LogUnionReader::TransferLog(ref item) => write!(f, "{}", item),
+            LogUnionReader::ContractLog(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<TransferLog> for LogUnion {
+    fn from(item: TransferLog) -> Self {
+        LogUnion::TransferLog(item)
+    }
+}
+impl ::core::convert::From<ContractLog> for LogUnion {
+    fn from(item: ContractLog) -> Self {
+        LogUnion::ContractLog(item)
+    }
+}
+impl<'r> ::core::convert::From<TransferLogReader<'r>> for LogUnionReader<'r> {
+    fn from(item: TransferLogReader<'r>) -> Self {
+        LogUnionReader::TransferLog(item)
+    }
+}
+impl<'r> ::core::convert::From<ContractLogReader<'r>> for LogUnionReader<'r> {
+    fn from(item: ContractLogReader<'r>) -> Self {
+        LogUnionReader::ContractLog(item)
+    }
+}
+impl LogUnion {
+    pub const NAME: &'static str = "LogUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            LogUnion::TransferLog(item) => item.as_bytes(),
+            LogUnion::ContractLog(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            LogUnion::TransferLog(item) => item.as_slice(),
+            LogUnion::ContractLog(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            LogUnion::TransferLog(_) => 0,
+            LogUnion::ContractLog(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            LogUnion::TransferLog(_) => "TransferLog",
+            LogUnion::ContractLog(_) => "ContractLog",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LogUnionReader<'r> {
+        match self {
+            LogUnion::TransferLog(item) => item.as_reader().into(),
+            LogUnion::ContractLog(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> LogUnionReader<'r> {
+    pub const NAME: &'r str = "LogUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            LogUnionReader::TransferLog(item) => item.as_slice(),
+            LogUnionReader::ContractLog(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            LogUnionReader::TransferLog(_) => 0,
+            LogUnionReader::ContractLog(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            LogUnionReader::TransferLog(_) => "TransferLog",
+            LogUnionReader::ContractLog(_) => "ContractLog",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct LogVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LogVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for LogVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for LogVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for LogVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        LogVec::new_unchecked(v.into())
+    }
+}
+impl LogVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Log> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Log {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            Log::new_unchecked(self.0.slice_from(start))
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            Log::new_unchecked(self.0.slice(start, end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LogVecReader<'r> {
+        LogVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for LogVec {
+    type Builder = LogVecBuilder;
+    const NAME: &'static str = "LogVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        LogVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LogVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LogVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LogVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LogVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LogVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LogVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> LogVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<LogReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> LogReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            LogReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            LogReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LogVecReader<'r> {
+    type Entity = LogVec;
+    const NAME: &'static str = "LogVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LogVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..item_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            LogReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct LogVecBuilder(pub(crate) Vec<Log>);
+impl LogVecBuilder {
+    pub fn set(mut self, v: Vec<Log>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Log) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Log>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for LogVecBuilder {
+    type Entity = LogVec;
+    const NAME: &'static str = "LogVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LogVec::new_unchecked(inner.into())
+    }
+}
+pub struct LogVecIterator(LogVec, usize, usize);
+impl ::core::iter::Iterator for LogVecIterator {
+    type Item = Log;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for LogVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for LogVec {
+    type Item = Log;
+    type IntoIter = LogVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        LogVecIterator(self, 0, len)
+    }
+}
+impl<'r> LogVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> LogVecReaderIterator<'t, 'r> {
+        LogVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct LogVecReaderIterator<'t, 'r>(&'t LogVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for LogVecReaderIterator<'t, 'r> {
+    type Item = LogReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for LogVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct AgBlock(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AgBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for AgBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for AgBlock {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "number", self.number())?;
+        write!(f, ", {}: {}", "tx_root", self.tx_root())?;
+        write!(f, ", {}: {}", "txs_count", self.txs_count())?;
+        write!(f, ", {}: {}", "logs_root", self.logs_root())?;
+        write!(f, ", {}: {}", "prev_account_root", self.prev_account_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "prev_account_count",
+            self.prev_account_count()
+        )?;
+        write!(f, ", {}: {}", "account_root", self.account_root())?;
+        write!(f, ", {}: {}", "ag_sig", self.ag_sig())?;
+        write!(f, ", {}: {}", "ag_index", self.ag_index())?;
+        write!(f, ", {}: {}", "aggregate_sig", self.aggregate_sig())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for AgBlock {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            105, 1, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0, 120, 0, 0, 0, 152, 0,
+            0, 0, 160, 0, 0, 0, 192, 0, 0, 0, 1, 1, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        AgBlock::new_unchecked(v.into())
+    }
+}
+impl AgBlock {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn number(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn tx_root(&self) -> Byte32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn txs_count(&self) -> Uint32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn logs_root(&self) -> Byte32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn prev_account_root(&self) -> Byte32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn prev_account_count(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        let end = molecule::unpack_number(&offsets[6][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn account_root(&self) -> Byte32 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[6][..]) as usize;
+        let end = molecule::unpack_number(&offsets[7][..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn ag_sig(&self) -> Byte65 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[7][..]) as usize;
+        let end = molecule::unpack_number(&offsets[8][..]) as usize;
+        Byte65::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn ag_index(&self) -> Uint64 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[8][..]) as usize;
+        let end = molecule::unpack_number(&offsets[9][..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn aggregate_sig(&self) -> Byte96 {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[9][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[10][..]) as usize;
+            Byte96::new_unchecked(self.0.slice(start, end))
+        } else {
+            Byte96::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> AgBlockReader<'r> {
+        AgBlockReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for AgBlock {
+    type Builder = AgBlockBuilder;
+    const NAME: &'static str = "AgBlock";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        AgBlock(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AgBlockReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        AgBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .number(self.number())
+            .tx_root(self.tx_root())
+            .txs_count(self.txs_count())
+            .logs_root(self.logs_root())
+            .prev_account_root(self.prev_account_root())
+            .prev_account_count(self.prev_account_count())
+            .account_root(self.account_root())
+            .ag_sig(self.ag_sig())
+            .ag_index(self.ag_index())
+            .aggregate_sig(self.aggregate_sig())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct AgBlockReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AgBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for AgBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for AgBlockReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "number", self.number())?;
+        write!(f, ", {}: {}", "tx_root", self.tx_root())?;
+        write!(f, ", {}: {}", "txs_count", self.txs_count())?;
+        write!(f, ", {}: {}", "logs_root", self.logs_root())?;
+        write!(f, ", {}: {}", "prev_account_root", self.prev_account_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "prev_account_count",
+            self.prev_account_count()
+        )?;
+        write!(f, ", {}: {}", "account_root", self.account_root())?;
+        write!(f, ", {}: {}", "ag_sig", self.ag_sig())?;
+        write!(f, ", {}: {}", "ag_index", self.ag_index())?;
+        write!(f, ", {}: {}", "aggregate_sig", self.aggregate_sig())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> AgBlockReader<'r> {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn number(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn tx_root(&self) -> Byte32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn txs_count(&self) -> Uint32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn logs_root(&self) -> Byte32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn prev_account_root(&self) -> Byte32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn prev_account_count(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        let end = molecule::unpack_number(&offsets[6][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn account_root(&self) -> Byte32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[6][..]) as usize;
+        let end = molecule::unpack_number(&offsets[7][..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn ag_sig(&self) -> Byte65Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[7][..]) as usize;
+        let end = molecule::unpack_number(&offsets[8][..]) as usize;
+        Byte65Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn ag_index(&self) -> Uint64Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[8][..]) as usize;
+        let end = molecule::unpack_number(&offsets[9][..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn aggregate_sig(&self) -> Byte96Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[9][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[10][..]) as usize;
+            Byte96Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte96Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for AgBlockReader<'r> {
+    type Entity = AgBlock;
+    const NAME: &'static str = "AgBlockReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        AgBlockReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Byte65Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Byte96Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AgBlockBuilder {
+    pub(crate) number: Uint64,
+    pub(crate) tx_root: Byte32,
+    pub(crate) txs_count: Uint32,
+    pub(crate) logs_root: Byte32,
+    pub(crate) prev_account_root: Byte32,
+    pub(crate) prev_account_count: Uint64,
+    pub(crate) account_root: Byte32,
+    pub(crate) ag_sig: Byte65,
+    pub(crate) ag_index: Uint64,
+    pub(crate) aggregate_sig: Byte96,
+}
+impl AgBlockBuilder {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn number(mut self, v: Uint64) -> Self {
+        self.number = v;
+        self
+    }
+    pub fn tx_root(mut self, v: Byte32) -> Self {
+        self.tx_root = v;
+        self
+    }
+    pub fn txs_count(mut self, v: Uint32) -> Self {
+        self.txs_count = v;
+        self
+    }
+    pub fn logs_root(mut self, v: Byte32) -> Self {
+        self.logs_root = v;
+        self
+    }
+    pub fn prev_account_root(mut self, v: Byte32) -> Self {
+        self.prev_account_root = v;
+        self
+    }
+    pub fn prev_account_count(mut self, v: Uint64) -> Self {
+        self.prev_account_count = v;
+        self
+    }
+    pub fn account_root(mut self, v: Byte32) -> Self {
+        self.account_root = v;
+        self
+    }
+    pub fn ag_sig(mut self, v: Byte65) -> Self {
+        self.ag_sig = v;
+        self
+    }
+    pub fn ag_index(mut self, v: Uint64) -> Self {
+        self.ag_index = v;
+        self
+    }
+    pub fn aggregate_sig(mut self, v: Byte96) -> Self {
+        self.aggregate_sig = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for AgBlockBuilder {
+    type Entity = AgBlock;
+    const NAME: &'static str = "AgBlockBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.number.as_slice().len()
+            + self.tx_root.as_slice().len()
+            + self.txs_count.as_slice().len()
+            + self.logs_root.as_slice().len()
+            + self.prev_account_root.as_slice().len()
+            + self.prev_account_count.as_slice().len()
+            + self.account_root.as_slice().len()
+            + self.ag_sig.as_slice().len()
+            + self.ag_index.as_slice().len()
+            + self.aggregate_sig.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.number.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.tx_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.txs_count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.logs_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.prev_account_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.prev_account_count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.account_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ag_sig.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ag_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.aggregate_sig.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.number.as_slice())?;
+        writer.write_all(self.tx_root.as_slice())?;
+        writer.write_all(self.txs_count.as_slice())?;
+        writer.write_all(self.logs_root.as_slice())?;
+        writer.write_all(self.prev_account_root.as_slice())?;
+        writer.write_all(self.prev_account_count.as_slice())?;
+        writer.write_all(self.account_root.as_slice())?;
+        writer.write_all(self.ag_sig.as_slice())?;
+        writer.write_all(self.ag_index.as_slice())?;
+        writer.write_all(self.aggregate_sig.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AgBlock::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Action(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Action {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Action {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Action {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for Action {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 93, 0, 0, 0, 12, 0, 0, 0, 73, 0, 0, 0, 61, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0,
+            0, 36, 0, 0, 0, 40, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0,
+            0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        Action::new_unchecked(v.into())
+    }
+}
+impl Action {
+    pub const ITEM_COUNT: usize = 4;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> ActionUnion {
+        let inner = self.0.slice_from(molecule::NUMBER_SIZE);
+        match self.item_id() {
+            0 => Register::new_unchecked(inner).into(),
+            1 => Deposit::new_unchecked(inner).into(),
+            2 => SubmitBlock::new_unchecked(inner).into(),
+            3 => RevertBlock::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ActionReader<'r> {
+        ActionReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Action {
+    type Builder = ActionBuilder;
+    const NAME: &'static str = "Action";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Action(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ActionReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ActionReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ActionReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ActionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ActionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ActionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
-    pub fn prev_account_root(mut self, v: Byte32) -> Self {
-        self.prev_account_root = v;
-        self
+}
+impl<'r> ActionReader<'r> {
+    pub const ITEM_COUNT: usize = 4;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
     }
-    pub fn prev_account_count(mut self, v: Uint64) -> Self {
-        self.prev_account_count = v;
-        self
+    pub fn to_enum(&self) -> ActionUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => RegisterReader::new_unchecked(inner).into(),
+            1 => DepositReader::new_unchecked(inner).into(),
+            2 => SubmitBlockReader::new_unchecked(inner).into(),
+            3 => RevertBlockReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
     }
-    pub fn account_root(mut self, v: Byte32) -> Self {
-        self.account_root = v;
-        self
+}
+impl<'r> molecule::prelude::Reader<'r> for ActionReader<'r> {
+    type Entity = Action;
+    const NAME: &'static str = "ActionReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
     }
-    pub fn ag_sig(mut self, v: Byte65) -> Self {
-        self.ag_sig = v;
-        self
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ActionReader(slice)
     }
-    pub fn ag_index(mut self, v: Uint64) -> Self {
-        self.ag_index = v;
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => RegisterReader::verify(inner_slice, compatible),
+            1 => DepositReader::verify(inner_slice, compatible),
+            2 => SubmitBlockReader::verify(inner_slice, compatible),
+            3 => RevertBlockReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEM_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ActionBuilder(pub(crate) ActionUnion);
+impl ActionBuilder {
+    pub const ITEM_COUNT: usize = 4;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<ActionUnion>,
+    {
+        self.0 = v.into();
         self
     }
 }
-impl molecule::prelude::Builder for AgBlockBuilder {
-    type Entity = AgBlock;
-    const NAME: &'static str = "AgBlockBuilder";
+impl molecule::prelude::Builder for ActionBuilder {
+    type Entity = Action;
+    const NAME: &'static str = "ActionBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.number.as_slice().len()
-            + self.tx_root.as_slice().len()
-            + self.txs_count.as_slice().len()
-            + self.prev_account_root.as_slice().len()
-            + self.prev_account_count.as_slice().len()
-            + self.account_root.as_slice().len()
-            + self.ag_sig.as_slice().len()
-            + self.ag_index.as_slice().len()
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Action::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum ActionUnion {
+    Register(Register),
+    Deposit(Deposit),
+    SubmitBlock(SubmitBlock),
+    RevertBlock(RevertBlock),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum ActionUnionReader<'r> {
+    Register(RegisterReader<'r>),
+    Deposit(DepositReader<'r>),
+    SubmitBlock(SubmitBlockReader<'r>),
+    RevertBlock(RevertBlockReader<'r>),
+}
+impl ::core::default::Default for ActionUnion {
+    fn default() -> Self {
+        ActionUnion::Register(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for ActionUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ActionUnion::Register(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Register::NAME, item)
+            }
+            ActionUnion::Deposit(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Deposit::NAME, item)
+            }
+            ActionUnion::SubmitBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SubmitBlock::NAME, item)
+            }
+            ActionUnion::RevertBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RevertBlock::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for ActionUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ActionUnionReader::Register(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Register::NAME, item)
+            }
+            ActionUnionReader::Deposit(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Deposit::NAME, item)
+            }
+            ActionUnionReader::SubmitBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SubmitBlock::NAME, item)
+            }
+            ActionUnionReader::RevertBlock(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, RevertBlock::NAME, item)
+            }
+        }
+    }
+}
+impl ActionUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ActionUnion::Register(ref item) => write!(f, "{}", item),
+            ActionUnion::Deposit(ref item) => write!(f, "{}", item),
+            ActionUnion::SubmitBlock(ref item) => write!(f, "{}", item),
+            ActionUnion::RevertBlock(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> ActionUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ActionUnionReader::Register(ref item) => write!(f, "{}", item),
+            ActionUnionReader::Deposit(ref item) => write!(f, "{}", item),
+            ActionUnionReader::SubmitBlock(ref item) => write!(f, "{}", item),
+            ActionUnionReader::RevertBlock(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<Register> for ActionUnion {
+    fn from(item: Register) -> Self {
+        ActionUnion::Register(item)
+    }
+}
+impl ::core::convert::From<Deposit> for ActionUnion {
+    fn from(item: Deposit) -> Self {
+        ActionUnion::Deposit(item)
+    }
+}
+impl ::core::convert::From<SubmitBlock> for ActionUnion {
+    fn from(item: SubmitBlock) -> Self {
+        ActionUnion::SubmitBlock(item)
+    }
+}
+impl ::core::convert::From<RevertBlock> for ActionUnion {
+    fn from(item: RevertBlock) -> Self {
+        ActionUnion::RevertBlock(item)
+    }
+}
+impl<'r> ::core::convert::From<RegisterReader<'r>> for ActionUnionReader<'r> {
+    fn from(item: RegisterReader<'r>) -> Self {
+        ActionUnionReader::Register(item)
+    }
+}
+impl<'r> ::core::convert::From<DepositReader<'r>> for ActionUnionReader<'r> {
+    fn from(item: DepositReader<'r>) -> Self {
+        ActionUnionReader::Deposit(item)
+    }
+}
+impl<'r> ::core::convert::From<SubmitBlockReader<'r>> for ActionUnionReader<'r> {
+    fn from(item: SubmitBlockReader<'r>) -> Self {
+        ActionUnionReader::SubmitBlock(item)
+    }
+}
+impl<'r> ::core::convert::From<RevertBlockReader<'r>> for ActionUnionReader<'r> {
+    fn from(item: RevertBlockReader<'r>) -> Self {
+        ActionUnionReader::RevertBlock(item)
+    }
+}
+impl ActionUnion {
+    pub const NAME: &'static str = "ActionUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            ActionUnion::Register(item) => item.as_bytes(),
+            ActionUnion::Deposit(item) => item.as_bytes(),
+            ActionUnion::SubmitBlock(item) => item.as_bytes(),
+            ActionUnion::RevertBlock(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            ActionUnion::Register(item) => item.as_slice(),
+            ActionUnion::Deposit(item) => item.as_slice(),
+            ActionUnion::SubmitBlock(item) => item.as_slice(),
+            ActionUnion::RevertBlock(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ActionUnion::Register(_) => 0,
+            ActionUnion::Deposit(_) => 1,
+            ActionUnion::SubmitBlock(_) => 2,
+            ActionUnion::RevertBlock(_) => 3,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            ActionUnion::Register(_) => "Register",
+            ActionUnion::Deposit(_) => "Deposit",
+            ActionUnion::SubmitBlock(_) => "SubmitBlock",
+            ActionUnion::RevertBlock(_) => "RevertBlock",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ActionUnionReader<'r> {
+        match self {
+            ActionUnion::Register(item) => item.as_reader().into(),
+            ActionUnion::Deposit(item) => item.as_reader().into(),
+            ActionUnion::SubmitBlock(item) => item.as_reader().into(),
+            ActionUnion::RevertBlock(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> ActionUnionReader<'r> {
+    pub const NAME: &'r str = "ActionUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            ActionUnionReader::Register(item) => item.as_slice(),
+            ActionUnionReader::Deposit(item) => item.as_slice(),
+            ActionUnionReader::SubmitBlock(item) => item.as_slice(),
+            ActionUnionReader::RevertBlock(item) => item.as_slice(),
+        }
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.number.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.tx_root.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.txs_count.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.prev_account_root.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.prev_account_count.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.account_root.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.ag_sig.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.ag_index.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ActionUnionReader::Register(_) => 0,
+            ActionUnionReader::Deposit(_) => 1,
+            ActionUnionReader::SubmitBlock(_) => 2,
+            ActionUnionReader::RevertBlock(_) => 3,
         }
-        writer.write_all(self.number.as_slice())?;
-        writer.write_all(self.tx_root.as_slice())?;
-        writer.write_all(self.txs_count.as_slice())?;
-        writer.write_all(self.prev_account_root.as_slice())?;
-        writer.write_all(self.prev_account_count.as_slice())?;
-        writer.write_all(self.account_root.as_slice())?;
-        writer.write_all(self.ag_sig.as_slice())?;
-        writer.write_all(self.ag_index.as_slice())?;
-        Ok(())
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AgBlock::new_unchecked(inner.into())
+    pub fn item_name(&self) -> &str {
+        match self {
+            ActionUnionReader::Register(_) => "Register",
+            ActionUnionReader::Deposit(_) => "Deposit",
+            ActionUnionReader::SubmitBlock(_) => "SubmitBlock",
+            ActionUnionReader::RevertBlock(_) => "RevertBlock",
+        }
     }
 }
 #[derive(Clone)]
-pub struct Action(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Action {
+pub struct Register(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Register {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5733,53 +12524,80 @@ impl ::core::fmt::LowerHex for Action {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Action {
+impl ::core::fmt::Debug for Register {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Action {
+impl ::core::fmt::Display for Register {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account", self.account())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for Action {
+impl ::core::default::Default for Register {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            0, 0, 0, 0, 84, 0, 0, 0, 12, 0, 0, 0, 64, 0, 0, 0, 52, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0,
-            0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0,
-            0, 0, 0, 0, 0,
+            93, 0, 0, 0, 12, 0, 0, 0, 73, 0, 0, 0, 61, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 36, 0, 0,
+            0, 40, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0,
+            0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
         ];
-        Action::new_unchecked(v.into())
+        Register::new_unchecked(v.into())
     }
 }
-impl Action {
-    pub const ITEM_COUNT: usize = 4;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
+impl Register {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn to_enum(&self) -> ActionUnion {
-        let inner = self.0.slice_from(molecule::NUMBER_SIZE);
-        match self.item_id() {
-            0 => Register::new_unchecked(inner).into(),
-            1 => Deposit::new_unchecked(inner).into(),
-            2 => SubmitBlock::new_unchecked(inner).into(),
-            3 => RevertBlock::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ActionReader<'r> {
-        ActionReader::new_unchecked(self.as_slice())
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn account(&self) -> Account {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        Account::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn proof(&self) -> SMTProof {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            SMTProof::new_unchecked(self.0.slice(start, end))
+        } else {
+            SMTProof::new_unchecked(self.0.slice_from(start))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RegisterReader<'r> {
+        RegisterReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Action {
-    type Builder = ActionBuilder;
-    const NAME: &'static str = "Action";
+impl molecule::prelude::Entity for Register {
+    type Builder = RegisterBuilder;
+    const NAME: &'static str = "Register";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Action(data)
+        Register(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5788,304 +12606,191 @@ impl molecule::prelude::Entity for Action {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ActionReader::from_slice(slice).map(|reader| reader.to_entity())
+        RegisterReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ActionReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        RegisterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set(self.to_enum())
+        Self::new_builder()
+            .account(self.account())
+            .proof(self.proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ActionReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ActionReader<'r> {
+pub struct RegisterReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RegisterReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
             write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl<'r> ::core::fmt::Debug for ActionReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
-    }
-}
-impl<'r> ::core::fmt::Display for ActionReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}(", Self::NAME)?;
-        self.to_enum().display_inner(f)?;
-        write!(f, ")")
-    }
-}
-impl<'r> ActionReader<'r> {
-    pub const ITEM_COUNT: usize = 4;
-    pub fn item_id(&self) -> molecule::Number {
-        molecule::unpack_number(self.as_slice())
-    }
-    pub fn to_enum(&self) -> ActionUnionReader<'r> {
-        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
-        match self.item_id() {
-            0 => RegisterReader::new_unchecked(inner).into(),
-            1 => DepositReader::new_unchecked(inner).into(),
-            2 => SubmitBlockReader::new_unchecked(inner).into(),
-            3 => RevertBlockReader::new_unchecked(inner).into(),
-            _ => panic!("{}: invalid data", Self::NAME),
-        }
-    }
-}
-impl<'r> molecule::prelude::Reader<'r> for ActionReader<'r> {
-    type Entity = Action;
-    const NAME: &'static str = "ActionReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().into())
-    }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        ActionReader(slice)
-    }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
-    }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_id = molecule::unpack_number(slice);
-        let inner_slice = &slice[molecule::NUMBER_SIZE..];
-        match item_id {
-            0 => RegisterReader::verify(inner_slice, compatible),
-            1 => DepositReader::verify(inner_slice, compatible),
-            2 => SubmitBlockReader::verify(inner_slice, compatible),
-            3 => RevertBlockReader::verify(inner_slice, compatible),
-            _ => ve!(Self, UnknownItem, Self::ITEM_COUNT, item_id),
-        }?;
-        Ok(())
-    }
-}
-#[derive(Debug, Default)]
-pub struct ActionBuilder(pub(crate) ActionUnion);
-impl ActionBuilder {
-    pub const ITEM_COUNT: usize = 4;
-    pub fn set<I>(mut self, v: I) -> Self
-    where
-        I: ::core::convert::Into<ActionUnion>,
-    {
-        self.0 = v.into();
-        self
-    }
-}
-impl molecule::prelude::Builder for ActionBuilder {
-    type Entity = Action;
-    const NAME: &'static str = "ActionBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + self.0.as_slice().len()
-    }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
-        writer.write_all(self.0.as_slice())
-    }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Action::new_unchecked(inner.into())
-    }
-}
-#[derive(Debug, Clone)]
-pub enum ActionUnion {
-    Register(Register),
-    Deposit(Deposit),
-    SubmitBlock(SubmitBlock),
-    RevertBlock(RevertBlock),
-}
-#[derive(Debug, Clone, Copy)]
-pub enum ActionUnionReader<'r> {
-    Register(RegisterReader<'r>),
-    Deposit(DepositReader<'r>),
-    SubmitBlock(SubmitBlockReader<'r>),
-    RevertBlock(RevertBlockReader<'r>),
-}
-impl ::core::default::Default for ActionUnion {
-    fn default() -> Self {
-        ActionUnion::Register(::core::default::Default::default())
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Display for ActionUnion {
+impl<'r> ::core::fmt::Debug for RegisterReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ActionUnion::Register(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Register::NAME, item)
-            }
-            ActionUnion::Deposit(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Deposit::NAME, item)
-            }
-            ActionUnion::SubmitBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SubmitBlock::NAME, item)
-            }
-            ActionUnion::RevertBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, RevertBlock::NAME, item)
-            }
-        }
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ActionUnionReader<'r> {
+impl<'r> ::core::fmt::Display for RegisterReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ActionUnionReader::Register(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Register::NAME, item)
-            }
-            ActionUnionReader::Deposit(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, Deposit::NAME, item)
-            }
-            ActionUnionReader::SubmitBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, SubmitBlock::NAME, item)
-            }
-            ActionUnionReader::RevertBlock(ref item) => {
-                write!(f, "{}::{}({})", Self::NAME, RevertBlock::NAME, item)
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "account", self.account())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
+        write!(f, " }}")
     }
 }
-impl ActionUnion {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ActionUnion::Register(ref item) => write!(f, "{}", item),
-            ActionUnion::Deposit(ref item) => write!(f, "{}", item),
-            ActionUnion::SubmitBlock(ref item) => write!(f, "{}", item),
-            ActionUnion::RevertBlock(ref item) => write!(f, "{}", item),
-        }
+impl<'r> RegisterReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-}
-impl<'r> ActionUnionReader<'r> {
-    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        match self {
-            ActionUnionReader::Register(ref item) => write!(f, "{}", item),
-            ActionUnionReader::Deposit(ref item) => write!(f, "{}", item),
-            ActionUnionReader::SubmitBlock(ref item) => write!(f, "{}", item),
-            ActionUnionReader::RevertBlock(ref item) => write!(f, "{}", item),
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-}
-impl ::core::convert::From<Register> for ActionUnion {
-    fn from(item: Register) -> Self {
-        ActionUnion::Register(item)
+    pub fn field_offsets(&self) -> &[[u8; 4]] {
+        molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
     }
-}
-impl ::core::convert::From<Deposit> for ActionUnion {
-    fn from(item: Deposit) -> Self {
-        ActionUnion::Deposit(item)
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-}
-impl ::core::convert::From<SubmitBlock> for ActionUnion {
-    fn from(item: SubmitBlock) -> Self {
-        ActionUnion::SubmitBlock(item)
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-}
-impl ::core::convert::From<RevertBlock> for ActionUnion {
-    fn from(item: RevertBlock) -> Self {
-        ActionUnion::RevertBlock(item)
+    pub fn account(&self) -> AccountReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[0][..]) as usize;
+        let end = molecule::unpack_number(&offsets[1][..]) as usize;
+        AccountReader::new_unchecked(&self.as_slice()[start..end])
     }
-}
-impl<'r> ::core::convert::From<RegisterReader<'r>> for ActionUnionReader<'r> {
-    fn from(item: RegisterReader<'r>) -> Self {
-        ActionUnionReader::Register(item)
+    pub fn proof(&self) -> SMTProofReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            SMTProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SMTProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> ::core::convert::From<DepositReader<'r>> for ActionUnionReader<'r> {
-    fn from(item: DepositReader<'r>) -> Self {
-        ActionUnionReader::Deposit(item)
+impl<'r> molecule::prelude::Reader<'r> for RegisterReader<'r> {
+    type Entity = Register;
+    const NAME: &'static str = "RegisterReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().into())
     }
-}
-impl<'r> ::core::convert::From<SubmitBlockReader<'r>> for ActionUnionReader<'r> {
-    fn from(item: SubmitBlockReader<'r>) -> Self {
-        ActionUnionReader::SubmitBlock(item)
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RegisterReader(slice)
     }
-}
-impl<'r> ::core::convert::From<RevertBlockReader<'r>> for ActionUnionReader<'r> {
-    fn from(item: RevertBlockReader<'r>) -> Self {
-        ActionUnionReader::RevertBlock(item)
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-}
-impl ActionUnion {
-    pub const NAME: &'static str = "ActionUnion";
-    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
-        match self {
-            ActionUnion::Register(item) => item.as_bytes(),
-            ActionUnion::Deposit(item) => item.as_bytes(),
-            ActionUnion::SubmitBlock(item) => item.as_bytes(),
-            ActionUnion::RevertBlock(item) => item.as_bytes(),
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-    }
-    pub fn as_slice(&self) -> &[u8] {
-        match self {
-            ActionUnion::Register(item) => item.as_slice(),
-            ActionUnion::Deposit(item) => item.as_slice(),
-            ActionUnion::SubmitBlock(item) => item.as_slice(),
-            ActionUnion::RevertBlock(item) => item.as_slice(),
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-    }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            ActionUnion::Register(_) => 0,
-            ActionUnion::Deposit(_) => 1,
-            ActionUnion::SubmitBlock(_) => 2,
-            ActionUnion::RevertBlock(_) => 3,
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
         }
-    }
-    pub fn item_name(&self) -> &str {
-        match self {
-            ActionUnion::Register(_) => "Register",
-            ActionUnion::Deposit(_) => "Deposit",
-            ActionUnion::SubmitBlock(_) => "SubmitBlock",
-            ActionUnion::RevertBlock(_) => "RevertBlock",
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
         }
-    }
-    pub fn as_reader<'r>(&'r self) -> ActionUnionReader<'r> {
-        match self {
-            ActionUnion::Register(item) => item.as_reader().into(),
-            ActionUnion::Deposit(item) => item.as_reader().into(),
-            ActionUnion::SubmitBlock(item) => item.as_reader().into(),
-            ActionUnion::RevertBlock(item) => item.as_reader().into(),
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
+        let mut offsets: Vec<usize> = ptr[..field_count]
+            .iter()
+            .map(|x| molecule::unpack_number(&x[..]) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        AccountReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SMTProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
     }
 }
-impl<'r> ActionUnionReader<'r> {
-    pub const NAME: &'r str = "ActionUnionReader";
-    pub fn as_slice(&self) -> &'r [u8] {
-        match self {
-            ActionUnionReader::Register(item) => item.as_slice(),
-            ActionUnionReader::Deposit(item) => item.as_slice(),
-            ActionUnionReader::SubmitBlock(item) => item.as_slice(),
-            ActionUnionReader::RevertBlock(item) => item.as_slice(),
-        }
+#[derive(Debug, Default)]
+pub struct RegisterBuilder {
+    pub(crate) account: Account,
+    pub(crate) proof: SMTProof,
+}
+impl RegisterBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn account(mut self, v: Account) -> Self {
+        self.account = v;
+        self
     }
-    pub fn item_id(&self) -> molecule::Number {
-        match self {
-            ActionUnionReader::Register(_) => 0,
-            ActionUnionReader::Deposit(_) => 1,
-            ActionUnionReader::SubmitBlock(_) => 2,
-            ActionUnionReader::RevertBlock(_) => 3,
-        }
+    pub fn proof(mut self, v: SMTProof) -> Self {
+        self.proof = v;
+        self
     }
-    pub fn item_name(&self) -> &str {
-        match self {
-            ActionUnionReader::Register(_) => "Register",
-            ActionUnionReader::Deposit(_) => "Deposit",
-            ActionUnionReader::SubmitBlock(_) => "SubmitBlock",
-            ActionUnionReader::RevertBlock(_) => "RevertBlock",
+}
+impl molecule::prelude::Builder for RegisterBuilder {
+    type Entity = Register;
+    const NAME: &'static str = "RegisterBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.account.as_slice().len()
+            + self.proof.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.account.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.account.as_slice())?;
+        writer.write_all(self.proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Register::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Register(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Register {
+pub struct Deposit(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Deposit {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6094,15 +12799,16 @@ impl ::core::fmt::LowerHex for Register {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Register {
+impl ::core::fmt::Debug for Deposit {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Register {
+impl ::core::fmt::Display for Deposit {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "account", self.account())?;
+        write!(f, ", {}: {}", "token_kv", self.token_kv())?;
         write!(f, ", {}: {}", "proof", self.proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -6111,18 +12817,19 @@ impl ::core::fmt::Display for Register {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for Register {
+impl ::core::default::Default for Deposit {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            84, 0, 0, 0, 12, 0, 0, 0, 64, 0, 0, 0, 52, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 28, 0, 0,
-            0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+            101, 0, 0, 0, 16, 0, 0, 0, 77, 0, 0, 0, 81, 0, 0, 0, 61, 0, 0, 0, 28, 0, 0, 0, 36, 0,
+            0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0,
+            0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
         ];
-        Register::new_unchecked(v.into())
+        Deposit::new_unchecked(v.into())
     }
 }
-impl Register {
-    pub const FIELD_COUNT: usize = 2;
+impl Deposit {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6148,25 +12855,31 @@ impl Register {
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
         Account::new_unchecked(self.0.slice(start, end))
     }
-    pub fn proof(&self) -> SMTProof {
+    pub fn token_kv(&self) -> KeyValueMap {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        KeyValueMap::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn proof(&self) -> SMTProof {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            let end = molecule::unpack_number(&offsets[3][..]) as usize;
             SMTProof::new_unchecked(self.0.slice(start, end))
         } else {
             SMTProof::new_unchecked(self.0.slice_from(start))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> RegisterReader<'r> {
-        RegisterReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> DepositReader<'r> {
+        DepositReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Register {
-    type Builder = RegisterBuilder;
-    const NAME: &'static str = "Register";
+impl molecule::prelude::Entity for Deposit {
+    type Builder = DepositBuilder;
+    const NAME: &'static str = "Deposit";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Register(data)
+        Deposit(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -6175,10 +12888,10 @@ impl molecule::prelude::Entity for Register {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RegisterReader::from_slice(slice).map(|reader| reader.to_entity())
+        DepositReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RegisterReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        DepositReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -6186,12 +12899,13 @@ impl molecule::prelude::Entity for Register {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .account(self.account())
+            .token_kv(self.token_kv())
             .proof(self.proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct RegisterReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RegisterReader<'r> {
+pub struct DepositReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DepositReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6200,15 +12914,16 @@ impl<'r> ::core::fmt::LowerHex for RegisterReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RegisterReader<'r> {
+impl<'r> ::core::fmt::Debug for DepositReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RegisterReader<'r> {
+impl<'r> ::core::fmt::Display for DepositReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "account", self.account())?;
+        write!(f, ", {}: {}", "token_kv", self.token_kv())?;
         write!(f, ", {}: {}", "proof", self.proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -6217,8 +12932,8 @@ impl<'r> ::core::fmt::Display for RegisterReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> RegisterReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> DepositReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6244,25 +12959,31 @@ impl<'r> RegisterReader<'r> {
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
         AccountReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn proof(&self) -> SMTProofReader<'r> {
+    pub fn token_kv(&self) -> KeyValueMapReader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[1][..]) as usize;
+        let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        KeyValueMapReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof(&self) -> SMTProofReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[2][..]) as usize;
+            let end = molecule::unpack_number(&offsets[3][..]) as usize;
             SMTProofReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             SMTProofReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for RegisterReader<'r> {
-    type Entity = Register;
-    const NAME: &'static str = "RegisterReader";
+impl<'r> molecule::prelude::Reader<'r> for DepositReader<'r> {
+    type Entity = Deposit;
+    const NAME: &'static str = "DepositReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RegisterReader(slice)
+        DepositReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -6307,32 +13028,39 @@ impl<'r> molecule::prelude::Reader<'r> for RegisterReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         AccountReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        SMTProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        KeyValueMapReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SMTProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct RegisterBuilder {
+pub struct DepositBuilder {
     pub(crate) account: Account,
+    pub(crate) token_kv: KeyValueMap,
     pub(crate) proof: SMTProof,
 }
-impl RegisterBuilder {
-    pub const FIELD_COUNT: usize = 2;
+impl DepositBuilder {
+    pub const FIELD_COUNT: usize = 3;
     pub fn account(mut self, v: Account) -> Self {
         self.account = v;
         self
     }
+    pub fn token_kv(mut self, v: KeyValueMap) -> Self {
+        self.token_kv = v;
+        self
+    }
     pub fn proof(mut self, v: SMTProof) -> Self {
         self.proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for RegisterBuilder {
-    type Entity = Register;
-    const NAME: &'static str = "RegisterBuilder";
+impl molecule::prelude::Builder for DepositBuilder {
+    type Entity = Deposit;
+    const NAME: &'static str = "DepositBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.account.as_slice().len()
+            + self.token_kv.as_slice().len()
             + self.proof.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
@@ -6341,12 +13069,15 @@ impl molecule::prelude::Builder for RegisterBuilder {
         offsets.push(total_size);
         total_size += self.account.as_slice().len();
         offsets.push(total_size);
+        total_size += self.token_kv.as_slice().len();
+        offsets.push(total_size);
         total_size += self.proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.account.as_slice())?;
+        writer.write_all(self.token_kv.as_slice())?;
         writer.write_all(self.proof.as_slice())?;
         Ok(())
     }
@@ -6354,12 +13085,12 @@ impl molecule::prelude::Builder for RegisterBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Register::new_unchecked(inner.into())
+        Deposit::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Deposit(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Deposit {
+pub struct SubmitBlock(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SubmitBlock {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6368,17 +13099,21 @@ impl ::core::fmt::LowerHex for Deposit {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Deposit {
+impl ::core::fmt::Debug for SubmitBlock {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Deposit {
+impl ::core::fmt::Display for SubmitBlock {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "account", self.account())?;
+        write!(f, "{}: {}", "txs", self.txs())?;
+        write!(f, ", {}: {}", "block", self.block())?;
+        write!(f, ", {}: {}", "last_block_hash", self.last_block_hash())?;
+        write!(f, ", {}: {}", "block_proof", self.block_proof())?;
+        write!(f, ", {}: {}", "ag_account", self.ag_account())?;
         write!(f, ", {}: {}", "token_kv", self.token_kv())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "account_proof", self.account_proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6386,19 +13121,34 @@ impl ::core::fmt::Display for Deposit {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for Deposit {
+impl ::core::default::Default for SubmitBlock {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            92, 0, 0, 0, 16, 0, 0, 0, 68, 0, 0, 0, 72, 0, 0, 0, 52, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0,
-            0, 28, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            6, 2, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 141, 1, 0, 0, 173, 1, 0, 0, 177, 1, 0, 0, 238, 1,
+            0, 0, 242, 1, 0, 0, 4, 0, 0, 0, 105, 1, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 84, 0, 0, 0,
+            88, 0, 0, 0, 120, 0, 0, 0, 152, 0, 0, 0, 160, 0, 0, 0, 192, 0, 0, 0, 1, 1, 0, 0, 9, 1,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0,
+            60, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0,
             0, 4, 0, 0, 0, 0, 0, 0, 0,
         ];
-        Deposit::new_unchecked(v.into())
+        SubmitBlock::new_unchecked(v.into())
     }
 }
-impl Deposit {
-    pub const FIELD_COUNT: usize = 3;
+impl SubmitBlock {
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6418,37 +13168,61 @@ impl Deposit {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn account(&self) -> Account {
+    pub fn txs(&self) -> TxVec {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        Account::new_unchecked(self.0.slice(start, end))
+        TxVec::new_unchecked(self.0.slice(start, end))
     }
-    pub fn token_kv(&self) -> KeyValueMap {
+    pub fn block(&self) -> AgBlock {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[1][..]) as usize;
         let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        KeyValueMap::new_unchecked(self.0.slice(start, end))
+        AgBlock::new_unchecked(self.0.slice(start, end))
     }
-    pub fn proof(&self) -> SMTProof {
+    pub fn last_block_hash(&self) -> Byte32 {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn block_proof(&self) -> Byte32Vec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        Byte32Vec::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn ag_account(&self) -> Account {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        Account::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn token_kv(&self) -> KeyValueMap {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        let end = molecule::unpack_number(&offsets[6][..]) as usize;
+        KeyValueMap::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn account_proof(&self) -> SMTProof {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[6][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            let end = molecule::unpack_number(&offsets[7][..]) as usize;
             SMTProof::new_unchecked(self.0.slice(start, end))
         } else {
             SMTProof::new_unchecked(self.0.slice_from(start))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> DepositReader<'r> {
-        DepositReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> SubmitBlockReader<'r> {
+        SubmitBlockReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Deposit {
-    type Builder = DepositBuilder;
-    const NAME: &'static str = "Deposit";
+impl molecule::prelude::Entity for SubmitBlock {
+    type Builder = SubmitBlockBuilder;
+    const NAME: &'static str = "SubmitBlock";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Deposit(data)
+        SubmitBlock(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -6457,24 +13231,28 @@ impl molecule::prelude::Entity for Deposit {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DepositReader::from_slice(slice).map(|reader| reader.to_entity())
+        SubmitBlockReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DepositReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        SubmitBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .account(self.account())
+            .txs(self.txs())
+            .block(self.block())
+            .last_block_hash(self.last_block_hash())
+            .block_proof(self.block_proof())
+            .ag_account(self.ag_account())
             .token_kv(self.token_kv())
-            .proof(self.proof())
+            .account_proof(self.account_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct DepositReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for DepositReader<'r> {
+pub struct SubmitBlockReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SubmitBlockReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6483,17 +13261,21 @@ impl<'r> ::core::fmt::LowerHex for DepositReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for DepositReader<'r> {
+impl<'r> ::core::fmt::Debug for SubmitBlockReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for DepositReader<'r> {
+impl<'r> ::core::fmt::Display for SubmitBlockReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "account", self.account())?;
+        write!(f, "{}: {}", "txs", self.txs())?;
+        write!(f, ", {}: {}", "block", self.block())?;
+        write!(f, ", {}: {}", "last_block_hash", self.last_block_hash())?;
+        write!(f, ", {}: {}", "block_proof", self.block_proof())?;
+        write!(f, ", {}: {}", "ag_account", self.ag_account())?;
         write!(f, ", {}: {}", "token_kv", self.token_kv())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "account_proof", self.account_proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6501,8 +13283,8 @@ impl<'r> ::core::fmt::Display for DepositReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> DepositReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> SubmitBlockReader<'r> {
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6522,37 +13304,61 @@ impl<'r> DepositReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn account(&self) -> AccountReader<'r> {
+    pub fn txs(&self) -> TxVecReader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[0][..]) as usize;
         let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        AccountReader::new_unchecked(&self.as_slice()[start..end])
+        TxVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn token_kv(&self) -> KeyValueMapReader<'r> {
+    pub fn block(&self) -> AgBlockReader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[1][..]) as usize;
         let end = molecule::unpack_number(&offsets[2][..]) as usize;
+        AgBlockReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn last_block_hash(&self) -> Byte32Reader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn block_proof(&self) -> Byte32VecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn ag_account(&self) -> AccountReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
+        let end = molecule::unpack_number(&offsets[5][..]) as usize;
+        AccountReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn token_kv(&self) -> KeyValueMapReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[5][..]) as usize;
+        let end = molecule::unpack_number(&offsets[6][..]) as usize;
         KeyValueMapReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn proof(&self) -> SMTProofReader<'r> {
+    pub fn account_proof(&self) -> SMTProofReader<'r> {
         let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let start = molecule::unpack_number(&offsets[6][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            let end = molecule::unpack_number(&offsets[7][..]) as usize;
             SMTProofReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             SMTProofReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for DepositReader<'r> {
-    type Entity = Deposit;
-    const NAME: &'static str = "DepositReader";
+impl<'r> molecule::prelude::Reader<'r> for SubmitBlockReader<'r> {
+    type Entity = SubmitBlock;
+    const NAME: &'static str = "SubmitBlockReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        DepositReader(slice)
+        SubmitBlockReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -6596,70 +13402,110 @@ impl<'r> molecule::prelude::Reader<'r> for DepositReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        AccountReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        KeyValueMapReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        SMTProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        TxVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        AgBlockReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        AccountReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        KeyValueMapReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        SMTProofReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct DepositBuilder {
-    pub(crate) account: Account,
+pub struct SubmitBlockBuilder {
+    pub(crate) txs: TxVec,
+    pub(crate) block: AgBlock,
+    pub(crate) last_block_hash: Byte32,
+    pub(crate) block_proof: Byte32Vec,
+    pub(crate) ag_account: Account,
     pub(crate) token_kv: KeyValueMap,
-    pub(crate) proof: SMTProof,
+    pub(crate) account_proof: SMTProof,
 }
-impl DepositBuilder {
-    pub const FIELD_COUNT: usize = 3;
-    pub fn account(mut self, v: Account) -> Self {
-        self.account = v;
+impl SubmitBlockBuilder {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn txs(mut self, v: TxVec) -> Self {
+        self.txs = v;
+        self
+    }
+    pub fn block(mut self, v: AgBlock) -> Self {
+        self.block = v;
+        self
+    }
+    pub fn last_block_hash(mut self, v: Byte32) -> Self {
+        self.last_block_hash = v;
+        self
+    }
+    pub fn block_proof(mut self, v: Byte32Vec) -> Self {
+        self.block_proof = v;
+        self
+    }
+    pub fn ag_account(mut self, v: Account) -> Self {
+        self.ag_account = v;
         self
     }
     pub fn token_kv(mut self, v: KeyValueMap) -> Self {
         self.token_kv = v;
         self
     }
-    pub fn proof(mut self, v: SMTProof) -> Self {
-        self.proof = v;
+    pub fn account_proof(mut self, v: SMTProof) -> Self {
+        self.account_proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for DepositBuilder {
-    type Entity = Deposit;
-    const NAME: &'static str = "DepositBuilder";
+impl molecule::prelude::Builder for SubmitBlockBuilder {
+    type Entity = SubmitBlock;
+    const NAME: &'static str = "SubmitBlockBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.account.as_slice().len()
+            + self.txs.as_slice().len()
+            + self.block.as_slice().len()
+            + self.last_block_hash.as_slice().len()
+            + self.block_proof.as_slice().len()
+            + self.ag_account.as_slice().len()
             + self.token_kv.as_slice().len()
-            + self.proof.as_slice().len()
+            + self.account_proof.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.account.as_slice().len();
+        total_size += self.txs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.block.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.last_block_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.block_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.ag_account.as_slice().len();
         offsets.push(total_size);
         total_size += self.token_kv.as_slice().len();
         offsets.push(total_size);
-        total_size += self.proof.as_slice().len();
+        total_size += self.account_proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.account.as_slice())?;
+        writer.write_all(self.txs.as_slice())?;
+        writer.write_all(self.block.as_slice())?;
+        writer.write_all(self.last_block_hash.as_slice())?;
+        writer.write_all(self.block_proof.as_slice())?;
+        writer.write_all(self.ag_account.as_slice())?;
         writer.write_all(self.token_kv.as_slice())?;
-        writer.write_all(self.proof.as_slice())?;
+        writer.write_all(self.account_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Deposit::new_unchecked(inner.into())
+        SubmitBlock::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SubmitBlock(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SubmitBlock {
+pub struct AccountVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AccountVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6668,125 +13514,76 @@ impl ::core::fmt::LowerHex for SubmitBlock {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SubmitBlock {
+impl ::core::fmt::Debug for AccountVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SubmitBlock {
+impl ::core::fmt::Display for AccountVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "txs", self.txs())?;
-        write!(f, ", {}: {}", "block", self.block())?;
-        write!(f, ", {}: {}", "last_block_hash", self.last_block_hash())?;
-        write!(f, ", {}: {}", "block_proof", self.block_proof())?;
-        write!(f, ", {}: {}", "ag_account", self.ag_account())?;
-        write!(f, ", {}: {}", "token_kv", self.token_kv())?;
-        write!(f, ", {}: {}", "account_proof", self.account_proof())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for SubmitBlock {
+impl ::core::default::Default for AccountVec {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            117, 1, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 5, 1, 0, 0, 37, 1, 0, 0, 41, 1, 0, 0, 93, 1, 0,
-            0, 97, 1, 0, 0, 4, 0, 0, 0, 225, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 76, 0, 0, 0, 80, 0,
-            0, 0, 112, 0, 0, 0, 120, 0, 0, 0, 152, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 28, 0,
-            0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0,
-            0, 0, 0, 0, 0,
-        ];
-        SubmitBlock::new_unchecked(v.into())
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        AccountVec::new_unchecked(v.into())
     }
 }
-impl SubmitBlock {
-    pub const FIELD_COUNT: usize = 7;
+impl AccountVec {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
+    pub fn item_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
         molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn txs(&self) -> TxVec {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        TxVec::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn block(&self) -> AgBlock {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        AgBlock::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn last_block_hash(&self) -> Byte32 {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start, end))
-    }
-    pub fn block_proof(&self) -> Byte32Vec {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        let end = molecule::unpack_number(&offsets[4][..]) as usize;
-        Byte32Vec::new_unchecked(self.0.slice(start, end))
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn ag_account(&self) -> Account {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[4][..]) as usize;
-        let end = molecule::unpack_number(&offsets[5][..]) as usize;
-        Account::new_unchecked(self.0.slice(start, end))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn token_kv(&self) -> KeyValueMap {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[5][..]) as usize;
-        let end = molecule::unpack_number(&offsets[6][..]) as usize;
-        KeyValueMap::new_unchecked(self.0.slice(start, end))
+    pub fn get(&self, idx: usize) -> Option<Account> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn account_proof(&self) -> SMTProof {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[6][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[7][..]) as usize;
-            SMTProof::new_unchecked(self.0.slice(start, end))
+    pub fn get_unchecked(&self, idx: usize) -> Account {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            Account::new_unchecked(self.0.slice_from(start))
         } else {
-            SMTProof::new_unchecked(self.0.slice_from(start))
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            Account::new_unchecked(self.0.slice(start, end))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> SubmitBlockReader<'r> {
-        SubmitBlockReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> AccountVecReader<'r> {
+        AccountVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SubmitBlock {
-    type Builder = SubmitBlockBuilder;
-    const NAME: &'static str = "SubmitBlock";
+impl molecule::prelude::Entity for AccountVec {
+    type Builder = AccountVecBuilder;
+    const NAME: &'static str = "AccountVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SubmitBlock(data)
+        AccountVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -6795,28 +13592,21 @@ impl molecule::prelude::Entity for SubmitBlock {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SubmitBlockReader::from_slice(slice).map(|reader| reader.to_entity())
+        AccountVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SubmitBlockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        AccountVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .txs(self.txs())
-            .block(self.block())
-            .last_block_hash(self.last_block_hash())
-            .block_proof(self.block_proof())
-            .ag_account(self.ag_account())
-            .token_kv(self.token_kv())
-            .account_proof(self.account_proof())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SubmitBlockReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SubmitBlockReader<'r> {
+pub struct AccountVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AccountVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6825,104 +13615,70 @@ impl<'r> ::core::fmt::LowerHex for SubmitBlockReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SubmitBlockReader<'r> {
+impl<'r> ::core::fmt::Debug for AccountVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SubmitBlockReader<'r> {
+impl<'r> ::core::fmt::Display for AccountVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "txs", self.txs())?;
-        write!(f, ", {}: {}", "block", self.block())?;
-        write!(f, ", {}: {}", "last_block_hash", self.last_block_hash())?;
-        write!(f, ", {}: {}", "block_proof", self.block_proof())?;
-        write!(f, ", {}: {}", "ag_account", self.ag_account())?;
-        write!(f, ", {}: {}", "token_kv", self.token_kv())?;
-        write!(f, ", {}: {}", "account_proof", self.account_proof())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl<'r> SubmitBlockReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
+impl<'r> AccountVecReader<'r> {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
+    pub fn item_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn field_offsets(&self) -> &[[u8; 4]] {
+    pub fn item_offsets(&self) -> &[[u8; 4]] {
         molecule::unpack_number_vec(&self.as_slice()[molecule::NUMBER_SIZE..])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn txs(&self) -> TxVecReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[0][..]) as usize;
-        let end = molecule::unpack_number(&offsets[1][..]) as usize;
-        TxVecReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn block(&self) -> AgBlockReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[1][..]) as usize;
-        let end = molecule::unpack_number(&offsets[2][..]) as usize;
-        AgBlockReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn last_block_hash(&self) -> Byte32Reader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[2][..]) as usize;
-        let end = molecule::unpack_number(&offsets[3][..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn block_proof(&self) -> Byte32VecReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[3][..]) as usize;
-        let end = molecule::unpack_number(&offsets[4][..]) as usize;
-        Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn ag_account(&self) -> AccountReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[4][..]) as usize;
-        let end = molecule::unpack_number(&offsets[5][..]) as usize;
-        AccountReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn token_kv(&self) -> KeyValueMapReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[5][..]) as usize;
-        let end = molecule::unpack_number(&offsets[6][..]) as usize;
-        KeyValueMapReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get(&self, idx: usize) -> Option<AccountReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn account_proof(&self) -> SMTProofReader<'r> {
-        let offsets = self.field_offsets();
-        let start = molecule::unpack_number(&offsets[6][..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[7][..]) as usize;
-            SMTProofReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get_unchecked(&self, idx: usize) -> AccountReader<'r> {
+        let offsets = self.item_offsets();
+        let start = molecule::unpack_number(&offsets[idx][..]) as usize;
+        if idx == self.len() - 1 {
+            AccountReader::new_unchecked(&self.as_slice()[start..])
         } else {
-            SMTProofReader::new_unchecked(&self.as_slice()[start..])
+            let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
+            AccountReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SubmitBlockReader<'r> {
-    type Entity = SubmitBlock;
-    const NAME: &'static str = "SubmitBlockReader";
+impl<'r> molecule::prelude::Reader<'r> for AccountVecReader<'r> {
+    type Entity = AccountVec;
+    const NAME: &'static str = "AccountVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SubmitBlockReader(slice)
+        AccountVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -6937,28 +13693,28 @@ impl<'r> molecule::prelude::Reader<'r> for SubmitBlockReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+        if slice_len == molecule::NUMBER_SIZE {
             return Ok(());
         }
         if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
         }
         let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
         if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
             return ve!(Self, OffsetsNotMatch);
         }
-        let field_count = offset_first / 4 - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
         if slice_len < header_size {
             return ve!(Self, HeaderIsBroken, header_size, slice_len);
         }
         let ptr = molecule::unpack_number_vec(&slice[molecule::NUMBER_SIZE..]);
-        let mut offsets: Vec<usize> = ptr[..field_count]
+        let mut offsets: Vec<usize> = ptr[..item_count]
             .iter()
             .map(|x| molecule::unpack_number(&x[..]) as usize)
             .collect();
@@ -6966,110 +13722,129 @@ impl<'r> molecule::prelude::Reader<'r> for SubmitBlockReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        TxVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        AgBlockReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Byte32VecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        AccountReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        KeyValueMapReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        SMTProofReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            AccountReader::verify(&slice[start..end], compatible)?;
+        }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SubmitBlockBuilder {
-    pub(crate) txs: TxVec,
-    pub(crate) block: AgBlock,
-    pub(crate) last_block_hash: Byte32,
-    pub(crate) block_proof: Byte32Vec,
-    pub(crate) ag_account: Account,
-    pub(crate) token_kv: KeyValueMap,
-    pub(crate) account_proof: SMTProof,
-}
-impl SubmitBlockBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn txs(mut self, v: TxVec) -> Self {
-        self.txs = v;
+pub struct AccountVecBuilder(pub(crate) Vec<Account>);
+impl AccountVecBuilder {
+    pub fn set(mut self, v: Vec<Account>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn block(mut self, v: AgBlock) -> Self {
-        self.block = v;
+    pub fn push(mut self, v: Account) -> Self {
+        self.0.push(v);
         self
     }
-    pub fn last_block_hash(mut self, v: Byte32) -> Self {
-        self.last_block_hash = v;
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Account>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
-    pub fn block_proof(mut self, v: Byte32Vec) -> Self {
-        self.block_proof = v;
-        self
+}
+impl molecule::prelude::Builder for AccountVecBuilder {
+    type Entity = AccountVec;
+    const NAME: &'static str = "AccountVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
-    pub fn ag_account(mut self, v: Account) -> Self {
-        self.ag_account = v;
-        self
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
     }
-    pub fn token_kv(mut self, v: KeyValueMap) -> Self {
-        self.token_kv = v;
-        self
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AccountVec::new_unchecked(inner.into())
     }
-    pub fn account_proof(mut self, v: SMTProof) -> Self {
-        self.account_proof = v;
-        self
+}
+pub struct AccountVecIterator(AccountVec, usize, usize);
+impl ::core::iter::Iterator for AccountVecIterator {
+    type Item = Account;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
     }
 }
-impl molecule::prelude::Builder for SubmitBlockBuilder {
-    type Entity = SubmitBlock;
-    const NAME: &'static str = "SubmitBlockBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.txs.as_slice().len()
-            + self.block.as_slice().len()
-            + self.last_block_hash.as_slice().len()
-            + self.block_proof.as_slice().len()
-            + self.ag_account.as_slice().len()
-            + self.token_kv.as_slice().len()
-            + self.account_proof.as_slice().len()
+impl ::core::iter::ExactSizeIterator for AccountVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
-    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.txs.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.block.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.last_block_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.block_proof.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.ag_account.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.token_kv.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.account_proof.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+}
+impl ::core::iter::IntoIterator for AccountVec {
+    type Item = Account;
+    type IntoIter = AccountVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        AccountVecIterator(self, 0, len)
+    }
+}
+impl<'r> AccountVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> AccountVecReaderIterator<'t, 'r> {
+        AccountVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct AccountVecReaderIterator<'t, 'r>(&'t AccountVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for AccountVecReaderIterator<'t, 'r> {
+    type Item = AccountReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
         }
-        writer.write_all(self.txs.as_slice())?;
-        writer.write_all(self.block.as_slice())?;
-        writer.write_all(self.last_block_hash.as_slice())?;
-        writer.write_all(self.block_proof.as_slice())?;
-        writer.write_all(self.ag_account.as_slice())?;
-        writer.write_all(self.token_kv.as_slice())?;
-        writer.write_all(self.account_proof.as_slice())?;
-        Ok(())
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SubmitBlock::new_unchecked(inner.into())
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for AccountVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
 #[derive(Clone)]
-pub struct AccountVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for AccountVec {
+pub struct KeyValueMapVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for KeyValueMapVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7078,12 +13853,12 @@ impl ::core::fmt::LowerHex for AccountVec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for AccountVec {
+impl ::core::fmt::Debug for KeyValueMapVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for AccountVec {
+impl ::core::fmt::Display for KeyValueMapVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -7096,13 +13871,13 @@ impl ::core::fmt::Display for AccountVec {
         write!(f, "]")
     }
 }
-impl ::core::default::Default for AccountVec {
+impl ::core::default::Default for KeyValueMapVec {
     fn default() -> Self {
         let v: Vec<u8> = vec![4, 0, 0, 0];
-        AccountVec::new_unchecked(v.into())
+        KeyValueMapVec::new_unchecked(v.into())
     }
 }
-impl AccountVec {
+impl KeyValueMapVec {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7122,32 +13897,32 @@ impl AccountVec {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<Account> {
+    pub fn get(&self, idx: usize) -> Option<KeyValueMap> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> Account {
+    pub fn get_unchecked(&self, idx: usize) -> KeyValueMap {
         let offsets = self.item_offsets();
         let start = molecule::unpack_number(&offsets[idx][..]) as usize;
         if idx == self.len() - 1 {
-            Account::new_unchecked(self.0.slice_from(start))
+            KeyValueMap::new_unchecked(self.0.slice_from(start))
         } else {
             let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            Account::new_unchecked(self.0.slice(start, end))
+            KeyValueMap::new_unchecked(self.0.slice(start, end))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> AccountVecReader<'r> {
-        AccountVecReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> KeyValueMapVecReader<'r> {
+        KeyValueMapVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for AccountVec {
-    type Builder = AccountVecBuilder;
-    const NAME: &'static str = "AccountVec";
+impl molecule::prelude::Entity for KeyValueMapVec {
+    type Builder = KeyValueMapVecBuilder;
+    const NAME: &'static str = "KeyValueMapVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        AccountVec(data)
+        KeyValueMapVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -7156,10 +13931,10 @@ impl molecule::prelude::Entity for AccountVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        KeyValueMapVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        AccountVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        KeyValueMapVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -7169,8 +13944,8 @@ impl molecule::prelude::Entity for AccountVec {
     }
 }
 #[derive(Clone, Copy)]
-pub struct AccountVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for AccountVecReader<'r> {
+pub struct KeyValueMapVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for KeyValueMapVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7179,12 +13954,12 @@ impl<'r> ::core::fmt::LowerHex for AccountVecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for AccountVecReader<'r> {
+impl<'r> ::core::fmt::Debug for KeyValueMapVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for AccountVecReader<'r> {
+impl<'r> ::core::fmt::Display for KeyValueMapVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -7197,7 +13972,7 @@ impl<'r> ::core::fmt::Display for AccountVecReader<'r> {
         write!(f, "]")
     }
 }
-impl<'r> AccountVecReader<'r> {
+impl<'r> KeyValueMapVecReader<'r> {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7217,32 +13992,32 @@ impl<'r> AccountVecReader<'r> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<AccountReader<'r>> {
+    pub fn get(&self, idx: usize) -> Option<KeyValueMapReader<'r>> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> AccountReader<'r> {
+    pub fn get_unchecked(&self, idx: usize) -> KeyValueMapReader<'r> {
         let offsets = self.item_offsets();
         let start = molecule::unpack_number(&offsets[idx][..]) as usize;
         if idx == self.len() - 1 {
-            AccountReader::new_unchecked(&self.as_slice()[start..])
+            KeyValueMapReader::new_unchecked(&self.as_slice()[start..])
         } else {
             let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            AccountReader::new_unchecked(&self.as_slice()[start..end])
+            KeyValueMapReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for AccountVecReader<'r> {
-    type Entity = AccountVec;
-    const NAME: &'static str = "AccountVecReader";
+impl<'r> molecule::prelude::Reader<'r> for KeyValueMapVecReader<'r> {
+    type Entity = KeyValueMapVec;
+    const NAME: &'static str = "KeyValueMapVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        AccountVecReader(slice)
+        KeyValueMapVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -7289,32 +14064,32 @@ impl<'r> molecule::prelude::Reader<'r> for AccountVecReader<'r> {
         for pair in offsets.windows(2) {
             let start = pair[0];
             let end = pair[1];
-            AccountReader::verify(&slice[start..end], compatible)?;
+            KeyValueMapReader::verify(&slice[start..end], compatible)?;
         }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct AccountVecBuilder(pub(crate) Vec<Account>);
-impl AccountVecBuilder {
-    pub fn set(mut self, v: Vec<Account>) -> Self {
+pub struct KeyValueMapVecBuilder(pub(crate) Vec<KeyValueMap>);
+impl KeyValueMapVecBuilder {
+    pub fn set(mut self, v: Vec<KeyValueMap>) -> Self {
         self.0 = v;
         self
     }
-    pub fn push(mut self, v: Account) -> Self {
+    pub fn push(mut self, v: KeyValueMap) -> Self {
         self.0.push(v);
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = Account>>(mut self, iter: T) -> Self {
+    pub fn extend<T: ::core::iter::IntoIterator<Item = KeyValueMap>>(mut self, iter: T) -> Self {
         for elem in iter {
             self.0.push(elem);
         }
         self
     }
 }
-impl molecule::prelude::Builder for AccountVecBuilder {
-    type Entity = AccountVec;
-    const NAME: &'static str = "AccountVecBuilder";
+impl molecule::prelude::Builder for KeyValueMapVecBuilder {
+    type Entity = KeyValueMapVec;
+    const NAME: &'static str = "KeyValueMapVecBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (self.0.len() + 1)
             + self
@@ -7354,12 +14129,12 @@ impl molecule::prelude::Builder for AccountVecBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        AccountVec::new_unchecked(inner.into())
+        KeyValueMapVec::new_unchecked(inner.into())
     }
 }
-pub struct AccountVecIterator(AccountVec, usize, usize);
-impl ::core::iter::Iterator for AccountVecIterator {
-    type Item = Account;
+pub struct KeyValueMapVecIterator(KeyValueMapVec, usize, usize);
+impl ::core::iter::Iterator for KeyValueMapVecIterator {
+    type Item = KeyValueMap;
     fn next(&mut self) -> Option<Self::Item> {
         if self.1 >= self.2 {
             None
@@ -7370,27 +14145,27 @@ impl ::core::iter::Iterator for AccountVecIterator {
         }
     }
 }
-impl ::core::iter::ExactSizeIterator for AccountVecIterator {
+impl ::core::iter::ExactSizeIterator for KeyValueMapVecIterator {
     fn len(&self) -> usize {
         self.2 - self.1
     }
 }
-impl ::core::iter::IntoIterator for AccountVec {
-    type Item = Account;
-    type IntoIter = AccountVecIterator;
+impl ::core::iter::IntoIterator for KeyValueMapVec {
+    type Item = KeyValueMap;
+    type IntoIter = KeyValueMapVecIterator;
     fn into_iter(self) -> Self::IntoIter {
         let len = self.len();
-        AccountVecIterator(self, 0, len)
+        KeyValueMapVecIterator(self, 0, len)
     }
 }
-impl<'r> AccountVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> AccountVecReaderIterator<'t, 'r> {
-        AccountVecReaderIterator(&self, 0, self.len())
+impl<'r> KeyValueMapVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> KeyValueMapVecReaderIterator<'t, 'r> {
+        KeyValueMapVecReaderIterator(&self, 0, self.len())
     }
 }
-pub struct AccountVecReaderIterator<'t, 'r>(&'t AccountVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for AccountVecReaderIterator<'t, 'r> {
-    type Item = AccountReader<'t>;
+pub struct KeyValueMapVecReaderIterator<'t, 'r>(&'t KeyValueMapVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for KeyValueMapVecReaderIterator<'t, 'r> {
+    type Item = KeyValueMapReader<'t>;
     fn next(&mut self) -> Option<Self::Item> {
         if self.1 >= self.2 {
             None
@@ -7401,14 +14176,14 @@ impl<'t: 'r, 'r> ::core::iter::Iterator for AccountVecReaderIterator<'t, 'r> {
         }
     }
 }
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for AccountVecReaderIterator<'t, 'r> {
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for KeyValueMapVecReaderIterator<'t, 'r> {
     fn len(&self) -> usize {
         self.2 - self.1
     }
 }
 #[derive(Clone)]
-pub struct KeyValueMapVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for KeyValueMapVec {
+pub struct StorageKeyValueMapVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for StorageKeyValueMapVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7417,12 +14192,12 @@ impl ::core::fmt::LowerHex for KeyValueMapVec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for KeyValueMapVec {
+impl ::core::fmt::Debug for StorageKeyValueMapVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for KeyValueMapVec {
+impl ::core::fmt::Display for StorageKeyValueMapVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -7435,13 +14210,13 @@ impl ::core::fmt::Display for KeyValueMapVec {
         write!(f, "]")
     }
 }
-impl ::core::default::Default for KeyValueMapVec {
+impl ::core::default::Default for StorageKeyValueMapVec {
     fn default() -> Self {
         let v: Vec<u8> = vec![4, 0, 0, 0];
-        KeyValueMapVec::new_unchecked(v.into())
+        StorageKeyValueMapVec::new_unchecked(v.into())
     }
 }
-impl KeyValueMapVec {
+impl StorageKeyValueMapVec {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7461,32 +14236,32 @@ impl KeyValueMapVec {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<KeyValueMap> {
+    pub fn get(&self, idx: usize) -> Option<StorageKeyValueMap> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> KeyValueMap {
+    pub fn get_unchecked(&self, idx: usize) -> StorageKeyValueMap {
         let offsets = self.item_offsets();
         let start = molecule::unpack_number(&offsets[idx][..]) as usize;
         if idx == self.len() - 1 {
-            KeyValueMap::new_unchecked(self.0.slice_from(start))
+            StorageKeyValueMap::new_unchecked(self.0.slice_from(start))
         } else {
             let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            KeyValueMap::new_unchecked(self.0.slice(start, end))
+            StorageKeyValueMap::new_unchecked(self.0.slice(start, end))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> KeyValueMapVecReader<'r> {
-        KeyValueMapVecReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> StorageKeyValueMapVecReader<'r> {
+        StorageKeyValueMapVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for KeyValueMapVec {
-    type Builder = KeyValueMapVecBuilder;
-    const NAME: &'static str = "KeyValueMapVec";
+impl molecule::prelude::Entity for StorageKeyValueMapVec {
+    type Builder = StorageKeyValueMapVecBuilder;
+    const NAME: &'static str = "StorageKeyValueMapVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        KeyValueMapVec(data)
+        StorageKeyValueMapVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -7495,10 +14270,10 @@ impl molecule::prelude::Entity for KeyValueMapVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        KeyValueMapVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        StorageKeyValueMapVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        KeyValueMapVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        StorageKeyValueMapVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -7508,8 +14283,8 @@ impl molecule::prelude::Entity for KeyValueMapVec {
     }
 }
 #[derive(Clone, Copy)]
-pub struct KeyValueMapVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for KeyValueMapVecReader<'r> {
+pub struct StorageKeyValueMapVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for StorageKeyValueMapVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7518,12 +14293,12 @@ impl<'r> ::core::fmt::LowerHex for KeyValueMapVecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for KeyValueMapVecReader<'r> {
+impl<'r> ::core::fmt::Debug for StorageKeyValueMapVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for KeyValueMapVecReader<'r> {
+impl<'r> ::core::fmt::Display for StorageKeyValueMapVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} [", Self::NAME)?;
         for i in 0..self.len() {
@@ -7536,7 +14311,7 @@ impl<'r> ::core::fmt::Display for KeyValueMapVecReader<'r> {
         write!(f, "]")
     }
 }
-impl<'r> KeyValueMapVecReader<'r> {
+impl<'r> StorageKeyValueMapVecReader<'r> {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7556,32 +14331,32 @@ impl<'r> KeyValueMapVecReader<'r> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
-    pub fn get(&self, idx: usize) -> Option<KeyValueMapReader<'r>> {
+    pub fn get(&self, idx: usize) -> Option<StorageKeyValueMapReader<'r>> {
         if idx >= self.len() {
             None
         } else {
             Some(self.get_unchecked(idx))
         }
     }
-    pub fn get_unchecked(&self, idx: usize) -> KeyValueMapReader<'r> {
+    pub fn get_unchecked(&self, idx: usize) -> StorageKeyValueMapReader<'r> {
         let offsets = self.item_offsets();
         let start = molecule::unpack_number(&offsets[idx][..]) as usize;
         if idx == self.len() - 1 {
-            KeyValueMapReader::new_unchecked(&self.as_slice()[start..])
+            StorageKeyValueMapReader::new_unchecked(&self.as_slice()[start..])
         } else {
             let end = molecule::unpack_number(&offsets[idx + 1][..]) as usize;
-            KeyValueMapReader::new_unchecked(&self.as_slice()[start..end])
+            StorageKeyValueMapReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for KeyValueMapVecReader<'r> {
-    type Entity = KeyValueMapVec;
-    const NAME: &'static str = "KeyValueMapVecReader";
+impl<'r> molecule::prelude::Reader<'r> for StorageKeyValueMapVecReader<'r> {
+    type Entity = StorageKeyValueMapVec;
+    const NAME: &'static str = "StorageKeyValueMapVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        KeyValueMapVecReader(slice)
+        StorageKeyValueMapVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -7628,32 +14403,35 @@ impl<'r> molecule::prelude::Reader<'r> for KeyValueMapVecReader<'r> {
         for pair in offsets.windows(2) {
             let start = pair[0];
             let end = pair[1];
-            KeyValueMapReader::verify(&slice[start..end], compatible)?;
+            StorageKeyValueMapReader::verify(&slice[start..end], compatible)?;
         }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct KeyValueMapVecBuilder(pub(crate) Vec<KeyValueMap>);
-impl KeyValueMapVecBuilder {
-    pub fn set(mut self, v: Vec<KeyValueMap>) -> Self {
+pub struct StorageKeyValueMapVecBuilder(pub(crate) Vec<StorageKeyValueMap>);
+impl StorageKeyValueMapVecBuilder {
+    pub fn set(mut self, v: Vec<StorageKeyValueMap>) -> Self {
         self.0 = v;
         self
     }
-    pub fn push(mut self, v: KeyValueMap) -> Self {
+    pub fn push(mut self, v: StorageKeyValueMap) -> Self {
         self.0.push(v);
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = KeyValueMap>>(mut self, iter: T) -> Self {
+    pub fn extend<T: ::core::iter::IntoIterator<Item = StorageKeyValueMap>>(
+        mut self,
+        iter: T,
+    ) -> Self {
         for elem in iter {
             self.0.push(elem);
         }
         self
     }
 }
-impl molecule::prelude::Builder for KeyValueMapVecBuilder {
-    type Entity = KeyValueMapVec;
-    const NAME: &'static str = "KeyValueMapVecBuilder";
+impl molecule::prelude::Builder for StorageKeyValueMapVecBuilder {
+    type Entity = StorageKeyValueMapVec;
+    const NAME: &'static str = "StorageKeyValueMapVecBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (self.0.len() + 1)
             + self
@@ -7693,12 +14471,12 @@ impl molecule::prelude::Builder for KeyValueMapVecBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        KeyValueMapVec::new_unchecked(inner.into())
+        StorageKeyValueMapVec::new_unchecked(inner.into())
     }
 }
-pub struct KeyValueMapVecIterator(KeyValueMapVec, usize, usize);
-impl ::core::iter::Iterator for KeyValueMapVecIterator {
-    type Item = KeyValueMap;
+pub struct StorageKeyValueMapVecIterator(StorageKeyValueMapVec, usize, usize);
+impl ::core::iter::Iterator for StorageKeyValueMapVecIterator {
+    type Item = StorageKeyValueMap;
     fn next(&mut self) -> Option<Self::Item> {
         if self.1 >= self.2 {
             None
@@ -7709,27 +14487,31 @@ impl ::core::iter::Iterator for KeyValueMapVecIterator {
         }
     }
 }
-impl ::core::iter::ExactSizeIterator for KeyValueMapVecIterator {
+impl ::core::iter::ExactSizeIterator for StorageKeyValueMapVecIterator {
     fn len(&self) -> usize {
         self.2 - self.1
     }
 }
-impl ::core::iter::IntoIterator for KeyValueMapVec {
-    type Item = KeyValueMap;
-    type IntoIter = KeyValueMapVecIterator;
+impl ::core::iter::IntoIterator for StorageKeyValueMapVec {
+    type Item = StorageKeyValueMap;
+    type IntoIter = StorageKeyValueMapVecIterator;
     fn into_iter(self) -> Self::IntoIter {
         let len = self.len();
-        KeyValueMapVecIterator(self, 0, len)
+        StorageKeyValueMapVecIterator(self, 0, len)
     }
 }
-impl<'r> KeyValueMapVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> KeyValueMapVecReaderIterator<'t, 'r> {
-        KeyValueMapVecReaderIterator(&self, 0, self.len())
+impl<'r> StorageKeyValueMapVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> StorageKeyValueMapVecReaderIterator<'t, 'r> {
+        StorageKeyValueMapVecReaderIterator(&self, 0, self.len())
     }
 }
-pub struct KeyValueMapVecReaderIterator<'t, 'r>(&'t KeyValueMapVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for KeyValueMapVecReaderIterator<'t, 'r> {
-    type Item = KeyValueMapReader<'t>;
+pub struct StorageKeyValueMapVecReaderIterator<'t, 'r>(
+    &'t StorageKeyValueMapVecReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for StorageKeyValueMapVecReaderIterator<'t, 'r> {
+    type Item = StorageKeyValueMapReader<'t>;
     fn next(&mut self) -> Option<Self::Item> {
         if self.1 >= self.2 {
             None
@@ -7740,7 +14522,7 @@ impl<'t: 'r, 'r> ::core::iter::Iterator for KeyValueMapVecReaderIterator<'t, 'r>
         }
     }
 }
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for KeyValueMapVecReaderIterator<'t, 'r> {
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for StorageKeyValueMapVecReaderIterator<'t, 'r> {
     fn len(&self) -> usize {
         self.2 - self.1
     }
@@ -7801,14 +14583,14 @@ impl ::core::fmt::Display for RevertBlock {
 impl ::core::default::Default for RevertBlock {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            200, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 120, 0, 0, 0, 172, 0, 0, 0, 176,
-            0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 28,
-            0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 28, 0, 0, 0, 32,
+            218, 0, 0, 0, 32, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 129, 0, 0, 0, 190, 0, 0, 0, 194,
+            0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 28, 0, 0, 0, 36, 0, 0, 0, 36,
+            0, 0, 0, 40, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 28, 0, 0, 0,
+            36, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0,
-            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
         ];
         RevertBlock::new_unchecked(v.into())
     }
@@ -8398,15 +15180,20 @@ impl ::core::fmt::Display for ChallengeContext {
 impl ::core::default::Default for ChallengeContext {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            253, 0, 0, 0, 16, 0, 0, 0, 241, 0, 0, 0, 245, 0, 0, 0, 225, 0, 0, 0, 36, 0, 0, 0, 44,
-            0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 112, 0, 0, 0, 120, 0, 0, 0, 152, 0, 0, 0, 217, 0, 0,
+            133, 1, 0, 0, 16, 0, 0, 0, 121, 1, 0, 0, 125, 1, 0, 0, 105, 1, 0, 0, 44, 0, 0, 0, 52,
+            0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0, 120, 0, 0, 0, 152, 0, 0, 0, 160, 0, 0, 0, 192, 0, 0,
+            0, 1, 1, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         ChallengeContext::new_unchecked(v.into())
     }
@@ -9640,6 +16427,18 @@ impl ::core::fmt::Display for InvalidChallenge {
             "touched_accounts_token_kv",
             self.touched_accounts_token_kv()
         )?;
+        write!(
+            f,
+            ", {}: {}",
+            "touched_accounts_withdraw_kv",
+            self.touched_accounts_withdraw_kv()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "touched_accounts_storage",
+            self.touched_accounts_storage()
+        )?;
         write!(
             f,
             ", {}: {}",
@@ -9656,14 +16455,15 @@ impl ::core::fmt::Display for InvalidChallenge {
 impl ::core::default::Default for InvalidChallenge {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            44, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 20, 0, 0,
-            0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+            60, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 4, 0, 0,
+            0, 4, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0,
+            0, 0, 0, 0, 0,
         ];
         InvalidChallenge::new_unchecked(v.into())
     }
 }
 impl InvalidChallenge {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -9695,11 +16495,23 @@ impl InvalidChallenge {
         let end = molecule::unpack_number(&offsets[2][..]) as usize;
         KeyValueMapVec::new_unchecked(self.0.slice(start, end))
     }
-    pub fn touched_accounts_proof(&self) -> SMTProof {
+    pub fn touched_accounts_withdraw_kv(&self) -> KeyValueMapVec {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        KeyValueMapVec::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn touched_accounts_storage(&self) -> StorageKeyValueMapVec {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        StorageKeyValueMapVec::new_unchecked(self.0.slice(start, end))
+    }
+    pub fn touched_accounts_proof(&self) -> SMTProof {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            let end = molecule::unpack_number(&offsets[5][..]) as usize;
             SMTProof::new_unchecked(self.0.slice(start, end))
         } else {
             SMTProof::new_unchecked(self.0.slice_from(start))
@@ -9734,6 +16546,8 @@ impl molecule::prelude::Entity for InvalidChallenge {
         Self::new_builder()
             .touched_accounts(self.touched_accounts())
             .touched_accounts_token_kv(self.touched_accounts_token_kv())
+            .touched_accounts_withdraw_kv(self.touched_accounts_withdraw_kv())
+            .touched_accounts_storage(self.touched_accounts_storage())
             .touched_accounts_proof(self.touched_accounts_proof())
     }
 }
@@ -9763,6 +16577,18 @@ impl<'r> ::core::fmt::Display for InvalidChallengeReader<'r> {
             "touched_accounts_token_kv",
             self.touched_accounts_token_kv()
         )?;
+        write!(
+            f,
+            ", {}: {}",
+            "touched_accounts_withdraw_kv",
+            self.touched_accounts_withdraw_kv()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "touched_accounts_storage",
+            self.touched_accounts_storage()
+        )?;
         write!(
             f,
             ", {}: {}",
@@ -9777,7 +16603,7 @@ impl<'r> ::core::fmt::Display for InvalidChallengeReader<'r> {
     }
 }
 impl<'r> InvalidChallengeReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -9809,11 +16635,23 @@ impl<'r> InvalidChallengeReader<'r> {
         let end = molecule::unpack_number(&offsets[2][..]) as usize;
         KeyValueMapVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn touched_accounts_proof(&self) -> SMTProofReader<'r> {
+    pub fn touched_accounts_withdraw_kv(&self) -> KeyValueMapVecReader<'r> {
         let offsets = self.field_offsets();
         let start = molecule::unpack_number(&offsets[2][..]) as usize;
+        let end = molecule::unpack_number(&offsets[3][..]) as usize;
+        KeyValueMapVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn touched_accounts_storage(&self) -> StorageKeyValueMapVecReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[3][..]) as usize;
+        let end = molecule::unpack_number(&offsets[4][..]) as usize;
+        StorageKeyValueMapVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn touched_accounts_proof(&self) -> SMTProofReader<'r> {
+        let offsets = self.field_offsets();
+        let start = molecule::unpack_number(&offsets[4][..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&offsets[3][..]) as usize;
+            let end = molecule::unpack_number(&offsets[5][..]) as usize;
             SMTProofReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             SMTProofReader::new_unchecked(&self.as_slice()[start..])
@@ -9873,7 +16711,9 @@ impl<'r> molecule::prelude::Reader<'r> for InvalidChallengeReader<'r> {
         }
         AccountVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         KeyValueMapVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        SMTProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        KeyValueMapVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        StorageKeyValueMapVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        SMTProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
@@ -9881,10 +16721,12 @@ impl<'r> molecule::prelude::Reader<'r> for InvalidChallengeReader<'r> {
 pub struct InvalidChallengeBuilder {
     pub(crate) touched_accounts: AccountVec,
     pub(crate) touched_accounts_token_kv: KeyValueMapVec,
+    pub(crate) touched_accounts_withdraw_kv: KeyValueMapVec,
+    pub(crate) touched_accounts_storage: StorageKeyValueMapVec,
     pub(crate) touched_accounts_proof: SMTProof,
 }
 impl InvalidChallengeBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 5;
     pub fn touched_accounts(mut self, v: AccountVec) -> Self {
         self.touched_accounts = v;
         self
@@ -9893,6 +16735,14 @@ impl InvalidChallengeBuilder {
         self.touched_accounts_token_kv = v;
         self
     }
+    pub fn touched_accounts_withdraw_kv(mut self, v: KeyValueMapVec) -> Self {
+        self.touched_accounts_withdraw_kv = v;
+        self
+    }
+    pub fn touched_accounts_storage(mut self, v: StorageKeyValueMapVec) -> Self {
+        self.touched_accounts_storage = v;
+        self
+    }
     pub fn touched_accounts_proof(mut self, v: SMTProof) -> Self {
         self.touched_accounts_proof = v;
         self
@@ -9905,6 +16755,8 @@ impl molecule::prelude::Builder for InvalidChallengeBuilder {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.touched_accounts.as_slice().len()
             + self.touched_accounts_token_kv.as_slice().len()
+            + self.touched_accounts_withdraw_kv.as_slice().len()
+            + self.touched_accounts_storage.as_slice().len()
             + self.touched_accounts_proof.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
@@ -9915,6 +16767,10 @@ impl molecule::prelude::Builder for InvalidChallengeBuilder {
         offsets.push(total_size);
         total_size += self.touched_accounts_token_kv.as_slice().len();
         offsets.push(total_size);
+        total_size += self.touched_accounts_withdraw_kv.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.touched_accounts_storage.as_slice().len();
+        offsets.push(total_size);
         total_size += self.touched_accounts_proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
@@ -9922,6 +16778,8 @@ impl molecule::prelude::Builder for InvalidChallengeBuilder {
         }
         writer.write_all(self.touched_accounts.as_slice())?;
         writer.write_all(self.touched_accounts_token_kv.as_slice())?;
+        writer.write_all(self.touched_accounts_withdraw_kv.as_slice())?;
+        writer.write_all(self.touched_accounts_storage.as_slice())?;
         writer.write_all(self.touched_accounts_proof.as_slice())?;
         Ok(())
     }
@@ -0,0 +1,21 @@
+//! CKB's blake2b, the hash of txs, blocks and the SMT.
+//!
+//! `godwoken-utils` re-exports it, the signing messages here can't depend on utils.
+
+use blake2b_ref::{Blake2b, Blake2bBuilder};
+
+pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+
+pub fn new_blake2b() -> Blake2b {
+    Blake2bBuilder::new(32)
+        .personal(CKB_HASH_PERSONALIZATION)
+        .build()
+}
+
+pub fn blake2b_256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    hasher.update(data);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
@@ -6,10 +6,13 @@ extern crate alloc;
 pub mod cache;
 mod conversion;
 pub mod core;
+pub mod error;
 mod extension;
 #[doc(hidden)]
 mod generated;
+pub mod hash;
 pub mod prelude;
+pub mod signing;
 pub use generated::packed;
 
 //re-exports
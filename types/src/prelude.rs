@@ -1,3 +1,4 @@
+pub use crate::error::ConversionError;
 pub use molecule::prelude::{Builder, Entity, Reader};
 
 pub trait Unpack<T> {
@@ -8,6 +9,16 @@ pub trait Pack<T: Entity> {
     fn pack(&self) -> T;
 }
 
+/// Fallible version of `Pack`, fails instead of truncating or producing invalid data
+pub trait TryPack<T: Entity> {
+    fn try_pack(&self) -> Result<T, ConversionError>;
+}
+
+/// Fallible version of `Unpack`, fails instead of truncating
+pub trait TryUnpack<T> {
+    fn try_unpack(&self) -> Result<T, ConversionError>;
+}
+
 pub trait PackVec<T: Entity, I: Entity>: IntoIterator<Item = I> {
     fn pack(self) -> T;
 }
@@ -0,0 +1,49 @@
+//! Signing messages of txs and blocks.
+//!
+//! A signing message is bound to a rollup by `RollupID`,
+//! a signature is invalid on other Godwoken deployments.
+
+use crate::{
+    core::RollupID,
+    hash::{blake2b_256, new_blake2b},
+    packed::*,
+    prelude::*,
+};
+
+const TX_DOMAIN: &[u8] = b"godwoken-tx";
+const BLOCK_DOMAIN: &[u8] = b"godwoken-block";
+
+fn signing_message(domain: &[u8], rollup_id: &RollupID, hash: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    hasher.update(domain);
+    hasher.update(rollup_id);
+    hasher.update(hash);
+    let mut message = [0u8; 32];
+    hasher.finalize(&mut message);
+    message
+}
+
+/// hash of the full tx including the witness
+pub fn tx_hash(tx: &TxReader) -> [u8; 32] {
+    blake2b_256(tx.as_slice())
+}
+
+pub fn raw_tx_hash(raw_tx: &RawTxReader) -> [u8; 32] {
+    blake2b_256(raw_tx.as_slice())
+}
+
+/// message signed by the tx sender
+pub fn tx_signing_message(rollup_id: &RollupID, raw_tx_hash: &[u8; 32]) -> [u8; 32] {
+    signing_message(TX_DOMAIN, rollup_id, raw_tx_hash)
+}
+
+/// message of `ag_sig`, the block is hashed with a zero `ag_sig`
+pub fn block_signing_message(rollup_id: &RollupID, block: &AgBlockReader) -> [u8; 32] {
+    let sig_block = block
+        .to_entity()
+        .as_builder()
+        .ag_sig(Byte65::default())
+        .build();
+    let block_hash = blake2b_256(sig_block.as_slice());
+    signing_message(BLOCK_DOMAIN, rollup_id, &block_hash)
+}
@@ -0,0 +1,104 @@
+//! BLS signatures on BLS12-381, pubkeys are in G1 and signatures are in G2.
+//!
+//! Messages are augmented with the signer's pubkey, so signatures of the same message
+//! can be aggregated without proof of possession.
+
+use alloc::vec::Vec;
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    multi_miller_loop, G1Affine, G2Affine, G2Prepared, G2Projective, Gt, Scalar,
+};
+
+pub const PUBKEY_LEN: usize = 48;
+pub const SIGNATURE_LEN: usize = 96;
+pub const SECRET_KEY_LEN: usize = 32;
+
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_AUG_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    InvalidSecretKey,
+    InvalidPubkey,
+    InvalidSignature,
+    VerifyFailed,
+}
+
+fn parse_secret_key(secret_key: &[u8; SECRET_KEY_LEN]) -> Result<Scalar, Error> {
+    Option::from(Scalar::from_bytes(secret_key))
+        .filter(|sk: &Scalar| sk != &Scalar::zero())
+        .ok_or(Error::InvalidSecretKey)
+}
+
+fn parse_pubkey(pubkey: &[u8; PUBKEY_LEN]) -> Result<G1Affine, Error> {
+    Option::from(G1Affine::from_compressed(pubkey))
+        .filter(|pk: &G1Affine| !bool::from(pk.is_identity()))
+        .ok_or(Error::InvalidPubkey)
+}
+
+fn parse_signature(signature: &[u8; SIGNATURE_LEN]) -> Result<G2Affine, Error> {
+    Option::from(G2Affine::from_compressed(signature)).ok_or(Error::InvalidSignature)
+}
+
+fn hash_to_g2(pubkey: &[u8; PUBKEY_LEN], message: &[u8]) -> G2Affine {
+    let mut augmented = Vec::with_capacity(PUBKEY_LEN + message.len());
+    augmented.extend_from_slice(pubkey);
+    augmented.extend_from_slice(message);
+    let point =
+        <G2Projective as HashToCurve<ExpandMsgXmd<sha2::Sha256>>>::hash_to_curve(&augmented, DST);
+    G2Affine::from(point)
+}
+
+/// pubkey of a little-endian secret key
+pub fn pubkey(secret_key: &[u8; SECRET_KEY_LEN]) -> Result<[u8; PUBKEY_LEN], Error> {
+    let sk = parse_secret_key(secret_key)?;
+    Ok(G1Affine::from(G1Affine::generator() * sk).to_compressed())
+}
+
+pub fn sign(
+    secret_key: &[u8; SECRET_KEY_LEN],
+    message: &[u8],
+) -> Result<[u8; SIGNATURE_LEN], Error> {
+    let sk = parse_secret_key(secret_key)?;
+    let pubkey = G1Affine::from(G1Affine::generator() * sk).to_compressed();
+    let point = hash_to_g2(&pubkey, message);
+    Ok(G2Affine::from(point * sk).to_compressed())
+}
+
+/// aggregate signatures into one signature
+pub fn aggregate_signatures(
+    signatures: &[[u8; SIGNATURE_LEN]],
+) -> Result<[u8; SIGNATURE_LEN], Error> {
+    let mut aggregated = G2Projective::identity();
+    for signature in signatures {
+        aggregated += parse_signature(signature)?;
+    }
+    Ok(G2Affine::from(aggregated).to_compressed())
+}
+
+pub fn verify(
+    pubkey: &[u8; PUBKEY_LEN],
+    message: &[u8],
+    signature: &[u8; SIGNATURE_LEN],
+) -> Result<(), Error> {
+    aggregate_verify(&[(*pubkey, message)], signature)
+}
+
+/// verify an aggregate signature of `(pubkey, message)` pairs
+pub fn aggregate_verify(
+    items: &[([u8; PUBKEY_LEN], &[u8])],
+    signature: &[u8; SIGNATURE_LEN],
+) -> Result<(), Error> {
+    let signature = parse_signature(signature)?;
+    let mut terms: Vec<(G1Affine, G2Prepared)> = Vec::with_capacity(items.len() + 1);
+    // e(-g1, signature) * prod(e(pubkey, H(pubkey | message))) == 1
+    terms.push((-G1Affine::generator(), G2Prepared::from(signature)));
+    for (pubkey, message) in items {
+        let pk = parse_pubkey(pubkey)?;
+        terms.push((pk, G2Prepared::from(hash_to_g2(pubkey, message))));
+    }
+    let terms: Vec<(&G1Affine, &G2Prepared)> = terms.iter().map(|(g1, g2)| (g1, g2)).collect();
+    if multi_miller_loop(&terms).final_exponentiation() != Gt::identity() {
+        return Err(Error::VerifyFailed);
+    }
+    Ok(())
+}
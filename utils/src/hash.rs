@@ -1,9 +1,8 @@
-use blake2b_ref::{Blake2b, Blake2bBuilder};
+use godwoken_types::{packed::AgBlock, prelude::*};
 
-pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+pub use godwoken_types::hash::{blake2b_256, new_blake2b, CKB_HASH_PERSONALIZATION};
 
-pub fn new_blake2b() -> Blake2b {
-    Blake2bBuilder::new(32)
-        .personal(CKB_HASH_PERSONALIZATION)
-        .build()
+/// hash of a block, the leaf of the blocks MMR
+pub fn block_hash(block: &AgBlock) -> [u8; 32] {
+    blake2b_256(block.as_slice())
 }
@@ -3,7 +3,9 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+pub mod bls;
 pub mod hash;
 pub mod mmr;
 pub mod secp256k1;
+pub mod signature;
 pub mod smt;
@@ -98,3 +98,16 @@ pub fn merkle_root(leaves: Vec<[u8; 32]>) -> [u8; 32] {
     }
     mmr.get_root().expect("root")
 }
+
+/// Generate merkle proof of leaves at `indices`, e.g. txs proof of a block
+pub fn merkle_proof(leaves: Vec<[u8; 32]>, indices: Vec<u64>) -> Result<Vec<[u8; 32]>, Error> {
+    if leaves.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut mmr: MemMMR<[u8; 32], HashMerge> = MemMMR::default();
+    for leaf in leaves {
+        mmr.push(leaf)?;
+    }
+    let proof = mmr.gen_proof(indices.into_iter().map(leaf_index_to_pos).collect())?;
+    Ok(proof.proof_items().to_vec())
+}
@@ -1,42 +1,68 @@
+//! secp256k1 recoverable signatures, the signer is identified by
+//! `blake2b(compressed pubkey)[..20]`.
+
 use crate::hash::new_blake2b;
-use secp256k1::{recover, Message, RecoveryId, Signature};
+use secp256k1::{Message, PublicKey, RecoveryId, Signature};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error {
     InvalidSignature,
     InvalidRecoveryId,
     InvalidMessage,
     InvalidPubkeyHash,
+    /// malleable signature, s is in the upper half of the curve order
+    HighS,
     RecoveryPubkey,
     IncorrectPubkeyHash,
 }
 
-const SIGNATURE_LEN: usize = 65;
-const MESSAGE_LEN: usize = 32;
-const PUBKEY_HASH_LEN: usize = 20;
+pub const SIGNATURE_LEN: usize = 65;
+pub const MESSAGE_LEN: usize = 32;
+pub const PUBKEY_HASH_LEN: usize = 20;
 
-pub fn verify_signature(signature: &[u8], message: &[u8], pubkey_hash: &[u8]) -> Result<(), Error> {
+/// Recover the signer's pubkey from a 65 bytes `r | s | recovery_id` signature.
+/// Overflowing or high-S signatures are rejected.
+pub fn recover(signature: &[u8], message: &[u8]) -> Result<PublicKey, Error> {
     if signature.len() != SIGNATURE_LEN {
         return Err(Error::InvalidSignature);
     }
     if message.len() != MESSAGE_LEN {
         return Err(Error::InvalidMessage);
     }
-    if pubkey_hash.len() != PUBKEY_HASH_LEN {
+    let msg = Message::parse_slice(message).map_err(|_| Error::InvalidMessage)?;
+    let sig = Signature::parse_slice(&signature[..64]).map_err(|_| Error::InvalidSignature)?;
+    // `parse_slice` reduces overflowing r and s
+    if sig.serialize()[..] != signature[..64] || sig.r.is_zero() || sig.s.is_zero() {
+        return Err(Error::InvalidSignature);
+    }
+    if sig.s.is_high() {
+        return Err(Error::HighS);
+    }
+    let recovery_id = RecoveryId::parse(signature[64]).map_err(|_| Error::InvalidRecoveryId)?;
+    secp256k1::recover(&msg, &sig, &recovery_id).map_err(|_| Error::RecoveryPubkey)
+}
+
+pub fn pubkey_hash(pubkey: &PublicKey) -> [u8; PUBKEY_HASH_LEN] {
+    let mut hash = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(&pubkey.serialize_compressed());
+    hasher.finalize(&mut hash);
+    let mut pubkey_hash = [0u8; PUBKEY_HASH_LEN];
+    pubkey_hash.copy_from_slice(&hash[..PUBKEY_HASH_LEN]);
+    pubkey_hash
+}
+
+/// Verify that `signature` of `message` is signed by the owner of `expected_pubkey_hash`
+pub fn verify_signature(
+    signature: &[u8],
+    message: &[u8],
+    expected_pubkey_hash: &[u8],
+) -> Result<(), Error> {
+    if expected_pubkey_hash.len() != PUBKEY_HASH_LEN {
         return Err(Error::InvalidPubkeyHash);
     }
-    let msg = Message::parse_slice(&message).map_err(|_| Error::InvalidMessage)?;
-    let sig = Signature::parse_slice(&signature[..64]).map_err(|_| Error::InvalidSignature)?;
-    let recover_id = RecoveryId::parse(signature[64]).map_err(|_| Error::InvalidRecoveryId)?;
-    let pubkey = recover(&msg, &sig, &recover_id).map_err(|_| Error::RecoveryPubkey)?;
-    let pubkey_hash = {
-        let mut hash = [0u8; 20];
-        let mut hasher = new_blake2b();
-        let pubkey_bytes = pubkey.serialize_compressed();
-        hasher.update(&pubkey_bytes);
-        hasher.finalize(&mut hash);
-        hash
-    };
-    if pubkey_hash[..] != pubkey_hash {
+    let pubkey = recover(signature, message)?;
+    if pubkey_hash(&pubkey)[..] != expected_pubkey_hash[..] {
         return Err(Error::IncorrectPubkeyHash);
     }
     Ok(())
@@ -0,0 +1,224 @@
+//! Signature schemes of accounts.
+//!
+//! An account declares its scheme by `Account.sig_scheme`, `Account.pubkey_hash` is
+//! the 20 bytes identifier of the signer, its meaning depends on the scheme.
+
+use crate::hash::new_blake2b;
+use core::convert::TryFrom;
+use tiny_keccak::{Hasher, Keccak};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    UnknownScheme(u8),
+    InvalidSignature,
+    InvalidPubkeyHash,
+    IncorrectPubkeyHash,
+    /// a secp256k1 signature is rejected, e.g. it's high-S or overflowing
+    Secp256k1(crate::secp256k1::Error),
+}
+
+impl From<crate::secp256k1::Error> for Error {
+    fn from(err: crate::secp256k1::Error) -> Self {
+        use crate::secp256k1::Error as Secp256k1Error;
+        match err {
+            Secp256k1Error::InvalidPubkeyHash => Error::InvalidPubkeyHash,
+            Secp256k1Error::IncorrectPubkeyHash => Error::IncorrectPubkeyHash,
+            err => Error::Secp256k1(err),
+        }
+    }
+}
+
+const PUBKEY_HASH_LEN: usize = 20;
+const SECP256K1_SIGNATURE_LEN: usize = 65;
+const SECP256K1_PUBKEY_LEN: usize = 33;
+const ED25519_PUBKEY_LEN: usize = 32;
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SignatureScheme {
+    /// pubkey_hash = blake2b(compressed pubkey)[..20], signature = rsv
+    Secp256k1Blake2b = 0,
+    /// Ethereum address, the message is signed by `personal_sign`, signature = rsv
+    Secp256k1Keccak = 1,
+    /// pubkey_hash = blake2b(pubkey)[..20], signature = pubkey | signature
+    Ed25519 = 2,
+    /// secp256k1 multisig, pubkey_hash = blake2b(threshold | pubkeys_count | pubkeys)[..20],
+    /// signature = threshold | pubkeys_count | pubkeys | signatures
+    Multisig = 3,
+}
+
+impl TryFrom<u8> for SignatureScheme {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Error> {
+        match value {
+            0 => Ok(SignatureScheme::Secp256k1Blake2b),
+            1 => Ok(SignatureScheme::Secp256k1Keccak),
+            2 => Ok(SignatureScheme::Ed25519),
+            3 => Ok(SignatureScheme::Multisig),
+            _ => Err(Error::UnknownScheme(value)),
+        }
+    }
+}
+
+pub trait SignatureVerifier {
+    /// verify `signature` of `message` is signed by the owner of `pubkey_hash`
+    fn verify(&self, signature: &[u8], message: &[u8; 32], pubkey_hash: &[u8])
+        -> Result<(), Error>;
+}
+
+pub struct Secp256k1Blake2b;
+pub struct Secp256k1Keccak;
+pub struct Ed25519;
+pub struct Multisig;
+
+/// verifier of a scheme
+pub fn verifier(scheme: SignatureScheme) -> &'static dyn SignatureVerifier {
+    match scheme {
+        SignatureScheme::Secp256k1Blake2b => &Secp256k1Blake2b,
+        SignatureScheme::Secp256k1Keccak => &Secp256k1Keccak,
+        SignatureScheme::Ed25519 => &Ed25519,
+        SignatureScheme::Multisig => &Multisig,
+    }
+}
+
+/// verify the signature with the account's signature scheme
+pub fn verify_signature(
+    sig_scheme: u8,
+    signature: &[u8],
+    message: &[u8; 32],
+    pubkey_hash: &[u8],
+) -> Result<(), Error> {
+    let scheme = SignatureScheme::try_from(sig_scheme)?;
+    verifier(scheme).verify(signature, message, pubkey_hash)
+}
+
+fn blake160(data: &[u8]) -> [u8; 20] {
+    let mut hash = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(data);
+    hasher.finalize(&mut hash);
+    let mut pubkey_hash = [0u8; 20];
+    pubkey_hash.copy_from_slice(&hash[..20]);
+    pubkey_hash
+}
+
+fn keccak256(data: &[&[u8]]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    for item in data {
+        hasher.update(item);
+    }
+    hasher.finalize(&mut hash);
+    hash
+}
+
+fn check_pubkey_hash(pubkey_hash: &[u8], expected: &[u8]) -> Result<(), Error> {
+    if pubkey_hash.len() != PUBKEY_HASH_LEN {
+        return Err(Error::InvalidPubkeyHash);
+    }
+    if pubkey_hash != expected {
+        return Err(Error::IncorrectPubkeyHash);
+    }
+    Ok(())
+}
+
+fn recover_pubkey(signature: &[u8], message: &[u8; 32]) -> Result<secp256k1::PublicKey, Error> {
+    if signature.len() != SECP256K1_SIGNATURE_LEN {
+        return Err(Error::InvalidSignature);
+    }
+    let mut sig = [0u8; SECP256K1_SIGNATURE_LEN];
+    sig.copy_from_slice(signature);
+    // Ethereum wallets use 27 / 28 as recovery id
+    if sig[64] == 27 || sig[64] == 28 {
+        sig[64] -= 27;
+    }
+    crate::secp256k1::recover(&sig, message).map_err(Error::from)
+}
+
+impl SignatureVerifier for Secp256k1Blake2b {
+    fn verify(
+        &self,
+        signature: &[u8],
+        message: &[u8; 32],
+        pubkey_hash: &[u8],
+    ) -> Result<(), Error> {
+        crate::secp256k1::verify_signature(signature, message, pubkey_hash).map_err(Error::from)
+    }
+}
+
+impl SignatureVerifier for Secp256k1Keccak {
+    fn verify(
+        &self,
+        signature: &[u8],
+        message: &[u8; 32],
+        pubkey_hash: &[u8],
+    ) -> Result<(), Error> {
+        // personal_sign message
+        let message = keccak256(&[b"\x19Ethereum Signed Message:\n32", message]);
+        let pubkey = recover_pubkey(signature, &message)?;
+        let address = keccak256(&[&pubkey.serialize()[1..]]);
+        check_pubkey_hash(pubkey_hash, &address[12..])
+    }
+}
+
+impl SignatureVerifier for Ed25519 {
+    fn verify(
+        &self,
+        signature: &[u8],
+        message: &[u8; 32],
+        pubkey_hash: &[u8],
+    ) -> Result<(), Error> {
+        if signature.len() != ED25519_PUBKEY_LEN + ED25519_SIGNATURE_LEN {
+            return Err(Error::InvalidSignature);
+        }
+        let (pubkey, signature) = signature.split_at(ED25519_PUBKEY_LEN);
+        check_pubkey_hash(pubkey_hash, &blake160(pubkey))?;
+        let pubkey =
+            ed25519_dalek::PublicKey::from_bytes(pubkey).map_err(|_| Error::InvalidSignature)?;
+        let signature =
+            ed25519_dalek::Signature::try_from(signature).map_err(|_| Error::InvalidSignature)?;
+        pubkey
+            .verify_strict(message, &signature)
+            .map_err(|_| Error::InvalidSignature)
+    }
+}
+
+impl SignatureVerifier for Multisig {
+    fn verify(
+        &self,
+        signature: &[u8],
+        message: &[u8; 32],
+        pubkey_hash: &[u8],
+    ) -> Result<(), Error> {
+        if signature.len() < 2 {
+            return Err(Error::InvalidSignature);
+        }
+        let threshold = signature[0] as usize;
+        let pubkeys_count = signature[1] as usize;
+        if threshold == 0 || threshold > pubkeys_count {
+            return Err(Error::InvalidSignature);
+        }
+        let config_len = 2 + pubkeys_count * SECP256K1_PUBKEY_LEN;
+        if signature.len() != config_len + threshold * SECP256K1_SIGNATURE_LEN {
+            return Err(Error::InvalidSignature);
+        }
+        let (config, signatures) = signature.split_at(config_len);
+        check_pubkey_hash(pubkey_hash, &blake160(config))?;
+        // each signature must be signed by a different pubkey
+        let mut signed = [false; 256];
+        for sig in signatures.chunks(SECP256K1_SIGNATURE_LEN) {
+            let pubkey = recover_pubkey(sig, message)?.serialize_compressed();
+            let i = config[2..]
+                .chunks(SECP256K1_PUBKEY_LEN)
+                .position(|item| item == &pubkey[..])
+                .ok_or(Error::InvalidSignature)?;
+            if signed[i] {
+                return Err(Error::InvalidSignature);
+            }
+            signed[i] = true;
+        }
+        Ok(())
+    }
+}
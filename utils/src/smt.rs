@@ -39,6 +39,40 @@ pub fn token_id_key(index: u64, token_id: &TokenID) -> H256 {
     key.into()
 }
 
+/// key of withdrawing balance
+pub fn withdraw_key(index: u64, token_id: &TokenID) -> H256 {
+    let mut key = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(&[SMTPrefix::Withdraw as u8]);
+    hasher.update(&index.to_le_bytes());
+    hasher.update(token_id);
+    hasher.finalize(&mut key);
+    key.into()
+}
+
+pub fn storage_key(index: u64, key: &[u8; 32]) -> H256 {
+    let mut storage_key = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(&[SMTPrefix::Store as u8]);
+    hasher.update(&index.to_le_bytes());
+    hasher.update(key);
+    hasher.finalize(&mut storage_key);
+    storage_key.into()
+}
+
+/// SMT value of a storage slot
+/// we always use the hash of storage value to avoid ambiguous short values
+pub fn storage_value(value: &[u8]) -> Value {
+    if value.is_empty() {
+        return Value::zero();
+    }
+    let mut buf = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(value);
+    hasher.finalize(&mut buf);
+    Value(buf.to_vec())
+}
+
 // shortcut
 
 pub fn compute_root(leaves: Vec<(H256, Value)>) -> Result<H256, Error> {
@@ -80,8 +114,8 @@ impl Hasher for Blake2bHasher {
 #[derive(Default, Clone, Debug)]
 pub struct Value(Vec<u8>);
 
-impl From<u64> for Value {
-    fn from(v: u64) -> Self {
+impl From<u128> for Value {
+    fn from(v: u128) -> Self {
         if v == 0 {
             return Self::zero();
         }
@@ -89,14 +123,14 @@ impl From<u64> for Value {
     }
 }
 
-impl Into<u64> for Value {
-    fn into(self) -> u64 {
+impl Into<u128> for Value {
+    fn into(self) -> u128 {
         if self.0.is_empty() {
             return 0;
         }
-        let mut buf = [0u8; 8];
+        let mut buf = [0u8; 16];
         buf.copy_from_slice(&self.0);
-        u64::from_le_bytes(buf)
+        u128::from_le_bytes(buf)
     }
 }
 
@@ -112,10 +146,20 @@ impl Into<Account> for Value {
     }
 }
 
+impl From<Vec<u8>> for Value {
+    fn from(v: Vec<u8>) -> Self {
+        Value(v)
+    }
+}
+
 impl Value {
     pub fn is_zero(&self) -> bool {
         self.0.is_empty()
     }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
 }
 
 impl ValueTrait for Value {